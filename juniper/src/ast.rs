@@ -5,6 +5,7 @@ use indexmap::IndexMap;
 use crate::{
     executor::Variables,
     parser::Spanning,
+    schema::model::DirectiveLocation,
     value::{DefaultScalarValue, ScalarValue},
 };
 
@@ -151,6 +152,155 @@ pub type Document<'a, S> = [Definition<'a, S>];
 #[doc(hidden)]
 pub type OwnedDocument<'a, S> = Vec<Definition<'a, S>>;
 
+/// A definition in a GraphQL type system document (SDL).
+///
+/// Extensions reuse the structures of the corresponding definitions, having
+/// no `description` and only the parts they extend with.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub enum TypeSystemDefinition<'a, S = DefaultScalarValue> {
+    Schema(Spanning<SchemaDefinition<'a, S>>),
+    Type(Spanning<TypeDefinition<'a, S>>),
+    Directive(Spanning<DirectiveDefinition<'a, S>>),
+    SchemaExtension(Spanning<SchemaDefinition<'a, S>>),
+    TypeExtension(Spanning<TypeDefinition<'a, S>>),
+}
+
+/// A parsed GraphQL type system document (SDL).
+pub type SchemaDocument<'a, S = DefaultScalarValue> = Vec<TypeSystemDefinition<'a, S>>;
+
+/// A `schema` definition, e.g. `schema { query: Query }`.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct SchemaDefinition<'a, S> {
+    pub description: Option<Spanning<String>>,
+    pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+    /// Root operation types along with the names of the types they refer to.
+    pub operation_types: Vec<(Spanning<OperationType>, Spanning<&'a str>)>,
+}
+
+/// A named type definition.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub enum TypeDefinition<'a, S> {
+    Scalar(ScalarTypeDefinition<'a, S>),
+    Object(ObjectTypeDefinition<'a, S>),
+    Interface(InterfaceTypeDefinition<'a, S>),
+    Union(UnionTypeDefinition<'a, S>),
+    Enum(EnumTypeDefinition<'a, S>),
+    InputObject(InputObjectTypeDefinition<'a, S>),
+}
+
+impl<'a, S> TypeDefinition<'a, S> {
+    /// Returns the name of the defined type.
+    pub fn name(&self) -> &Spanning<&'a str> {
+        match self {
+            Self::Scalar(t) => &t.name,
+            Self::Object(t) | Self::Interface(t) => &t.name,
+            Self::Union(t) => &t.name,
+            Self::Enum(t) => &t.name,
+            Self::InputObject(t) => &t.name,
+        }
+    }
+}
+
+/// A `scalar` type definition, e.g. `scalar DateTime`.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct ScalarTypeDefinition<'a, S> {
+    pub description: Option<Spanning<String>>,
+    pub name: Spanning<&'a str>,
+    pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+}
+
+/// A `type` definition, e.g. `type User implements Node { id: ID! }`.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct ObjectTypeDefinition<'a, S> {
+    pub description: Option<Spanning<String>>,
+    pub name: Spanning<&'a str>,
+    pub interfaces: Vec<Spanning<&'a str>>,
+    pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+    pub fields: Vec<Spanning<FieldDefinition<'a, S>>>,
+}
+
+/// An `interface` type definition, e.g. `interface Node { id: ID! }`.
+///
+/// Interfaces are defined in the same way as objects are.
+pub type InterfaceTypeDefinition<'a, S> = ObjectTypeDefinition<'a, S>;
+
+/// A `union` type definition, e.g. `union SearchResult = User | Post`.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct UnionTypeDefinition<'a, S> {
+    pub description: Option<Spanning<String>>,
+    pub name: Spanning<&'a str>,
+    pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+    pub members: Vec<Spanning<&'a str>>,
+}
+
+/// An `enum` type definition, e.g. `enum Color { RED GREEN }`.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct EnumTypeDefinition<'a, S> {
+    pub description: Option<Spanning<String>>,
+    pub name: Spanning<&'a str>,
+    pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+    pub values: Vec<Spanning<EnumValueDefinition<'a, S>>>,
+}
+
+/// An `input` type definition, e.g. `input Filter { first: Int = 10 }`.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct InputObjectTypeDefinition<'a, S> {
+    pub description: Option<Spanning<String>>,
+    pub name: Spanning<&'a str>,
+    pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+    pub fields: Vec<Spanning<InputValueDefinition<'a, S>>>,
+}
+
+/// A field definition of an object or an interface type.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct FieldDefinition<'a, S> {
+    pub description: Option<Spanning<String>>,
+    pub name: Spanning<&'a str>,
+    pub arguments: Vec<Spanning<InputValueDefinition<'a, S>>>,
+    pub field_type: Spanning<Type<'a>>,
+    pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+}
+
+/// A definition of an argument or of an input object field.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct InputValueDefinition<'a, S> {
+    pub description: Option<Spanning<String>>,
+    pub name: Spanning<&'a str>,
+    pub value_type: Spanning<Type<'a>>,
+    pub default_value: Option<Spanning<InputValue<S>>>,
+    pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+}
+
+/// A value definition of an enum type.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct EnumValueDefinition<'a, S> {
+    pub description: Option<Spanning<String>>,
+    pub name: Spanning<&'a str>,
+    pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+}
+
+/// A `directive` definition, e.g. `directive @auth(role: String) on FIELD`.
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct DirectiveDefinition<'a, S> {
+    pub description: Option<Spanning<String>>,
+    pub name: Spanning<&'a str>,
+    pub arguments: Vec<Spanning<InputValueDefinition<'a, S>>>,
+    pub is_repeatable: bool,
+    pub locations: Vec<Spanning<DirectiveLocation>>,
+}
+
 /// Parsing of an unstructured input value into a Rust data type.
 ///
 /// The conversion _can_ fail, and must in that case return [`Err`]. Thus not
@@ -433,10 +583,13 @@ impl<S> InputValue<S> {
             (Self::Null, Self::Null) => true,
             (Self::Scalar(s1), Self::Scalar(s2)) => s1 == s2,
             (Self::Enum(s1), Self::Enum(s2)) | (Self::Variable(s1), Self::Variable(s2)) => s1 == s2,
-            (Self::List(l1), Self::List(l2)) => l1
-                .iter()
-                .zip(l2.iter())
-                .all(|(v1, v2)| v1.item.unlocated_eq(&v2.item)),
+            (Self::List(l1), Self::List(l2)) => {
+                l1.len() == l2.len()
+                    && l1
+                        .iter()
+                        .zip(l2.iter())
+                        .all(|(v1, v2)| v1.item.unlocated_eq(&v2.item))
+            }
             (Self::Object(o1), Self::Object(o2)) => {
                 o1.len() == o2.len()
                     && o1.iter().all(|(sk1, sv1)| {
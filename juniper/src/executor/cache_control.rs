@@ -0,0 +1,449 @@
+//! Caching policies of operations, computed from the `@cacheControl` hints
+//! applied to the fields and types of a schema.
+
+use std::{collections::HashSet, convert::TryFrom as _};
+
+use crate::{
+    ast::{Definition, Document, InputValue, Operation, OperationType, Selection},
+    schema::{
+        meta::{AppliedDirective, MetaType},
+        model::SchemaType,
+    },
+    value::ScalarValue,
+    GraphQLEnum,
+};
+
+/// Name of the directive providing caching hints.
+pub(crate) const DIRECTIVE_NAME: &str = "cacheControl";
+
+/// Scope of a [`CachePolicy`], defining who may reuse the cached response.
+#[derive(Clone, Copy, Debug, Default, Eq, GraphQLEnum, Hash, PartialEq)]
+#[graphql(name = "CacheControlScope")]
+pub enum CacheScope {
+    /// Response may be cached by shared caches and reused for any client.
+    #[default]
+    Public,
+
+    /// Response may be cached for the client it was sent to only.
+    Private,
+}
+
+/// Caching policy of an operation's response, as specified by the
+/// `@cacheControl` hints of the fields it selects.
+///
+/// Hints are applied to fields and types, either with the
+/// `#[graphql(cache_control(max_age = 60, scope = "private"))]` attribute
+/// argument, or with the `@cacheControl(maxAge: 60, scope: PRIVATE)`
+/// directive:
+/// - the `maxAge` of the policy is the minimum one of all the selected fields;
+///   a field without a hint uses the hint of its type, if any, or inherits the
+///   `maxAge` of its parent if it returns a scalar or an enum and isn't a root
+///   field, or has the `maxAge` of `0` otherwise;
+/// - the `scope` of the policy is [`CacheScope::Private`] if any of the
+///   selected fields or their types is private.
+///
+/// Only the responses of queries may be cached.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CachePolicy {
+    /// Number of seconds the response may be cached for.
+    pub max_age: u32,
+
+    /// Who may reuse the cached response.
+    pub scope: CacheScope,
+}
+
+impl CachePolicy {
+    /// [`CachePolicy`] forbidding caching the response at all.
+    pub const NO_STORE: Self = Self {
+        max_age: 0,
+        scope: CacheScope::Public,
+    };
+
+    /// Creates a new [`CachePolicy`] with the provided `max_age` (in seconds)
+    /// and `scope`.
+    pub fn new(max_age: u32, scope: CacheScope) -> Self {
+        Self { max_age, scope }
+    }
+
+    /// Indicates whether this [`CachePolicy`] allows caching the response.
+    pub fn is_cacheable(&self) -> bool {
+        self.max_age > 0
+    }
+
+    /// Combines this [`CachePolicy`] with the `other` one, so the result
+    /// satisfies both of them.
+    #[must_use]
+    pub fn restrict(self, other: Self) -> Self {
+        Self {
+            max_age: self.max_age.min(other.max_age),
+            scope: if self.scope == CacheScope::Private || other.scope == CacheScope::Private {
+                CacheScope::Private
+            } else {
+                CacheScope::Public
+            },
+        }
+    }
+
+    /// Returns the value of the HTTP `Cache-Control` header representing this
+    /// [`CachePolicy`].
+    pub fn header_value(&self) -> String {
+        if self.is_cacheable() {
+            format!(
+                "max-age={}, {}",
+                self.max_age,
+                match self.scope {
+                    CacheScope::Public => "public",
+                    CacheScope::Private => "private",
+                },
+            )
+        } else {
+            "no-store".into()
+        }
+    }
+}
+
+/// Caching hint of a field or a type.
+#[derive(Clone, Copy, Debug, Default)]
+struct CacheHint {
+    max_age: Option<u32>,
+    scope: Option<CacheScope>,
+}
+
+impl CacheHint {
+    /// Parses the hint from the `@cacheControl` directive among the provided
+    /// `directives`, if any.
+    fn from_directives<S: ScalarValue>(directives: &[AppliedDirective<S>]) -> Self {
+        let mut hint = Self::default();
+        for (name, value) in directives
+            .iter()
+            .filter(|d| d.name == DIRECTIVE_NAME)
+            .flat_map(|d| &d.arguments)
+        {
+            match (name.as_str(), value) {
+                ("maxAge", InputValue::Scalar(s)) => {
+                    hint.max_age = s.as_int().and_then(|n| u32::try_from(n).ok());
+                }
+                ("scope", InputValue::Enum(scope)) => {
+                    hint.scope = match scope.as_str() {
+                        "PUBLIC" => Some(CacheScope::Public),
+                        "PRIVATE" => Some(CacheScope::Private),
+                        _ => None,
+                    };
+                }
+                _ => {}
+            }
+        }
+        hint
+    }
+}
+
+/// Indicates whether any field or type among the provided `types` has a
+/// `@cacheControl` hint.
+pub(crate) fn is_used<'a, 'm: 'a, S: 'a>(
+    types: impl IntoIterator<Item = &'a MetaType<'m, S>>,
+) -> bool {
+    let is_hint = |d: &AppliedDirective<S>| d.name == DIRECTIVE_NAME;
+    types.into_iter().any(|t| {
+        t.directives().iter().any(is_hint)
+            || match t {
+                MetaType::Object(o) => o.fields.iter().any(|f| f.directives.iter().any(is_hint)),
+                MetaType::Interface(i) => i.fields.iter().any(|f| f.directives.iter().any(is_hint)),
+                _ => false,
+            }
+    })
+}
+
+/// Computes the [`CachePolicy`] of the provided validated `operation` of the
+/// `document`, from the hints of the fields it selects in the `schema`.
+///
+/// Fields in fragments are taken into account regardless of whether their type
+/// conditions match at runtime, so the policy is never less restrictive than
+/// the one of the actually resolved fields.
+pub fn cache_policy<S: ScalarValue>(
+    schema: &SchemaType<S>,
+    document: &Document<S>,
+    operation: &Operation<S>,
+) -> CachePolicy {
+    if operation.operation_type != OperationType::Query {
+        return CachePolicy::NO_STORE;
+    }
+
+    let mut collector = Collector {
+        schema,
+        document,
+        visited_fragments: HashSet::new(),
+        max_age: None,
+        scope: CacheScope::Public,
+    };
+    collector.selection_set(&operation.selection_set, schema.concrete_query_type());
+
+    CachePolicy::new(collector.max_age.unwrap_or(0), collector.scope)
+}
+
+/// Collector of the [`CachePolicy`] of the selected fields.
+struct Collector<'a, 'd, S> {
+    schema: &'a SchemaType<'a, S>,
+    document: &'a Document<'d, S>,
+    visited_fragments: HashSet<&'d str>,
+    max_age: Option<u32>,
+    scope: CacheScope,
+}
+
+impl<'a, 'd, S: ScalarValue> Collector<'a, 'd, S> {
+    fn selection_set(&mut self, selection_set: &'a [Selection<'d, S>], parent: &'a MetaType<S>) {
+        for selection in selection_set {
+            match selection {
+                Selection::Field(f) => {
+                    let field = match parent.field_by_name(f.item.name.item) {
+                        Some(field) => field,
+                        // `__typename` doesn't affect caching.
+                        None => continue,
+                    };
+                    let ty = self
+                        .schema
+                        .concrete_type_by_name(field.field_type.innermost_name());
+
+                    let field_hint = CacheHint::from_directives(&field.directives);
+                    let type_hint = ty
+                        .map(|t| CacheHint::from_directives(t.directives()))
+                        .unwrap_or_default();
+                    let inherits = ty.map(MetaType::is_leaf).unwrap_or(true)
+                        && !std::ptr::eq(parent, self.schema.concrete_query_type());
+
+                    let max_age = field_hint.max_age.or(type_hint.max_age).or(if inherits {
+                        None
+                    } else {
+                        Some(0)
+                    });
+                    if let Some(max_age) = max_age {
+                        self.max_age = Some(self.max_age.map_or(max_age, |m| m.min(max_age)));
+                    }
+                    if field_hint.scope == Some(CacheScope::Private)
+                        || type_hint.scope == Some(CacheScope::Private)
+                    {
+                        self.scope = CacheScope::Private;
+                    }
+
+                    if let (Some(selection_set), Some(ty)) = (&f.item.selection_set, ty) {
+                        self.selection_set(selection_set, ty);
+                    }
+                }
+                Selection::FragmentSpread(s) => {
+                    let name = s.item.name.item;
+                    if !self.visited_fragments.insert(name) {
+                        continue;
+                    }
+                    let fragment = self.document.iter().find_map(|def| match def {
+                        Definition::Fragment(f) if f.item.name.item == name => Some(&f.item),
+                        _ => None,
+                    });
+                    if let Some(fragment) = fragment {
+                        if let Some(ty) = self
+                            .schema
+                            .concrete_type_by_name(fragment.type_condition.item)
+                        {
+                            self.selection_set(&fragment.selection_set, ty);
+                        }
+                    }
+                }
+                Selection::InlineFragment(f) => {
+                    let ty = match &f.item.type_condition {
+                        Some(name) => self.schema.concrete_type_by_name(name.item),
+                        None => Some(parent),
+                    };
+                    if let Some(ty) = ty {
+                        self.selection_set(&f.item.selection_set, ty);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        executor::get_operation, graphql_object, parser::parse_document_source, EmptyMutation,
+        EmptySubscription, GraphQLObject, RootNode,
+    };
+
+    use super::{cache_policy, CachePolicy, CacheScope, DIRECTIVE_NAME};
+
+    #[derive(GraphQLObject)]
+    #[graphql(cache_control(max_age = 60))]
+    struct Post {
+        id: i32,
+        #[graphql(cache_control(max_age = 30))]
+        votes: i32,
+        author: Author,
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(cache_control(max_age = 300))]
+    struct Author {
+        name: String,
+        #[graphql(cache_control(scope = "private"))]
+        email: String,
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        #[graphql(cache_control(max_age = 120))]
+        fn latest_post() -> Post {
+            Post {
+                id: 1,
+                votes: 0,
+                author: Author {
+                    name: "Jane".into(),
+                    email: "jane@example.com".into(),
+                },
+            }
+        }
+
+        fn posts() -> Vec<Post> {
+            vec![]
+        }
+
+        #[graphql(cache_control(max_age = 10))]
+        fn version() -> i32 {
+            1
+        }
+
+        fn now() -> i32 {
+            0
+        }
+
+        fn me() -> Option<Viewer> {
+            None
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    struct Viewer {
+        name: String,
+    }
+
+    fn policy(source: &str) -> CachePolicy {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let document = parse_document_source(source, &schema.schema).unwrap();
+        let operation = get_operation(&document, None).unwrap();
+        cache_policy(&schema.schema, &document, &operation.item)
+    }
+
+    #[test]
+    fn registers_directive_when_used() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        assert!(schema.schema.directive_by_name(DIRECTIVE_NAME).is_some());
+
+        let schema = RootNode::new(
+            crate::tests::fixtures::starwars::schema::Query,
+            EmptyMutation::<crate::tests::fixtures::starwars::schema::Database>::new(),
+            EmptySubscription::<crate::tests::fixtures::starwars::schema::Database>::new(),
+        );
+        assert!(schema.schema.directive_by_name(DIRECTIVE_NAME).is_none());
+    }
+
+    #[cfg(feature = "schema-language")]
+    #[test]
+    fn exposes_directive_in_schema() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        assert!(schema
+            .as_schema_language()
+            .contains("directive @cacheControl(maxAge: Int, scope: CacheControlScope)"));
+    }
+
+    #[test]
+    fn uses_minimum_max_age() {
+        assert_eq!(
+            policy("{ latestPost { id } }"),
+            CachePolicy::new(120, CacheScope::Public)
+        );
+        assert_eq!(
+            policy("{ latestPost { id votes } }"),
+            CachePolicy::new(30, CacheScope::Public),
+        );
+        assert_eq!(
+            policy("{ version latestPost { id } }"),
+            CachePolicy::new(10, CacheScope::Public),
+        );
+    }
+
+    #[test]
+    fn falls_back_to_type_hints() {
+        assert_eq!(
+            policy("{ posts { id } }"),
+            CachePolicy::new(60, CacheScope::Public)
+        );
+        assert_eq!(
+            policy("{ latestPost { author { name } } }"),
+            CachePolicy::new(120, CacheScope::Public),
+        );
+    }
+
+    #[test]
+    fn forbids_caching_unhinted_fields() {
+        assert_eq!(policy("{ me { name } }"), CachePolicy::NO_STORE);
+        assert_eq!(policy("{ version me { name } }"), CachePolicy::NO_STORE);
+        assert_eq!(policy("{ __typename }"), CachePolicy::NO_STORE);
+        assert_eq!(policy("{ version now }"), CachePolicy::NO_STORE);
+    }
+
+    #[test]
+    fn restricts_scope_to_private() {
+        assert_eq!(
+            policy("{ latestPost { author { email } } }"),
+            CachePolicy::new(120, CacheScope::Private),
+        );
+    }
+
+    #[test]
+    fn follows_fragments() {
+        assert_eq!(
+            policy(
+                "query { ...Q ... on Query { version } } \
+                 fragment Q on Query { latestPost { ...P } } \
+                 fragment P on Post { votes }",
+            ),
+            CachePolicy::new(10, CacheScope::Public),
+        );
+        assert_eq!(
+            policy(
+                "query { latestPost { ...P } } \
+                 fragment P on Post { author { email } }",
+            ),
+            CachePolicy::new(120, CacheScope::Private),
+        );
+    }
+
+    #[test]
+    fn renders_header_values() {
+        assert_eq!(
+            CachePolicy::new(60, CacheScope::Public).header_value(),
+            "max-age=60, public",
+        );
+        assert_eq!(
+            CachePolicy::new(5, CacheScope::Private).header_value(),
+            "max-age=5, private",
+        );
+        assert_eq!(CachePolicy::NO_STORE.header_value(), "no-store");
+        assert_eq!(
+            CachePolicy::new(60, CacheScope::Public)
+                .restrict(CachePolicy::new(90, CacheScope::Private)),
+            CachePolicy::new(60, CacheScope::Private),
+        );
+    }
+}
@@ -0,0 +1,543 @@
+//! Guards checked before resolving [GraphQL fields][1], authorizing access to
+//! them.
+//!
+//! Guards are applied to fields with the `#[graphql(guard = <expr>)]`
+//! attribute argument, or to all the fields of a type by placing it on a whole
+//! `#[graphql_object]` or `#[graphql_interface]`. The `<expr>` is any
+//! expression of a type implementing [`Guard`], evaluated each time the field
+//! is resolved. A failed guard prevents the resolver from running, and its
+//! [`FieldError`] is reported at the field's path, as if it was returned by the
+//! resolver itself.
+//!
+//! ```rust
+//! # use juniper::{
+//! #     executor::guard, graphql_object, Arguments, FieldError, FieldResult, Guard,
+//! #     ScalarValue,
+//! # };
+//! #
+//! #[derive(Clone, Copy, PartialEq)]
+//! enum Role {
+//!     Admin,
+//!     Editor,
+//! }
+//!
+//! struct Context {
+//!     roles: Vec<Role>,
+//! }
+//!
+//! impl juniper::Context for Context {}
+//!
+//! impl<S: ScalarValue> Guard<Context, S> for Role {
+//!     fn check(&self, ctx: &Context, _: &Arguments<S>) -> FieldResult<(), S> {
+//!         if ctx.roles.contains(self) {
+//!             Ok(())
+//!         } else {
+//!             Err(FieldError::from("Forbidden"))
+//!         }
+//!     }
+//! }
+//!
+//! struct Article;
+//!
+//! #[graphql_object(context = Context)]
+//! impl Article {
+//!     fn title() -> &'static str {
+//!         "Guards"
+//!     }
+//!
+//!     #[graphql(guard = guard::or(Role::Admin, Role::Editor))]
+//!     fn draft() -> &'static str {
+//!         "Guards are checked before resolvers."
+//!     }
+//! }
+//! ```
+//!
+//! [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+
+use std::fmt;
+
+use futures::future;
+
+use crate::{
+    types::base::Arguments, value::DefaultScalarValue, BoxFuture, FieldError, FieldResult,
+};
+
+/// Guard authorizing the resolution of a [GraphQL field][1] against its
+/// `Context` and arguments.
+///
+/// [`Guard::check()`] is used when the field is resolved synchronously, and
+/// [`Guard::check_async()`] when it's resolved asynchronously. The latter
+/// defaults to the former, so only guards requiring asynchronous checks (a
+/// database lookup, for example) should override it.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+pub trait Guard<CtxT: ?Sized, S = DefaultScalarValue>: Send + Sync {
+    /// Checks whether the field may be resolved with the provided `context`
+    /// and `arguments`, returning the [`FieldError`] to report otherwise.
+    fn check(&self, context: &CtxT, arguments: &Arguments<S>) -> FieldResult<(), S>;
+
+    /// Checks asynchronously whether the field may be resolved with the
+    /// provided `context` and `arguments`, returning the [`FieldError`] to
+    /// report otherwise.
+    fn check_async<'a>(
+        &'a self,
+        context: &'a CtxT,
+        arguments: &'a Arguments<S>,
+    ) -> BoxFuture<'a, FieldResult<(), S>>
+    where
+        CtxT: Sync,
+        S: Send + Sync,
+    {
+        Box::pin(future::ready(self.check(context, arguments)))
+    }
+}
+
+impl<CtxT, S, G> Guard<CtxT, S> for &G
+where
+    CtxT: ?Sized,
+    G: Guard<CtxT, S> + ?Sized,
+{
+    fn check(&self, context: &CtxT, arguments: &Arguments<S>) -> FieldResult<(), S> {
+        (**self).check(context, arguments)
+    }
+
+    fn check_async<'a>(
+        &'a self,
+        context: &'a CtxT,
+        arguments: &'a Arguments<S>,
+    ) -> BoxFuture<'a, FieldResult<(), S>>
+    where
+        CtxT: Sync,
+        S: Send + Sync,
+    {
+        (**self).check_async(context, arguments)
+    }
+}
+
+/// [`Guard`] passing only if both of the guards pass.
+///
+/// Created with the [`and()`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct And<A, B>(A, B);
+
+/// Combines the provided guards into a [`Guard`] passing only if both of them
+/// pass, reporting the error of the first failed one otherwise.
+///
+/// The `second` guard isn't checked if the `first` one fails.
+pub fn and<A, B>(first: A, second: B) -> And<A, B> {
+    And(first, second)
+}
+
+impl<CtxT, S, A, B> Guard<CtxT, S> for And<A, B>
+where
+    CtxT: ?Sized,
+    A: Guard<CtxT, S>,
+    B: Guard<CtxT, S>,
+{
+    fn check(&self, context: &CtxT, arguments: &Arguments<S>) -> FieldResult<(), S> {
+        self.0.check(context, arguments)?;
+        self.1.check(context, arguments)
+    }
+
+    fn check_async<'a>(
+        &'a self,
+        context: &'a CtxT,
+        arguments: &'a Arguments<S>,
+    ) -> BoxFuture<'a, FieldResult<(), S>>
+    where
+        CtxT: Sync,
+        S: Send + Sync,
+    {
+        Box::pin(async move {
+            self.0.check_async(context, arguments).await?;
+            self.1.check_async(context, arguments).await
+        })
+    }
+}
+
+/// [`Guard`] passing if any of the guards passes.
+///
+/// Created with the [`or()`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct Or<A, B>(A, B);
+
+/// Combines the provided guards into a [`Guard`] passing if any of them
+/// passes, reporting the error of the `second` one if both fail.
+///
+/// The `second` guard isn't checked if the `first` one passes.
+pub fn or<A, B>(first: A, second: B) -> Or<A, B> {
+    Or(first, second)
+}
+
+impl<CtxT, S, A, B> Guard<CtxT, S> for Or<A, B>
+where
+    CtxT: ?Sized,
+    A: Guard<CtxT, S>,
+    B: Guard<CtxT, S>,
+{
+    fn check(&self, context: &CtxT, arguments: &Arguments<S>) -> FieldResult<(), S> {
+        self.0
+            .check(context, arguments)
+            .or_else(|_| self.1.check(context, arguments))
+    }
+
+    fn check_async<'a>(
+        &'a self,
+        context: &'a CtxT,
+        arguments: &'a Arguments<S>,
+    ) -> BoxFuture<'a, FieldResult<(), S>>
+    where
+        CtxT: Sync,
+        S: Send + Sync,
+    {
+        Box::pin(async move {
+            match self.0.check_async(context, arguments).await {
+                Ok(()) => Ok(()),
+                Err(_) => self.1.check_async(context, arguments).await,
+            }
+        })
+    }
+}
+
+/// [`Guard`] passing only if the inner guard fails.
+///
+/// Created with the [`not()`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct Not<G>(G);
+
+/// Negates the provided `guard`, so the returned [`Guard`] passes only if it
+/// fails, reporting a `Forbidden` error otherwise.
+pub fn not<G>(guard: G) -> Not<G> {
+    Not(guard)
+}
+
+impl<G> Not<G> {
+    /// Returns the error reported when the inner guard passes.
+    fn forbidden<S>() -> FieldError<S> {
+        FieldError::from("Forbidden")
+    }
+}
+
+impl<CtxT, S, G> Guard<CtxT, S> for Not<G>
+where
+    CtxT: ?Sized,
+    G: Guard<CtxT, S>,
+{
+    fn check(&self, context: &CtxT, arguments: &Arguments<S>) -> FieldResult<(), S> {
+        match self.0.check(context, arguments) {
+            Ok(()) => Err(Self::forbidden()),
+            Err(_) => Ok(()),
+        }
+    }
+
+    fn check_async<'a>(
+        &'a self,
+        context: &'a CtxT,
+        arguments: &'a Arguments<S>,
+    ) -> BoxFuture<'a, FieldResult<(), S>>
+    where
+        CtxT: Sync,
+        S: Send + Sync,
+    {
+        Box::pin(async move {
+            match self.0.check_async(context, arguments).await {
+                Ok(()) => Err(Self::forbidden()),
+                Err(_) => Ok(()),
+            }
+        })
+    }
+}
+
+/// [`Guard`] checked with a function.
+///
+/// Created with the [`from_fn()`] function.
+#[derive(Clone, Copy)]
+pub struct FnGuard<F>(F);
+
+/// Creates a [`Guard`] checked synchronously with the provided function.
+pub fn from_fn<F>(f: F) -> FnGuard<F> {
+    FnGuard(f)
+}
+
+impl<F> fmt::Debug for FnGuard<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("FnGuard")
+    }
+}
+
+impl<CtxT, S, F> Guard<CtxT, S> for FnGuard<F>
+where
+    CtxT: ?Sized,
+    F: Fn(&CtxT, &Arguments<S>) -> FieldResult<(), S> + Send + Sync,
+{
+    fn check(&self, context: &CtxT, arguments: &Arguments<S>) -> FieldResult<(), S> {
+        (self.0)(context, arguments)
+    }
+}
+
+/// [`Guard`] checked asynchronously with a function returning a future.
+///
+/// Created with the [`from_async_fn()`] function.
+#[derive(Clone, Copy)]
+pub struct AsyncFnGuard<F>(F);
+
+/// Creates a [`Guard`] checked asynchronously with the provided function.
+///
+/// As it can't be checked synchronously, the returned [`Guard`] always fails
+/// when the field is resolved synchronously.
+pub fn from_async_fn<F>(f: F) -> AsyncFnGuard<F> {
+    AsyncFnGuard(f)
+}
+
+impl<F> fmt::Debug for AsyncFnGuard<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("AsyncFnGuard")
+    }
+}
+
+impl<CtxT, S, F> Guard<CtxT, S> for AsyncFnGuard<F>
+where
+    CtxT: ?Sized,
+    F: for<'a> Fn(&'a CtxT, &'a Arguments<S>) -> BoxFuture<'a, FieldResult<(), S>> + Send + Sync,
+{
+    fn check(&self, _: &CtxT, _: &Arguments<S>) -> FieldResult<(), S> {
+        Err(FieldError::from(
+            "Guard can only be checked when resolving asynchronously",
+        ))
+    }
+
+    fn check_async<'a>(
+        &'a self,
+        context: &'a CtxT,
+        arguments: &'a Arguments<S>,
+    ) -> BoxFuture<'a, FieldResult<(), S>>
+    where
+        CtxT: Sync,
+        S: Send + Sync,
+    {
+        (self.0)(context, arguments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        execute, execute_sync, graphql_object, Arguments, BoxFuture, DefaultScalarValue,
+        EmptyMutation, EmptySubscription, ExecutionError, FieldError, FieldResult, GraphQLObject,
+        RootNode, Value, Variables,
+    };
+
+    use super::{and, from_async_fn, from_fn, not, or, Guard};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Role {
+        Admin,
+        Editor,
+    }
+
+    struct Context {
+        roles: Vec<Role>,
+    }
+
+    impl crate::Context for Context {}
+
+    impl Guard<Context> for Role {
+        fn check(&self, ctx: &Context, _: &Arguments) -> FieldResult<()> {
+            if ctx.roles.contains(self) {
+                Ok(())
+            } else {
+                Err(FieldError::from(format!("{:?} role required", self)))
+            }
+        }
+    }
+
+    fn has_id(id: i32) -> impl Guard<Context> {
+        from_fn(move |_: &Context, args: &Arguments| {
+            if args.get::<i32>("id")? == Some(id) {
+                Ok(())
+            } else {
+                Err(FieldError::from("Unexpected `id`"))
+            }
+        })
+    }
+
+    fn is_admin_async<'a>(ctx: &'a Context, _: &'a Arguments) -> BoxFuture<'a, FieldResult<()>> {
+        Box::pin(async move { Role::Admin.check(ctx, &Arguments::new(None, &None)) })
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(context = Context, scalar = DefaultScalarValue, guard = Role::Editor)]
+    struct Draft {
+        title: String,
+    }
+
+    struct Query;
+
+    #[graphql_object(context = Context, scalar = DefaultScalarValue)]
+    impl Query {
+        fn public() -> i32 {
+            1
+        }
+
+        #[graphql(guard = Role::Admin)]
+        fn admin() -> i32 {
+            2
+        }
+
+        #[graphql(guard = or(Role::Admin, Role::Editor))]
+        fn staff() -> i32 {
+            3
+        }
+
+        #[graphql(guard = and(Role::Admin, not(Role::Editor)))]
+        fn admin_only() -> i32 {
+            4
+        }
+
+        #[graphql(guard = has_id(42))]
+        fn by_id(id: i32) -> i32 {
+            id
+        }
+
+        #[graphql(guard = from_async_fn(is_admin_async))]
+        async fn audit() -> i32 {
+            5
+        }
+
+        fn draft() -> Draft {
+            Draft {
+                title: "Guards".into(),
+            }
+        }
+    }
+
+    type Schema = RootNode<'static, Query, EmptyMutation<Context>, EmptySubscription<Context>>;
+
+    fn schema() -> Schema {
+        Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    fn run(query: &str, roles: Vec<Role>) -> (Value, Vec<ExecutionError<DefaultScalarValue>>) {
+        execute_sync(
+            query,
+            None,
+            &schema(),
+            &Variables::new(),
+            &Context { roles },
+        )
+        .expect("valid query")
+    }
+
+    fn errors(errs: &[ExecutionError<DefaultScalarValue>]) -> Vec<(Vec<String>, String)> {
+        errs.iter()
+            .map(|e| (e.path().to_vec(), e.error().message().to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn passes_unguarded_field() {
+        let (res, errs) = run("{ public }", vec![]);
+
+        assert_eq!(res, graphql_value!({"public": 1}));
+        assert!(errs.is_empty());
+    }
+
+    #[test]
+    fn reports_failed_guard_at_field_path() {
+        let (res, errs) = run("{ public admin }", vec![Role::Editor]);
+
+        assert_eq!(res, graphql_value!(null));
+        assert_eq!(
+            errors(&errs),
+            vec![(vec!["admin".into()], "Admin role required".into())],
+        );
+
+        let (res, errs) = run("{ public admin }", vec![Role::Admin]);
+
+        assert_eq!(res, graphql_value!({"public": 1, "admin": 2}));
+        assert!(errs.is_empty());
+    }
+
+    #[test]
+    fn combines_guards() {
+        let query = "{ staff adminOnly }";
+
+        let (res, errs) = run(query, vec![Role::Admin]);
+        assert_eq!(res, graphql_value!({"staff": 3, "adminOnly": 4}));
+        assert!(errs.is_empty());
+
+        let (_, errs) = run(query, vec![Role::Admin, Role::Editor]);
+        assert_eq!(
+            errors(&errs),
+            vec![(vec!["adminOnly".into()], "Forbidden".into())],
+        );
+
+        let (_, errs) = run("{ staff }", vec![]);
+        assert_eq!(
+            errors(&errs),
+            vec![(vec!["staff".into()], "Editor role required".into())],
+        );
+    }
+
+    #[test]
+    fn checks_field_arguments() {
+        let (res, errs) = run("{ byId(id: 42) }", vec![]);
+        assert_eq!(res, graphql_value!({"byId": 42}));
+        assert!(errs.is_empty());
+
+        let (_, errs) = run("{ byId(id: 1) }", vec![]);
+        assert_eq!(
+            errors(&errs),
+            vec![(vec!["byId".into()], "Unexpected `id`".into())],
+        );
+    }
+
+    #[test]
+    fn applies_type_guard_to_all_fields() {
+        let (_, errs) = run("{ draft { title } }", vec![]);
+        assert_eq!(
+            errors(&errs),
+            vec![(
+                vec!["draft".into(), "title".into()],
+                "Editor role required".into(),
+            )],
+        );
+
+        let (res, errs) = run("{ draft { title } }", vec![Role::Editor]);
+        assert_eq!(res, graphql_value!({"draft": {"title": "Guards"}}));
+        assert!(errs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn checks_guards_asynchronously() {
+        let schema = schema();
+        let query = "{ staff audit draft { title } }";
+
+        let ctx = Context {
+            roles: vec![Role::Admin],
+        };
+        let (res, errs) = execute(query, None, &schema, &Variables::new(), &ctx)
+            .await
+            .expect("valid query");
+        assert_eq!(res, graphql_value!(null));
+        assert_eq!(
+            errors(&errs),
+            vec![(
+                vec!["draft".into(), "title".into()],
+                "Editor role required".into(),
+            )],
+        );
+
+        let ctx = Context {
+            roles: vec![Role::Admin, Role::Editor],
+        };
+        let (res, errs) = execute(query, None, &schema, &Variables::new(), &ctx)
+            .await
+            .expect("valid query");
+        assert_eq!(
+            res,
+            graphql_value!({"staff": 3, "audit": 5, "draft": {"title": "Guards"}}),
+        );
+        assert!(errs.is_empty());
+    }
+}
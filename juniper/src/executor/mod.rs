@@ -36,15 +36,21 @@ use crate::{
 };
 
 pub use self::{
+    cache_control::{cache_policy, CachePolicy, CacheScope},
+    guard::Guard,
     look_ahead::{
         Applies, ChildSelection, ConcreteLookAheadSelection, LookAheadArgument, LookAheadMethods,
         LookAheadSelection, LookAheadValue,
     },
     owned_executor::OwnedExecutor,
+    validators::Validator,
 };
 
+pub(crate) mod cache_control;
+pub mod guard;
 mod look_ahead;
 mod owned_executor;
+pub mod validators;
 
 /// A type registry used to build schemas
 ///
@@ -54,6 +60,10 @@ mod owned_executor;
 pub struct Registry<'r, S = DefaultScalarValue> {
     /// Currently registered types
     pub types: FnvHashMap<Name, MetaType<'r, S>>,
+
+    /// Names of the Rust types already checked to define the registered types
+    /// with the same name.
+    origins: FnvHashMap<Name, Vec<&'static str>>,
 }
 
 #[allow(missing_docs)]
@@ -87,7 +97,7 @@ where
 ///
 /// All execution errors contain the source position in the query of the field
 /// that failed to resolve. It also contains the field stack.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ExecutionError<S> {
     location: SourcePosition,
     path: Vec<String>,
@@ -1154,7 +1164,10 @@ where
 impl<'r, S: 'r> Registry<'r, S> {
     /// Constructs a new [`Registry`] out of the given `types`.
     pub fn new(types: FnvHashMap<Name, MetaType<'r, S>>) -> Self {
-        Self { types }
+        Self {
+            types,
+            origins: FnvHashMap::default(),
+        }
     }
 
     /// Returns a [`Type`] instance for the given [`GraphQLType`], registered in
@@ -1162,6 +1175,12 @@ impl<'r, S: 'r> Registry<'r, S> {
     ///
     /// If this [`Registry`] hasn't seen a [`Type`] with such
     /// [`GraphQLType::name`] before, it will construct the one and store it.
+    ///
+    /// # Panics
+    ///
+    /// If another Rust type has already registered a different [`Type`] with
+    /// the same [`GraphQLType::name`] (like different instantiations of a
+    /// generic GraphQL object with a fixed name).
     pub fn get_type<T>(&mut self, info: &T::TypeInfo) -> Type<'r>
     where
         T: GraphQLType<S> + ?Sized,
@@ -1175,7 +1194,11 @@ impl<'r, S: 'r> Registry<'r, S> {
                     Type::NonNullNamed(Cow::Owned(name.to_string())),
                 );
                 let meta = T::meta(info, self);
-                self.types.insert(validated_name, meta);
+                self.types.insert(validated_name.clone(), meta);
+                self.origins
+                    .insert(validated_name, vec![std::any::type_name::<T>()]);
+            } else {
+                self.ensure_same_definition::<T>(info, name);
             }
             self.types[name].as_type()
         } else {
@@ -1183,6 +1206,42 @@ impl<'r, S: 'r> Registry<'r, S> {
         }
     }
 
+    /// Ensures that the given [`GraphQLType`] defines the same [`Type`] as the
+    /// one already registered in this [`Registry`] under the same `name`.
+    ///
+    /// Every Rust type is checked only once per `name`.
+    ///
+    /// # Panics
+    ///
+    /// If the given [`GraphQLType`] defines a different [`Type`].
+    fn ensure_same_definition<T>(&mut self, info: &T::TypeInfo, name: &str)
+    where
+        T: GraphQLType<S> + ?Sized,
+        S: ScalarValue,
+    {
+        let rust_ty = std::any::type_name::<T>();
+        let origins = match self.origins.get(name) {
+            Some(origins) => origins,
+            // The type is still being constructed or was put into this
+            // `Registry` directly, so there is nothing to compare with yet.
+            None => return,
+        };
+        if origins.contains(&rust_ty) {
+            return;
+        }
+        let origin = origins[0];
+
+        let meta = T::meta(info, self);
+        if !meta.is_same_definition(&self.types[name]) {
+            panic!(
+                "GraphQL type `{}` is defined differently by `{}` and `{}` Rust types, \
+                 consider giving them distinct names",
+                name, origin, rust_ty,
+            );
+        }
+        self.origins.get_mut(name).unwrap().push(rust_ty);
+    }
+
     /// Creates a [`Field`] with the provided `name`.
     pub fn field<T>(&mut self, name: &str, info: &T::TypeInfo) -> Field<'r, S>
     where
@@ -1195,6 +1254,7 @@ impl<'r, S: 'r> Registry<'r, S> {
             arguments: None,
             field_type: self.get_type::<T>(info),
             deprecation_status: DeprecationStatus::Current,
+            directives: vec![],
         }
     }
 
@@ -1214,6 +1274,7 @@ impl<'r, S: 'r> Registry<'r, S> {
             arguments: None,
             field_type: self.get_type::<I>(info),
             deprecation_status: DeprecationStatus::Current,
+            directives: vec![],
         }
     }
 
@@ -1309,7 +1370,7 @@ impl<'r, S: 'r> Registry<'r, S> {
     pub fn build_enum_type<T>(
         &mut self,
         info: &T::TypeInfo,
-        values: &[EnumValue],
+        values: &[EnumValue<S>],
     ) -> EnumMeta<'r, S>
     where
         T: GraphQLType<S> + FromInputValue<S>,
@@ -1339,7 +1400,11 @@ impl<'r, S: 'r> Registry<'r, S> {
     }
 
     /// Creates an [`UnionMeta`] type of the given `types`.
-    pub fn build_union_type<T>(&mut self, info: &T::TypeInfo, types: &[Type<'r>]) -> UnionMeta<'r>
+    pub fn build_union_type<T>(
+        &mut self,
+        info: &T::TypeInfo,
+        types: &[Type<'r>],
+    ) -> UnionMeta<'r, S>
     where
         T: GraphQLType<S> + ?Sized,
         S: ScalarValue,
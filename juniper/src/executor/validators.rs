@@ -0,0 +1,747 @@
+//! Validators of [GraphQL arguments][1] and [input object fields][2] values,
+//! checked after converting them with [`FromInputValue`] and before resolving
+//! the field they're passed to.
+//!
+//! Validators are applied with the `#[graphql(validate(...))]` attribute
+//! argument, placed on resolver arguments or on [`GraphQLInputObject`] fields,
+//! and listing any of the built-in validators:
+//! - `min = <number>` and `max = <number>` ([`min()`] and [`max()`]);
+//! - `min_length = <int>` and `max_length = <int>` ([`min_length()`] and
+//!   [`max_length()`]);
+//! - `regex = "<pattern>"` ([`regex()`], requires `regex` feature);
+//! - `email` ([`email()`]);
+//! - `url` ([`url()`], requires `url` feature);
+//! - `min_items = <int>` and `max_items = <int>` ([`min_items()`] and
+//!   [`max_items()`]);
+//! - `custom = <function>` ([`custom()`]), where the function is any
+//!   `Fn(&T) -> Result<(), String>`.
+//!
+//! A failed validator is reported as a [`FieldError`] with the
+//! `VALIDATION_FAILED` code and the name of the invalid argument or input
+//! field in its extensions. The built-in validators are also exposed in the
+//! schema as a `@constraint` directive applied to the validated argument or
+//! input field.
+//!
+//! ```rust
+//! # use juniper::{graphql_object, GraphQLInputObject};
+//! #
+//! #[derive(GraphQLInputObject)]
+//! struct NewUser {
+//!     #[graphql(validate(min_length = 3, max_length = 20))]
+//!     login: String,
+//!     #[graphql(validate(email))]
+//!     email: Option<String>,
+//! }
+//!
+//! struct Query;
+//!
+//! #[graphql_object]
+//! impl Query {
+//!     fn users(#[graphql(validate(min = 1, max = 100))] first: i32) -> Vec<String> {
+//!         vec![]
+//!     }
+//! }
+//! ```
+//!
+//! [`FromInputValue`]: crate::FromInputValue
+//! [`GraphQLInputObject`]: crate::GraphQLInputObject
+//! [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+//! [2]: https://spec.graphql.org/October2021#sec-Input-Objects
+
+use std::fmt;
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
+
+use crate::{
+    schema::meta::{AppliedDirective, Argument, MetaType},
+    types::nullable::Nullable,
+    value::{Object, ScalarValue, Value},
+    FieldError, FieldResult,
+};
+
+/// Name of the directive exposing the built-in validators in the schema.
+pub(crate) const DIRECTIVE_NAME: &str = "constraint";
+
+/// Indicates whether any argument or input field among the provided `types`
+/// has a `@constraint` directive.
+pub(crate) fn is_used<'a, 'm: 'a, S: 'a>(
+    types: impl IntoIterator<Item = &'a MetaType<'m, S>>,
+) -> bool {
+    let is_constraint = |d: &AppliedDirective<S>| d.name == DIRECTIVE_NAME;
+    let is_constrained = |a: &Argument<S>| a.directives.iter().any(is_constraint);
+    types.into_iter().any(|t| match t {
+        MetaType::Object(o) => o
+            .fields
+            .iter()
+            .any(|f| f.arguments.iter().flatten().any(is_constrained)),
+        MetaType::Interface(i) => i
+            .fields
+            .iter()
+            .any(|f| f.arguments.iter().flatten().any(is_constrained)),
+        MetaType::InputObject(i) => i.input_fields.iter().any(is_constrained),
+        _ => false,
+    })
+}
+
+/// Validator of a value of an argument or an input field.
+pub trait Validator<T: ?Sized>: Send + Sync {
+    /// Validates the provided `value`, returning the message describing why
+    /// it's invalid otherwise.
+    ///
+    /// The message is appended to the name of the invalid argument or input
+    /// field, so it should read like `must be ...`.
+    fn validate(&self, value: &T) -> Result<(), String>;
+}
+
+impl<T, V> Validator<T> for &V
+where
+    T: ?Sized,
+    V: Validator<T> + ?Sized,
+{
+    fn validate(&self, value: &T) -> Result<(), String> {
+        (**self).validate(value)
+    }
+}
+
+/// Validates the provided `value` of the argument with the provided `name`,
+/// reporting a [`FieldError`] if it's invalid.
+pub fn validate_argument<T, V, S>(name: &str, value: &T, validator: &V) -> FieldResult<(), S>
+where
+    T: ?Sized,
+    V: Validator<T> + ?Sized,
+    S: ScalarValue,
+{
+    validator.validate(value).map_err(|msg| {
+        error(
+            format!("Invalid value for argument `{}`", name),
+            "argument",
+            name,
+            msg,
+        )
+    })
+}
+
+/// Validates the provided `value` of the input object field with the provided
+/// `name`, reporting a [`FieldError`] if it's invalid.
+pub fn validate_input_field<T, V, S>(name: &str, value: &T, validator: &V) -> FieldResult<(), S>
+where
+    T: ?Sized,
+    V: Validator<T> + ?Sized,
+    S: ScalarValue,
+{
+    validator.validate(value).map_err(|msg| {
+        error(
+            format!("Invalid value for input field `{}`", name),
+            "inputField",
+            name,
+            msg,
+        )
+    })
+}
+
+/// Creates a [`FieldError`] of a failed validation.
+fn error<S: ScalarValue>(prefix: String, kind: &str, name: &str, msg: String) -> FieldError<S> {
+    let mut extensions = Object::with_capacity(2);
+    extensions.add_field("code", Value::scalar("VALIDATION_FAILED".to_owned()));
+    extensions.add_field(kind, Value::scalar(name.to_owned()));
+    FieldError::new(format!("{}: {}", prefix, msg), Value::Object(extensions))
+}
+
+/// Value validated as a number by the [`min()`] and [`max()`] validators.
+///
+/// Absent values (like [`None`]) are always valid.
+pub trait AsNumber {
+    /// Returns this value as a number, if it's present.
+    fn as_number(&self) -> Option<f64>;
+}
+
+/// Value validated as a text by the [`min_length()`], [`max_length()`],
+/// [`regex()`], [`email()`] and [`url()`] validators.
+///
+/// Absent values (like [`None`]) are always valid.
+pub trait AsText {
+    /// Returns this value as a text, if it's present.
+    fn as_text(&self) -> Option<&str>;
+}
+
+/// Value validated as a list by the [`min_items()`] and [`max_items()`]
+/// validators.
+///
+/// Absent values (like [`None`]) are always valid.
+pub trait AsList {
+    /// Returns the number of items in this value, if it's present.
+    fn items_count(&self) -> Option<usize>;
+}
+
+impl AsNumber for i32 {
+    fn as_number(&self) -> Option<f64> {
+        Some(f64::from(*self))
+    }
+}
+
+impl AsNumber for f64 {
+    fn as_number(&self) -> Option<f64> {
+        Some(*self)
+    }
+}
+
+impl AsText for str {
+    fn as_text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl AsText for String {
+    fn as_text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T> AsList for [T] {
+    fn items_count(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T> AsList for Vec<T> {
+    fn items_count(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+macro_rules! impl_for_optional {
+    ($ty:ident, |$v:ident| $get:expr) => {
+        impl<T: AsNumber> AsNumber for $ty<T> {
+            fn as_number(&self) -> Option<f64> {
+                let $v = self;
+                $get.and_then(AsNumber::as_number)
+            }
+        }
+
+        impl<T: AsText> AsText for $ty<T> {
+            fn as_text(&self) -> Option<&str> {
+                let $v = self;
+                $get.and_then(AsText::as_text)
+            }
+        }
+
+        impl<T: AsList> AsList for $ty<T> {
+            fn items_count(&self) -> Option<usize> {
+                let $v = self;
+                $get.and_then(AsList::items_count)
+            }
+        }
+    };
+}
+
+impl_for_optional!(Option, |v| v.as_ref());
+impl_for_optional!(Nullable, |v| match v {
+    Nullable::Some(v) => Some(v),
+    Nullable::ImplicitNull | Nullable::ExplicitNull => None,
+});
+
+/// Validator of a minimal number.
+///
+/// Created with the [`min()`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct Min(f64);
+
+/// Creates a [`Validator`] checking that a number is greater than or equal to
+/// the provided `min`imum.
+pub const fn min(min: f64) -> Min {
+    Min(min)
+}
+
+impl<T: AsNumber + ?Sized> Validator<T> for Min {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        match value.as_number() {
+            Some(n) if n < self.0 => Err(format!("must be greater than or equal to {}", self.0)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Validator of a maximal number.
+///
+/// Created with the [`max()`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct Max(f64);
+
+/// Creates a [`Validator`] checking that a number is less than or equal to
+/// the provided `max`imum.
+pub const fn max(max: f64) -> Max {
+    Max(max)
+}
+
+impl<T: AsNumber + ?Sized> Validator<T> for Max {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        match value.as_number() {
+            Some(n) if n > self.0 => Err(format!("must be less than or equal to {}", self.0)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Validator of a minimal text length.
+///
+/// Created with the [`min_length()`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct MinLength(usize);
+
+/// Creates a [`Validator`] checking that a text has at least the provided
+/// number of characters.
+pub const fn min_length(min: usize) -> MinLength {
+    MinLength(min)
+}
+
+impl<T: AsText + ?Sized> Validator<T> for MinLength {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        match value.as_text() {
+            Some(s) if s.chars().count() < self.0 => {
+                Err(format!("must be at least {} characters long", self.0))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Validator of a maximal text length.
+///
+/// Created with the [`max_length()`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct MaxLength(usize);
+
+/// Creates a [`Validator`] checking that a text has at most the provided
+/// number of characters.
+pub const fn max_length(max: usize) -> MaxLength {
+    MaxLength(max)
+}
+
+impl<T: AsText + ?Sized> Validator<T> for MaxLength {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        match value.as_text() {
+            Some(s) if s.chars().count() > self.0 => {
+                Err(format!("must be at most {} characters long", self.0))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Validator of a text matching a regular expression.
+///
+/// Created with the [`regex()`] function. The regular expression is compiled
+/// once, on the first validation.
+#[cfg(feature = "regex")]
+#[derive(Debug)]
+pub struct Pattern {
+    pattern: &'static str,
+    regex: OnceLock<Result<regex::Regex, regex::Error>>,
+}
+
+/// Creates a [`Validator`] checking that a text matches the provided regular
+/// expression `pattern`.
+///
+/// The returned [`Pattern`] should be stored in a `static` to compile the
+/// `pattern` only once.
+#[cfg(feature = "regex")]
+pub const fn regex(pattern: &'static str) -> Pattern {
+    Pattern {
+        pattern,
+        regex: OnceLock::new(),
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<T: AsText + ?Sized> Validator<T> for Pattern {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        let s = match value.as_text() {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+        match self.regex.get_or_init(|| regex::Regex::new(self.pattern)) {
+            Ok(re) if re.is_match(s) => Ok(()),
+            Ok(_) => Err(format!("must match pattern `{}`", self.pattern)),
+            Err(e) => Err(format!(
+                "cannot be matched with pattern `{}`: {}",
+                self.pattern, e
+            )),
+        }
+    }
+}
+
+/// Validator of an email address.
+///
+/// Created with the [`email()`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct Email;
+
+/// Creates a [`Validator`] checking that a text is an email address.
+///
+/// Only the overall shape of the address (`local@domain.tld`) is checked.
+pub const fn email() -> Email {
+    Email
+}
+
+impl<T: AsText + ?Sized> Validator<T> for Email {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        let s = match value.as_text() {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+        let is_valid = !s.contains(char::is_whitespace)
+            && s.rsplit_once('@').is_some_and(|(local, domain)| {
+                !local.is_empty()
+                    && domain.contains('.')
+                    && domain.split('.').all(|part| !part.is_empty())
+            });
+        if is_valid {
+            Ok(())
+        } else {
+            Err("must be a valid email address".into())
+        }
+    }
+}
+
+/// Validator of an absolute URL.
+///
+/// Created with the [`url()`] function.
+#[cfg(feature = "url")]
+#[derive(Clone, Copy, Debug)]
+pub struct Url;
+
+/// Creates a [`Validator`] checking that a text is an absolute URL.
+#[cfg(feature = "url")]
+pub const fn url() -> Url {
+    Url
+}
+
+#[cfg(feature = "url")]
+impl<T: AsText + ?Sized> Validator<T> for Url {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        match value.as_text() {
+            Some(s) if url::Url::parse(s).is_err() => Err("must be a valid URL".into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Validator of a minimal number of list items.
+///
+/// Created with the [`min_items()`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct MinItems(usize);
+
+/// Creates a [`Validator`] checking that a list has at least the provided
+/// number of items.
+pub const fn min_items(min: usize) -> MinItems {
+    MinItems(min)
+}
+
+impl<T: AsList + ?Sized> Validator<T> for MinItems {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        match value.items_count() {
+            Some(n) if n < self.0 => Err(format!("must contain at least {} items", self.0)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Validator of a maximal number of list items.
+///
+/// Created with the [`max_items()`] function.
+#[derive(Clone, Copy, Debug)]
+pub struct MaxItems(usize);
+
+/// Creates a [`Validator`] checking that a list has at most the provided
+/// number of items.
+pub const fn max_items(max: usize) -> MaxItems {
+    MaxItems(max)
+}
+
+impl<T: AsList + ?Sized> Validator<T> for MaxItems {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        match value.items_count() {
+            Some(n) if n > self.0 => Err(format!("must contain at most {} items", self.0)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Validator checking a value with a function.
+///
+/// Created with the [`custom()`] function.
+#[derive(Clone, Copy)]
+pub struct Custom<F>(F);
+
+/// Creates a [`Validator`] checking a value with the provided function,
+/// returning the message describing why the value is invalid.
+pub const fn custom<F>(f: F) -> Custom<F> {
+    Custom(f)
+}
+
+impl<F> fmt::Debug for Custom<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Custom")
+    }
+}
+
+impl<T, F> Validator<T> for Custom<F>
+where
+    T: ?Sized,
+    F: Fn(&T) -> Result<(), String> + Send + Sync,
+{
+    fn validate(&self, value: &T) -> Result<(), String> {
+        (self.0)(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        execute_sync, graphql_object, DefaultScalarValue, EmptyMutation, EmptySubscription,
+        ExecutionError, GraphQLInputObject, Nullable, RootNode, Value, Variables,
+    };
+
+    use super::{custom, email, max, max_items, max_length, min, min_items, min_length, Validator};
+
+    #[test]
+    fn validates_numbers() {
+        assert_eq!(min(1.0).validate(&1), Ok(()));
+        assert_eq!(
+            min(1.0).validate(&0),
+            Err("must be greater than or equal to 1".into()),
+        );
+        assert_eq!(
+            max(0.5).validate(&Some(0.75)),
+            Err("must be less than or equal to 0.5".into()),
+        );
+        assert_eq!(max(0.5).validate(&None::<f64>), Ok(()));
+    }
+
+    #[test]
+    fn validates_texts() {
+        assert_eq!(min_length(2).validate("ab"), Ok(()));
+        assert_eq!(
+            min_length(2).validate("a"),
+            Err("must be at least 2 characters long".into()),
+        );
+        assert_eq!(max_length(2).validate("äö"), Ok(()));
+        assert_eq!(
+            max_length(2).validate(&Nullable::Some("abc".to_owned())),
+            Err("must be at most 2 characters long".into()),
+        );
+        assert_eq!(
+            max_length(2).validate(&Nullable::<String>::ExplicitNull),
+            Ok(())
+        );
+
+        assert_eq!(email().validate("jane@example.com"), Ok(()));
+        for invalid in [
+            "jane",
+            "@example.com",
+            "jane@example",
+            "jane@.com",
+            "ja ne@example.com",
+        ] {
+            assert_eq!(
+                email().validate(invalid),
+                Err("must be a valid email address".into()),
+                "{}",
+                invalid,
+            );
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn validates_patterns() {
+        use super::regex;
+
+        static PATTERN: super::Pattern = regex("^[a-z]+$");
+
+        assert_eq!(PATTERN.validate("abc"), Ok(()));
+        assert_eq!(
+            PATTERN.validate("Abc"),
+            Err("must match pattern `^[a-z]+$`".into()),
+        );
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn validates_urls() {
+        use super::url;
+
+        assert_eq!(url().validate("https://example.com/path"), Ok(()));
+        assert_eq!(
+            url().validate("example.com"),
+            Err("must be a valid URL".into())
+        );
+    }
+
+    #[test]
+    fn validates_lists() {
+        assert_eq!(min_items(1).validate(&vec![1]), Ok(()));
+        assert_eq!(
+            min_items(1).validate(&Vec::<i32>::new()),
+            Err("must contain at least 1 items".into()),
+        );
+        assert_eq!(
+            max_items(1).validate(&Some(vec![1, 2])),
+            Err("must contain at most 1 items".into()),
+        );
+    }
+
+    #[test]
+    fn validates_with_functions() {
+        let even = custom(|n: &i32| {
+            if n % 2 == 0 {
+                Ok(())
+            } else {
+                Err("must be even".into())
+            }
+        });
+
+        assert_eq!(even.validate(&2), Ok(()));
+        assert_eq!(even.validate(&3), Err("must be even".into()));
+    }
+
+    fn is_even(n: &Option<i32>) -> Result<(), String> {
+        match n {
+            Some(n) if n % 2 != 0 => Err("must be even".into()),
+            _ => Ok(()),
+        }
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct NewUser {
+        #[graphql(validate(min_length = 3))]
+        login: String,
+        #[graphql(validate(email))]
+        email: Option<String>,
+        #[graphql(validate(custom = is_even))]
+        lucky_number: Option<i32>,
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn users(
+            #[graphql(validate(min = 1, max = 100))] first: i32,
+            #[graphql(validate(min_items = 1))] ids: Option<Vec<i32>>,
+        ) -> i32 {
+            first + ids.map_or(0, |ids| ids.len() as i32)
+        }
+
+        fn register(user: NewUser) -> String {
+            user.login
+        }
+    }
+
+    type Schema = RootNode<'static, Query, EmptyMutation, EmptySubscription>;
+
+    fn schema() -> Schema {
+        Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    fn run(query: &str) -> (Value, Vec<ExecutionError<DefaultScalarValue>>) {
+        execute_sync(query, None, &schema(), &Variables::new(), &()).expect("valid query")
+    }
+
+    #[test]
+    fn passes_valid_arguments() {
+        let (res, errs) = run(r#"{ users(first: 10, ids: [1]) register(user: {login: "jane"}) }"#);
+
+        assert_eq!(res, graphql_value!({"users": 11, "register": "jane"}));
+        assert!(errs.is_empty());
+    }
+
+    #[test]
+    fn reports_invalid_argument() {
+        let (res, errs) = run("{ users(first: 0) }");
+
+        assert_eq!(res, graphql_value!(null));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].path(), ["users"]);
+        assert_eq!(
+            errs[0].error().message(),
+            "Invalid value for argument `first`: must be greater than or equal to 1",
+        );
+        assert_eq!(
+            errs[0].error().extensions(),
+            &graphql_value!({"code": "VALIDATION_FAILED", "argument": "first"}),
+        );
+
+        let (_, errs) = run("{ users(first: 1, ids: []) }");
+
+        assert_eq!(
+            errs[0].error().message(),
+            "Invalid value for argument `ids`: must contain at least 1 items",
+        );
+    }
+
+    #[test]
+    fn reports_invalid_input_field() {
+        let (_, errs) = run(r#"{ register(user: {login: "jo"}) }"#);
+
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].path(), ["register"]);
+        assert_eq!(
+            errs[0].error().message(),
+            "Invalid value for input field `login`: must be at least 3 characters long",
+        );
+        assert_eq!(
+            errs[0].error().extensions(),
+            &graphql_value!({"code": "VALIDATION_FAILED", "inputField": "login"}),
+        );
+
+        let (_, errs) = run(r#"{ register(user: {login: "jane", luckyNumber: 7}) }"#);
+
+        assert_eq!(
+            errs[0].error().message(),
+            "Invalid value for input field `luckyNumber`: must be even",
+        );
+
+        let (_, errs) = run(r#"{ register(user: {login: "jane", email: "jane"}) }"#);
+
+        assert_eq!(
+            errs[0].error().message(),
+            "Invalid value for input field `email`: must be a valid email address",
+        );
+    }
+
+    #[cfg(feature = "schema-language")]
+    #[test]
+    fn exposes_constraints_in_schema() {
+        let sdl = schema().as_schema_language();
+
+        assert!(
+            sdl.contains(
+                "users(first: Int! @constraint(min: 1, max: 100), \
+                 ids: [Int!] @constraint(minItems: 1)): Int!",
+            ),
+            "{}",
+            sdl,
+        );
+        assert!(
+            sdl.contains("login: String! @constraint(minLength: 3)"),
+            "{}",
+            sdl,
+        );
+        assert!(
+            sdl.contains("email: String @constraint(format: \"email\")"),
+            "{}",
+            sdl,
+        );
+        assert!(
+            sdl.contains(
+                "directive @constraint(min: Float, max: Float, minLength: Int, maxLength: Int, \
+                 pattern: String, format: String, minItems: Int, maxItems: Int) \
+                 on ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION",
+            ),
+            "{}",
+            sdl,
+        );
+    }
+}
@@ -0,0 +1,456 @@
+//! In-process caching of the responses to queries, following their
+//! [`CachePolicy`].
+//!
+//! Set a [`ResponseCache`] with [`RootNode::with_response_cache()`] to reuse
+//! the responses to the cacheable queries executed with
+//! [`GraphQLRequest::execute()`] and [`GraphQLRequest::execute_sync()`].
+//! Responses are keyed by the [`CacheKey`] of their queries, so the
+//! [`CacheScope::Private`] ones are cached only for the requests having a
+//! [`GraphQLRequest::cache_session`].
+//!
+//! [`GraphQLRequest::cache_session`]: super::GraphQLRequest::cache_session
+//! [`GraphQLRequest::execute()`]: super::GraphQLRequest::execute
+//! [`GraphQLRequest::execute_sync()`]: super::GraphQLRequest::execute_sync
+//! [`RootNode::with_response_cache()`]: crate::RootNode::with_response_cache
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    ast::{Document, Operation},
+    executor::{cache_policy, CachePolicy, CacheScope, ExecutionError, Variables},
+    parser::{print_document, PrintStyle},
+    schema::model::SchemaType,
+    value::{ScalarValue, Value},
+};
+
+/// Key of a cached response, identifying the query it responds to.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CacheKey {
+    /// Minified source of the document containing the query.
+    pub document: String,
+
+    /// Name of the executed operation, if any.
+    pub operation_name: Option<String>,
+
+    /// Variables of the query, sorted by name and printed as GraphQL values.
+    pub variables: String,
+
+    /// Scope of the [`CachePolicy`] of the query.
+    pub scope: CacheScope,
+
+    /// Session of the client the [`CacheScope::Private`] response is cached
+    /// for.
+    pub session: Option<String>,
+}
+
+impl CacheKey {
+    /// Creates a new [`CacheKey`] for the operation with the provided
+    /// `operation_name` in the provided `document`.
+    ///
+    /// Returns [`None`] if the `scope` is [`CacheScope::Private`], but there
+    /// is no `session` to cache the response for.
+    pub fn new<S: ScalarValue>(
+        document: &Document<S>,
+        operation_name: Option<&str>,
+        variables: &Variables<S>,
+        scope: CacheScope,
+        session: Option<&str>,
+    ) -> Option<Self> {
+        let session = match scope {
+            CacheScope::Public => None,
+            CacheScope::Private => Some(session?.to_owned()),
+        };
+
+        let mut vars = variables.iter().collect::<Vec<_>>();
+        vars.sort_by_key(|(name, _)| *name);
+
+        Some(Self {
+            document: print_document(document, PrintStyle::Minified),
+            operation_name: operation_name.map(Into::into),
+            variables: vars
+                .into_iter()
+                .map(|(name, value)| format!("${}:{}", name, value))
+                .collect::<Vec<_>>()
+                .join(","),
+            scope,
+            session,
+        })
+    }
+}
+
+/// Response cached in a [`ResponseCache`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CachedResponse<S> {
+    /// Data of the response.
+    pub data: Value<S>,
+
+    /// [`CachePolicy`] of the response. When returned from a cache, its
+    /// `max_age` is the number of seconds remaining until it expires.
+    pub policy: CachePolicy,
+}
+
+/// Store of the [`CachedResponse`]s, reusing them for the same queries until
+/// they expire.
+pub trait ResponseCache<S>: Send + Sync {
+    /// Returns the unexpired response cached with the provided `key`, if any.
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse<S>>;
+
+    /// Caches the provided `response` with the provided `key`, for the
+    /// `max_age` of its [`CachePolicy`].
+    fn insert(&self, key: CacheKey, response: CachedResponse<S>);
+}
+
+/// [`ResponseCache`] keeping the responses in memory, up to a limited number
+/// of them.
+pub struct InMemoryResponseCache<S> {
+    max_entries: usize,
+    entries: Mutex<HashMap<CacheKey, CacheEntry<S>>>,
+}
+
+/// Response cached in an [`InMemoryResponseCache`].
+struct CacheEntry<S> {
+    data: Value<S>,
+    scope: CacheScope,
+    expires_at: Instant,
+}
+
+impl<S> InMemoryResponseCache<S> {
+    /// Creates a new empty [`InMemoryResponseCache`], keeping at most the
+    /// provided number of responses.
+    ///
+    /// When full, new responses aren't cached until the cached ones expire.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the number of the cached responses, including the expired ones
+    /// which haven't been removed yet.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// Indicates whether there are no cached responses.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<S> fmt::Debug for InMemoryResponseCache<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InMemoryResponseCache")
+            .field("max_entries", &self.max_entries)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<S: ScalarValue + Send> ResponseCache<S> for InMemoryResponseCache<S> {
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse<S>> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.get(key)?;
+        let max_age = entry
+            .expires_at
+            .saturating_duration_since(Instant::now())
+            .as_secs();
+        if max_age == 0 {
+            entries.remove(key);
+            return None;
+        }
+        Some(CachedResponse {
+            data: entry.data.clone(),
+            policy: CachePolicy::new(max_age as u32, entry.scope),
+        })
+    }
+
+    fn insert(&self, key: CacheKey, response: CachedResponse<S>) {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= self.max_entries {
+            entries.retain(|_, entry| entry.expires_at > now);
+        }
+        if entries.len() < self.max_entries {
+            let entry = CacheEntry {
+                data: response.data,
+                scope: response.policy.scope,
+                expires_at: now + Duration::from_secs(response.policy.max_age.into()),
+            };
+            entries.insert(key, entry);
+        }
+    }
+}
+
+/// [`ResponseCache`] of a schema.
+pub(crate) struct SchemaResponseCache<S>(pub(crate) Box<dyn ResponseCache<S>>);
+
+impl<S> fmt::Debug for SchemaResponseCache<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ResponseCache")
+    }
+}
+
+/// Lookup of the response to an operation in a [`ResponseCache`].
+pub(crate) struct CacheLookup<'c, S> {
+    /// [`CachePolicy`] of the operation, if its schema uses caching hints.
+    policy: Option<CachePolicy>,
+
+    /// Cache to reuse the response from, and its key.
+    cache: Option<(&'c dyn ResponseCache<S>, CacheKey)>,
+}
+
+impl<'c, S: ScalarValue> CacheLookup<'c, S> {
+    /// Computes the [`CachePolicy`] of the provided `operation` and the key
+    /// of its response in the provided `cache`, if it's cacheable.
+    pub(crate) fn new(
+        schema: &SchemaType<S>,
+        cache: Option<&'c dyn ResponseCache<S>>,
+        document: &Document<S>,
+        operation: &Operation<S>,
+        variables: &Variables<S>,
+        session: Option<&str>,
+    ) -> Self {
+        if schema
+            .directive_by_name(crate::executor::cache_control::DIRECTIVE_NAME)
+            .is_none()
+        {
+            return Self {
+                policy: None,
+                cache: None,
+            };
+        }
+
+        let policy = cache_policy(schema, document, operation);
+        let operation_name = operation.name.as_ref().map(|n| n.item);
+        Self {
+            policy: Some(policy),
+            cache: cache.filter(|_| policy.is_cacheable()).and_then(|cache| {
+                let key =
+                    CacheKey::new(document, operation_name, variables, policy.scope, session)?;
+                Some((cache, key))
+            }),
+        }
+    }
+
+    /// Returns the cached response, if any.
+    pub(crate) fn get(&self) -> Option<CachedResponse<S>> {
+        let (cache, key) = self.cache.as_ref()?;
+        cache.get(key)
+    }
+
+    /// Caches the provided response, if it has no errors, and returns its
+    /// [`CachePolicy`].
+    pub(crate) fn insert(
+        self,
+        data: &Value<S>,
+        errors: &[ExecutionError<S>],
+    ) -> Option<CachePolicy> {
+        if !errors.is_empty() {
+            return self.policy.map(|_| CachePolicy::NO_STORE);
+        }
+        if let (Some((cache, key)), Some(policy)) = (self.cache, self.policy) {
+            cache.insert(
+                key,
+                CachedResponse {
+                    data: data.clone(),
+                    policy,
+                },
+            );
+        }
+        self.policy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::{
+        graphql_object, http::GraphQLRequest, parser::parse_document_source, CachePolicy,
+        CacheScope, EmptyMutation, EmptySubscription, InputValue, RootNode, Variables,
+    };
+
+    use super::{CacheKey, InMemoryResponseCache};
+
+    #[derive(Default)]
+    struct Context {
+        calls: AtomicUsize,
+    }
+
+    impl crate::Context for Context {}
+
+    struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        #[graphql(cache_control(max_age = 60))]
+        fn count(context: &Context, offset: Option<i32>) -> i32 {
+            context.calls.fetch_add(1, Ordering::SeqCst) as i32 + offset.unwrap_or(0)
+        }
+
+        #[graphql(cache_control(max_age = 60, scope = "private"))]
+        fn secret(context: &Context) -> i32 {
+            context.calls.fetch_add(1, Ordering::SeqCst) as i32
+        }
+
+        fn now(context: &Context) -> i32 {
+            context.calls.fetch_add(1, Ordering::SeqCst) as i32
+        }
+    }
+
+    type Schema = RootNode<'static, Query, EmptyMutation<Context>, EmptySubscription<Context>>;
+
+    fn schema() -> Schema {
+        RootNode::new(
+            Query,
+            EmptyMutation::<Context>::new(),
+            EmptySubscription::<Context>::new(),
+        )
+        .with_response_cache(InMemoryResponseCache::new(10))
+    }
+
+    fn request(query: &str) -> GraphQLRequest {
+        GraphQLRequest::new(query.into(), None, None)
+    }
+
+    #[test]
+    fn reuses_cached_responses() {
+        let schema = schema();
+        let ctx = Context::default();
+
+        let req = request("{ count }");
+        let resp = req.execute_sync(&schema, &ctx);
+        assert_eq!(resp.0, Ok((graphql_value!({"count": 0}), vec![])));
+        assert_eq!(
+            resp.cache_policy(),
+            Some(CachePolicy::new(60, CacheScope::Public)),
+        );
+
+        // Formatting doesn't matter, as documents are keyed in their minified
+        // printed form.
+        let req = request("query {\n  count\n}");
+        let resp = req.execute_sync(&schema, &ctx);
+        assert_eq!(resp.0, Ok((graphql_value!({"count": 0}), vec![])));
+        assert!(resp.cache_policy().unwrap().is_cacheable());
+
+        let req = request("{ count(offset: 10) }");
+        let resp = req.execute_sync(&schema, &ctx);
+        assert_eq!(resp.0, Ok((graphql_value!({"count": 11}), vec![])));
+
+        assert_eq!(ctx.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn reuses_cached_responses_async() {
+        let schema = schema();
+        let ctx = Context::default();
+
+        for _ in 0..3 {
+            let req = request("{ count }");
+            let resp = req.execute(&schema, &ctx).await;
+            assert_eq!(resp.0, Ok((graphql_value!({"count": 0}), vec![])));
+        }
+
+        assert_eq!(ctx.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn caches_private_responses_per_session() {
+        let schema = schema();
+        let ctx = Context::default();
+
+        let req = request("{ secret }");
+        let resp = req.execute_sync(&schema, &ctx);
+        assert_eq!(
+            resp.cache_policy(),
+            Some(CachePolicy::new(60, CacheScope::Private)),
+        );
+        let _ = request("{ secret }").execute_sync(&schema, &ctx);
+        assert_eq!(ctx.calls.load(Ordering::SeqCst), 2);
+
+        for session in ["alice", "alice", "bob"] {
+            let _ = request("{ secret }")
+                .with_cache_session(session)
+                .execute_sync(&schema, &ctx);
+        }
+        assert_eq!(ctx.calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn skips_uncacheable_responses() {
+        let schema = schema();
+        let ctx = Context::default();
+
+        for _ in 0..2 {
+            let req = request("{ count now }");
+            let resp = req.execute_sync(&schema, &ctx);
+            assert_eq!(resp.cache_policy(), Some(CachePolicy::NO_STORE));
+        }
+        assert_eq!(ctx.calls.load(Ordering::SeqCst), 4);
+
+        let req = request("{ unknown }");
+        let resp = req.execute_sync(&schema, &ctx);
+        assert_eq!(resp.cache_policy(), None);
+    }
+
+    #[test]
+    fn keys_by_sorted_variables() {
+        let schema = schema();
+        let document = parse_document_source::<crate::DefaultScalarValue>(
+            "query($a: Int, $b: Int) { count }",
+            &schema.schema,
+        )
+        .unwrap();
+        let vars = |a, b| {
+            let mut vars = Variables::new();
+            vars.insert("b".into(), InputValue::scalar(b));
+            vars.insert("a".into(), InputValue::scalar(a));
+            vars
+        };
+
+        let key = CacheKey::new(&document, None, &vars(1, 2), CacheScope::Public, None).unwrap();
+        assert_eq!(key.variables, "$a:1,$b:2");
+        assert_eq!(key.session, None);
+        assert_ne!(
+            Some(key),
+            CacheKey::new(&document, None, &vars(2, 1), CacheScope::Public, None),
+        );
+
+        assert_eq!(
+            CacheKey::new(&document, None, &vars(1, 2), CacheScope::Private, None),
+            None,
+        );
+        let key = CacheKey::new(
+            &document,
+            None,
+            &vars(1, 2),
+            CacheScope::Private,
+            Some("alice"),
+        )
+        .unwrap();
+        assert_eq!(key.session.as_deref(), Some("alice"));
+    }
+
+    #[test]
+    fn limits_entries() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Context>::new(),
+            EmptySubscription::<Context>::new(),
+        )
+        .with_response_cache(InMemoryResponseCache::new(1));
+        let ctx = Context::default();
+
+        for _ in 0..2 {
+            let _ = request("{ count }").execute_sync(&schema, &ctx);
+            let _ = request("{ count(offset: 1) }").execute_sync(&schema, &ctx);
+        }
+        assert_eq!(ctx.calls.load(Ordering::SeqCst), 3);
+    }
+}
@@ -1,7 +1,12 @@
 //! Utilities for building HTTP endpoints in a library-agnostic manner
 
+pub mod cache;
 pub mod graphiql;
 pub mod playground;
+#[cfg(feature = "sse")]
+pub mod sse;
+#[cfg(feature = "trusted-documents")]
+pub mod trusted_documents;
 
 use serde::{
     de,
@@ -11,25 +16,31 @@ use serde::{
 
 use crate::{
     ast::InputValue,
-    executor::{ExecutionError, ValuesStream},
+    executor::{
+        execute_validated_query, execute_validated_query_async, CachePolicy, ExecutionError,
+        ValuesStream,
+    },
     value::{DefaultScalarValue, ScalarValue},
     FieldError, GraphQLError, GraphQLSubscriptionType, GraphQLType, GraphQLTypeAsync, RootNode,
     Value, Variables,
 };
 
+use self::cache::CacheLookup;
+
 /// The expected structure of the decoded JSON document for either POST or GET requests.
 ///
 /// For POST, you can use Serde to deserialize the incoming JSON data directly
 /// into this struct - it derives Deserialize for exactly this reason.
 ///
 /// For GET, you will need to parse the query string and extract "query",
-/// "operationName", and "variables" manually.
+/// "operationName", "variables" and "documentId" manually.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GraphQLRequest<S = DefaultScalarValue>
 where
     S: ScalarValue,
 {
     /// GraphQL query representing this request.
+    #[serde(default)]
     pub query: String,
 
     /// Optional name of the operation associated with this request.
@@ -42,6 +53,25 @@ where
         serialize = "InputValue<S>: Serialize",
     ))]
     pub variables: Option<InputValue<S>>,
+
+    /// Optional ID of the trusted document to execute instead of the `query`.
+    ///
+    /// See the [`trusted_documents`] module for details.
+    #[serde(
+        rename = "documentId",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub document_id: Option<String>,
+
+    /// Optional session of the client sending this request, allowing to cache
+    /// the [`CacheScope::Private`] responses for it.
+    ///
+    /// See the [`cache`] module for details.
+    ///
+    /// [`CacheScope::Private`]: crate::CacheScope::Private
+    #[serde(skip)]
+    pub cache_session: Option<String>,
 }
 
 impl<S> GraphQLRequest<S>
@@ -79,13 +109,78 @@ where
             query,
             operation_name,
             variables,
+            document_id: None,
+            cache_session: None,
         }
     }
 
+    /// Sets the ID of the trusted document to execute instead of the `query`.
+    ///
+    /// See the [`trusted_documents`] module for details.
+    #[must_use]
+    pub fn with_document_id(mut self, document_id: impl Into<String>) -> Self {
+        self.document_id = Some(document_id.into());
+        self
+    }
+
+    /// Sets the session of the client sending this request, allowing to cache
+    /// the [`CacheScope::Private`] responses for it.
+    ///
+    /// See the [`cache`] module for details.
+    ///
+    /// [`CacheScope::Private`]: crate::CacheScope::Private
+    #[must_use]
+    pub fn with_cache_session(mut self, session: impl Into<String>) -> Self {
+        self.cache_session = Some(session.into());
+        self
+    }
+
+    /// Returns the source of the document to execute and the name of its
+    /// operation, resolving the `document_id` with the [`TrustedDocuments`]
+    /// of the provided `root_node`.
+    ///
+    /// Rejects the `query` with [`GraphQLError::UnknownDocument`] if the
+    /// `root_node` is restricted to [`TrustedDocuments`] and it isn't one of
+    /// them.
+    ///
+    /// [`TrustedDocuments`]: trusted_documents::TrustedDocuments
+    fn document<'a, QueryT, MutationT, SubscriptionT>(
+        &'a self,
+        #[allow(unused_variables)] root_node: &'a RootNode<QueryT, MutationT, SubscriptionT, S>,
+    ) -> Result<(&'a str, Option<&'a str>), GraphQLError<'a>>
+    where
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S>,
+        SubscriptionT: GraphQLType<S>,
+    {
+        let operation_name = self.operation_name.as_deref();
+
+        #[cfg(feature = "trusted-documents")]
+        if let Some(documents) = root_node.trusted_documents() {
+            return match self.document_id.as_deref() {
+                Some(id) => documents
+                    .lookup(id)
+                    .map(|(source, name)| (source, operation_name.or(name))),
+                None => documents
+                    .contains(&self.query)
+                    .then_some((self.query.as_str(), operation_name)),
+            }
+            .ok_or(GraphQLError::UnknownDocument);
+        }
+
+        if self.document_id.is_some() && self.query.is_empty() {
+            return Err(GraphQLError::UnknownDocument);
+        }
+        Ok((&self.query, operation_name))
+    }
+
     /// Execute a GraphQL request synchronously using the specified schema and context
     ///
     /// This is a simple wrapper around the `execute_sync` function exposed at the
-    /// top level of this crate.
+    /// top level of this crate, reusing the responses cached in the
+    /// [`ResponseCache`] of the provided `root_node`, if any.
+    ///
+    /// [`ResponseCache`]: cache::ResponseCache
     pub fn execute_sync<'a, QueryT, MutationT, SubscriptionT>(
         &'a self,
         root_node: &'a RootNode<QueryT, MutationT, SubscriptionT, S>,
@@ -97,19 +192,38 @@ where
         MutationT: GraphQLType<S, Context = QueryT::Context>,
         SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
     {
-        GraphQLResponse(crate::execute_sync(
-            &self.query,
-            self.operation_name.as_deref(),
-            root_node,
-            &self.variables(),
-            context,
-        ))
+        GraphQLResponse::from_cached_result((|| {
+            let (source, op) = self.document(root_node)?;
+            let vars = &self.variables();
+            let document = crate::prepare_document(source, root_node)?;
+            let operation = crate::prepare_operation(&document, op, root_node, vars)?;
+
+            let lookup = CacheLookup::new(
+                &root_node.schema,
+                root_node.response_cache(),
+                &document,
+                &operation.item,
+                vars,
+                self.cache_session.as_deref(),
+            );
+            if let Some(cached) = lookup.get() {
+                return Ok((cached.data, vec![], Some(cached.policy)));
+            }
+
+            let (data, errors) =
+                execute_validated_query(&document, operation, root_node, vars, context)?;
+            let policy = lookup.insert(&data, &errors);
+            Ok((data, errors, policy))
+        })())
     }
 
     /// Execute a GraphQL request using the specified schema and context
     ///
     /// This is a simple wrapper around the `execute` function exposed at the
-    /// top level of this crate.
+    /// top level of this crate, reusing the responses cached in the
+    /// [`ResponseCache`] of the provided `root_node`, if any.
+    ///
+    /// [`ResponseCache`]: cache::ResponseCache
     pub async fn execute<'a, QueryT, MutationT, SubscriptionT>(
         &'a self,
         root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
@@ -125,10 +239,33 @@ where
         SubscriptionT::TypeInfo: Sync,
         S: ScalarValue + Send + Sync,
     {
-        let op = self.operation_name.as_deref();
-        let vars = &self.variables();
-        let res = crate::execute(&self.query, op, root_node, vars, context).await;
-        GraphQLResponse(res)
+        GraphQLResponse::from_cached_result(
+            async {
+                let (source, op) = self.document(root_node)?;
+                let vars = &self.variables();
+                let document = crate::prepare_document(source, root_node)?;
+                let operation = crate::prepare_operation(&document, op, root_node, vars)?;
+
+                let lookup = CacheLookup::new(
+                    &root_node.schema,
+                    root_node.response_cache(),
+                    &document,
+                    &operation.item,
+                    vars,
+                    self.cache_session.as_deref(),
+                );
+                if let Some(cached) = lookup.get() {
+                    return Ok((cached.data, vec![], Some(cached.policy)));
+                }
+
+                let (data, errors) =
+                    execute_validated_query_async(&document, operation, root_node, vars, context)
+                        .await?;
+                let policy = lookup.insert(&data, &errors);
+                Ok((data, errors, policy))
+            }
+            .await,
+        )
     }
 }
 
@@ -154,12 +291,16 @@ where
     SubscriptionT::TypeInfo: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let op = req.operation_name.as_deref();
+    let (source, op) = req.document(root_node)?;
     let vars = req.variables();
 
-    crate::resolve_into_stream(&req.query, op, root_node, &vars, context).await
+    crate::resolve_into_stream(source, op, root_node, &vars, context).await
 }
 
+/// Result of executing a GraphQL query along with its [`CachePolicy`].
+type CachedResult<'a, S> =
+    Result<(Value<S>, Vec<ExecutionError<S>>, Option<CachePolicy>), GraphQLError<'a>>;
+
 /// Simple wrapper around the result from executing a GraphQL query
 ///
 /// This struct implements Serialize, so you can simply serialize this
@@ -168,6 +309,7 @@ where
 #[derive(Debug)]
 pub struct GraphQLResponse<'a, S = DefaultScalarValue>(
     Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError<'a>>,
+    Option<CachePolicy>,
 );
 
 impl<'a, S> GraphQLResponse<'a, S>
@@ -176,12 +318,35 @@ where
 {
     /// Constructs new `GraphQLResponse` using the given result
     pub fn from_result(r: Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError<'a>>) -> Self {
-        Self(r)
+        Self(r, None)
+    }
+
+    /// Constructs new `GraphQLResponse` using the given result along with its
+    /// [`CachePolicy`].
+    fn from_cached_result(r: CachedResult<'a, S>) -> Self {
+        match r {
+            Ok((data, errors, policy)) => Self(Ok((data, errors)), policy),
+            Err(e) => Self(Err(e), None),
+        }
     }
 
     /// Constructs an error response outside of the normal execution flow
     pub fn error(error: FieldError<S>) -> Self {
-        GraphQLResponse(Ok((Value::null(), vec![ExecutionError::at_origin(error)])))
+        GraphQLResponse(
+            Ok((Value::null(), vec![ExecutionError::at_origin(error)])),
+            None,
+        )
+    }
+
+    /// Returns the [`CachePolicy`] of this response, if its schema has any
+    /// `@cacheControl` hints.
+    ///
+    /// Responses with errors are never cacheable.
+    pub fn cache_policy(&self) -> Option<CachePolicy> {
+        match &self.0 {
+            Ok((_, errors)) if errors.is_empty() => self.1,
+            _ => self.1.map(|_| CachePolicy::NO_STORE),
+        }
     }
 
     /// Was the request successful or not?
@@ -362,6 +527,21 @@ where
             Self::Batch(resps) => resps.iter().all(GraphQLResponse::is_ok),
         }
     }
+
+    /// Returns the [`CachePolicy`] satisfying all the GraphQLResponse in this
+    /// operation, if their schema has any `@cacheControl` hints.
+    ///
+    /// Use it to determine the value of the HTTP `Cache-Control` header.
+    pub fn cache_policy(&self) -> Option<CachePolicy> {
+        match self {
+            Self::Single(resp) => resp.cache_policy(),
+            Self::Batch(resps) => resps
+                .iter()
+                .map(GraphQLResponse::cache_policy)
+                .reduce(|a, b| Some(a?.restrict(b?)))
+                .flatten(),
+        }
+    }
 }
 
 #[cfg(feature = "expose-test-schema")]
@@ -430,6 +610,9 @@ pub mod tests {
 
         println!("  - test_invalid_graphql_post");
         test_invalid_graphql_post(integration);
+
+        println!("  - test_too_deep_graphql_post");
+        test_too_deep_graphql_post(integration);
     }
 
     fn unwrap_json_response(response: &TestResponse) -> Json {
@@ -596,6 +779,190 @@ pub mod tests {
         assert_eq!(resp.status_code, 400);
     }
 
+    fn test_too_deep_graphql_post<T: HttpIntegration>(integration: &T) {
+        let query = format!(
+            "{{hero{}{{name}}{}}}",
+            "{friends".repeat(100),
+            "}".repeat(100),
+        );
+        let resp = integration.post_graphql("/", &query);
+
+        assert_eq!(resp.status_code, 400);
+        assert_eq!(
+            unwrap_json_response(&resp)["errors"][0]["message"],
+            "Document exceeds the maximum nesting depth of 64",
+        );
+    }
+
+    #[cfg(feature = "trusted-documents")]
+    pub use self::trusted_documents::{
+        run_trusted_documents_test_suite, with_trusted_documents, TRUSTED_DOCUMENTS,
+    };
+
+    #[cfg(feature = "trusted-documents")]
+    mod trusted_documents {
+        use crate::{
+            http::trusted_documents::{operation_hash, TrustedDocuments},
+            parser::parse_document_source,
+            tests::fixtures::starwars::schema::{Database, Query},
+            DefaultScalarValue, EmptyMutation, EmptySubscription, GraphQLType, RootNode,
+            ScalarValue,
+        };
+
+        use super::*;
+
+        /// Trusted documents (pairs of an ID and a source) of the schema tested with
+        /// [`run_trusted_documents_test_suite()`].
+        pub const TRUSTED_DOCUMENTS: &[(&str, &str)] = &[
+            ("hero", "{hero{name}}"),
+            (
+                "characters",
+                r#"query Human($id: String!) { human(id: $id) { name } }
+                   query Droid { droid(id: "2001") { name } }"#,
+            ),
+        ];
+
+        /// Restricts the provided `root_node` to the [`TRUSTED_DOCUMENTS`], for
+        /// testing the integration serving it with
+        /// [`run_trusted_documents_test_suite()`].
+        pub fn with_trusted_documents<'a, QueryT, MutationT, SubscriptionT, S>(
+            root_node: RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+        ) -> RootNode<'a, QueryT, MutationT, SubscriptionT, S>
+        where
+            QueryT: GraphQLType<S>,
+            MutationT: GraphQLType<S>,
+            SubscriptionT: GraphQLType<S>,
+            S: ScalarValue + 'a,
+        {
+            let documents =
+                TrustedDocuments::new(&root_node.schema, TRUSTED_DOCUMENTS.iter().copied())
+                    .expect("Invalid trusted documents in test");
+            root_node.with_trusted_documents(documents)
+        }
+
+        #[allow(missing_docs)]
+        pub fn run_trusted_documents_test_suite<T: HttpIntegration>(integration: &T) {
+            println!("Running trusted documents test suite for integration");
+
+            println!("  - test_trusted_document_get");
+            test_trusted_document_get(integration);
+
+            println!("  - test_trusted_document_post");
+            test_trusted_document_post(integration);
+
+            println!("  - test_trusted_document_hash");
+            test_trusted_document_hash(integration);
+
+            println!("  - test_trusted_query");
+            test_trusted_query(integration);
+
+            println!("  - test_untrusted_documents");
+            test_untrusted_documents(integration);
+        }
+
+        fn test_trusted_document_get<T: HttpIntegration>(integration: &T) {
+            let resp = integration.get("/?documentId=hero");
+
+            assert_eq!(resp.status_code, 200);
+            assert_eq!(
+                unwrap_json_response(&resp),
+                serde_json::from_str::<Json>(r#"{"data": {"hero": {"name": "R2-D2"}}}"#)
+                    .expect("Invalid JSON constant in test"),
+            );
+        }
+
+        fn test_trusted_document_post<T: HttpIntegration>(integration: &T) {
+            let resp = integration.post_json(
+                "/",
+                r#"{
+                    "documentId": "characters",
+                    "operationName": "Human",
+                    "variables": {"id": "1000"}
+                }"#,
+            );
+
+            assert_eq!(resp.status_code, 200);
+            assert_eq!(
+                unwrap_json_response(&resp),
+                serde_json::from_str::<Json>(r#"{"data": {"human": {"name": "Luke Skywalker"}}}"#)
+                    .expect("Invalid JSON constant in test"),
+            );
+        }
+
+        /// Computes the [`operation_hash()`] of the single operation in the
+        /// provided `source`.
+        fn hash(source: &str) -> String {
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<Database>::new(),
+                EmptySubscription::<Database>::new(),
+            );
+            let document = parse_document_source::<DefaultScalarValue>(source, &schema.schema)
+                .expect("Invalid document in test");
+            operation_hash(&document, None).expect("Missing operation in test")
+        }
+
+        fn test_trusted_document_hash<T: HttpIntegration>(integration: &T) {
+            // Hashes don't depend on formatting.
+            let hash = hash("query Droid {\n  droid(id: \"2001\") {\n    name\n  }\n}");
+
+            let resp = integration.post_json("/", &format!(r#"{{"documentId": "{}"}}"#, hash));
+
+            assert_eq!(resp.status_code, 200);
+            assert_eq!(
+                unwrap_json_response(&resp),
+                serde_json::from_str::<Json>(r#"{"data": {"droid": {"name": "R2-D2"}}}"#)
+                    .expect("Invalid JSON constant in test"),
+            );
+        }
+
+        fn test_trusted_query<T: HttpIntegration>(integration: &T) {
+            let resp = integration.post_graphql("/", "{hero{name}}");
+
+            assert_eq!(resp.status_code, 200);
+            assert_eq!(
+                unwrap_json_response(&resp),
+                serde_json::from_str::<Json>(r#"{"data": {"hero": {"name": "R2-D2"}}}"#)
+                    .expect("Invalid JSON constant in test"),
+            );
+        }
+
+        fn test_untrusted_documents<T: HttpIntegration>(integration: &T) {
+            for resp in [
+                // Equivalent, but not the exact source of a trusted document.
+                integration.post_graphql("/", "{ hero { name } }"),
+                // Rejected before parsing.
+                integration.post_graphql("/", "{hero{name}"),
+                integration.post_json("/", r#"{"documentId": "villain"}"#),
+                integration.get("/?documentId=villain"),
+                // Hashes depend on literals and aliases.
+                integration.post_json(
+                    "/",
+                    &format!(
+                        r#"{{"documentId": "{}"}}"#,
+                        hash(r#"query Droid { droid(id: "2000") { name } }"#),
+                    ),
+                ),
+                integration.post_json(
+                    "/",
+                    &format!(
+                        r#"{{"documentId": "{}"}}"#,
+                        hash(r#"query Droid { robot: droid(id: "2001") { name } }"#),
+                    ),
+                ),
+            ] {
+                assert_eq!(resp.status_code, 400);
+                assert_eq!(
+                    unwrap_json_response(&resp),
+                    serde_json::from_str::<Json>(
+                        r#"{"errors": [{"message": "Unknown document"}]}"#
+                    )
+                    .expect("Invalid JSON constant in test"),
+                );
+            }
+        }
+    }
+
     /// Normalized way to make requests to the WebSocket framework integration we are testing.
     pub trait WsIntegration {
         /// Runs a test with the given messages
@@ -763,4 +1130,265 @@ pub mod tests {
 
         integration.run(messages).await.unwrap();
     }
+
+    /// Normalized response we expect to get back from the SSE framework
+    /// integration we are testing.
+    #[cfg(feature = "sse")]
+    pub struct SseTestResponse {
+        pub status_code: i32,
+        pub content_type: String,
+        /// Chunks of the response body, as they arrive.
+        pub body: futures::stream::LocalBoxStream<'static, String>,
+    }
+
+    /// Normalized way to make requests to the SSE framework integration we are testing.
+    #[cfg(feature = "sse")]
+    pub trait SseIntegration {
+        /// Sends HTTP request with the provided `method`, `url` parameters string, `headers` and
+        /// `body` to this integration, and returns response returned by this integration as soon
+        /// as its headers are received.
+        fn request<'a>(
+            &'a self,
+            method: &'a str,
+            url: &'a str,
+            headers: &'a [(&'a str, &'a str)],
+            body: Option<&'a str>,
+        ) -> LocalBoxFuture<'a, SseTestResponse>;
+    }
+
+    #[cfg(feature = "sse")]
+    #[allow(missing_docs)]
+    pub async fn run_sse_test_suite<T: SseIntegration>(integration: &T) {
+        println!("Running SSE Test suite for integration");
+
+        println!("  - test_sse_distinct_subscription");
+        test_sse_distinct_subscription(integration).await;
+
+        println!("  - test_sse_distinct_query");
+        test_sse_distinct_query(integration).await;
+
+        println!("  - test_sse_distinct_invalid_query");
+        test_sse_distinct_invalid_query(integration).await;
+
+        println!("  - test_sse_single_connection");
+        test_sse_single_connection(integration).await;
+
+        println!("  - test_sse_single_connection_unknown_token");
+        test_sse_single_connection_unknown_token(integration).await;
+    }
+
+    /// Reader of the events from an [`SseTestResponse::body`].
+    #[cfg(feature = "sse")]
+    struct SseEvents {
+        body: futures::stream::LocalBoxStream<'static, String>,
+        buf: String,
+    }
+
+    #[cfg(feature = "sse")]
+    impl SseEvents {
+        fn new(resp: SseTestResponse) -> Self {
+            Self {
+                body: resp.body,
+                buf: String::new(),
+            }
+        }
+
+        /// Reads the next event, returning its name and JSON data.
+        async fn next(&mut self) -> Option<(String, Json)> {
+            use futures::StreamExt as _;
+
+            loop {
+                if let Some(end) = self.buf.find("\n\n") {
+                    let raw = self.buf.drain(..end + 2).collect::<String>();
+                    let (mut event, mut data) = (String::new(), String::new());
+                    for line in raw.lines() {
+                        if let Some(e) = line.strip_prefix("event:") {
+                            event = e.trim().into();
+                        } else if let Some(d) = line.strip_prefix("data:") {
+                            data.push_str(d.trim());
+                        }
+                    }
+                    let data = if data.is_empty() {
+                        Json::Null
+                    } else {
+                        serde_json::from_str(&data).expect("Invalid JSON in event data")
+                    };
+                    return Some((event, data));
+                }
+                self.buf.push_str(&self.body.next().await?);
+            }
+        }
+    }
+
+    #[cfg(feature = "sse")]
+    async fn collect_body(resp: SseTestResponse) -> String {
+        use futures::StreamExt as _;
+
+        resp.body.collect::<Vec<_>>().await.concat()
+    }
+
+    #[cfg(feature = "sse")]
+    const SSE_JSON_HEADERS: &[(&str, &str)] = &[
+        ("content-type", "application/json"),
+        ("accept", "text/event-stream"),
+    ];
+
+    #[cfg(feature = "sse")]
+    async fn test_sse_distinct_subscription<T: SseIntegration>(integration: &T) {
+        let resp = integration
+            .request(
+                "POST",
+                "/",
+                SSE_JSON_HEADERS,
+                Some(r#"{"query": "subscription { asyncHuman { id, name, homePlanet } }"}"#),
+            )
+            .await;
+
+        assert_eq!(resp.status_code, 200);
+        assert!(resp
+            .content_type
+            .starts_with(crate::http::sse::CONTENT_TYPE));
+
+        let mut events = SseEvents::new(resp);
+        assert_eq!(
+            events.next().await,
+            Some((
+                "next".into(),
+                serde_json::json!({"data": {"asyncHuman": {
+                    "id": "1000",
+                    "name": "Luke Skywalker",
+                    "homePlanet": "Tatooine",
+                }}}),
+            )),
+        );
+        assert_eq!(events.next().await, Some(("complete".into(), Json::Null)));
+        assert_eq!(events.next().await, None);
+    }
+
+    #[cfg(feature = "sse")]
+    async fn test_sse_distinct_query<T: SseIntegration>(integration: &T) {
+        // {hero{name}}
+        let resp = integration
+            .request(
+                "GET",
+                "/?query=%7Bhero%7Bname%7D%7D",
+                &[("accept", "text/event-stream")],
+                None,
+            )
+            .await;
+
+        assert_eq!(resp.status_code, 200);
+
+        let mut events = SseEvents::new(resp);
+        assert_eq!(
+            events.next().await,
+            Some((
+                "next".into(),
+                serde_json::json!({"data": {"hero": {"name": "R2-D2"}}}),
+            )),
+        );
+        assert_eq!(events.next().await, Some(("complete".into(), Json::Null)));
+    }
+
+    #[cfg(feature = "sse")]
+    async fn test_sse_distinct_invalid_query<T: SseIntegration>(integration: &T) {
+        let resp = integration
+            .request(
+                "POST",
+                "/",
+                SSE_JSON_HEADERS,
+                Some(r#"{"query": "subscription { asyncHuman }"}"#),
+            )
+            .await;
+
+        assert_eq!(resp.status_code, 400);
+        assert_eq!(resp.content_type, "application/json");
+
+        let body = serde_json::from_str::<Json>(&collect_body(resp).await)
+            .expect("Could not parse JSON object");
+        assert!(body["errors"].is_array());
+    }
+
+    #[cfg(feature = "sse")]
+    async fn test_sse_single_connection<T: SseIntegration>(integration: &T) {
+        use crate::http::sse::TOKEN_HEADER;
+
+        let resp = integration.request("PUT", "/", &[], None).await;
+        assert_eq!(resp.status_code, 201);
+        let token = collect_body(resp).await;
+
+        let headers = [("accept", "text/event-stream"), (TOKEN_HEADER, &*token)];
+        let resp = integration.request("GET", "/", &headers, None).await;
+        assert_eq!(resp.status_code, 200);
+        assert!(resp
+            .content_type
+            .starts_with(crate::http::sse::CONTENT_TYPE));
+        let mut events = SseEvents::new(resp);
+
+        let resp = integration.request("GET", "/", &headers, None).await;
+        assert_eq!(resp.status_code, 409);
+
+        let headers = [
+            ("content-type", "application/json"),
+            (TOKEN_HEADER, &*token),
+        ];
+        let resp = integration
+            .request(
+                "POST",
+                "/",
+                &headers,
+                Some(
+                    r#"{
+                        "query": "subscription { asyncHuman { name } }",
+                        "extensions": {"operationId": "1"}
+                    }"#,
+                ),
+            )
+            .await;
+        assert_eq!(resp.status_code, 202);
+
+        assert_eq!(
+            events.next().await,
+            Some((
+                "next".into(),
+                serde_json::json!({
+                    "id": "1",
+                    "payload": {"data": {"asyncHuman": {"name": "Luke Skywalker"}}},
+                }),
+            )),
+        );
+        assert_eq!(
+            events.next().await,
+            Some(("complete".into(), serde_json::json!({"id": "1"}))),
+        );
+
+        let resp = integration
+            .request(
+                "POST",
+                "/",
+                &headers,
+                Some(r#"{"query": "{ hero }", "extensions": {"operationId": "2"}}"#),
+            )
+            .await;
+        assert_eq!(resp.status_code, 400);
+
+        let resp = integration
+            .request("DELETE", "/?operationId=1", &headers, None)
+            .await;
+        assert_eq!(resp.status_code, 200);
+    }
+
+    #[cfg(feature = "sse")]
+    async fn test_sse_single_connection_unknown_token<T: SseIntegration>(integration: &T) {
+        use crate::http::sse::TOKEN_HEADER;
+
+        let headers = [("accept", "text/event-stream"), (TOKEN_HEADER, "unknown")];
+        let resp = integration.request("GET", "/", &headers, None).await;
+        assert_eq!(resp.status_code, 404);
+
+        let resp = integration
+            .request("GET", "/?token=unknown", &headers[..1], None)
+            .await;
+        assert_eq!(resp.status_code, 404);
+    }
 }
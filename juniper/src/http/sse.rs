@@ -0,0 +1,969 @@
+//! [Server-Sent Events][1] transport for GraphQL operations, following the
+//! [GraphQL over SSE protocol][2].
+//!
+//! Both modes of the protocol are supported:
+//! - "distinct connections" mode, where every operation is served over its own
+//!   HTTP response (see [`subscribe()`]);
+//! - "single connection" mode, where all the operations of a client are
+//!   multiplexed over a single event stream, reserved and addressed with a
+//!   token (see [`SingleConnections`]).
+//!
+//! Queries and mutations are accepted as well, and produce a single `next`
+//! event followed by a `complete` one.
+//!
+//! This module is transport-agnostic: it doesn't do any HTTP routing, which is
+//! left to the framework integrations.
+//!
+//! [1]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+//! [2]: https://github.com/enisdenjo/graphql-sse/blob/master/PROTOCOL.md
+
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error as StdError,
+    fmt,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{self, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use futures::{
+    channel::mpsc,
+    future,
+    stream::{self, BoxStream},
+    FutureExt as _, SinkExt as _, Stream, StreamExt as _,
+};
+use serde::{
+    ser::{SerializeMap as _, Serializer},
+    Deserialize, Serialize,
+};
+
+use crate::{
+    http::{GraphQLRequest, GraphQLResponse},
+    value::{DefaultScalarValue, Object, ScalarValue, Value},
+    ExecutionError, ExecutionOutput, GraphQLError, GraphQLSubscriptionType, GraphQLTypeAsync,
+    RootNode, ValuesStream,
+};
+
+/// `Content-Type` of the event stream responses.
+pub const CONTENT_TYPE: &str = "text/event-stream";
+
+/// Name of the HTTP header carrying the token of a reserved event stream in
+/// "single connection" mode.
+pub const TOKEN_HEADER: &str = "x-graphql-event-stream-token";
+
+/// Name of the query parameter carrying the token of a reserved event stream
+/// in "single connection" mode, as an alternative to the [`TOKEN_HEADER`].
+pub const TOKEN_PARAM: &str = "token";
+
+/// Name of the query parameter carrying the ID of the operation to stop in
+/// "single connection" mode.
+pub const OPERATION_ID_PARAM: &str = "operationId";
+
+/// Stream of [`Event`]s to be sent to a client.
+pub type EventStream<S = DefaultScalarValue> = BoxStream<'static, Event<S>>;
+
+/// Single event of an [`EventStream`].
+///
+/// Its [`Display`] implementation renders it in the [`CONTENT_TYPE`] format,
+/// ready to be written into the response body.
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Debug)]
+pub enum Event<S = DefaultScalarValue> {
+    /// Result of an operation.
+    Next {
+        /// ID of the operation this result belongs to.
+        ///
+        /// Only present in "single connection" mode.
+        id: Option<String>,
+
+        /// Result itself.
+        payload: ExecutionOutput<S>,
+    },
+
+    /// Completion of an operation, after which no more results are produced
+    /// for it.
+    Complete {
+        /// ID of the completed operation.
+        ///
+        /// Only present in "single connection" mode.
+        id: Option<String>,
+    },
+}
+
+impl<S: ScalarValue> fmt::Display for Event<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Next { id: None, payload } => {
+                let data = serde_json::to_string(&Payload(payload)).map_err(|_| fmt::Error)?;
+                write!(f, "event: next\ndata: {}\n\n", data)
+            }
+            Self::Next {
+                id: Some(id),
+                payload,
+            } => {
+                let data = serde_json::to_string(&IdentifiedPayload { id, payload })
+                    .map_err(|_| fmt::Error)?;
+                write!(f, "event: next\ndata: {}\n\n", data)
+            }
+            Self::Complete { id: None } => write!(f, "event: complete\ndata:\n\n"),
+            Self::Complete { id: Some(id) } => {
+                let data = serde_json::to_string(&Identified { id }).map_err(|_| fmt::Error)?;
+                write!(f, "event: complete\ndata: {}\n\n", data)
+            }
+        }
+    }
+}
+
+/// [`ExecutionOutput`] serialized in the same way as a [`GraphQLResponse`].
+struct Payload<'a, S>(&'a ExecutionOutput<S>);
+
+impl<'a, S: ScalarValue> Serialize for Payload<'a, S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("data", &self.0.data)?;
+        if !self.0.errors.is_empty() {
+            map.serialize_entry("errors", &self.0.errors)?;
+        }
+        map.end()
+    }
+}
+
+#[derive(Serialize)]
+#[serde(bound = "S: ScalarValue")]
+struct IdentifiedPayload<'a, S> {
+    id: &'a str,
+    #[serde(serialize_with = "serialize_payload")]
+    payload: &'a ExecutionOutput<S>,
+}
+
+fn serialize_payload<S: ScalarValue, Ser: Serializer>(
+    payload: &&ExecutionOutput<S>,
+    serializer: Ser,
+) -> Result<Ser::Ok, Ser::Error> {
+    Payload(*payload).serialize(serializer)
+}
+
+#[derive(Serialize)]
+struct Identified<'a> {
+    id: &'a str,
+}
+
+/// Operation rejected before producing any result, because of failed parsing
+/// or validation, for example.
+///
+/// Should be responded with a `400 Bad Request` status and the
+/// [`Rejection::body()`] as an `application/json` body.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rejection {
+    body: String,
+}
+
+impl Rejection {
+    /// Returns the JSON-encoded GraphQL response describing this [`Rejection`].
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Converts this [`Rejection`] into the JSON-encoded GraphQL response
+    /// describing it.
+    pub fn into_body(self) -> String {
+        self.body
+    }
+}
+
+impl<'a> From<GraphQLError<'a>> for Rejection {
+    fn from(e: GraphQLError<'a>) -> Self {
+        let resp = GraphQLResponse::<DefaultScalarValue>::from_result(Err(e));
+        Self {
+            // Serializing `GraphQLError`s never fails.
+            body: serde_json::to_string(&resp).unwrap(),
+        }
+    }
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.body)
+    }
+}
+
+impl StdError for Rejection {}
+
+/// Starts executing the provided [`GraphQLRequest`] in "distinct connections"
+/// mode, returning the [`EventStream`] to be sent in the response.
+///
+/// Subscriptions produce a `next` event for every item of their stream, while
+/// queries and mutations produce a single one. The returned [`EventStream`]
+/// always ends with a `complete` event.
+///
+/// # Errors
+///
+/// If the operation cannot be executed at all.
+pub async fn subscribe<QueryT, MutationT, SubscriptionT, CtxT, S>(
+    root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+    context: Arc<CtxT>,
+    request: GraphQLRequest<S>,
+) -> Result<EventStream<S>, Rejection>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    QueryT::TypeInfo: Send + Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    MutationT::TypeInfo: Send + Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    SubscriptionT::TypeInfo: Send + Sync,
+    CtxT: Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+{
+    let outputs = start(root_node, context, request).await?;
+    Ok(outputs
+        .map(|payload| Event::Next { id: None, payload })
+        .chain(stream::once(future::ready(Event::Complete { id: None })))
+        .boxed())
+}
+
+/// Message sent from an operation being executed by [`start()`].
+enum Message<S> {
+    /// Operation has passed validation and started producing results.
+    Accepted,
+
+    /// Operation has failed before producing any result.
+    Rejected(Rejection),
+
+    /// Result produced by the operation.
+    Output(ExecutionOutput<S>),
+}
+
+/// Starts executing the provided [`GraphQLRequest`], returning the stream of
+/// its results.
+///
+/// As the results borrow the [`RootNode`], the context and the request, the
+/// execution is driven inside a future owning all of them, and its results are
+/// forwarded via a channel.
+async fn start<QueryT, MutationT, SubscriptionT, CtxT, S>(
+    root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+    context: Arc<CtxT>,
+    request: GraphQLRequest<S>,
+) -> Result<BoxStream<'static, ExecutionOutput<S>>, Rejection>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    QueryT::TypeInfo: Send + Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    MutationT::TypeInfo: Send + Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    SubscriptionT::TypeInfo: Send + Sync,
+    CtxT: Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+{
+    // No buffering, so the execution advances only as fast as the client
+    // reads its results.
+    let (mut tx, rx) = mpsc::channel(0);
+
+    let driver = async move {
+        match super::resolve_into_stream(&request, &*root_node, &*context).await {
+            Ok((value, errors)) => {
+                if tx.send(Message::Accepted).await.is_err() {
+                    return;
+                }
+                let mut outputs = outputs_stream(value, errors);
+                while let Some(output) = outputs.next().await {
+                    if tx.send(Message::Output(output)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            Err(GraphQLError::NotSubscription) => {
+                match request.execute(&*root_node, &*context).await.0 {
+                    Ok((data, errors)) => {
+                        if tx.send(Message::Accepted).await.is_ok() {
+                            let output = ExecutionOutput { data, errors };
+                            let _ = tx.send(Message::Output(output)).await;
+                        }
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Message::Rejected(e.into())).await;
+                    }
+                }
+            }
+            Err(e) => {
+                let _ = tx.send(Message::Rejected(e.into())).await;
+            }
+        }
+    };
+
+    let mut messages = stream::select(
+        rx,
+        driver
+            .into_stream()
+            .filter_map(|()| future::ready(None::<Message<S>>)),
+    )
+    .boxed();
+    match messages.next().await {
+        Some(Message::Accepted) => Ok(messages
+            .filter_map(|msg| {
+                future::ready(match msg {
+                    Message::Output(output) => Some(output),
+                    Message::Accepted | Message::Rejected(_) => None,
+                })
+            })
+            .boxed()),
+        Some(Message::Rejected(rejection)) => Err(rejection),
+        // The driver always reports acceptance or rejection first, and cannot
+        // fail sending it while the receiver is alive.
+        Some(Message::Output(_)) | None => unreachable!("operation neither accepted nor rejected"),
+    }
+}
+
+/// Converts the result of [`resolve_into_stream()`] into a stream of whole
+/// [`ExecutionOutput`]s, one per each item of every root field stream.
+///
+/// [`resolve_into_stream()`]: crate::resolve_into_stream
+fn outputs_stream<'a, S>(
+    value: Value<ValuesStream<'a, S>>,
+    errors: Vec<ExecutionError<S>>,
+) -> BoxStream<'a, ExecutionOutput<S>>
+where
+    S: ScalarValue + Send + Sync + 'a,
+{
+    if !errors.is_empty() {
+        return stream::once(future::ready(ExecutionOutput {
+            data: Value::null(),
+            errors,
+        }))
+        .boxed();
+    }
+
+    match value {
+        Value::Object(fields) => stream::select_all(fields.into_iter().map(|(name, field)| {
+            match field {
+                Value::Scalar(values) => values
+                    .map(move |res| match res {
+                        Ok(v) => {
+                            let mut data = Object::with_capacity(1);
+                            data.add_field(name.clone(), v);
+                            ExecutionOutput::from_data(Value::Object(data))
+                        }
+                        Err(e) => ExecutionOutput {
+                            data: Value::null(),
+                            errors: vec![e],
+                        },
+                    })
+                    .boxed(),
+                _ => stream::once(future::ready(ExecutionOutput::from_data(Value::null()))).boxed(),
+            }
+        }))
+        .boxed(),
+        _ => stream::once(future::ready(ExecutionOutput::from_data(Value::null()))).boxed(),
+    }
+}
+
+/// Error of serving "single connection" mode with [`SingleConnections`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SingleConnectionError {
+    /// No event stream is reserved for the provided token.
+    UnknownToken,
+
+    /// Event stream for the provided token is open already.
+    StreamAlreadyOpen,
+
+    /// Maximum number of event streams reserved, but not opened yet, has been
+    /// reached (see [`SingleConnections::with_max_reservations()`]).
+    TooManyReservations,
+
+    /// Operation with the provided ID is running already on the event stream.
+    DuplicateOperation(String),
+
+    /// Request body is not a valid GraphQL request with an operation ID.
+    InvalidRequest(String),
+
+    /// Operation has been rejected before producing any result.
+    Rejected(Rejection),
+}
+
+impl SingleConnectionError {
+    /// Returns the HTTP status code this [`SingleConnectionError`] should be
+    /// responded with.
+    ///
+    /// [`SingleConnectionError::Rejected`] should be responded with an
+    /// `application/json` body, while the other errors with a `text/plain`
+    /// one. [`Display`] implementation renders both.
+    ///
+    /// [`Display`]: fmt::Display
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Self::UnknownToken => 404,
+            Self::StreamAlreadyOpen | Self::DuplicateOperation(_) => 409,
+            Self::TooManyReservations => 503,
+            Self::InvalidRequest(_) | Self::Rejected(_) => 400,
+        }
+    }
+}
+
+impl fmt::Display for SingleConnectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownToken => write!(f, "Event stream is not reserved"),
+            Self::StreamAlreadyOpen => write!(f, "Event stream is open already"),
+            Self::TooManyReservations => write!(f, "Too many event streams are reserved"),
+            Self::DuplicateOperation(id) => write!(f, "Operation `{}` is running already", id),
+            Self::InvalidRequest(e) => write!(f, "Invalid request: {}", e),
+            Self::Rejected(r) => fmt::Display::fmt(r, f),
+        }
+    }
+}
+
+impl StdError for SingleConnectionError {}
+
+impl From<Rejection> for SingleConnectionError {
+    fn from(r: Rejection) -> Self {
+        Self::Rejected(r)
+    }
+}
+
+/// Registry of event streams served in "single connection" mode.
+///
+/// Serving a client in this mode goes as follows:
+/// 1. `PUT` request reserves an event stream, responding with
+///    `201 Created` and the token returned by [`SingleConnections::reserve()`]
+///    as a `text/plain` body. The reservation expires unless the event stream
+///    is opened within [`SingleConnections::with_reservation_ttl()`].
+/// 2. `GET` request with the token opens the reserved event stream with
+///    [`SingleConnections::open()`].
+/// 3. `POST` requests with the token start operations on the event stream with
+///    [`SingleConnections::execute()`], responding with `202 Accepted`.
+/// 4. `DELETE` requests with the token and the [`OPERATION_ID_PARAM`] stop
+///    operations with [`SingleConnections::stop()`], responding with `200 OK`.
+///
+/// The token is passed either in the [`TOKEN_HEADER`] or in the
+/// [`TOKEN_PARAM`] query parameter.
+///
+/// Tokens are generated from the random source of the operating system, so
+/// can't be guessed by other clients.
+///
+/// Cloning a [`SingleConnections`] is cheap, and all the clones share the same
+/// registry.
+pub struct SingleConnections<S = DefaultScalarValue> {
+    streams: Reservations<S>,
+    reservation_ttl: Duration,
+    max_reservations: usize,
+}
+
+impl<S> Clone for SingleConnections<S> {
+    fn clone(&self) -> Self {
+        Self {
+            streams: self.streams.clone(),
+            reservation_ttl: self.reservation_ttl,
+            max_reservations: self.max_reservations,
+        }
+    }
+}
+
+impl<S> Default for SingleConnections<S> {
+    fn default() -> Self {
+        Self {
+            streams: Arc::new(Mutex::new(HashMap::new())),
+            reservation_ttl: Self::DEFAULT_RESERVATION_TTL,
+            max_reservations: Self::DEFAULT_MAX_RESERVATIONS,
+        }
+    }
+}
+
+impl<S> fmt::Debug for SingleConnections<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SingleConnections")
+            .field("reserved", &lock(&self.streams).len())
+            .finish()
+    }
+}
+
+/// Event streams reserved in [`SingleConnections`] by their tokens.
+type Reservations<S> = Arc<Mutex<HashMap<String, Arc<Mutex<Reservation<S>>>>>>;
+
+/// State of an event stream reserved in [`SingleConnections`].
+struct Reservation<S> {
+    /// Indicator whether the event stream has been opened.
+    open: bool,
+
+    /// Moment this reservation expires at, unless the event stream is opened.
+    expires_at: Instant,
+
+    /// IDs of the running operations along with their unique keys.
+    running: HashMap<String, u64>,
+
+    /// Key of the next started operation.
+    next_key: u64,
+
+    /// Operations started, but not picked up by the event stream yet.
+    started: Vec<Operation<S>>,
+
+    /// Keys of the stopped operations, not dropped by the event stream yet.
+    stopped: Vec<u64>,
+
+    /// [`Waker`] of the event stream, waiting for new operations.
+    waker: Option<Waker>,
+}
+
+impl<S> Reservation<S> {
+    /// Indicates whether this reservation has expired without the event stream
+    /// being opened.
+    fn is_expired(&self, now: Instant) -> bool {
+        !self.open && self.expires_at <= now
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Operation running on an event stream in "single connection" mode.
+struct Operation<S> {
+    key: u64,
+    id: String,
+    outputs: BoxStream<'static, ExecutionOutput<S>>,
+}
+
+/// Request body in "single connection" mode.
+#[derive(Deserialize)]
+#[serde(bound = "GraphQLRequest<S>: Deserialize<'de>")]
+struct IdentifiedRequest<S: ScalarValue> {
+    #[serde(flatten)]
+    request: GraphQLRequest<S>,
+    extensions: Extensions,
+}
+
+#[derive(Deserialize)]
+struct Extensions {
+    #[serde(rename = "operationId")]
+    operation_id: String,
+}
+
+impl<S> SingleConnections<S> {
+    /// Default duration an event stream stays reserved for without being
+    /// opened.
+    pub const DEFAULT_RESERVATION_TTL: Duration = Duration::from_secs(60);
+
+    /// Default maximum number of event streams reserved, but not opened yet.
+    pub const DEFAULT_MAX_RESERVATIONS: usize = 1024;
+
+    /// Creates a new empty [`SingleConnections`] registry, with
+    /// [`SingleConnections::DEFAULT_RESERVATION_TTL`] and
+    /// [`SingleConnections::DEFAULT_MAX_RESERVATIONS`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specifies the duration an event stream stays reserved for without
+    /// being opened. Once it passes, the reservation is released, and its
+    /// token becomes unknown.
+    #[must_use]
+    pub fn with_reservation_ttl(mut self, ttl: Duration) -> Self {
+        self.reservation_ttl = ttl;
+        self
+    }
+
+    /// Specifies the maximum number of event streams reserved, but not opened
+    /// yet. Once reached, [`SingleConnections::reserve()`] fails with a
+    /// [`SingleConnectionError::TooManyReservations`], until some of the
+    /// reservations are either opened or expired.
+    #[must_use]
+    pub fn with_max_reservations(mut self, max: usize) -> Self {
+        self.max_reservations = max;
+        self
+    }
+
+    /// Reserves a new event stream, returning its token.
+    ///
+    /// # Errors
+    ///
+    /// [`SingleConnectionError::TooManyReservations`] if the maximum number of
+    /// not opened event streams has been reached.
+    pub fn reserve(&self) -> Result<String, SingleConnectionError> {
+        let now = Instant::now();
+        let mut streams = lock(&self.streams);
+        streams.retain(|_, r| !lock(r).is_expired(now));
+        let pending = streams.values().filter(|r| !lock(r).open).count();
+        if pending >= self.max_reservations {
+            return Err(SingleConnectionError::TooManyReservations);
+        }
+
+        let token = loop {
+            let token = random_token();
+            if !streams.contains_key(&token) {
+                break token;
+            }
+        };
+        streams.insert(
+            token.clone(),
+            Arc::new(Mutex::new(Reservation {
+                open: false,
+                expires_at: now + self.reservation_ttl,
+                running: HashMap::new(),
+                next_key: 0,
+                started: vec![],
+                stopped: vec![],
+                waker: None,
+            })),
+        );
+        Ok(token)
+    }
+
+    /// Opens the event stream reserved for the provided `token`.
+    ///
+    /// The returned [`EventStream`] never ends on its own, and the reservation
+    /// is released once it's dropped.
+    ///
+    /// # Errors
+    ///
+    /// - [`SingleConnectionError::UnknownToken`] if no event stream is reserved
+    ///   for the `token`.
+    /// - [`SingleConnectionError::StreamAlreadyOpen`] if the event stream has
+    ///   been opened already.
+    pub fn open(&self, token: &str) -> Result<EventStream<S>, SingleConnectionError>
+    where
+        S: ScalarValue + Send + 'static,
+    {
+        let reservation = self.reservation(token)?;
+        {
+            let mut r = lock(&reservation);
+            if r.open {
+                return Err(SingleConnectionError::StreamAlreadyOpen);
+            }
+            r.open = true;
+        }
+        Ok(ConnectionStream {
+            token: token.into(),
+            streams: self.streams.clone(),
+            reservation,
+            operations: VecDeque::new(),
+        }
+        .boxed())
+    }
+
+    /// Starts executing the GraphQL request, JSON-encoded in the provided
+    /// `body`, on the event stream reserved for the provided `token`.
+    ///
+    /// The ID of the operation is taken from the `extensions.operationId`
+    /// field of the request. Operations may be started before the event stream
+    /// is opened, and are executed once it is.
+    ///
+    /// # Errors
+    ///
+    /// - [`SingleConnectionError::UnknownToken`] if no event stream is reserved
+    ///   for the `token`.
+    /// - [`SingleConnectionError::InvalidRequest`] if the `body` cannot be
+    ///   parsed.
+    /// - [`SingleConnectionError::DuplicateOperation`] if an operation with the
+    ///   same ID is running already.
+    /// - [`SingleConnectionError::Rejected`] if the operation cannot be
+    ///   executed at all.
+    pub async fn execute<QueryT, MutationT, SubscriptionT, CtxT>(
+        &self,
+        token: &str,
+        root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+        context: Arc<CtxT>,
+        body: &str,
+    ) -> Result<(), SingleConnectionError>
+    where
+        QueryT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+        QueryT::TypeInfo: Send + Sync,
+        MutationT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+        MutationT::TypeInfo: Send + Sync,
+        SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+        SubscriptionT::TypeInfo: Send + Sync,
+        CtxT: Send + Sync + 'static,
+        S: ScalarValue + Send + Sync + 'static,
+    {
+        let reservation = self.reservation(token)?;
+        let IdentifiedRequest {
+            request,
+            extensions: Extensions { operation_id: id },
+        } = serde_json::from_str::<IdentifiedRequest<S>>(body)
+            .map_err(|e| SingleConnectionError::InvalidRequest(e.to_string()))?;
+
+        if lock(&reservation).running.contains_key(&id) {
+            return Err(SingleConnectionError::DuplicateOperation(id));
+        }
+
+        let outputs = start(root_node, context, request).await?;
+
+        let mut r = lock(&reservation);
+        // Another operation with the same ID might have been started while
+        // this one has been validated.
+        if r.running.contains_key(&id) {
+            return Err(SingleConnectionError::DuplicateOperation(id));
+        }
+        let key = r.next_key;
+        r.next_key += 1;
+        r.running.insert(id.clone(), key);
+        r.started.push(Operation { key, id, outputs });
+        r.wake();
+        Ok(())
+    }
+
+    /// Stops the operation with the provided `operation_id` running on the
+    /// event stream reserved for the provided `token`.
+    ///
+    /// No `complete` event is sent for the stopped operation. Stopping an
+    /// unknown or already completed operation is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// [`SingleConnectionError::UnknownToken`] if no event stream is reserved
+    /// for the `token`.
+    pub fn stop(&self, token: &str, operation_id: &str) -> Result<(), SingleConnectionError> {
+        let reservation = self.reservation(token)?;
+        let mut r = lock(&reservation);
+        if let Some(key) = r.running.remove(operation_id) {
+            r.stopped.push(key);
+            r.wake();
+        }
+        Ok(())
+    }
+
+    /// Returns the number of currently reserved event streams, including the
+    /// opened ones.
+    pub fn reserved_count(&self) -> usize {
+        let now = Instant::now();
+        let mut streams = lock(&self.streams);
+        streams.retain(|_, r| !lock(r).is_expired(now));
+        streams.len()
+    }
+
+    fn reservation(
+        &self,
+        token: &str,
+    ) -> Result<Arc<Mutex<Reservation<S>>>, SingleConnectionError> {
+        let mut streams = lock(&self.streams);
+        let reservation = streams
+            .get(token)
+            .cloned()
+            .ok_or(SingleConnectionError::UnknownToken)?;
+        if lock(&reservation).is_expired(Instant::now()) {
+            streams.remove(token);
+            return Err(SingleConnectionError::UnknownToken);
+        }
+        Ok(reservation)
+    }
+}
+
+/// Generates a new random token for reserving an event stream.
+fn random_token() -> String {
+    let mut bytes = [0; 16];
+    getrandom::getrandom(&mut bytes).expect("random source of the OS is unavailable");
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// [`EventStream`] opened in "single connection" mode.
+struct ConnectionStream<S> {
+    token: String,
+    streams: Reservations<S>,
+    reservation: Arc<Mutex<Reservation<S>>>,
+    operations: VecDeque<Operation<S>>,
+}
+
+impl<S> Stream for ConnectionStream<S> {
+    type Item = Event<S>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        {
+            let mut r = lock(&this.reservation);
+            this.operations.extend(r.started.drain(..));
+            for key in r.stopped.drain(..) {
+                this.operations.retain(|op| op.key != key);
+            }
+            r.waker = Some(cx.waker().clone());
+        }
+
+        for i in 0..this.operations.len() {
+            match this.operations[i].outputs.poll_next_unpin(cx) {
+                Poll::Ready(Some(payload)) => {
+                    // Moving to the back, so the other operations are not
+                    // starved by this one.
+                    let op = this.operations.remove(i).unwrap();
+                    let id = Some(op.id.clone());
+                    this.operations.push_back(op);
+                    return Poll::Ready(Some(Event::Next { id, payload }));
+                }
+                Poll::Ready(None) => {
+                    let op = this.operations.remove(i).unwrap();
+                    let mut r = lock(&this.reservation);
+                    if r.running.get(&op.id) == Some(&op.key) {
+                        r.running.remove(&op.id);
+                    }
+                    return Poll::Ready(Some(Event::Complete { id: Some(op.id) }));
+                }
+                Poll::Pending => {}
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<S> Drop for ConnectionStream<S> {
+    fn drop(&mut self) {
+        lock(&self.streams).remove(&self.token);
+    }
+}
+
+/// Locks the provided [`Mutex`], ignoring its poisoning, as the guarded state
+/// is never left inconsistent.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use futures::{FutureExt as _, StreamExt as _};
+
+    use crate::{
+        tests::fixtures::starwars::schema::{Database, Query, Subscription},
+        DefaultScalarValue, EmptyMutation, RootNode,
+    };
+
+    use super::{subscribe, Event, SingleConnectionError, SingleConnections};
+
+    type Schema = RootNode<'static, Query, EmptyMutation<Database>, Subscription>;
+
+    fn schema() -> Arc<Schema> {
+        Arc::new(RootNode::new(Query, EmptyMutation::new(), Subscription))
+    }
+
+    const SUBSCRIPTION: &str = r#"{
+        "query": "subscription { asyncHuman { name } }",
+        "extensions": {"operationId": "1"}
+    }"#;
+
+    #[tokio::test]
+    async fn renders_events() {
+        let req = serde_json::from_str(SUBSCRIPTION).unwrap();
+        let events = subscribe(schema(), Arc::new(Database::new()), req)
+            .await
+            .unwrap()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            events,
+            [
+                "event: next\n\
+                 data: {\"data\":{\"asyncHuman\":{\"name\":\"Luke Skywalker\"}}}\n\n",
+                "event: complete\ndata:\n\n",
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_operation() {
+        let req = serde_json::from_str(r#"{"query": "subscription { asyncHuman }"}"#).unwrap();
+        let err = subscribe(schema(), Arc::new(Database::new()), req)
+            .await
+            .err()
+            .unwrap();
+
+        assert!(err.body().starts_with(r#"{"errors":[{"message":"#));
+    }
+
+    #[tokio::test]
+    async fn single_connection_lifecycle() {
+        let conns = SingleConnections::new();
+        let token = conns.reserve().unwrap();
+
+        assert_eq!(
+            conns.open("unknown").err(),
+            Some(SingleConnectionError::UnknownToken),
+        );
+        let mut stream = conns.open(&token).unwrap();
+        assert_eq!(
+            conns.open(&token).err(),
+            Some(SingleConnectionError::StreamAlreadyOpen),
+        );
+
+        let db = Arc::new(Database::new());
+        conns
+            .execute(&token, schema(), db.clone(), SUBSCRIPTION)
+            .await
+            .unwrap();
+        assert!(matches!(
+            conns.execute(&token, schema(), db.clone(), SUBSCRIPTION).await,
+            Err(SingleConnectionError::DuplicateOperation(id)) if id == "1",
+        ));
+        assert!(matches!(
+            conns
+                .execute(&token, schema(), db, r#"{"query": "{ hero { name } }"}"#)
+                .await,
+            Err(SingleConnectionError::InvalidRequest(_)),
+        ));
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Event::Next { id: Some(id), .. }) if id == "1",
+        ));
+        assert_eq!(
+            stream.next().await.unwrap().to_string(),
+            "event: complete\ndata: {\"id\":\"1\"}\n\n",
+        );
+        assert!(stream.next().now_or_never().is_none());
+
+        drop(stream);
+        assert_eq!(conns.reserved_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn stops_operation() {
+        let conns = SingleConnections::new();
+        let token = conns.reserve().unwrap();
+
+        conns
+            .execute(&token, schema(), Arc::new(Database::new()), SUBSCRIPTION)
+            .await
+            .unwrap();
+        conns.stop(&token, "1").unwrap();
+        assert_eq!(
+            conns.stop("unknown", "1"),
+            Err(SingleConnectionError::UnknownToken),
+        );
+
+        let mut stream = conns.open(&token).unwrap();
+        assert!(stream.next().now_or_never().is_none());
+    }
+
+    #[test]
+    fn limits_reservations() {
+        let conns = SingleConnections::<DefaultScalarValue>::new().with_max_reservations(2);
+        let (first, second) = (conns.reserve().unwrap(), conns.reserve().unwrap());
+        assert_ne!(first, second);
+        assert_eq!(first.len(), 32);
+        assert_eq!(
+            conns.reserve(),
+            Err(SingleConnectionError::TooManyReservations),
+        );
+
+        let _stream = conns.open(&first).unwrap();
+        assert!(conns.reserve().is_ok());
+        assert_eq!(conns.reserved_count(), 3);
+    }
+
+    #[test]
+    fn expires_unopened_reservations() {
+        let conns = SingleConnections::<DefaultScalarValue>::new()
+            .with_reservation_ttl(Duration::ZERO)
+            .with_max_reservations(1);
+        let token = conns.reserve().unwrap();
+
+        assert_eq!(conns.reserved_count(), 0);
+        assert_eq!(
+            conns.open(&token).err(),
+            Some(SingleConnectionError::UnknownToken),
+        );
+        assert!(conns.reserve().is_ok());
+    }
+}
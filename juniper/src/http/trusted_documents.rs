@@ -0,0 +1,505 @@
+//! Trusted documents mode, executing only the operations from a manifest.
+//!
+//! Requests reference the trusted documents by their IDs in the manifest, or
+//! by the [`operation_hash()`] of their operations, in the `documentId` field
+//! of a [`GraphQLRequest`]. Requests with a `query` are executed only if it's
+//! exactly the source of a trusted document. Documents executed directly,
+//! without a [`GraphQLRequest`], aren't restricted.
+//!
+//! Requires the `trusted-documents` Cargo feature.
+//!
+//! [`GraphQLRequest`]: super::GraphQLRequest
+
+use std::{collections::HashMap, error::Error, fmt, sync::Arc};
+
+use sha2::{Digest as _, Sha256};
+
+use crate::{
+    ast::{Definition, Document, OwnedDocument},
+    parser::{operation_document, print_document, PrintStyle},
+    schema::model::SchemaType,
+    validation::RuleError,
+    value::{DefaultScalarValue, ScalarValue},
+};
+
+/// Prefix of the [`operation_hash()`]es.
+const HASH_PREFIX: &str = "sha256:";
+
+/// Set of trusted documents, parsed and validated against a schema once, when
+/// loaded from a manifest.
+///
+/// Set with [`RootNode::with_trusted_documents()`] to reject all the other
+/// requested documents before parsing them.
+///
+/// [`RootNode::with_trusted_documents()`]: crate::RootNode::with_trusted_documents
+#[derive(Debug)]
+pub struct TrustedDocuments<S = DefaultScalarValue> {
+    /// Parsed documents, indexed by their sources.
+    documents: HashMap<Arc<str>, TrustedDocument<S>>,
+
+    /// Sources of the documents by their IDs in the manifest.
+    by_id: HashMap<String, Arc<str>>,
+
+    /// Sources of the documents, along with the names of the operations to
+    /// execute, by the [`operation_hash()`]es of their operations.
+    by_hash: HashMap<String, (Arc<str>, Option<String>)>,
+}
+
+/// Parsed trusted document, owning the source it borrows from.
+#[derive(Debug)]
+struct TrustedDocument<S> {
+    /// Parsed document, borrowing from the `source`.
+    ///
+    /// Declared before the `source`, so is dropped before it.
+    document: OwnedDocument<'static, S>,
+
+    /// Source of the `document`.
+    source: Arc<str>,
+}
+
+impl<S> TrustedDocument<S> {
+    /// Returns the parsed document, borrowing from this [`TrustedDocument`]
+    /// only.
+    fn document(&self) -> &Document<'_, S> {
+        &self.document
+    }
+}
+
+impl<S: ScalarValue> TrustedDocuments<S> {
+    /// Parses the provided `documents` (pairs of an ID and a source) and
+    /// validates them against the provided `schema`.
+    ///
+    /// # Errors
+    ///
+    /// If any of the `documents` is invalid, or the same ID is used more than
+    /// once.
+    pub fn new<I, K, V>(schema: &SchemaType<S>, documents: I) -> Result<Self, TrustedDocumentsError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let mut this = Self {
+            documents: HashMap::new(),
+            by_id: HashMap::new(),
+            by_hash: HashMap::new(),
+        };
+        for (id, source) in documents {
+            let (id, source) = (id.into(), source.into());
+            if this.by_id.contains_key(&id) {
+                return Err(TrustedDocumentsError::DuplicateId(id));
+            }
+            let source = match this.documents.get(source.as_str()) {
+                Some(document) => document.source.clone(),
+                None => this.insert(schema, &id, source)?,
+            };
+            this.by_id.insert(id, source);
+        }
+        Ok(this)
+    }
+
+    /// Parses and validates the provided `source` against the provided
+    /// `schema`, and indexes it by the hashes of its operations.
+    fn insert(
+        &mut self,
+        schema: &SchemaType<S>,
+        id: &str,
+        source: String,
+    ) -> Result<Arc<str>, TrustedDocumentsError> {
+        let source = Arc::<str>::from(source);
+        let document = schema.parse_valid_document(&source).map_err(|errors| {
+            TrustedDocumentsError::InvalidDocument {
+                id: id.into(),
+                errors,
+            }
+        })?;
+
+        let operations = document
+            .iter()
+            .filter_map(|def| match def {
+                Definition::Operation(op) => Some(op.item.name.as_ref().map(|n| n.item)),
+                Definition::Fragment(_) => None,
+            })
+            .collect::<Vec<_>>();
+        for name in &operations {
+            // Documents with a single operation don't require its name.
+            let name = if operations.len() > 1 { *name } else { None };
+            if let Some(hash) = operation_hash(&document, name) {
+                if self.by_hash.contains_key(&hash) {
+                    return Err(TrustedDocumentsError::DuplicateHash {
+                        id: id.into(),
+                        hash,
+                    });
+                }
+                self.by_hash
+                    .insert(hash, (source.clone(), name.map(Into::into)));
+            }
+        }
+
+        // SAFETY: The `document` borrows from the `source` allocation only,
+        //         which is never mutated and is freed only after the
+        //         `document` is dropped, as `TrustedDocument` owns both,
+        //         dropping the `document` first. The `'static` lifetime never
+        //         escapes, as `TrustedDocument::document()` shortens it back to
+        //         the lifetime of the `TrustedDocument`.
+        let document = unsafe {
+            std::mem::transmute::<OwnedDocument<'_, S>, OwnedDocument<'static, S>>(document)
+        };
+        self.documents.insert(
+            source.clone(),
+            TrustedDocument {
+                document,
+                source: source.clone(),
+            },
+        );
+        Ok(source)
+    }
+
+    /// Loads the trusted documents from the provided JSON `manifest`, and
+    /// validates them against the provided `schema`.
+    ///
+    /// The `manifest` is either an object mapping IDs to sources, or an
+    /// [Apollo persisted query manifest][0].
+    ///
+    /// # Errors
+    ///
+    /// If the `manifest` is malformed, or any of its documents is invalid.
+    ///
+    /// [0]: https://www.apollographql.com/docs/kotlin/advanced/persisted-queries
+    #[cfg(feature = "json")]
+    pub fn from_json(
+        schema: &SchemaType<S>,
+        manifest: &str,
+    ) -> Result<Self, TrustedDocumentsError> {
+        use indexmap::IndexMap;
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Manifest {
+            Apollo { operations: Vec<Operation> },
+            Map(IndexMap<String, String>),
+        }
+
+        #[derive(Deserialize)]
+        struct Operation {
+            id: String,
+            body: String,
+        }
+
+        let manifest = serde_json::from_str(manifest)
+            .map_err(|e| TrustedDocumentsError::Manifest(e.to_string()))?;
+        match manifest {
+            Manifest::Apollo { operations } => {
+                Self::new(schema, operations.into_iter().map(|op| (op.id, op.body)))
+            }
+            Manifest::Map(documents) => Self::new(schema, documents),
+        }
+    }
+}
+
+impl<S> TrustedDocuments<S> {
+    /// Returns the source of the trusted document referenced by the provided
+    /// `document_id`, along with the name of the operation to execute, if the
+    /// `document_id` is a hash of one of its multiple operations.
+    pub fn lookup(&self, document_id: &str) -> Option<(&str, Option<&str>)> {
+        if let Some(source) = self.by_id.get(document_id) {
+            return Some((source, None));
+        }
+        self.by_hash
+            .get(document_id)
+            .map(|(source, name)| (&**source, name.as_deref()))
+    }
+
+    /// Indicates whether the provided `source` is one of the trusted documents.
+    pub fn contains(&self, source: &str) -> bool {
+        self.documents.contains_key(source)
+    }
+
+    /// Returns the parsed trusted document with the provided `source`.
+    pub(crate) fn document(&self, source: &str) -> Option<&Document<'_, S>> {
+        self.documents.get(source).map(TrustedDocument::document)
+    }
+
+    /// Returns the number of the trusted documents.
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// Indicates whether there are no trusted documents.
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+}
+
+/// Computes the hash of the operation with the provided `operation_name` in
+/// the provided `document`, referencing it as a trusted document.
+///
+/// The hash is the SHA-256 of the operation along with the fragments it uses
+/// (sorted by name), printed with [`PrintStyle::Minified`], in lowercase hex
+/// prefixed with `sha256:`. So it doesn't depend on formatting, comments and
+/// the other definitions of the `document`, but does depend on everything
+/// affecting the execution, including aliases and literals.
+///
+/// Returns [`None`] if there is no such operation.
+pub fn operation_hash<S: ScalarValue>(
+    document: &Document<S>,
+    operation_name: Option<&str>,
+) -> Option<String> {
+    let operation = operation_document(document, operation_name)?;
+    let digest = Sha256::digest(print_document(&operation, PrintStyle::Minified));
+    Some(
+        std::iter::once(HASH_PREFIX.to_owned())
+            .chain(digest.iter().map(|b| format!("{:02x}", b)))
+            .collect(),
+    )
+}
+
+/// Error loading [`TrustedDocuments`].
+#[derive(Debug, PartialEq)]
+pub enum TrustedDocumentsError {
+    /// Manifest cannot be deserialized.
+    Manifest(String),
+
+    /// Same document ID is used more than once.
+    DuplicateId(String),
+
+    /// Operation of a document has the same [`operation_hash()`] as an
+    /// operation of another document.
+    DuplicateHash {
+        /// ID of the document.
+        id: String,
+
+        /// [`operation_hash()`] of the operation.
+        hash: String,
+    },
+
+    /// Document is invalid against the schema.
+    InvalidDocument {
+        /// ID of the document.
+        id: String,
+
+        /// Syntax and validation errors found in the document.
+        errors: Vec<RuleError>,
+    },
+}
+
+impl fmt::Display for TrustedDocumentsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Manifest(e) => write!(f, "Malformed manifest: {}", e),
+            Self::DuplicateId(id) => write!(f, "Document `{}` is defined more than once", id),
+            Self::DuplicateHash { id, hash } => write!(
+                f,
+                "Document `{}` has an operation with the hash `{}` of another document",
+                id, hash,
+            ),
+            Self::InvalidDocument { id, errors } => {
+                write!(f, "Document `{}` is invalid:", id)?;
+                for error in errors {
+                    write!(f, " {}", error)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for TrustedDocumentsError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        execute_sync,
+        http::GraphQLRequest,
+        introspect,
+        parser::parse_document_source,
+        tests::fixtures::starwars::schema::{Database, Query},
+        EmptyMutation, EmptySubscription, GraphQLError, IntrospectionFormat, RootNode,
+    };
+
+    use super::{operation_hash, TrustedDocuments, TrustedDocumentsError};
+
+    type Schema = RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>>;
+
+    fn schema() -> Schema {
+        RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        )
+    }
+
+    fn hash(source: &str, operation_name: Option<&str>) -> String {
+        let document = parse_document_source(source, &schema().schema).unwrap();
+        operation_hash(&document, operation_name).unwrap()
+    }
+
+    #[test]
+    fn looks_up_ids_and_hashes() {
+        let multiple = "query A { hero { name } } query B { human(id: \"1000\") { name } }";
+        let documents = TrustedDocuments::new(
+            &schema().schema,
+            [("single", "{ hero { id } }"), ("multiple", multiple)],
+        )
+        .unwrap();
+
+        assert_eq!(documents.len(), 2);
+        assert_eq!(documents.lookup("single"), Some(("{ hero { id } }", None)));
+        assert_eq!(documents.lookup("multiple"), Some((multiple, None)));
+        assert_eq!(
+            documents.lookup(&hash("{ hero { id } }", None)),
+            Some(("{ hero { id } }", None)),
+        );
+        assert_eq!(
+            documents.lookup(&hash(
+                "query B {\n  human(id: \"1000\") {\n    name\n  }\n}",
+                None
+            )),
+            Some((multiple, Some("B"))),
+        );
+        assert_eq!(documents.lookup(&hash("{ hero { name } }", None)), None);
+        // Literals and aliases are part of the hashed operation.
+        for untrusted in [
+            "query B { human(id: \"1001\") { name } }",
+            "query B { person: human(id: \"1000\") { name } }",
+        ] {
+            assert_eq!(
+                documents.lookup(&hash(untrusted, None)),
+                None,
+                "{}",
+                untrusted
+            );
+        }
+        assert_eq!(documents.lookup("unknown"), None);
+    }
+
+    #[test]
+    fn reuses_identical_sources() {
+        let documents = TrustedDocuments::new(
+            &schema().schema,
+            [("a", "{ hero { id } }"), ("b", "{ hero { id } }")],
+        )
+        .unwrap();
+
+        assert_eq!(documents.len(), 1);
+        assert_eq!(documents.lookup("a"), documents.lookup("b"));
+    }
+
+    #[test]
+    fn rejects_duplicate_ids() {
+        let err = TrustedDocuments::new(
+            &schema().schema,
+            [("a", "{ hero { id } }"), ("a", "{ hero { name } }")],
+        )
+        .unwrap_err();
+
+        assert_eq!(err, TrustedDocumentsError::DuplicateId("a".into()));
+    }
+
+    #[test]
+    fn rejects_duplicate_hashes() {
+        let err = TrustedDocuments::new(
+            &schema().schema,
+            [("a", "{ hero { id } }"), ("b", "{hero{id}}")],
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            TrustedDocumentsError::DuplicateHash {
+                id: "b".into(),
+                hash: hash("{ hero { id } }", None),
+            },
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        for source in ["{ hero { id }", "{ hero { unknown } }"] {
+            match TrustedDocuments::new(&schema().schema, [("a", source)]) {
+                Err(TrustedDocumentsError::InvalidDocument { id, errors }) => {
+                    assert_eq!(id, "a");
+                    assert!(!errors.is_empty(), "{}", source);
+                }
+                res => panic!("Unexpected result for {}: {:?}", source, res),
+            }
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn loads_json_manifests() {
+        let map = TrustedDocuments::from_json(
+            &schema().schema,
+            r#"{"a": "{ hero { id } }", "b": "{ hero { name } }"}"#,
+        )
+        .unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.lookup("b"), Some(("{ hero { name } }", None)));
+
+        let apollo = TrustedDocuments::from_json(
+            &schema().schema,
+            r#"{
+                "format": "apollo-persisted-query-manifest",
+                "version": 1,
+                "operations": [
+                    {"id": "a", "name": "A", "type": "query", "body": "query A { hero { id } }"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(apollo.len(), 1);
+        assert_eq!(apollo.lookup("a"), Some(("query A { hero { id } }", None)));
+
+        assert!(matches!(
+            TrustedDocuments::from_json(&schema().schema, r#"["{ hero { id } }"]"#),
+            Err(TrustedDocumentsError::Manifest(_)),
+        ));
+    }
+
+    #[test]
+    fn executes_trusted_requests_only() {
+        let documents = TrustedDocuments::new(&schema().schema, [("a", "{ hero { id } }")]);
+        let schema = schema().with_trusted_documents(documents.unwrap());
+        let request = |query: &str| GraphQLRequest::new(query.into(), None, None);
+
+        assert_eq!(
+            request("{ hero { id } }")
+                .execute_sync(&schema, &Database::new())
+                .0,
+            Ok((graphql_value!({"hero": {"id": "2001"}}), vec![])),
+        );
+        for source in ["{ hero { name } }", "{ hero { id }"] {
+            assert_eq!(
+                request(source).execute_sync(&schema, &Database::new()).0,
+                Err(GraphQLError::UnknownDocument),
+            );
+        }
+    }
+
+    #[test]
+    fn executes_any_documents_directly() {
+        let documents = TrustedDocuments::new(&schema().schema, [("a", "{ hero { id } }")]);
+        let schema = schema().with_trusted_documents(documents.unwrap());
+
+        assert_eq!(
+            execute_sync(
+                "{ hero { name } }",
+                None,
+                &schema,
+                &Default::default(),
+                &Database::new(),
+            ),
+            Ok((graphql_value!({"hero": {"name": "R2-D2"}}), vec![])),
+        );
+
+        let (introspection, errors) =
+            introspect(&schema, &Database::new(), IntrospectionFormat::default()).unwrap();
+        assert_eq!(errors, vec![]);
+        assert!(introspection
+            .as_object_value()
+            .unwrap()
+            .contains_field("__schema"));
+    }
+}
@@ -0,0 +1,394 @@
+//! GraphQL support for [`serde_json`] crate types.
+//!
+//! # Supported types
+//!
+//! | Rust type                   | GraphQL scalar    |
+//! |-----------------------------|-------------------|
+//! | [`JsonValue`][1]            | [`JSON`][s1]      |
+//! | [`Map<String, JsonValue>`]  | [`JSONObject`][s2]|
+//!
+//! Both scalars accept JSON values either via variables or inline in a query,
+//! written as GraphQL literals: objects and lists are converted into JSON
+//! objects and arrays, and GraphQL `null`, booleans, numbers and strings into
+//! their JSON counterparts. Variables nested inside such inline literals are
+//! not supported.
+//!
+//! [`Map<String, JsonValue>`]: serde_json::Map
+//! [1]: serde_json::Value
+//! [s1]: https://graphql-scalars.dev/docs/scalars/json
+//! [s2]: https://graphql-scalars.dev/docs/scalars/json-object
+
+use std::convert::TryFrom as _;
+
+use serde_json::{Map, Number, Value as JsonValue};
+
+use crate::{
+    graphql_scalar,
+    parser::{ParseError, ScalarToken, Token},
+    InputValue, Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value,
+};
+
+/// Arbitrary JSON value.
+///
+/// [`JSON` scalar][1] compliant.
+///
+/// See also [`serde_json::Value`][2] for details.
+///
+/// [1]: https://graphql-scalars.dev/docs/scalars/json
+/// [2]: https://docs.rs/serde_json/latest/serde_json/enum.Value.html
+#[graphql_scalar(
+    name = "JSON",
+    with = json_value,
+    specified_by_url = "https://www.rfc-editor.org/rfc/rfc8259",
+    structured_input,
+)]
+type Json = JsonValue;
+
+mod json_value {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(v: &Json) -> Value<S> {
+        json_to_value(v)
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Json, String> {
+        input_to_json(v)
+    }
+
+    pub(super) fn parse_token<S: ScalarValue>(value: ScalarToken<'_>) -> ParseScalarResult<'_, S> {
+        parse_json_token(value)
+    }
+}
+
+/// JSON object with [`String`] keys and arbitrary JSON values.
+///
+/// [`JSONObject` scalar][1] compliant.
+///
+/// See also [`serde_json::Map`][2] for details.
+///
+/// [1]: https://graphql-scalars.dev/docs/scalars/json-object
+/// [2]: https://docs.rs/serde_json/latest/serde_json/struct.Map.html
+#[graphql_scalar(
+    name = "JSONObject",
+    with = json_object,
+    specified_by_url = "https://www.rfc-editor.org/rfc/rfc8259#section-4",
+    structured_input,
+)]
+type JsonObject = Map<String, JsonValue>;
+
+mod json_object {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(v: &JsonObject) -> Value<S> {
+        Value::Object(map_to_object(v))
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<JsonObject, String> {
+        match input_to_json(v)? {
+            JsonValue::Object(o) => Ok(o),
+            _ => Err(format!("Expected `JSONObject`, found: {}", v)),
+        }
+    }
+
+    pub(super) fn parse_token<S: ScalarValue>(value: ScalarToken<'_>) -> ParseScalarResult<'_, S> {
+        // Only object literals are valid, which are not scalar tokens at all.
+        Err(ParseError::UnexpectedToken(Token::Scalar(value)))
+    }
+}
+
+/// Converts the provided [`JsonValue`] into a [`Value`].
+///
+/// Numbers are stored as integers whenever the [`ScalarValue`] is able to hold
+/// them losslessly (see [`ScalarValue::from_big_int()`]), and as floats
+/// otherwise.
+pub(crate) fn json_to_value<S: ScalarValue>(v: &JsonValue) -> Value<S> {
+    match v {
+        JsonValue::Null => Value::Null,
+        JsonValue::Bool(b) => Value::scalar(*b),
+        JsonValue::Number(n) => Value::Scalar(number_to_scalar(n)),
+        JsonValue::String(s) => Value::scalar(s.clone()),
+        JsonValue::Array(a) => Value::List(a.iter().map(json_to_value).collect()),
+        JsonValue::Object(o) => Value::Object(map_to_object(o)),
+    }
+}
+
+/// Converts the provided JSON [`Map`] into an [`Object`].
+fn map_to_object<S: ScalarValue>(o: &Map<String, JsonValue>) -> Object<S> {
+    o.iter()
+        .fold(Object::with_capacity(o.len()), |mut obj, (k, v)| {
+            obj.add_field(k.as_str(), json_to_value(v));
+            obj
+        })
+}
+
+/// Converts the provided JSON [`Number`] into a [`ScalarValue`].
+fn number_to_scalar<S: ScalarValue>(n: &Number) -> S {
+    n.as_i64()
+        .map(i128::from)
+        .or_else(|| n.as_u64().map(i128::from))
+        .and_then(S::from_big_int)
+        // `as_f64()` always returns `Some` without `arbitrary_precision`.
+        .unwrap_or_else(|| S::from(n.as_f64().unwrap_or(f64::NAN)))
+}
+
+/// Converts the provided [`InputValue`] into a [`JsonValue`].
+fn input_to_json<S: ScalarValue>(v: &InputValue<S>) -> Result<JsonValue, String> {
+    match v {
+        InputValue::Null => Ok(JsonValue::Null),
+        InputValue::Scalar(s) => {
+            scalar_to_json(s).ok_or_else(|| format!("Expected `JSON` value, found: {}", v))
+        }
+        InputValue::List(l) => l
+            .iter()
+            .map(|i| input_to_json(&i.item))
+            .collect::<Result<_, _>>()
+            .map(JsonValue::Array),
+        InputValue::Object(o) => o
+            .iter()
+            .map(|(k, v)| Ok((k.item.clone(), input_to_json(&v.item)?)))
+            .collect::<Result<_, String>>()
+            .map(JsonValue::Object),
+        InputValue::Enum(_) | InputValue::Variable(_) => {
+            Err(format!("Expected `JSON` value, found: {}", v))
+        }
+    }
+}
+
+/// Converts the provided [`ScalarValue`] into a [`JsonValue`], if it represents
+/// a valid JSON scalar.
+fn scalar_to_json<S: ScalarValue>(s: &S) -> Option<JsonValue> {
+    if let Some(i) = s.as_int() {
+        Some(i.into())
+    } else if let Some(n) = s.as_big_int() {
+        i64::try_from(n)
+            .map(JsonValue::from)
+            .or_else(|_| u64::try_from(n).map(JsonValue::from))
+            .ok()
+    } else if let Some(b) = s.as_bool() {
+        Some(b.into())
+    } else if let Some(s) = s.as_str() {
+        Some(s.into())
+    } else {
+        s.as_float()
+            .and_then(Number::from_f64)
+            .map(JsonValue::Number)
+    }
+}
+
+/// Parses the provided [`ScalarToken`] as a JSON scalar.
+///
+/// Integers not fitting into the [`ScalarValue`] are parsed as floats.
+fn parse_json_token<S: ScalarValue>(value: ScalarToken<'_>) -> ParseScalarResult<'_, S> {
+    match value {
+        ScalarToken::Int(v) => v
+            .parse::<i128>()
+            .ok()
+            .and_then(S::from_big_int)
+            .map(Ok)
+            .unwrap_or_else(|| {
+                v.parse::<f64>()
+                    .map(S::from)
+                    .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value)))
+            }),
+        ScalarToken::Float(_) => <f64 as ParseScalarValue<S>>::from_str(value),
+        ScalarToken::String(_) => <String as ParseScalarValue<S>>::from_str(value),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Map, Value as JsonValue};
+
+    use crate::{
+        execute, graphql_input_value, graphql_object, graphql_value, graphql_vars, EmptyMutation,
+        EmptySubscription, FromInputValue, GraphQLScalar, InputValue, LongScalarValue, RootNode,
+        ScalarValue, ToInputValue, Value,
+    };
+
+    #[test]
+    fn json_from_input() {
+        let input: InputValue = graphql_input_value!({
+            "a": [1, 2.5, "str", null, true],
+            "b": {"c": {}},
+        });
+
+        let parsed: JsonValue = FromInputValue::from_input_value(&input).unwrap();
+
+        assert_eq!(
+            parsed,
+            json!({"a": [1, 2.5, "str", null, true], "b": {"c": {}}}),
+        );
+    }
+
+    #[test]
+    fn json_from_invalid_input() {
+        for input in [
+            graphql_input_value!(ENUM),
+            graphql_input_value!([@var]),
+            graphql_input_value!({"a": (f64::NAN)}),
+        ] {
+            assert!(
+                <JsonValue as FromInputValue>::from_input_value(&input).is_err(),
+                "input: {}",
+                input,
+            );
+        }
+    }
+
+    #[test]
+    fn json_to_input() {
+        let json = json!({"a": [1, 2.5, "str", null, true], "b": i64::MAX});
+
+        let input: InputValue = json.to_input_value();
+        assert_eq!(
+            input,
+            graphql_input_value!({
+                "a": [1, 2.5, "str", null, true],
+                "b": 9_223_372_036_854_775_807.0,
+            }),
+        );
+
+        let input: InputValue<LongScalarValue> = json.to_input_value();
+        assert_eq!(
+            <JsonValue as FromInputValue<LongScalarValue>>::from_input_value(&input),
+            Ok(json),
+        );
+    }
+
+    #[test]
+    fn json_object_from_input() {
+        let input: InputValue = graphql_input_value!({"a": [1]});
+        let parsed: Map<String, JsonValue> = FromInputValue::from_input_value(&input).unwrap();
+        assert_eq!(JsonValue::Object(parsed), json!({"a": [1]}));
+
+        let input: InputValue = graphql_input_value!([1]);
+        assert!(<Map<String, JsonValue> as FromInputValue>::from_input_value(&input).is_err());
+    }
+
+    /// Scalar able to parse any JSON value, but not opted into structured input.
+    #[derive(GraphQLScalar)]
+    #[graphql(
+        to_output_with = Self::to_output,
+        from_input_with = Self::from_input,
+        parse_token(String),
+    )]
+    struct Opaque(JsonValue);
+
+    impl Opaque {
+        fn to_output<S: ScalarValue>(&self) -> Value<S> {
+            super::json_to_value(&self.0)
+        }
+
+        fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Self, String> {
+            super::input_to_json(v).map(Self)
+        }
+    }
+
+    struct Root;
+
+    #[graphql_object]
+    impl Root {
+        fn echo(value: JsonValue) -> JsonValue {
+            value
+        }
+
+        fn echo_object(value: Map<String, JsonValue>) -> Map<String, JsonValue> {
+            value
+        }
+
+        fn echo_opaque(value: Opaque) -> Opaque {
+            value
+        }
+    }
+
+    fn schema() -> RootNode<'static, Root, EmptyMutation, EmptySubscription> {
+        RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new())
+    }
+
+    #[tokio::test]
+    async fn accepts_inline_literals() {
+        let query = r#"{
+            list: echo(value: [1, "two", {three: 3.5}, null])
+            scalar: echo(value: "str")
+            obj: echoObject(value: {a: {b: [true]}})
+        }"#;
+
+        assert_eq!(
+            execute(query, None, &schema(), &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "list": [1, "two", {"three": 3.5}, null],
+                    "scalar": "str",
+                    "obj": {"a": {"b": [true]}},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_variables() {
+        let query = r#"query($v: JSON!, $o: JSONObject!) {
+            echo(value: $v)
+            echoObject(value: $o)
+        }"#;
+        let vars = graphql_vars! {
+            "v": [{"a": 1}, "b"],
+            "o": {"c": null},
+        };
+
+        assert_eq!(
+            execute(query, None, &schema(), &vars, &()).await,
+            Ok((
+                graphql_value!({
+                    "echo": [{"a": 1}, "b"],
+                    "echoObject": {"c": null},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_non_object_for_json_object() {
+        let query = r#"{ echoObject(value: [1]) }"#;
+
+        assert!(execute(query, None, &schema(), &graphql_vars! {}, &())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn rejects_lists_and_objects_for_other_scalars() {
+        for (query, vars) in [
+            ("{ echoOpaque(value: [1]) }", graphql_vars! {}),
+            ("{ echoOpaque(value: {a: 1}) }", graphql_vars! {}),
+            (
+                "query($v: Opaque!) { echoOpaque(value: $v) }",
+                graphql_vars! {"v": [1]},
+            ),
+            (
+                "query($v: Opaque!) { echoOpaque(value: $v) }",
+                graphql_vars! {"v": {"a": 1}},
+            ),
+        ] {
+            assert!(
+                execute(query, None, &schema(), &vars, &()).await.is_err(),
+                "query: {}",
+                query,
+            );
+        }
+
+        assert_eq!(
+            execute(
+                r#"{ echoOpaque(value: "str") }"#,
+                None,
+                &schema(),
+                &graphql_vars! {},
+                &(),
+            )
+            .await,
+            Ok((graphql_value!({"echoOpaque": "str"}), vec![])),
+        );
+    }
+}
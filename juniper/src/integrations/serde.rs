@@ -16,7 +16,7 @@ use crate::{
     executor::ExecutionError,
     parser::{ParseError, SourcePosition, Spanning},
     validation::RuleError,
-    DefaultScalarValue, GraphQLError, Object, Value,
+    DefaultScalarValue, GraphQLError, LongScalarValue, Object, ScalarValue, Value,
 };
 
 impl<T: Serialize> Serialize for ExecutionError<T> {
@@ -73,6 +73,10 @@ impl<'a> Serialize for GraphQLError<'a> {
                 message: "Expected subscription, got query",
             }]
             .serialize(ser),
+            Self::UnknownDocument => [Helper {
+                message: "Unknown document",
+            }]
+            .serialize(ser),
         }
     }
 }
@@ -289,6 +293,121 @@ impl<T: Serialize> Serialize for Value<T> {
     }
 }
 
+impl<'de, S: Deserialize<'de>> Deserialize<'de> for Value<S> {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        /// Converts the provided deserialized `input` into a [`Value`].
+        ///
+        /// Deserialized [`InputValue`]s never contain enum values or variables.
+        fn into_value<S>(input: InputValue<S>) -> Value<S> {
+            match input {
+                InputValue::Null | InputValue::Enum(_) | InputValue::Variable(_) => Value::Null,
+                InputValue::Scalar(s) => Value::Scalar(s),
+                InputValue::List(l) => {
+                    Value::List(l.into_iter().map(|v| into_value(v.item)).collect())
+                }
+                InputValue::Object(o) => Value::Object(
+                    o.into_iter()
+                        .map(|(k, v)| (k.item, into_value(v.item)))
+                        .collect(),
+                ),
+            }
+        }
+
+        InputValue::deserialize(de).map(into_value)
+    }
+}
+
+impl<'de, S: Deserialize<'de>> Deserialize<'de> for Object<S> {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        Value::deserialize(de)?
+            .into_object()
+            .ok_or_else(|| de::Error::custom("expected an object"))
+    }
+}
+
+/// Deserializes the [`Value`] into any [`Deserialize`] type, so the results of
+/// executed operations can be used as Rust types.
+///
+/// [`Value::Null`] is deserialized as `()` or [`None`], and the enum values
+/// (represented as strings) as unit variants.
+impl<'de, S: ScalarValue> Deserializer<'de> for Value<S> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Self::Null => visitor.visit_unit(),
+            Self::Scalar(s) => {
+                if let Some(b) = s.as_bool() {
+                    visitor.visit_bool(b)
+                } else if let Some(n) = s.as_int() {
+                    visitor.visit_i32(n)
+                } else if let Some(n) = s.as_big_int() {
+                    match i64::try_from(n) {
+                        Ok(n) => visitor.visit_i64(n),
+                        Err(_) => visitor.visit_i128(n),
+                    }
+                } else if let Some(f) = s.as_float() {
+                    visitor.visit_f64(f)
+                } else if let Some(s) = s.into_string() {
+                    visitor.visit_string(s)
+                } else {
+                    Err(de::Error::custom("unsupported scalar value"))
+                }
+            }
+            Self::List(l) => visitor.visit_seq(de::value::SeqDeserializer::new(l.into_iter())),
+            Self::Object(o) => visitor.visit_map(de::value::MapDeserializer::new(o.into_iter())),
+        }
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Self::Null => visitor.visit_none(),
+            v => visitor.visit_some(v),
+        }
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self {
+            Self::Scalar(s) if s.as_str().is_some() => {
+                let s = s.into_string().unwrap();
+                visitor.visit_enum(s.into_deserializer())
+            }
+            Self::Object(o) if o.field_count() == 1 => {
+                de::value::MapDeserializer::new(o.into_iter())
+                    .deserialize_enum(name, variants, visitor)
+            }
+            v => v.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+impl<'de, S: ScalarValue> de::IntoDeserializer<'de, de::value::Error> for Value<S> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
 impl<'de> Deserialize<'de> for DefaultScalarValue {
     fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
         struct Visitor;
@@ -351,8 +470,60 @@ impl<'de> Deserialize<'de> for DefaultScalarValue {
     }
 }
 
+impl<'de> Deserialize<'de> for LongScalarValue {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = LongScalarValue;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a valid input value")
+            }
+
+            fn visit_bool<E: de::Error>(self, b: bool) -> Result<Self::Value, E> {
+                Ok(LongScalarValue::Boolean(b))
+            }
+
+            fn visit_i64<E: de::Error>(self, n: i64) -> Result<Self::Value, E> {
+                Ok(i32::try_from(n)
+                    .map(LongScalarValue::Int)
+                    .unwrap_or(LongScalarValue::Long(n)))
+            }
+
+            fn visit_u64<E: de::Error>(self, n: u64) -> Result<Self::Value, E> {
+                if let Ok(n) = i64::try_from(n) {
+                    self.visit_i64(n)
+                } else {
+                    // Browser's `JSON.stringify()` serializes all numbers
+                    // having no fractional part as integers (no decimal point),
+                    // so we must parse large integers as floating point,
+                    // otherwise we would error on transferring large floating
+                    // point numbers.
+                    Ok(LongScalarValue::Float(n as f64))
+                }
+            }
+
+            fn visit_f64<E: de::Error>(self, f: f64) -> Result<Self::Value, E> {
+                Ok(LongScalarValue::Float(f))
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                self.visit_string(s.into())
+            }
+
+            fn visit_string<E: de::Error>(self, s: String) -> Result<Self::Value, E> {
+                Ok(LongScalarValue::String(s))
+            }
+        }
+
+        de.deserialize_any(Visitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use serde::Deserialize;
     use serde_json::{from_str, to_string};
 
     use crate::{
@@ -406,4 +577,72 @@ mod tests {
             r#"{"message":"foo error","locations":[{"line":1,"column":1}],"path":[],"extensions":{"foo":"bar"}}"#,
         );
     }
+
+    #[test]
+    fn value() {
+        assert_eq!(
+            from_str::<Value>(r#"{"a": [1, 2.5, "b", true, null], "c": {}}"#).unwrap(),
+            graphql_value!({"a": [1, 2.5, "b", true, null], "c": {}}),
+        );
+        assert_eq!(
+            from_str::<Object<DefaultScalarValue>>(r#"{"a": 1}"#).unwrap(),
+            graphql_value!({"a": 1}).into_object().unwrap(),
+        );
+        assert!(from_str::<Object<DefaultScalarValue>>("[1]").is_err());
+    }
+
+    #[test]
+    fn value_into_type() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        #[serde(rename_all = "camelCase")]
+        struct Hero {
+            name: String,
+            home_planet: Option<String>,
+            height: f64,
+            appears_in: Vec<Episode>,
+            friend: Option<Box<Hero>>,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+        enum Episode {
+            NewHope,
+            Empire,
+        }
+
+        let value: Value = graphql_value!({
+            "name": "Luke",
+            "homePlanet": null,
+            "height": 2,
+            "appearsIn": ["NEW_HOPE", "EMPIRE"],
+            "friend": {
+                "name": "Han",
+                "homePlanet": "Corellia",
+                "height": 1.8,
+                "appearsIn": [],
+                "friend": null,
+            },
+        });
+
+        assert_eq!(
+            Hero::deserialize(value).unwrap(),
+            Hero {
+                name: "Luke".into(),
+                home_planet: None,
+                height: 2.0,
+                appears_in: vec![Episode::NewHope, Episode::Empire],
+                friend: Some(Box::new(Hero {
+                    name: "Han".into(),
+                    home_planet: Some("Corellia".into()),
+                    height: 1.8,
+                    appears_in: vec![],
+                    friend: None,
+                })),
+            },
+        );
+        let (invalid, unknown): (Value, Value) =
+            (graphql_value!({"name": 1}), graphql_value!("JEDI"));
+        assert!(Hero::deserialize(invalid).is_err());
+        assert!(Episode::deserialize(unknown).is_err());
+    }
 }
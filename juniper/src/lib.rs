@@ -8,7 +8,7 @@
 // absolute `::juniper` path correctly, without errors.
 extern crate self as juniper;
 
-use std::fmt;
+use std::{borrow::Cow, fmt};
 
 // These are required by the code generated via the `juniper_codegen` macros.
 #[doc(hidden)]
@@ -22,7 +22,8 @@ pub use futures::future::{BoxFuture, LocalBoxFuture};
 // functionality automatically.
 pub use juniper_codegen::{
     graphql_interface, graphql_object, graphql_scalar, graphql_subscription, graphql_union,
-    GraphQLEnum, GraphQLInputObject, GraphQLInterface, GraphQLObject, GraphQLScalar, GraphQLUnion,
+    GraphQLEnum, GraphQLInputObject, GraphQLInterface, GraphQLMergedObject, GraphQLObject,
+    GraphQLScalar, GraphQLUnion,
 };
 
 #[doc(hidden)]
@@ -32,7 +33,8 @@ mod ast;
 pub mod executor;
 mod introspection;
 pub mod parser;
-pub(crate) mod schema;
+pub mod schema;
+pub mod testing;
 mod types;
 mod util;
 pub mod validation;
@@ -62,20 +64,27 @@ use crate::{
 
 pub use crate::{
     ast::{
-        Definition, Document, FromInputValue, InputValue, Operation, OperationType, Selection,
-        ToInputValue, Type,
+        Definition, DirectiveDefinition, Document, EnumTypeDefinition, EnumValueDefinition,
+        FieldDefinition, FromInputValue, InputObjectTypeDefinition, InputValue,
+        InputValueDefinition, InterfaceTypeDefinition, ObjectTypeDefinition, Operation,
+        OperationType, ScalarTypeDefinition, SchemaDefinition, SchemaDocument, Selection,
+        ToInputValue, Type, TypeDefinition, TypeSystemDefinition, UnionTypeDefinition,
     },
     executor::{
-        Applies, Context, ExecutionError, ExecutionResult, Executor, FieldError, FieldResult,
-        FromContext, IntoFieldError, IntoResolvable, LookAheadArgument, LookAheadMethods,
-        LookAheadSelection, LookAheadValue, OwnedExecutor, Registry, ValuesStream, Variables,
+        Applies, CachePolicy, CacheScope, Context, ExecutionError, ExecutionResult, Executor,
+        FieldError, FieldResult, FromContext, Guard, IntoFieldError, IntoResolvable,
+        LookAheadArgument, LookAheadMethods, LookAheadSelection, LookAheadValue, OwnedExecutor,
+        Registry, Validator, ValuesStream, Variables,
     },
     introspection::IntrospectionFormat,
     macros::helper::subscription::{ExtractTypeFromStream, IntoFieldResult},
-    parser::{ParseError, ScalarToken, Spanning},
+    parser::{
+        Diagnostic, DiagnosticStyle, ParseError, ParserLimit, ParserLimits, ScalarToken, Spanning,
+    },
     schema::{
         meta,
-        model::{RootNode, SchemaType},
+        model::{DirectiveLocation, DirectiveType, RootNode, SchemaType},
+        remote::SchemaBuildError,
     },
     types::{
         async_await::{GraphQLTypeAsync, GraphQLValueAsync},
@@ -89,7 +98,10 @@ pub use crate::{
         },
     },
     validation::RuleError,
-    value::{DefaultScalarValue, Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value},
+    value::{
+        DefaultScalarValue, LongScalarValue, Object, ParseScalarResult, ParseScalarValue,
+        ScalarValue, Value,
+    },
 };
 
 /// An error that prevented query execution
@@ -103,6 +115,7 @@ pub enum GraphQLError<'a> {
     UnknownOperationName,
     IsSubscription,
     NotSubscription,
+    UnknownDocument,
 }
 
 impl<'a> fmt::Display for GraphQLError<'a> {
@@ -120,12 +133,34 @@ impl<'a> fmt::Display for GraphQLError<'a> {
             GraphQLError::UnknownOperationName => write!(f, "Unknown operation name"),
             GraphQLError::IsSubscription => write!(f, "Operation is a subscription"),
             GraphQLError::NotSubscription => write!(f, "Operation is not a subscription"),
+            GraphQLError::UnknownDocument => write!(f, "Unknown document"),
         }
     }
 }
 
 impl<'a> std::error::Error for GraphQLError<'a> {}
 
+impl<'a> GraphQLError<'a> {
+    /// Returns [`Diagnostic`]s describing this error.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        match self {
+            GraphQLError::ParseError(error) => vec![error.into()],
+            GraphQLError::ValidationError(errors) => errors.iter().map(Into::into).collect(),
+            e => vec![Diagnostic::new(e.to_string())],
+        }
+    }
+
+    /// Renders this error as [`Diagnostic`]s, showing the lines of the
+    /// provided document `source` it's about.
+    pub fn render(&self, source: &str, style: DiagnosticStyle) -> String {
+        self.diagnostics()
+            .iter()
+            .map(|d| d.render(source, style))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
 /// Execute a query synchronously in a provided schema
 pub fn execute_sync<'a, S, QueryT, MutationT, SubscriptionT>(
     document_source: &'a str,
@@ -140,27 +175,9 @@ where
     MutationT: GraphQLType<S, Context = QueryT::Context>,
     SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
 {
-    let document = parse_document_source(document_source, &root_node.schema)?;
+    let document = prepare_document(document_source, root_node)?;
 
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
-
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
-
-    let operation = get_operation(&document, operation_name)?;
-
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
-
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
+    let operation = prepare_operation(&document, operation_name, root_node, variables)?;
 
     execute_validated_query(&document, operation, root_node, variables, context)
 }
@@ -183,27 +200,9 @@ where
     SubscriptionT::TypeInfo: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let document = parse_document_source(document_source, &root_node.schema)?;
-
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
-
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
+    let document = prepare_document(document_source, root_node)?;
 
-    let operation = get_operation(&document, operation_name)?;
-
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
-
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
-    }
+    let operation = prepare_operation(&document, operation_name, root_node, variables)?;
 
     executor::execute_validated_query_async(&document, operation, root_node, variables, context)
         .await
@@ -227,31 +226,76 @@ where
     SubscriptionT::TypeInfo: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let document: crate::ast::OwnedDocument<'a, S> =
-        parse_document_source(document_source, &root_node.schema)?;
+    let document = prepare_document(document_source, root_node)?;
 
+    let operation = prepare_operation(&document, operation_name, root_node, variables)?;
+
+    executor::resolve_validated_subscription(&document, operation, root_node, variables, context)
+        .await
+}
+
+/// Returns the already parsed and validated trusted document with the provided
+/// `source`, if the provided `root_node` is restricted to
+/// [`TrustedDocuments`] and it's one of them, or parses and validates it
+/// otherwise.
+///
+/// Untrusted documents aren't rejected here, but in [`http::GraphQLRequest`]
+/// only.
+///
+/// [`TrustedDocuments`]: http::trusted_documents::TrustedDocuments
+fn prepare_document<'a, S, QueryT, MutationT, SubscriptionT>(
+    source: &'a str,
+    root_node: &'a RootNode<QueryT, MutationT, SubscriptionT, S>,
+) -> Result<Cow<'a, Document<'a, S>>, GraphQLError<'a>>
+where
+    S: ScalarValue,
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S, Context = QueryT::Context>,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+{
+    #[cfg(feature = "trusted-documents")]
+    if let Some(document) = root_node
+        .trusted_documents()
+        .and_then(|documents| documents.document(source))
     {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
+        return Ok(Cow::Borrowed(document));
+    }
 
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
+    let document = parse_document_source(source, &root_node.schema)?;
+
+    let mut ctx = ValidatorContext::new(&root_node.schema, &document);
+    visit_all_rules(&mut ctx, &document);
+
+    let errors = ctx.into_errors();
+    if !errors.is_empty() {
+        return Err(GraphQLError::ValidationError(errors));
     }
 
-    let operation = get_operation(&document, operation_name)?;
+    Ok(Cow::Owned(document))
+}
 
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
+/// Returns the operation with the provided `operation_name` from the provided
+/// `document`, validating the provided `variables` against it.
+fn prepare_operation<'b, 'd, 'e, S, QueryT, MutationT, SubscriptionT>(
+    document: &'b Document<'d, S>,
+    operation_name: Option<&str>,
+    root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+) -> Result<&'b Spanning<Operation<'d, S>>, GraphQLError<'e>>
+where
+    S: ScalarValue,
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S, Context = QueryT::Context>,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+{
+    let operation = get_operation(document, operation_name)?;
 
-        if !errors.is_empty() {
-            return Err(GraphQLError::ValidationError(errors));
-        }
+    let errors = validate_input_values(variables, operation, &root_node.schema);
+    if !errors.is_empty() {
+        return Err(GraphQLError::ValidationError(errors));
     }
 
-    executor::resolve_validated_subscription(&document, operation, root_node, variables, context)
-        .await
+    Ok(operation)
 }
 
 /// Execute the reference introspection query in the provided schema
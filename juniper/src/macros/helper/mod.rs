@@ -1,12 +1,18 @@
 //! Helper traits and definitions for macros.
 
+pub mod resolver;
 pub mod subscription;
 
 use std::fmt;
 
 use futures::future::{self, BoxFuture};
 
-use crate::FieldError;
+use crate::{
+    macros::reflect,
+    meta::{Argument, Field, InputObjectMeta, MetaType, ObjectMeta},
+    Arguments, ExecutionResult, Executor, FieldError, GraphQLValue, GraphQLValueAsync, InputValue,
+    Registry, ScalarValue,
+};
 
 /// This trait is used by [`graphql_scalar!`] macro to retrieve [`Error`] type
 /// from a [`Result`].
@@ -54,3 +60,152 @@ where
 {
     Box::pin(future::err(err_unnamed_type(name)))
 }
+
+/// Extracts the [`Field`]s of the given [`MetaType`] of a [GraphQL object][1]
+/// being merged into the `merged` one, omitting the `__typename` field.
+///
+/// # Panics
+///
+/// If the given [`MetaType`] doesn't represent a [GraphQL object][1].
+///
+/// [`Field`]: crate::meta::Field
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+pub fn merged_object_fields<'r, S>(meta: MetaType<'r, S>, merged: &str) -> Vec<Field<'r, S>> {
+    match meta {
+        MetaType::Object(ObjectMeta { fields, .. }) => fields
+            .into_iter()
+            .filter(|f| f.name != "__typename")
+            .collect(),
+        _ => panic!(
+            "Only GraphQL objects can be merged into `{}`, got `{}`",
+            merged,
+            meta.name().unwrap_or_default(),
+        ),
+    }
+}
+
+/// Extracts the [`Field`]s of the given [`MetaType`] of a [GraphQL object][1]
+/// being flattened into the `flattened_into` one, omitting the `__typename`
+/// field.
+///
+/// # Panics
+///
+/// If the given [`MetaType`] doesn't represent a [GraphQL object][1].
+///
+/// [`Field`]: crate::meta::Field
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+pub fn flattened_object_fields<'r, S>(
+    meta: MetaType<'r, S>,
+    flattened_into: &str,
+) -> Vec<Field<'r, S>> {
+    match meta {
+        MetaType::Object(ObjectMeta { fields, .. }) => fields
+            .into_iter()
+            .filter(|f| f.name != "__typename")
+            .collect(),
+        _ => panic!(
+            "Only GraphQL objects can be flattened into `{}`, got `{}`",
+            flattened_into,
+            meta.name().unwrap_or_default(),
+        ),
+    }
+}
+
+/// Extracts the input [`Argument`]s of the given [`MetaType`] of a
+/// [GraphQL input object][1] being flattened into the `flattened_into` one.
+///
+/// # Panics
+///
+/// If the given [`MetaType`] doesn't represent a [GraphQL input object][1].
+///
+/// [`Argument`]: crate::meta::Argument
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+pub fn flattened_input_object_fields<'r, S>(
+    meta: MetaType<'r, S>,
+    flattened_into: &str,
+) -> Vec<Argument<'r, S>> {
+    match meta {
+        MetaType::InputObject(InputObjectMeta { input_fields, .. }) => input_fields,
+        _ => panic!(
+            "Only GraphQL input objects can be flattened into `{}`, got `{}`",
+            flattened_into,
+            meta.name().unwrap_or_default(),
+        ),
+    }
+}
+
+/// Extracts the fields of the given [`InputValue`] of a
+/// [GraphQL input object][1] being flattened into the `flattened_into` one.
+///
+/// # Panics
+///
+/// If the given [`InputValue`] doesn't represent a [GraphQL input object][1].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+pub fn flattened_input_object_value<S>(
+    value: InputValue<S>,
+    flattened_into: &str,
+) -> Vec<(String, InputValue<S>)> {
+    match value {
+        InputValue::Object(fields) => fields.into_iter().map(|(k, v)| (k.item, v.item)).collect(),
+        _ => panic!(
+            "Only GraphQL input objects can be flattened into `{}`",
+            flattened_into,
+        ),
+    }
+}
+
+/// Computed [GraphQL fields][1] of a [GraphQL object][2] declared in a separate
+/// `impl` block with `#[graphql_object(computed)]` attribute, being merged into
+/// the fields of a struct with `#[derive(GraphQLObject)]` and
+/// `#[graphql(computed)]` attribute.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+/// [2]: https://spec.graphql.org/October2021#sec-Objects
+pub trait ComputedFields<S: ScalarValue>: GraphQLValue<S> {
+    /// [`reflect::Names`] of the computed [GraphQL fields][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    const NAMES: reflect::Names;
+
+    /// Returns the [`Field`]s metadata of the computed [GraphQL fields][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    fn computed_fields<'r>(
+        info: &Self::TypeInfo,
+        registry: &mut Registry<'r, S>,
+    ) -> Vec<Field<'r, S>>
+    where
+        S: 'r;
+
+    /// Resolves the computed [GraphQL field][1] with the given `field_name`.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    fn resolve_computed_field(
+        &self,
+        info: &Self::TypeInfo,
+        field_name: &str,
+        arguments: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S>;
+}
+
+/// Asynchronous version of [`ComputedFields`].
+pub trait ComputedFieldsAsync<S>: ComputedFields<S> + GraphQLValueAsync<S>
+where
+    Self::TypeInfo: Sync,
+    Self::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    /// Resolves the computed [GraphQL field][1] with the given `field_name`
+    /// asynchronously.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    fn resolve_computed_field_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        field_name: &'a str,
+        arguments: &'a Arguments<S>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>>;
+}
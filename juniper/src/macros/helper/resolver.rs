@@ -0,0 +1,189 @@
+//! Helper definitions for resolving struct fields with custom functions.
+//!
+//! Used in `#[derive(GraphQLObject)]` macro to support `#[graphql(with = ...)]`
+//! attribute argument on struct fields, inferring the GraphQL type of a field
+//! from the return type of the provided (sync or async) function.
+
+use std::future::Future;
+
+use futures::future;
+
+use crate::{
+    macros::reflect::{self, BaseSubTypes, BaseType, WrappedType},
+    marker::IsOutputType,
+    meta::Field,
+    Executor, GraphQLType, IntoResolvable, Registry, ScalarValue,
+};
+
+/// Marker of a [`FieldResolver`] implemented for a synchronous function.
+pub enum Synchronous {}
+
+/// Marker of a [`FieldResolver`] implemented for an asynchronous function.
+pub enum Asynchronous {}
+
+/// Function resolving a struct field's value of type `T` into a
+/// [GraphQL field][1] value, given the context `C` and the [`Executor`].
+///
+/// `M` is a [`Synchronous`] or [`Asynchronous`] marker, allowing to implement
+/// this trait for both kinds of functions without overlapping.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+pub trait FieldResolver<'a, T: ?Sized + 'a, C: 'a, S: 'a, M> {
+    /// Type of the value returned by this function.
+    type Output;
+
+    /// [`Future`] resolving into the [`Output`] of this function.
+    ///
+    /// [`Output`]: FieldResolver::Output
+    type Future: Future<Output = Self::Output> + 'a;
+
+    /// Calls this function synchronously.
+    ///
+    /// Returns [`None`] if this function is asynchronous.
+    fn call_sync(
+        &self,
+        value: &'a T,
+        context: &'a C,
+        executor: &'a Executor<'a, 'a, C, S>,
+    ) -> Option<Self::Output>;
+
+    /// Calls this function asynchronously.
+    fn call_async(
+        &self,
+        value: &'a T,
+        context: &'a C,
+        executor: &'a Executor<'a, 'a, C, S>,
+    ) -> Self::Future;
+}
+
+impl<'a, F, R, T, C, S> FieldResolver<'a, T, C, S, Synchronous> for F
+where
+    F: Fn(&'a T, &'a C, &'a Executor<'a, 'a, C, S>) -> R,
+    R: BaseType<S> + 'a,
+    T: ?Sized + 'a,
+    C: 'a,
+    S: 'a,
+{
+    type Output = R;
+    type Future = future::Ready<R>;
+
+    fn call_sync(
+        &self,
+        value: &'a T,
+        context: &'a C,
+        executor: &'a Executor<'a, 'a, C, S>,
+    ) -> Option<R> {
+        Some(self(value, context, executor))
+    }
+
+    fn call_async(
+        &self,
+        value: &'a T,
+        context: &'a C,
+        executor: &'a Executor<'a, 'a, C, S>,
+    ) -> Self::Future {
+        future::ready(self(value, context, executor))
+    }
+}
+
+impl<'a, F, Fut, T, C, S> FieldResolver<'a, T, C, S, Asynchronous> for F
+where
+    F: Fn(&'a T, &'a C, &'a Executor<'a, 'a, C, S>) -> Fut,
+    Fut: Future + 'a,
+    Fut::Output: BaseType<S>,
+    T: ?Sized + 'a,
+    C: 'a,
+    S: 'a,
+{
+    type Output = Fut::Output;
+    type Future = Fut;
+
+    fn call_sync(&self, _: &'a T, _: &'a C, _: &'a Executor<'a, 'a, C, S>) -> Option<Self::Output> {
+        None
+    }
+
+    fn call_async(
+        &self,
+        value: &'a T,
+        context: &'a C,
+        executor: &'a Executor<'a, 'a, C, S>,
+    ) -> Fut {
+        self(value, context, executor)
+    }
+}
+
+/// Returns the [`BaseType::NAME`] of the [`FieldResolver::Output`] of the
+/// given function.
+pub const fn output_type<'a, F, T, C, S, M>(_: &F) -> reflect::Type
+where
+    F: FieldResolver<'a, T, C, S, M>,
+    F::Output: BaseType<S>,
+    T: ?Sized + 'a,
+    C: 'a,
+    S: 'a,
+{
+    <F::Output as BaseType<S>>::NAME
+}
+
+/// Returns the [`BaseSubTypes::NAMES`] of the [`FieldResolver::Output`] of the
+/// given function.
+pub const fn output_sub_types<'a, F, T, C, S, M>(_: &F) -> reflect::Types
+where
+    F: FieldResolver<'a, T, C, S, M>,
+    F::Output: BaseSubTypes<S>,
+    T: ?Sized + 'a,
+    C: 'a,
+    S: 'a,
+{
+    <F::Output as BaseSubTypes<S>>::NAMES
+}
+
+/// Returns the [`WrappedType::VALUE`] of the [`FieldResolver::Output`] of the
+/// given function.
+pub const fn output_wrapped_value<'a, F, T, C, S, M>(_: &F) -> reflect::WrappedValue
+where
+    F: FieldResolver<'a, T, C, S, M>,
+    F::Output: WrappedType<S>,
+    T: ?Sized + 'a,
+    C: 'a,
+    S: 'a,
+{
+    <F::Output as WrappedType<S>>::VALUE
+}
+
+/// Registers a [GraphQL field][1] with the given `name`, having the type of
+/// the [`FieldResolver::Output`] of the given function.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+pub fn output_field<'a, 'r, F, T, C, S, M, I>(
+    _: &F,
+    name: &str,
+    info: &I::TypeInfo,
+    registry: &mut Registry<'r, S>,
+) -> Field<'r, S>
+where
+    F: FieldResolver<'a, T, C, S, M>,
+    F::Output: IntoResolvable<'a, S, I, C>,
+    I: GraphQLType<S>,
+    T: ?Sized + 'a,
+    C: 'a,
+    S: ScalarValue + 'a,
+{
+    registry.field_convert::<F::Output, I, C>(name, info)
+}
+
+/// Performs static checks of the [`FieldResolver::Output`] of the given
+/// function being a valid [GraphQL output type][1].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Input-and-Output-Types
+pub fn mark_output<'a, F, T, C, S, M, I>(_: &F)
+where
+    F: FieldResolver<'a, T, C, S, M>,
+    F::Output: IntoResolvable<'a, S, I, C>,
+    I: IsOutputType<S>,
+    T: ?Sized + 'a,
+    C: 'a,
+    S: ScalarValue + 'a,
+{
+    I::mark()
+}
@@ -281,18 +281,21 @@ pub type Arguments = &'static [(Name, Type, WrappedValue)];
 /// Alias for a `const`-hashed [`Name`] used in a `const` context.
 pub type FieldName = u128;
 
-/// [GraphQL object][1] or [interface][2] [field arguments][3] [`Names`].
+/// [GraphQL object][1], [interface][2] or [input object][4]
+/// [field arguments][3] [`Names`].
 ///
 /// [1]: https://spec.graphql.org/October2021#sec-Objects
 /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
 /// [3]: https://spec.graphql.org/October2021#sec-Language.Arguments
+/// [4]: https://spec.graphql.org/October2021#sec-Input-Objects
 pub trait Fields<S> {
-    /// [`Names`] of the [GraphQL object][1] or [interface][2]
-    /// [field arguments][3].
+    /// [`Names`] of the [GraphQL object][1], [interface][2] or
+    /// [input object][4] [field arguments][3].
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
     /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
     /// [3]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    /// [4]: https://spec.graphql.org/October2021#sec-Input-Objects
     const NAMES: Names;
 }
 
@@ -414,6 +417,22 @@ pub const fn fnv1a128(str: Name) -> u128 {
     hash
 }
 
+/// Hashes the [`Name`] of a [`Field`] derived from a struct field, which is
+/// merged with the `computed` [`Field`]s of its `impl` block.
+///
+/// Differs from [`fnv1a128()`] only if the same [`Name`] is `computed` too, so
+/// the implementations of the [`Field`] traits don't overlap, leaving the
+/// collision to be reported by the [`assert_computed_fields!`] check.
+#[must_use]
+pub const fn struct_field_name(name: Name, computed: Names) -> FieldName {
+    let hash = fnv1a128(name);
+    if str_exists_in_arr(name, computed) {
+        !hash
+    } else {
+        hash
+    }
+}
+
 /// Length __in bytes__ of the [`format_type!`] macro result.
 #[must_use]
 pub const fn type_len_with_wrapped_val(ty: Type, val: WrappedValue) -> usize {
@@ -470,6 +489,46 @@ pub const fn str_exists_in_arr(val: &str, arr: &[&str]) -> bool {
     false
 }
 
+/// Returns the first [`str`](prim@str) of the given `l` array, which exists in
+/// the given `r` array too, if any.
+#[must_use]
+pub const fn first_common_str<'a>(l: &[&'a str], r: &[&str]) -> Option<&'a str> {
+    let mut i = 0;
+    while i < l.len() {
+        if str_exists_in_arr(l[i], r) {
+            return Some(l[i]);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Concatenates two [`Names`] into an array of `N` [`Name`]s in a `const`
+/// context.
+///
+/// # Panics
+///
+/// If `N` doesn't equal to the total length of the given [`Names`].
+#[must_use]
+pub const fn concat_names<const N: usize>(l: Names, r: Names) -> [Name; N] {
+    assert!(
+        l.len() + r.len() == N,
+        "wrong length of concatenated `Names`"
+    );
+
+    let mut out = [""; N];
+    let mut i = 0;
+    while i < l.len() {
+        out[i] = l[i];
+        i += 1;
+    }
+    while i < N {
+        out[i] = r[i - l.len()];
+        i += 1;
+    }
+    out
+}
+
 /// Compares strings in a `const` context.
 ///
 /// As there is no `const impl Trait` and `l == r` calls [`Eq`], we have to
@@ -523,6 +582,146 @@ macro_rules! assert_implemented_for {
     };
 }
 
+/// Asserts that none of the [GraphQL objects][1] merged into the `$merged`
+/// type by `#[derive(GraphQLMergedObject)]` define a [`Field`] with the same
+/// name.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+#[macro_export]
+macro_rules! assert_merged_fields {
+    ($scalar: ty, $merged: ty $(,)?) => {};
+    ($scalar: ty, $merged: ty, $part: ty $(, $others: ty)* $(,)?) => {
+        const _: () = {
+            $({
+                const DUPLICATE: ::std::option::Option<$crate::macros::reflect::Name> =
+                    $crate::macros::reflect::first_common_str(
+                        <$part as $crate::macros::reflect::Fields<$scalar>>::NAMES,
+                        <$others as $crate::macros::reflect::Fields<$scalar>>::NAMES,
+                    );
+                if DUPLICATE.is_some() {
+                    const FIELD_NAME: &str = match DUPLICATE {
+                        ::std::option::Option::Some(name) => name,
+                        ::std::option::Option::None => "",
+                    };
+                    const MSG: &str = $crate::const_concat!(
+                        "Failed to merge `",
+                        <$part as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                        "` and `",
+                        <$others as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                        "` into `",
+                        <$merged as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                        "`: field `",
+                        FIELD_NAME,
+                        "` is defined by both.",
+                    );
+                    ::std::panic!("{}", MSG);
+                }
+            })*
+        };
+        $crate::assert_merged_fields!($scalar, $merged $(, $others)*);
+    };
+}
+
+/// Asserts that none of the computed [`Field`]s of the `$ty` type, declared by
+/// `#[graphql_object(computed)]`, has the same name as one of its `$fields`
+/// derived by `#[derive(GraphQLObject)]`.
+#[macro_export]
+macro_rules! assert_computed_fields {
+    ($scalar: ty, $ty: ty $(, $fields: expr)* $(,)?) => {
+        const _: () = {
+            const DUPLICATE: ::std::option::Option<$crate::macros::reflect::Name> =
+                $crate::macros::reflect::first_common_str(
+                    &[$($fields),*],
+                    <$ty as $crate::macros::helper::ComputedFields<$scalar>>::NAMES,
+                );
+            if DUPLICATE.is_some() {
+                const FIELD_NAME: &str = match DUPLICATE {
+                    ::std::option::Option::Some(name) => name,
+                    ::std::option::Option::None => "",
+                };
+                const MSG: &str = $crate::const_concat!(
+                    "Field `",
+                    FIELD_NAME,
+                    "` of `",
+                    <$ty as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                    "` is defined both by the struct and its computed fields.",
+                );
+                ::std::panic!("{}", MSG);
+            }
+        };
+    };
+}
+
+/// Asserts that none of the [`Fields`] of the `$flattened` types, being
+/// flattened into the `$ty` type with `#[graphql(flatten)]` attribute, has the
+/// same name as one of the own `$fields` [`Names`] of the `$ty` type, or as one
+/// of the [`Fields`] of another `$flattened` type.
+#[macro_export]
+macro_rules! assert_flattened_fields {
+    (@pairs $scalar: ty, $ty: ty $(,)?) => {};
+    (@pairs $scalar: ty, $ty: ty, $part: ty $(, $others: ty)* $(,)?) => {
+        const _: () = {
+            $({
+                const DUPLICATE: ::std::option::Option<$crate::macros::reflect::Name> =
+                    $crate::macros::reflect::first_common_str(
+                        <$part as $crate::macros::reflect::Fields<$scalar>>::NAMES,
+                        <$others as $crate::macros::reflect::Fields<$scalar>>::NAMES,
+                    );
+                if DUPLICATE.is_some() {
+                    const FIELD_NAME: &str = match DUPLICATE {
+                        ::std::option::Option::Some(name) => name,
+                        ::std::option::Option::None => "",
+                    };
+                    const MSG: &str = $crate::const_concat!(
+                        "Failed to flatten `",
+                        <$part as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                        "` and `",
+                        <$others as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                        "` into `",
+                        <$ty as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                        "`: field `",
+                        FIELD_NAME,
+                        "` is defined by both.",
+                    );
+                    ::std::panic!("{}", MSG);
+                }
+            })*
+        };
+        $crate::assert_flattened_fields!(@pairs $scalar, $ty $(, $others)*);
+    };
+    ($scalar: ty, $ty: ty, $fields: expr $(, $flattened: ty)* $(,)?) => {
+        const _: () = {
+            const FIELDS: $crate::macros::reflect::Names = $fields;
+            $({
+                const DUPLICATE: ::std::option::Option<$crate::macros::reflect::Name> =
+                    $crate::macros::reflect::first_common_str(
+                        FIELDS,
+                        <$flattened as $crate::macros::reflect::Fields<$scalar>>::NAMES,
+                    );
+                if DUPLICATE.is_some() {
+                    const FIELD_NAME: &str = match DUPLICATE {
+                        ::std::option::Option::Some(name) => name,
+                        ::std::option::Option::None => "",
+                    };
+                    const MSG: &str = $crate::const_concat!(
+                        "Failed to flatten `",
+                        <$flattened as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                        "` into `",
+                        <$ty as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                        "`: field `",
+                        FIELD_NAME,
+                        "` is already defined by `",
+                        <$ty as $crate::macros::reflect::BaseType<$scalar>>::NAME,
+                        "`.",
+                    );
+                    ::std::panic!("{}", MSG);
+                }
+            })*
+        };
+        $crate::assert_flattened_fields!(@pairs $scalar, $ty $(, $flattened)*);
+    };
+}
+
 /// Asserts that `impl = ...` attribute argument has all the types referencing
 /// this GraphQL type in `#[graphql_interface(for = ...)]`.
 ///
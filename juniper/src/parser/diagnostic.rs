@@ -0,0 +1,230 @@
+use std::fmt::Write as _;
+
+use crate::parser::{ParseError, SourcePosition, Spanning};
+
+/// Style of a rendered [`Diagnostic`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DiagnosticStyle {
+    /// Plain text.
+    Plain,
+
+    /// Text colored with [ANSI escape codes][0], for terminals.
+    ///
+    /// [0]: https://en.wikipedia.org/wiki/ANSI_escape_code
+    Ansi,
+}
+
+impl DiagnosticStyle {
+    /// Wraps the provided `text` into the provided ANSI `code`, if this style
+    /// uses them.
+    fn paint(self, code: &str, text: &str) -> String {
+        match self {
+            Self::Plain => text.to_owned(),
+            Self::Ansi => format!("\x1b[{}m{}\x1b[0m", code, text),
+        }
+    }
+}
+
+const ERROR: &str = "1;31";
+const NOTE: &str = "1;34";
+const EMPHASIS: &str = "1";
+
+/// Location in a document a [`Diagnostic`] points to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Label {
+    start: SourcePosition,
+    end: Option<SourcePosition>,
+    text: Option<String>,
+}
+
+/// Error in a GraphQL document, rendered in the style of `rustc` diagnostics
+/// along with the lines of the document it points to.
+///
+/// ```text
+/// error: Unknown field "nme" on type "Human"
+///  --> 1:10
+///   |
+/// 1 | { human { nme } }
+///   |           ^^^
+/// ```
+///
+/// Created from a [`Spanning`] [`ParseError`] or a [`RuleError`].
+///
+/// [`RuleError`]: crate::RuleError
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    message: String,
+    primary: Option<Label>,
+    related: Vec<Label>,
+    hints: Vec<String>,
+}
+
+impl Diagnostic {
+    /// Creates a new [`Diagnostic`] with the provided `message`, not pointing
+    /// to any location.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            primary: None,
+            related: vec![],
+            hints: vec![],
+        }
+    }
+
+    /// Points this [`Diagnostic`] to the provided `start` position.
+    ///
+    /// Without an `end`, the token starting at `start` is underlined.
+    #[must_use]
+    pub fn at(mut self, start: SourcePosition, end: Option<SourcePosition>) -> Self {
+        self.primary = Some(Label {
+            start,
+            end,
+            text: None,
+        });
+        self
+    }
+
+    /// Adds a related location, described with the provided `label`.
+    #[must_use]
+    pub fn with_related(mut self, position: SourcePosition, label: impl Into<String>) -> Self {
+        self.related.push(Label {
+            start: position,
+            end: None,
+            text: Some(label.into()),
+        });
+        self
+    }
+
+    /// Adds the provided `hint` on fixing this [`Diagnostic`].
+    #[must_use]
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hints.push(hint.into());
+        self
+    }
+
+    /// Returns the message of this [`Diagnostic`].
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Renders this [`Diagnostic`] with the lines of the provided `source` it
+    /// points to, which should be the document it was produced for.
+    pub fn render(&self, source: &str, style: DiagnosticStyle) -> String {
+        let lines = source.split('\n').collect::<Vec<_>>();
+        let gutter_width = self
+            .primary
+            .iter()
+            .chain(&self.related)
+            .map(|l| (l.start.line() + 1).to_string().len())
+            .max()
+            .unwrap_or(0);
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{}{}",
+            style.paint(ERROR, "error"),
+            style.paint(EMPHASIS, &format!(": {}", self.message)),
+        );
+        if let Some(label) = &self.primary {
+            render_label(&mut out, &lines, label, "-->", ERROR, gutter_width, style);
+        }
+        for label in &self.related {
+            render_label(&mut out, &lines, label, ":::", NOTE, gutter_width, style);
+        }
+        if !self.hints.is_empty() {
+            let gutter = " ".repeat(gutter_width + 1);
+            if self.primary.is_some() || !self.related.is_empty() {
+                let _ = writeln!(out, "{}{}", gutter, style.paint(NOTE, "|"));
+            }
+            for hint in &self.hints {
+                let _ = writeln!(
+                    out,
+                    "{}{} {}",
+                    gutter,
+                    style.paint(NOTE, "="),
+                    style.paint(EMPHASIS, &format!("hint: {}", hint)),
+                );
+            }
+        }
+        out
+    }
+}
+
+/// Renders the provided `label` as a header with its position, followed by
+/// the source line it points to, underlined.
+fn render_label(
+    out: &mut String,
+    lines: &[&str],
+    label: &Label,
+    arrow: &str,
+    color: &str,
+    gutter_width: usize,
+    style: DiagnosticStyle,
+) {
+    let gutter = " ".repeat(gutter_width);
+    let pipe = style.paint(NOTE, "|");
+    let _ = writeln!(
+        out,
+        "{}{} {}:{}",
+        gutter,
+        style.paint(NOTE, arrow),
+        label.start.line() + 1,
+        label.start.column() + 1,
+    );
+
+    let line = match lines.get(label.start.line()) {
+        Some(line) => line.trim_end_matches('\r'),
+        None => return,
+    };
+    let line_number = (label.start.line() + 1).to_string();
+    let (column, width) = underline(line, label);
+
+    let _ = writeln!(out, "{} {}", gutter, pipe);
+    let _ = writeln!(
+        out,
+        "{} {} {}",
+        style.paint(NOTE, &format!("{:>1$}", line_number, gutter_width)),
+        pipe,
+        line,
+    );
+    let mut marker = style.paint(color, &"^".repeat(width));
+    if let Some(text) = &label.text {
+        marker = format!("{} {}", marker, style.paint(color, text));
+    }
+    let _ = writeln!(out, "{} {} {}{}", gutter, pipe, " ".repeat(column), marker);
+}
+
+/// Returns the column (in characters) and the width of the underline of the
+/// provided `label` in its `line`.
+fn underline(line: &str, label: &Label) -> (usize, usize) {
+    let line_len = line.chars().count();
+    let column = label.start.column().min(line_len);
+    let width = match label.end {
+        Some(end) if end.line() == label.start.line() => end.column().saturating_sub(column),
+        Some(_) => line_len - column,
+        None => {
+            // Underline the whole name starting at the position, if any.
+            line.chars()
+                .skip(column)
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .count()
+        }
+    };
+    (column, width.max(1))
+}
+
+impl<'a> From<&Spanning<ParseError<'a>>> for Diagnostic {
+    fn from(error: &Spanning<ParseError<'a>>) -> Self {
+        let diagnostic = Self::new(error.item.to_string()).at(error.start, Some(error.end));
+        match &error.item {
+            ParseError::UnexpectedEndOfFile => {
+                diagnostic.with_hint("the document may be missing a closing bracket or a value")
+            }
+            ParseError::LimitExceeded(_) => {
+                diagnostic.with_hint("the limits of the parser are set with `ParserLimits`")
+            }
+            _ => diagnostic,
+        }
+    }
+}
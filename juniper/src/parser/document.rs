@@ -27,7 +27,7 @@ where
     S: ScalarValue,
 {
     let mut lexer = Lexer::new(s);
-    let mut parser = Parser::new(&mut lexer).map_err(|s| s.map(ParseError::LexerError))?;
+    let mut parser = Parser::new_with_limits(&mut lexer, schema.parser_limits())?;
     parse_document(&mut parser, schema)
 }
 
@@ -109,7 +109,7 @@ where
             _ => None,
         };
         let variable_definitions = parse_variable_definitions(parser, schema)?;
-        let directives = parse_directives(parser, schema)?;
+        let directives = parse_directives(parser, schema, false)?;
         let selection_set = parse_selection_set(parser, schema, fields)?;
 
         Ok(Spanning::start_end(
@@ -155,7 +155,7 @@ where
         .and_then(|m| m.fields(schema));
     let fields = fields.as_ref().map(|c| c as &[_]);
 
-    let directives = parse_directives(parser, schema)?;
+    let directives = parse_directives(parser, schema, false)?;
     let selection_set = parse_selection_set(parser, schema, fields)?;
 
     Ok(Spanning::start_end(
@@ -236,7 +236,7 @@ where
                 .concrete_type_by_name(name.item)
                 .and_then(|m| m.fields(schema));
             let fields = fields.as_ref().map(|c| c as &[_]);
-            let directives = parse_directives(parser, schema)?;
+            let directives = parse_directives(parser, schema, false)?;
             let selection_set = parse_selection_set(parser, schema, fields)?;
 
             Ok(Selection::InlineFragment(Spanning::start_end(
@@ -264,7 +264,7 @@ where
         }
         Token::Name(_) => {
             let frag_name = parser.expect_name()?;
-            let directives = parse_directives(parser, schema)?;
+            let directives = parse_directives(parser, schema, false)?;
 
             Ok(Selection::FragmentSpread(Spanning::start_end(
                 &start_pos.clone(),
@@ -279,7 +279,7 @@ where
             )))
         }
         Token::At => {
-            let directives = parse_directives(parser, schema)?;
+            let directives = parse_directives(parser, schema, false)?;
             let selection_set = parse_selection_set(parser, schema, fields)?;
 
             Ok(Selection::InlineFragment(Spanning::start_end(
@@ -323,9 +323,9 @@ where
         .and_then(|m| m.fields(schema));
     let fields = fields.as_ref().map(|c| c as &[_]);
 
-    let arguments = parse_arguments(parser, schema, args)?;
+    let arguments = parse_arguments(parser, schema, args, false)?;
 
-    let directives = parse_directives(parser, schema)?;
+    let directives = parse_directives(parser, schema, false)?;
     let selection_set = parse_optional_selection_set(parser, schema, fields)?;
 
     Ok(Spanning::start_end(
@@ -351,6 +351,7 @@ fn parse_arguments<'a, 'b, S>(
     parser: &mut Parser<'a>,
     schema: &'b SchemaType<'b, S>,
     arguments: Option<&[Argument<'b, S>]>,
+    is_const: bool,
 ) -> OptionParseResult<'a, Arguments<'a, S>>
 where
     S: ScalarValue,
@@ -362,7 +363,7 @@ where
             parser
                 .delimited_nonempty_list(
                     &Token::ParenOpen,
-                    |p| parse_argument(p, schema, arguments),
+                    |p| parse_argument(p, schema, arguments, is_const),
                     &Token::ParenClose,
                 )?
                 .map(|args| Arguments {
@@ -376,6 +377,7 @@ fn parse_argument<'a, 'b, S>(
     parser: &mut Parser<'a>,
     schema: &'b SchemaType<'b, S>,
     arguments: Option<&[Argument<'b, S>]>,
+    is_const: bool,
 ) -> ParseResult<'a, (Spanning<&'a str>, Spanning<InputValue<S>>)>
 where
     S: ScalarValue,
@@ -386,7 +388,7 @@ where
         .and_then(|arg| schema.lookup_type(&arg.arg_type));
 
     parser.expect(&Token::Colon)?;
-    let value = parse_value_literal(parser, false, schema, tpe)?;
+    let value = parse_value_literal(parser, is_const, schema, tpe)?;
 
     Ok(Spanning::start_end(
         &name.start.clone(),
@@ -395,7 +397,7 @@ where
     ))
 }
 
-fn parse_operation_type<'a>(parser: &mut Parser<'a>) -> ParseResult<'a, OperationType> {
+pub(super) fn parse_operation_type<'a>(parser: &mut Parser<'a>) -> ParseResult<'a, OperationType> {
     match parser.peek().item {
         Token::Name("query") => Ok(parser.next_token()?.map(|_| OperationType::Query)),
         Token::Name("mutation") => Ok(parser.next_token()?.map(|_| OperationType::Mutation)),
@@ -451,7 +453,7 @@ where
         None
     };
 
-    let directives = parse_directives(parser, schema)?;
+    let directives = parse_directives(parser, schema, false)?;
 
     Ok(Spanning::start_end(
         &start_pos,
@@ -470,9 +472,10 @@ where
     ))
 }
 
-fn parse_directives<'a, 'b, S>(
+pub(super) fn parse_directives<'a, 'b, S>(
     parser: &mut Parser<'a>,
     schema: &'b SchemaType<'b, S>,
+    is_const: bool,
 ) -> OptionParseResult<'a, Vec<Spanning<Directive<'a, S>>>>
 where
     S: ScalarValue,
@@ -482,7 +485,7 @@ where
     } else {
         let mut items = Vec::new();
         while parser.peek().item == Token::At {
-            items.push(parse_directive(parser, schema)?);
+            items.push(parse_directive(parser, schema, is_const)?);
         }
 
         Ok(Spanning::spanning(items))
@@ -492,6 +495,7 @@ where
 fn parse_directive<'a, 'b, S>(
     parser: &mut Parser<'a>,
     schema: &'b SchemaType<'b, S>,
+    is_const: bool,
 ) -> ParseResult<'a, Directive<'a, S>>
 where
     S: ScalarValue,
@@ -507,6 +511,7 @@ where
         parser,
         schema,
         directive.as_ref().map(|d| &d.arguments as &[_]),
+        is_const,
     )?;
 
     Ok(Spanning::start_end(
@@ -521,8 +526,10 @@ pub fn parse_type<'a>(parser: &mut Parser<'a>) -> ParseResult<'a, Type<'a>> {
         start: start_pos, ..
     }) = parser.skip(&Token::BracketOpen)?
     {
+        parser.enter_nested(&start_pos)?;
         let inner_type = parse_type(parser)?;
         let Spanning { end: end_pos, .. } = parser.expect(&Token::BracketClose)?;
+        parser.leave_nested();
         Spanning::start_end(
             &start_pos,
             &end_pos,
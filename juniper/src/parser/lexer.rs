@@ -34,6 +34,7 @@ pub enum ScalarToken<'a> {
 pub enum Token<'a> {
     Name(&'a str),
     Scalar(ScalarToken<'a>),
+    BlockString(&'a str),
     ExclamationMark,
     Dollar,
     ParenOpen,
@@ -47,6 +48,7 @@ pub enum Token<'a> {
     Equals,
     At,
     Pipe,
+    Amp,
     EndOfFile,
 }
 
@@ -108,6 +110,11 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Returns the length of the source being tokenized, in bytes.
+    pub(crate) fn source_len(&self) -> usize {
+        self.length
+    }
+
     fn peek_char(&mut self) -> Option<(usize, char)> {
         assert!(self.position.index() <= self.length);
         assert!(!self.has_reached_eof);
@@ -224,6 +231,9 @@ impl<'a> Lexer<'a> {
                 LexerError::UnterminatedString,
             ));
         }
+        if self.source[start_idx..].starts_with(BLOCK_STRING_QUOTES) {
+            return self.scan_block_string(start_pos, start_idx);
+        }
 
         let mut escaped = false;
         let mut old_pos = self.position;
@@ -273,6 +283,46 @@ impl<'a> Lexer<'a> {
         ))
     }
 
+    fn scan_block_string(
+        &mut self,
+        start_pos: SourcePosition,
+        start_idx: usize,
+    ) -> LexerResult<'a> {
+        // The opening `"` is already consumed.
+        self.next_char();
+        self.next_char();
+
+        let content_idx = start_idx + BLOCK_STRING_QUOTES.len();
+        let mut old_pos = self.position;
+        while let Some((idx, ch)) = self.next_char() {
+            let rest = &self.source[idx..];
+            if ch == '\\' && rest[1..].starts_with(BLOCK_STRING_QUOTES) {
+                for _ in 0..BLOCK_STRING_QUOTES.len() {
+                    self.next_char();
+                }
+            } else if rest.starts_with(BLOCK_STRING_QUOTES) {
+                self.next_char();
+                self.next_char();
+                return Ok(Spanning::start_end(
+                    &start_pos,
+                    &self.position,
+                    Token::BlockString(&self.source[content_idx..idx]),
+                ));
+            } else if !is_source_char(ch) {
+                return Err(Spanning::zero_width(
+                    &old_pos,
+                    LexerError::UnknownCharacterInString(ch),
+                ));
+            }
+            old_pos = self.position;
+        }
+
+        Err(Spanning::zero_width(
+            &self.position,
+            LexerError::UnterminatedString,
+        ))
+    }
+
     fn scan_escaped_unicode(
         &mut self,
         start_pos: &SourcePosition,
@@ -458,6 +508,7 @@ impl<'a> Iterator for Lexer<'a> {
             Some('=') => Ok(self.emit_single_char(Token::Equals)),
             Some('@') => Ok(self.emit_single_char(Token::At)),
             Some('|') => Ok(self.emit_single_char(Token::Pipe)),
+            Some('&') => Ok(self.emit_single_char(Token::Amp)),
             Some('.') => self.scan_ellipsis(),
             Some('"') => self.scan_string(),
             Some(ch) => {
@@ -490,6 +541,9 @@ impl<'a> fmt::Display for Token<'a> {
             Token::Scalar(ScalarToken::String(s)) => {
                 write!(f, "\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
             }
+            Token::BlockString(s) => {
+                write!(f, "{}{}{}", BLOCK_STRING_QUOTES, s, BLOCK_STRING_QUOTES)
+            }
             Token::ExclamationMark => write!(f, "!"),
             Token::Dollar => write!(f, "$"),
             Token::ParenOpen => write!(f, "("),
@@ -503,11 +557,51 @@ impl<'a> fmt::Display for Token<'a> {
             Token::Equals => write!(f, "="),
             Token::At => write!(f, "@"),
             Token::Pipe => write!(f, "|"),
+            Token::Amp => write!(f, "&"),
             Token::EndOfFile => write!(f, "End of file"),
         }
     }
 }
 
+/// Computes the value of a block string from its raw content, as described in
+/// the [spec](https://spec.graphql.org/October2021#BlockStringValue()): the
+/// escaped triple quotes are unescaped, the common indentation is removed and
+/// the leading and trailing blank lines are dropped.
+pub(crate) fn block_string_value(raw: &str) -> String {
+    let raw = raw.replace("\\\"\"\"", BLOCK_STRING_QUOTES);
+    let lines = raw.split("\r\n").flat_map(|l| l.split(&['\n', '\r'][..]));
+    let is_blank = |l: &str| l.chars().all(|c| c == ' ' || c == '\t');
+    let indent = |l: &str| l.len() - l.trim_start_matches(&[' ', '\t'][..]).len();
+
+    let common_indent = lines
+        .clone()
+        .skip(1)
+        .filter(|l| !is_blank(l))
+        .map(indent)
+        .min()
+        .unwrap_or(0);
+    let mut lines = lines
+        .enumerate()
+        .map(|(i, l)| {
+            if i == 0 {
+                l
+            } else {
+                &l[common_indent.min(l.len())..]
+            }
+        })
+        .collect::<Vec<_>>();
+
+    while matches!(lines.first(), Some(l) if is_blank(l)) {
+        lines.remove(0);
+    }
+    while matches!(lines.last(), Some(l) if is_blank(l)) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+const BLOCK_STRING_QUOTES: &str = "\"\"\"";
+
 fn is_source_char(c: char) -> bool {
     c == '\t' || c == '\n' || c == '\r' || c >= ' '
 }
@@ -1,19 +1,36 @@
 //! Query parser and language utilities
 #![allow(clippy::module_inception)]
 
+mod diagnostic;
 mod document;
 mod lexer;
+mod normalizer;
 mod parser;
+mod printer;
+mod type_system;
 mod utils;
 mod value;
 
 #[cfg(test)]
 mod tests;
 
-pub use self::document::parse_document_source;
+pub use self::{
+    document::parse_document_source,
+    normalizer::normalize_document,
+    printer::{print_document, PrintStyle},
+    type_system::parse_schema_document,
+};
+
+#[cfg(feature = "trusted-documents")]
+pub(crate) use self::normalizer::operation_document;
+pub(crate) use self::{printer::print_value, value::parse_value_literal};
 
 pub use self::{
+    diagnostic::{Diagnostic, DiagnosticStyle},
     lexer::{Lexer, LexerError, ScalarToken, Token},
-    parser::{OptionParseResult, ParseError, ParseResult, Parser, UnlocatedParseResult},
+    parser::{
+        OptionParseResult, ParseError, ParseResult, Parser, ParserLimit, ParserLimits,
+        UnlocatedParseResult,
+    },
     utils::{SourcePosition, Spanning},
 };
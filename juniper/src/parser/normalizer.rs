@@ -0,0 +1,346 @@
+#[cfg(feature = "trusted-documents")]
+use std::collections::HashSet;
+
+use crate::{
+    ast::{
+        Arguments, Definition, Directive, Document, InlineFragment, InputValue, Operation,
+        OperationType, OwnedDocument, Selection,
+    },
+    parser::{printer::print_selection, Spanning},
+    schema::model::SchemaType,
+    value::ScalarValue,
+};
+
+/// Normalizes the signature of the operation with the provided
+/// `operation_name` in the provided `document`, so equivalent operations
+/// result in the same document.
+///
+/// If `operation_name` is [`None`], the `document` is expected to contain a
+/// single operation. Returns [`None`] if there is no such operation.
+///
+/// The returned document contains the operation only, where:
+/// - fragment spreads are inlined, and inline fragments without directives on
+///   the type of their parent (as defined by the `schema`) are merged into it;
+/// - fields and inline fragments are sorted (in this order), and repeated ones
+///   are removed;
+/// - arguments, directives and variable definitions are sorted by name;
+/// - aliases of fields are removed;
+/// - literals are stripped: strings become `""`, numbers become `0`, lists
+///   and objects become empty, while booleans, enum values, `null`s and
+///   variables are kept as is.
+///
+/// Printed with [`print_document()`], the result is fit for hashing
+/// operations for metrics, allow-lists or persisted queries.
+///
+/// ```
+/// # use juniper::{
+/// #     parser::{normalize_document, parse_document_source, print_document, PrintStyle},
+/// #     DefaultScalarValue, EmptyMutation, EmptySubscription, RootNode,
+/// # };
+/// #
+/// # struct Query;
+/// #
+/// # #[juniper::graphql_object]
+/// # impl Query {
+/// #     fn hero(id: i32, name: String) -> i32 { id }
+/// #     fn villain() -> i32 { 0 }
+/// # }
+/// #
+/// # let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// let document = parse_document_source::<DefaultScalarValue>(
+///     r#"{ villain ...Main } fragment Main on Query { main: hero(name: "Luke", id: 1) }"#,
+///     &schema.schema,
+/// )
+/// .unwrap();
+/// let normalized = normalize_document(&document, None, &schema.schema).unwrap();
+///
+/// assert_eq!(
+///     print_document(&normalized, PrintStyle::Minified),
+///     r#"{hero(id:0,name:"")villain}"#,
+/// );
+/// ```
+///
+/// [`print_document()`]: crate::parser::print_document
+pub fn normalize_document<'a, S: ScalarValue>(
+    document: &Document<'a, S>,
+    operation_name: Option<&str>,
+    schema: &SchemaType<S>,
+) -> Option<OwnedDocument<'a, S>> {
+    let mut operation = find_operation(document, operation_name)?.clone();
+    let op = &mut operation.item;
+    let root_type = match op.operation_type {
+        OperationType::Query => Some(schema.query_type_name.as_str()),
+        OperationType::Mutation => schema.mutation_type_name.as_deref(),
+        OperationType::Subscription => schema.subscription_type_name.as_deref(),
+    };
+
+    if let Some(defs) = &mut op.variable_definitions {
+        for (_, def) in &mut defs.item.items {
+            if let Some(value) = &mut def.default_value {
+                strip_literals(&mut value.item);
+            }
+            normalize_directives(&mut def.directives);
+        }
+        defs.item.items.sort_by(|(a, _), (b, _)| a.item.cmp(b.item));
+    }
+    normalize_directives(&mut op.directives);
+    op.selection_set =
+        inline_fragments(&op.selection_set, root_type, document, schema, &mut vec![]);
+    normalize_selection_set(&mut op.selection_set);
+
+    Some(vec![Definition::Operation(operation)])
+}
+
+/// Finds the operation with the provided `operation_name` in the provided
+/// `document`.
+///
+/// If `operation_name` is [`None`], the `document` is expected to contain a
+/// single operation.
+fn find_operation<'d, 'a, S>(
+    document: &'d Document<'a, S>,
+    operation_name: Option<&str>,
+) -> Option<&'d Spanning<Operation<'a, S>>> {
+    let mut operations = document.iter().filter_map(|def| match def {
+        Definition::Operation(op) => Some(op),
+        Definition::Fragment(_) => None,
+    });
+    match operation_name {
+        Some(name) => operations.find(|op| matches!(&op.item.name, Some(n) if n.item == name)),
+        None => {
+            let op = operations.next()?;
+            if operations.next().is_some() {
+                return None;
+            }
+            Some(op)
+        }
+    }
+}
+
+/// Extracts the operation with the provided `operation_name` from the
+/// provided `document`, along with the fragments it uses (directly or through
+/// other fragments), sorted by name.
+///
+/// If `operation_name` is [`None`], the `document` is expected to contain a
+/// single operation. Returns [`None`] if there is no such operation.
+#[cfg(feature = "trusted-documents")]
+pub(crate) fn operation_document<'a, S: Clone>(
+    document: &Document<'a, S>,
+    operation_name: Option<&str>,
+) -> Option<OwnedDocument<'a, S>> {
+    let operation = find_operation(document, operation_name)?;
+
+    let mut used = HashSet::new();
+    let mut pending = vec![];
+    collect_spreads(&operation.item.selection_set, &mut used, &mut pending);
+    let mut fragments = vec![];
+    while let Some(name) = pending.pop() {
+        let fragment = document.iter().find_map(|def| match def {
+            Definition::Fragment(f) if f.item.name.item == name => Some(f),
+            _ => None,
+        });
+        if let Some(fragment) = fragment {
+            collect_spreads(&fragment.item.selection_set, &mut used, &mut pending);
+            fragments.push(fragment.clone());
+        }
+    }
+    fragments.sort_by(|a, b| a.item.name.item.cmp(b.item.name.item));
+
+    Some(
+        std::iter::once(Definition::Operation(operation.clone()))
+            .chain(fragments.into_iter().map(Definition::Fragment))
+            .collect(),
+    )
+}
+
+/// Inlines the fragment spreads of the provided `selection_set` of the
+/// `parent_type` (if known) recursively, taking the fragments from the
+/// `document`.
+///
+/// Inline fragments without directives on the `parent_type` are merged into
+/// the `selection_set`, and the other ones lose their type condition if it's
+/// the `parent_type`. Spreads of unknown fragments, or of the ones `spreading`
+/// themselves, are left as is.
+fn inline_fragments<'a, S: ScalarValue>(
+    selection_set: &[Selection<'a, S>],
+    parent_type: Option<&str>,
+    document: &Document<'a, S>,
+    schema: &SchemaType<S>,
+    spreading: &mut Vec<&'a str>,
+) -> Vec<Selection<'a, S>> {
+    let mut inlined = vec![];
+    for selection in selection_set {
+        let (fragment, start, end) = match selection {
+            Selection::Field(f) => {
+                let mut field = f.clone();
+                let name = field.item.name.item;
+                if let Some(selection_set) = &mut field.item.selection_set {
+                    let field_type = parent_type
+                        .and_then(|t| schema.concrete_type_by_name(t))
+                        .and_then(|t| t.field_by_name(name))
+                        .map(|f| f.field_type.innermost_name());
+                    *selection_set =
+                        inline_fragments(selection_set, field_type, document, schema, spreading);
+                }
+                inlined.push(Selection::Field(field));
+                continue;
+            }
+            Selection::FragmentSpread(s) => {
+                let name = s.item.name.item;
+                let fragment = document.iter().find_map(|def| match def {
+                    Definition::Fragment(f) if f.item.name.item == name => Some(&f.item),
+                    _ => None,
+                });
+                let fragment = match fragment {
+                    Some(f) if !spreading.contains(&name) => f,
+                    _ => {
+                        inlined.push(selection.clone());
+                        continue;
+                    }
+                };
+                spreading.push(name);
+                let selection_set = inline_fragments(
+                    &fragment.selection_set,
+                    Some(fragment.type_condition.item),
+                    document,
+                    schema,
+                    spreading,
+                );
+                spreading.pop();
+                let fragment = InlineFragment {
+                    type_condition: Some(fragment.type_condition),
+                    directives: s.item.directives.clone(),
+                    selection_set,
+                };
+                (fragment, &s.start, &s.end)
+            }
+            Selection::InlineFragment(f) => {
+                let fragment_type = f.item.type_condition.as_ref().map(|t| t.item);
+                let fragment = InlineFragment {
+                    type_condition: f.item.type_condition,
+                    directives: f.item.directives.clone(),
+                    selection_set: inline_fragments(
+                        &f.item.selection_set,
+                        fragment_type.or(parent_type),
+                        document,
+                        schema,
+                        spreading,
+                    ),
+                };
+                (fragment, &f.start, &f.end)
+            }
+        };
+
+        let on_parent_type = match &fragment.type_condition {
+            Some(t) => parent_type == Some(t.item),
+            None => true,
+        };
+        if on_parent_type && fragment.directives.is_none() {
+            inlined.extend(fragment.selection_set);
+        } else {
+            let type_condition = fragment.type_condition.filter(|_| !on_parent_type);
+            inlined.push(Selection::InlineFragment(Spanning::start_end(
+                start,
+                end,
+                InlineFragment {
+                    type_condition,
+                    ..fragment
+                },
+            )));
+        }
+    }
+    inlined
+}
+
+/// Normalizes the provided `selection_set` recursively, sorts it and removes
+/// the repeated selections.
+fn normalize_selection_set<S: ScalarValue>(selection_set: &mut Vec<Selection<S>>) {
+    for selection in selection_set.iter_mut() {
+        match selection {
+            Selection::Field(f) => {
+                let field = &mut f.item;
+                field.alias = None;
+                if let Some(args) = &mut field.arguments {
+                    normalize_arguments(&mut args.item);
+                }
+                normalize_directives(&mut field.directives);
+                if let Some(selection_set) = &mut field.selection_set {
+                    normalize_selection_set(selection_set);
+                }
+            }
+            Selection::FragmentSpread(s) => normalize_directives(&mut s.item.directives),
+            Selection::InlineFragment(f) => {
+                normalize_directives(&mut f.item.directives);
+                normalize_selection_set(&mut f.item.selection_set);
+            }
+        }
+    }
+    selection_set.sort_by_cached_key(|selection| {
+        let rank = match selection {
+            Selection::Field(_) => 0,
+            Selection::FragmentSpread(_) => 1,
+            Selection::InlineFragment(_) => 2,
+        };
+        (rank, print_selection(selection))
+    });
+    selection_set.dedup_by(|a, b| print_selection(a) == print_selection(b));
+}
+
+/// Strips the literals of the provided `directives` and sorts them by name.
+fn normalize_directives<S: ScalarValue>(directives: &mut Option<Vec<Spanning<Directive<S>>>>) {
+    if let Some(directives) = directives {
+        for directive in directives.iter_mut() {
+            if let Some(args) = &mut directive.item.arguments {
+                normalize_arguments(&mut args.item);
+            }
+        }
+        directives.sort_by(|a, b| a.item.name.item.cmp(b.item.name.item));
+    }
+}
+
+/// Strips the literals of the provided `args` and sorts them by name.
+fn normalize_arguments<S: ScalarValue>(args: &mut Arguments<S>) {
+    for (_, value) in args.iter_mut() {
+        strip_literals(&mut value.item);
+    }
+    args.items.sort_by(|(a, _), (b, _)| a.item.cmp(b.item));
+}
+
+/// Replaces the provided `value` with an empty value of the same kind, unless
+/// it's a boolean, an enum value, `null` or a variable.
+fn strip_literals<S: ScalarValue>(value: &mut InputValue<S>) {
+    match value {
+        InputValue::Scalar(s) if s.as_str().is_some() => *s = S::from(String::new()),
+        InputValue::Scalar(s) if s.as_bool().is_none() => *s = S::from(0),
+        InputValue::List(items) => items.clear(),
+        InputValue::Object(fields) => fields.clear(),
+        InputValue::Null
+        | InputValue::Scalar(_)
+        | InputValue::Enum(_)
+        | InputValue::Variable(_) => {}
+    }
+}
+
+/// Collects the names of the fragments spread in the provided
+/// `selection_set` (recursively), which aren't `used` yet, into `pending`.
+#[cfg(feature = "trusted-documents")]
+fn collect_spreads<'a, S>(
+    selection_set: &[Selection<'a, S>],
+    used: &mut HashSet<&'a str>,
+    pending: &mut Vec<&'a str>,
+) {
+    for selection in selection_set {
+        match selection {
+            Selection::Field(f) => {
+                if let Some(selection_set) = &f.item.selection_set {
+                    collect_spreads(selection_set, used, pending);
+                }
+            }
+            Selection::FragmentSpread(s) => {
+                if used.insert(s.item.name.item) {
+                    pending.push(s.item.name.item);
+                }
+            }
+            Selection::InlineFragment(f) => collect_spreads(&f.item.selection_set, used, pending),
+        }
+    }
+}
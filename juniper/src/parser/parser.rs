@@ -1,6 +1,6 @@
 use std::{fmt, result::Result};
 
-use crate::parser::{Lexer, LexerError, Spanning, Token};
+use crate::parser::{Lexer, LexerError, SourcePosition, Spanning, Token};
 
 /// Error while parsing a GraphQL query
 #[derive(Debug, PartialEq)]
@@ -16,6 +16,64 @@ pub enum ParseError<'a> {
 
     /// A scalar of unexpected type occurred in the source
     ExpectedScalarError(&'static str),
+
+    /// The source exceeds one of the [`ParserLimits`]
+    LimitExceeded(ParserLimit),
+}
+
+/// Limits of the resources a document may take while being parsed, protecting
+/// against maliciously large or deeply nested documents.
+///
+/// A [`None`] limit means no limit. By default, only the nesting depth is
+/// limited (with [`ParserLimits::DEFAULT_MAX_DEPTH`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParserLimits {
+    /// Maximum length of a document, in bytes.
+    pub max_length: Option<usize>,
+
+    /// Maximum number of tokens in a document.
+    pub max_tokens: Option<usize>,
+
+    /// Maximum nesting depth of selection sets, arguments, list and object
+    /// literals, and list types in a document.
+    pub max_depth: Option<usize>,
+}
+
+impl ParserLimits {
+    /// Default maximum nesting depth of a document.
+    pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+    /// Creates [`ParserLimits`] not limiting anything.
+    pub fn unlimited() -> Self {
+        Self {
+            max_length: None,
+            max_tokens: None,
+            max_depth: None,
+        }
+    }
+}
+
+impl Default for ParserLimits {
+    fn default() -> Self {
+        Self {
+            max_length: None,
+            max_tokens: None,
+            max_depth: Some(Self::DEFAULT_MAX_DEPTH),
+        }
+    }
+}
+
+/// One of the [`ParserLimits`] along with its exceeded value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParserLimit {
+    /// [`ParserLimits::max_length`] is exceeded.
+    Length(usize),
+
+    /// [`ParserLimits::max_tokens`] is exceeded.
+    Tokens(usize),
+
+    /// [`ParserLimits::max_depth`] is exceeded.
+    Depth(usize),
 }
 
 #[doc(hidden)]
@@ -31,6 +89,8 @@ pub type OptionParseResult<'a, T> = Result<Option<Spanning<T>>, Spanning<ParseEr
 #[derive(Debug)]
 pub struct Parser<'a> {
     tokens: Vec<Spanning<Token<'a>>>,
+    depth: usize,
+    max_depth: Option<usize>,
 }
 
 impl<'a> Parser<'a> {
@@ -45,7 +105,43 @@ impl<'a> Parser<'a> {
             }
         }
 
-        Ok(Parser { tokens })
+        Ok(Parser {
+            tokens,
+            depth: 0,
+            max_depth: None,
+        })
+    }
+
+    #[doc(hidden)]
+    pub fn new_with_limits(
+        lexer: &mut Lexer<'a>,
+        limits: &ParserLimits,
+    ) -> Result<Parser<'a>, Spanning<ParseError<'a>>> {
+        if let Some(max) = limits.max_length {
+            if lexer.source_len() > max {
+                return Err(Spanning::zero_width(
+                    &SourcePosition::new_origin(),
+                    ParseError::LimitExceeded(ParserLimit::Length(max)),
+                ));
+            }
+        }
+
+        let mut tokens = Vec::new();
+        for res in lexer {
+            let token = res.map_err(|e| e.map(ParseError::LexerError))?;
+            if let Some(max) = limits.max_tokens {
+                if tokens.len() == max && token.item != Token::EndOfFile {
+                    return Err(token.map(|_| ParseError::LimitExceeded(ParserLimit::Tokens(max))));
+                }
+            }
+            tokens.push(token);
+        }
+
+        Ok(Parser {
+            tokens,
+            depth: 0,
+            max_depth: limits.max_depth,
+        })
     }
 
     #[doc(hidden)]
@@ -106,10 +202,12 @@ impl<'a> Parser<'a> {
         let Spanning {
             start: start_pos, ..
         } = self.expect(opening)?;
+        self.enter_nested(&start_pos)?;
         let mut items = Vec::new();
 
         loop {
             if let Some(Spanning { end: end_pos, .. }) = self.skip(closing)? {
+                self.leave_nested();
                 return Ok(Spanning::start_end(&start_pos, &end_pos, items));
             }
 
@@ -131,12 +229,14 @@ impl<'a> Parser<'a> {
         let Spanning {
             start: start_pos, ..
         } = self.expect(opening)?;
+        self.enter_nested(&start_pos)?;
         let mut items = Vec::new();
 
         loop {
             items.push(parser(self)?);
 
             if let Some(Spanning { end: end_pos, .. }) = self.skip(closing)? {
+                self.leave_nested();
                 return Ok(Spanning::start_end(&start_pos, &end_pos, items));
             }
         }
@@ -156,17 +256,40 @@ impl<'a> Parser<'a> {
         let Spanning {
             start: start_pos, ..
         } = self.expect(opening)?;
+        self.enter_nested(&start_pos)?;
         let mut items = Vec::new();
 
         loop {
             items.push(parser(self)?);
 
             if let Some(Spanning { end: end_pos, .. }) = self.skip(closing)? {
+                self.leave_nested();
                 return Ok(Spanning::start_end(&start_pos, &end_pos, items));
             }
         }
     }
 
+    /// Enters a nested construct starting at the provided position, failing
+    /// if this exceeds the maximum nesting depth.
+    pub(crate) fn enter_nested(
+        &mut self,
+        start: &SourcePosition,
+    ) -> Result<(), Spanning<ParseError<'a>>> {
+        self.depth += 1;
+        match self.max_depth {
+            Some(max) if self.depth > max => Err(Spanning::zero_width(
+                start,
+                ParseError::LimitExceeded(ParserLimit::Depth(max)),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Leaves a nested construct entered with [`Parser::enter_nested()`].
+    pub(crate) fn leave_nested(&mut self) {
+        self.depth -= 1;
+    }
+
     #[doc(hidden)]
     pub fn expect_name(&mut self) -> ParseResult<'a, &'a str> {
         match *self.peek() {
@@ -200,6 +323,23 @@ impl<'a> fmt::Display for ParseError<'a> {
             ParseError::UnexpectedEndOfFile => write!(f, "Unexpected end of input"),
             ParseError::LexerError(ref err) => err.fmt(f),
             ParseError::ExpectedScalarError(err) => err.fmt(f),
+            ParseError::LimitExceeded(ref limit) => limit.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for ParserLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParserLimit::Length(max) => {
+                write!(f, "Document exceeds the maximum length of {} bytes", max)
+            }
+            ParserLimit::Tokens(max) => {
+                write!(f, "Document exceeds the maximum of {} tokens", max)
+            }
+            ParserLimit::Depth(max) => {
+                write!(f, "Document exceeds the maximum nesting depth of {}", max)
+            }
         }
     }
 }
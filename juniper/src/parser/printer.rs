@@ -0,0 +1,352 @@
+use crate::{
+    ast::{
+        Arguments, Definition, Directive, Document, Field, Fragment, FragmentSpread,
+        InlineFragment, InputValue, Operation, OperationType, Selection, VariableDefinitions,
+    },
+    parser::Spanning,
+    value::ScalarValue,
+};
+
+/// Style of a document printed with [`print_document()`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PrintStyle {
+    /// Multiple lines, indented with two spaces.
+    Pretty,
+
+    /// Single line, without any whitespace not required for separating
+    /// tokens.
+    Minified,
+}
+
+/// Prints the provided executable `document` back as GraphQL source.
+///
+/// Parsing the printed source results in the same `document`, except for the
+/// source positions, so the printed source is fit for comparing and hashing
+/// documents (see [`normalize_document()`] for making equivalent documents
+/// print the same).
+///
+/// ```
+/// # use juniper::{
+/// #     parser::{parse_document_source, print_document, PrintStyle},
+/// #     DefaultScalarValue, EmptyMutation, EmptySubscription, RootNode,
+/// # };
+/// #
+/// # struct Query;
+/// #
+/// # #[juniper::graphql_object]
+/// # impl Query {
+/// #     fn hero(id: i32) -> i32 { id }
+/// # }
+/// #
+/// # let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// let document = parse_document_source::<DefaultScalarValue>(
+///     "query   Hero { hero(id: 1) }",
+///     &schema.schema,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     print_document(&document, PrintStyle::Pretty),
+///     "query Hero {\n  hero(id: 1)\n}",
+/// );
+/// assert_eq!(
+///     print_document(&document, PrintStyle::Minified),
+///     "query Hero{hero(id:1)}",
+/// );
+/// ```
+///
+/// [`normalize_document()`]: crate::parser::normalize_document
+pub fn print_document<S: ScalarValue>(document: &Document<S>, style: PrintStyle) -> String {
+    let mut printer = Printer {
+        out: String::new(),
+        style,
+        indent: 0,
+    };
+    for (i, def) in document.iter().enumerate() {
+        if i > 0 && style == PrintStyle::Pretty {
+            printer.out.push_str("\n\n");
+        }
+        match def {
+            Definition::Operation(op) => printer.operation(&op.item),
+            Definition::Fragment(f) => printer.fragment(&f.item),
+        }
+    }
+    printer.out
+}
+
+/// Prints the provided `selection` alone, in the [`PrintStyle::Minified`].
+pub(super) fn print_selection<S: ScalarValue>(selection: &Selection<S>) -> String {
+    let mut printer = Printer {
+        out: String::new(),
+        style: PrintStyle::Minified,
+        indent: 0,
+    };
+    printer.selection(selection);
+    printer.out
+}
+
+/// Prints the provided input `value` alone as a GraphQL literal, in the
+/// [`PrintStyle::Minified`].
+pub(crate) fn print_value<S: ScalarValue>(value: &InputValue<S>) -> String {
+    let mut printer = Printer {
+        out: String::new(),
+        style: PrintStyle::Minified,
+        indent: 0,
+    };
+    printer.value(value);
+    printer.out
+}
+
+/// Printer of executable documents, accumulating the printed source.
+struct Printer {
+    out: String,
+    style: PrintStyle,
+    indent: usize,
+}
+
+impl Printer {
+    /// Prints the provided `token`, separating it from the previous one only
+    /// if they would be lexed as a single token otherwise.
+    fn token(&mut self, token: &str) {
+        let continues_name = |c: char| c.is_ascii_alphanumeric() || c == '_';
+        if self.style == PrintStyle::Minified
+            && self.out.ends_with(continues_name)
+            && token.starts_with(continues_name)
+        {
+            self.out.push(' ');
+        }
+        self.out.push_str(token);
+    }
+
+    /// Prints a space, if the style is [`PrintStyle::Pretty`].
+    fn space(&mut self) {
+        if self.style == PrintStyle::Pretty {
+            self.out.push(' ');
+        }
+    }
+
+    /// Prints a separator of list items and arguments.
+    fn comma(&mut self) {
+        self.out.push(',');
+        self.space();
+    }
+
+    /// Prints a colon, followed by a space in the [`PrintStyle::Pretty`].
+    fn colon(&mut self) {
+        self.out.push(':');
+        self.space();
+    }
+
+    /// Starts a new line at the current indentation, if the style is
+    /// [`PrintStyle::Pretty`].
+    fn newline(&mut self) {
+        if self.style == PrintStyle::Pretty {
+            self.out.push('\n');
+            self.out.push_str(&"  ".repeat(self.indent));
+        }
+    }
+
+    fn operation<S: ScalarValue>(&mut self, op: &Operation<S>) {
+        let is_shorthand = op.operation_type == OperationType::Query
+            && op.name.is_none()
+            && op.variable_definitions.is_none()
+            && op.directives.is_none();
+        if !is_shorthand {
+            self.token(match op.operation_type {
+                OperationType::Query => "query",
+                OperationType::Mutation => "mutation",
+                OperationType::Subscription => "subscription",
+            });
+            if let Some(name) = &op.name {
+                self.out.push(' ');
+                self.token(name.item);
+            }
+            if let Some(defs) = &op.variable_definitions {
+                self.variable_definitions(&defs.item);
+            }
+            self.directives(&op.directives);
+        }
+        self.selection_set(&op.selection_set);
+    }
+
+    fn fragment<S: ScalarValue>(&mut self, fragment: &Fragment<S>) {
+        self.token("fragment");
+        self.out.push(' ');
+        self.token(fragment.name.item);
+        self.out.push_str(" on ");
+        self.token(fragment.type_condition.item);
+        self.directives(&fragment.directives);
+        self.selection_set(&fragment.selection_set);
+    }
+
+    fn variable_definitions<S: ScalarValue>(&mut self, defs: &VariableDefinitions<S>) {
+        if defs.items.is_empty() {
+            return;
+        }
+        self.out.push('(');
+        for (i, (name, def)) in defs.iter().enumerate() {
+            if i > 0 {
+                self.comma();
+            }
+            self.token(&format!("${}", name.item));
+            self.colon();
+            self.token(&def.var_type.item.to_string());
+            if let Some(value) = &def.default_value {
+                self.space();
+                self.out.push('=');
+                self.space();
+                self.value(&value.item);
+            }
+            self.directives(&def.directives);
+        }
+        self.out.push(')');
+    }
+
+    fn selection_set<S: ScalarValue>(&mut self, selection_set: &[Selection<S>]) {
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.space();
+        }
+        self.out.push('{');
+        self.indent += 1;
+        for selection in selection_set {
+            self.newline();
+            self.selection(selection);
+        }
+        self.indent -= 1;
+        self.newline();
+        self.out.push('}');
+    }
+
+    fn selection<S: ScalarValue>(&mut self, selection: &Selection<S>) {
+        match selection {
+            Selection::Field(f) => self.field(&f.item),
+            Selection::FragmentSpread(s) => self.fragment_spread(&s.item),
+            Selection::InlineFragment(f) => self.inline_fragment(&f.item),
+        }
+    }
+
+    fn field<S: ScalarValue>(&mut self, field: &Field<S>) {
+        if let Some(alias) = &field.alias {
+            self.token(alias.item);
+            self.colon();
+        }
+        self.token(field.name.item);
+        if let Some(args) = &field.arguments {
+            self.arguments(&args.item);
+        }
+        self.directives(&field.directives);
+        if let Some(selection_set) = &field.selection_set {
+            self.selection_set(selection_set);
+        }
+    }
+
+    fn fragment_spread<S: ScalarValue>(&mut self, spread: &FragmentSpread<S>) {
+        self.token("...");
+        self.token(spread.name.item);
+        self.directives(&spread.directives);
+    }
+
+    fn inline_fragment<S: ScalarValue>(&mut self, fragment: &InlineFragment<S>) {
+        self.token("...");
+        if let Some(type_condition) = &fragment.type_condition {
+            self.space();
+            self.token("on");
+            self.out.push(' ');
+            self.token(type_condition.item);
+        }
+        self.directives(&fragment.directives);
+        self.selection_set(&fragment.selection_set);
+    }
+
+    fn directives<S: ScalarValue>(&mut self, directives: &Option<Vec<Spanning<Directive<S>>>>) {
+        for directive in directives.iter().flatten() {
+            self.space();
+            self.token(&format!("@{}", directive.item.name.item));
+            if let Some(args) = &directive.item.arguments {
+                self.arguments(&args.item);
+            }
+        }
+    }
+
+    fn arguments<S: ScalarValue>(&mut self, args: &Arguments<S>) {
+        if args.items.is_empty() {
+            return;
+        }
+        self.out.push('(');
+        for (i, (name, value)) in args.iter().enumerate() {
+            if i > 0 {
+                self.comma();
+            }
+            self.token(name.item);
+            self.colon();
+            self.value(&value.item);
+        }
+        self.out.push(')');
+    }
+
+    fn value<S: ScalarValue>(&mut self, value: &InputValue<S>) {
+        match value {
+            InputValue::Null => self.token("null"),
+            InputValue::Scalar(s) => self.token(&print_scalar(s)),
+            InputValue::Enum(name) => self.token(name),
+            InputValue::Variable(name) => self.token(&format!("${}", name)),
+            InputValue::List(items) => {
+                self.out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        self.comma();
+                    }
+                    self.value(&item.item);
+                }
+                self.out.push(']');
+            }
+            InputValue::Object(fields) => {
+                self.out.push('{');
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        self.comma();
+                    }
+                    self.token(&name.item);
+                    self.colon();
+                    self.value(&value.item);
+                }
+                self.out.push('}');
+            }
+        }
+    }
+}
+
+/// Prints the provided scalar as a GraphQL literal.
+fn print_scalar<S: ScalarValue>(scalar: &S) -> String {
+    if let Some(s) = scalar.as_str() {
+        return print_string(s);
+    }
+    match scalar.as_float() {
+        // Keep the fractional part, so the value isn't parsed back as an `Int`.
+        Some(f) if scalar.as_int().is_none() && scalar.as_big_int().is_none() => {
+            format!("{:?}", f)
+        }
+        _ => scalar.to_string(),
+    }
+}
+
+/// Prints the provided string as a quoted GraphQL string literal.
+fn print_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04X}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,98 @@
+use crate::{
+    parser::{parse_document_source, Diagnostic, DiagnosticStyle, SourcePosition},
+    schema::model::RootNode,
+    tests::fixtures::starwars::schema::{Database, Query},
+    types::scalars::{EmptyMutation, EmptySubscription},
+    value::DefaultScalarValue,
+    GraphQLError,
+};
+
+fn schema() -> RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>> {
+    RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    )
+}
+
+#[test]
+fn renders_parse_errors() {
+    let source = "query {\n  hero { name\n}";
+    let error = parse_document_source::<DefaultScalarValue>(source, &schema().schema).unwrap_err();
+
+    assert_eq!(
+        Diagnostic::from(&error).render(source, DiagnosticStyle::Plain),
+        "error: Unexpected end of input\n \
+         --> 3:2\n  \
+         |\n\
+         3 | }\n  \
+         |  ^\n  \
+         |\n  \
+         = hint: the document may be missing a closing bracket or a value\n",
+    );
+
+    let source = "{ hero(episode: ) { name } }";
+    let error = parse_document_source::<DefaultScalarValue>(source, &schema().schema).unwrap_err();
+
+    assert_eq!(
+        GraphQLError::ParseError(error).render(source, DiagnosticStyle::Plain),
+        "error: Unexpected \")\"\n \
+         --> 1:17\n  \
+         |\n\
+         1 | { hero(episode: ) { name } }\n  \
+         |                 ^\n",
+    );
+}
+
+#[test]
+fn renders_validation_errors() {
+    let source = "{ hero { ...A } }\n\
+                  fragment A on Character { name }\n\
+                  fragment A on Character { nme }";
+    let errors = schema().validate_document(source).unwrap_err();
+
+    assert_eq!(
+        GraphQLError::ValidationError(errors).render(source, DiagnosticStyle::Plain),
+        "error: There can only be one fragment named A\n \
+         --> 2:10\n  \
+         |\n\
+         2 | fragment A on Character { name }\n  \
+         |          ^\n \
+         ::: 3:10\n  \
+         |\n\
+         3 | fragment A on Character { nme }\n  \
+         |          ^ related location\n\
+         \n\
+         error: Unknown field \"nme\" on type \"Character\"\n \
+         --> 3:27\n  \
+         |\n\
+         3 | fragment A on Character { nme }\n  \
+         |                           ^^^\n",
+    );
+}
+
+#[test]
+fn renders_ansi_colors() {
+    let diagnostic = Diagnostic::new("Oops")
+        .at(SourcePosition::new(2, 0, 2), None)
+        .with_hint("try harder");
+
+    assert_eq!(
+        diagnostic.render("{ a }", DiagnosticStyle::Ansi),
+        "\x1b[1;31merror\x1b[0m\x1b[1m: Oops\x1b[0m\n \
+         \x1b[1;34m-->\x1b[0m 1:3\n  \
+         \x1b[1;34m|\x1b[0m\n\
+         \x1b[1;34m1\x1b[0m \x1b[1;34m|\x1b[0m { a }\n  \
+         \x1b[1;34m|\x1b[0m   \x1b[1;31m^\x1b[0m\n  \
+         \x1b[1;34m|\x1b[0m\n  \
+         \x1b[1;34m=\x1b[0m \x1b[1mhint: try harder\x1b[0m\n",
+    );
+}
+
+#[test]
+fn renders_without_location() {
+    assert_eq!(
+        GraphQLError::NoOperationProvided.render("", DiagnosticStyle::Plain),
+        "error: No operation provided\n",
+    );
+}
@@ -1,7 +1,10 @@
 use crate::{
     ast::{Arguments, Definition, Field, Operation, OperationType, OwnedDocument, Selection},
     graphql_input_value,
-    parser::{document::parse_document_source, ParseError, SourcePosition, Spanning, Token},
+    parser::{
+        document::parse_document_source, ParseError, ParserLimit, ParserLimits, SourcePosition,
+        Spanning, Token,
+    },
     schema::model::SchemaType,
     types::scalars::{EmptyMutation, EmptySubscription},
     validation::test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
@@ -173,3 +176,80 @@ fn issue_427_panic_is_not_expected() {
         ParseError::ExpectedScalarError("There needs to be a Float type")
     );
 }
+
+#[test]
+fn limits() {
+    fn parse_limited(s: &str, limits: ParserLimits) -> Result<(), Spanning<ParseError<'_>>> {
+        let mut schema = SchemaType::<DefaultScalarValue>::new::<
+            QueryRoot,
+            MutationRoot,
+            SubscriptionRoot,
+        >(&(), &(), &());
+        schema.set_parser_limits(limits);
+        parse_document_source(s, &schema).map(drop)
+    }
+
+    let depth = ParserLimits {
+        max_depth: Some(3),
+        ..ParserLimits::unlimited()
+    };
+    assert_eq!(parse_limited("{ a(x: [1]) { b } }", depth), Ok(()));
+    assert_eq!(
+        parse_limited("{ a(x: [[1]]) }", depth),
+        Err(Spanning::zero_width(
+            &SourcePosition::new(8, 0, 8),
+            ParseError::LimitExceeded(ParserLimit::Depth(3)),
+        )),
+    );
+    assert_eq!(
+        parse_limited("{ a { b { c { d } } } }", depth),
+        Err(Spanning::zero_width(
+            &SourcePosition::new(12, 0, 12),
+            ParseError::LimitExceeded(ParserLimit::Depth(3)),
+        )),
+    );
+    assert_eq!(
+        parse_limited("query ($v: [[[Int]]]) { a }", depth),
+        Err(Spanning::zero_width(
+            &SourcePosition::new(13, 0, 13),
+            ParseError::LimitExceeded(ParserLimit::Depth(3)),
+        )),
+    );
+
+    let tokens = ParserLimits {
+        max_tokens: Some(4),
+        ..ParserLimits::unlimited()
+    };
+    assert_eq!(parse_limited("{ a b }", tokens), Ok(()));
+    assert_eq!(
+        parse_limited("{ a b c }", tokens),
+        Err(Spanning::start_end(
+            &SourcePosition::new(8, 0, 8),
+            &SourcePosition::new(9, 0, 9),
+            ParseError::LimitExceeded(ParserLimit::Tokens(4)),
+        )),
+    );
+
+    let length = ParserLimits {
+        max_length: Some(7),
+        ..ParserLimits::unlimited()
+    };
+    assert_eq!(parse_limited("{ a b }", length), Ok(()));
+    assert_eq!(
+        parse_limited("{ a bc }", length),
+        Err(Spanning::zero_width(
+            &SourcePosition::new(0, 0, 0),
+            ParseError::LimitExceeded(ParserLimit::Length(7)),
+        )),
+    );
+
+    let nested = format!("{}{}", "{ a ".repeat(100), "}".repeat(100));
+    assert_eq!(
+        parse_limited(&nested, ParserLimits::default()),
+        Err(Spanning::zero_width(
+            &SourcePosition::new(256, 0, 256),
+            ParseError::LimitExceeded(ParserLimit::Depth(ParserLimits::DEFAULT_MAX_DEPTH)),
+        )),
+    );
+    assert_eq!(parse_limited(&nested, ParserLimits::unlimited()), Ok(()));
+}
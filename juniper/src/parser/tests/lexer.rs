@@ -1,4 +1,6 @@
-use crate::parser::{Lexer, LexerError, ScalarToken, SourcePosition, Spanning, Token};
+use crate::parser::{
+    lexer::block_string_value, Lexer, LexerError, ScalarToken, SourcePosition, Spanning, Token,
+};
 
 fn tokenize_to_vec<'a>(s: &'a str) -> Vec<Spanning<Token<'a>>> {
     let mut tokens = Vec::new();
@@ -201,6 +203,62 @@ fn strings() {
     );
 }
 
+#[test]
+fn block_strings() {
+    assert_eq!(
+        tokenize_single("\"\"\"\"\"\""),
+        Spanning::start_end(
+            &SourcePosition::new(0, 0, 0),
+            &SourcePosition::new(6, 0, 6),
+            Token::BlockString(""),
+        )
+    );
+
+    assert_eq!(
+        tokenize_single("\"\"\"\n  multi \"quoted\"\n  line\n\"\"\""),
+        Spanning::start_end(
+            &SourcePosition::new(0, 0, 0),
+            &SourcePosition::new(31, 3, 3),
+            Token::BlockString("\n  multi \"quoted\"\n  line\n"),
+        )
+    );
+
+    assert_eq!(
+        tokenize_single(r##""""escaped \""" and \n""""##),
+        Spanning::start_end(
+            &SourcePosition::new(0, 0, 0),
+            &SourcePosition::new(25, 0, 25),
+            Token::BlockString(r#"escaped \""" and \n"#),
+        )
+    );
+
+    assert_eq!(
+        tokenize_error(r#""""unterminated """#),
+        Spanning::zero_width(
+            &SourcePosition::new(18, 0, 18),
+            LexerError::UnterminatedString,
+        )
+    );
+}
+
+#[test]
+fn block_string_values() {
+    assert_eq!(block_string_value(""), "");
+    assert_eq!(block_string_value("  single line  "), "  single line  ");
+    assert_eq!(
+        block_string_value("\n    first\n      indented\n    last\n  "),
+        "first\n  indented\nlast",
+    );
+    assert_eq!(
+        block_string_value("kept\r\n  common\r  indent"),
+        "kept\ncommon\nindent",
+    );
+    assert_eq!(
+        block_string_value(r#"escaped \""" and \n"#),
+        r#"escaped """ and \n"#,
+    );
+}
+
 #[test]
 fn string_errors() {
     assert_eq!(
@@ -623,6 +681,11 @@ fn punctuation() {
         tokenize_single("|"),
         Spanning::single_width(&SourcePosition::new(0, 0, 0), Token::Pipe)
     );
+
+    assert_eq!(
+        tokenize_single("&"),
+        Spanning::single_width(&SourcePosition::new(0, 0, 0), Token::Amp)
+    );
 }
 
 #[test]
@@ -697,4 +760,9 @@ fn display() {
     assert_eq!(format!("{}", Token::Equals), "=");
     assert_eq!(format!("{}", Token::At), "@");
     assert_eq!(format!("{}", Token::Pipe), "|");
+    assert_eq!(format!("{}", Token::Amp), "&");
+    assert_eq!(
+        format!("{}", Token::BlockString("a \"b\"")),
+        "\"\"\"a \"b\"\"\"\""
+    );
 }
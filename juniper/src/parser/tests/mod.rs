@@ -1,3 +1,6 @@
+mod diagnostic;
 mod document;
 mod lexer;
+mod printer;
+mod type_system;
 mod value;
@@ -0,0 +1,234 @@
+use crate::{
+    ast::OwnedDocument,
+    parser::{normalize_document, parse_document_source, print_document, PrintStyle},
+    schema::model::SchemaType,
+    validation::test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
+    value::DefaultScalarValue,
+};
+
+fn schema() -> SchemaType<'static, DefaultScalarValue> {
+    SchemaType::new::<QueryRoot, MutationRoot, SubscriptionRoot>(&(), &(), &())
+}
+
+fn parse_document(s: &str) -> OwnedDocument<'_, DefaultScalarValue> {
+    parse_document_source(s, &schema()).unwrap_or_else(|_| panic!("Parse error on input {:#?}", s))
+}
+
+/// Asserts that printing the provided `source` gives the `expected` pretty
+/// source, and that both pretty and minified sources are parsed back into the
+/// same document.
+fn assert_round_trip(source: &str, expected: &str) {
+    let pretty = print_document(&parse_document(source), PrintStyle::Pretty);
+    assert_eq!(pretty, expected);
+
+    let minified = print_document(&parse_document(source), PrintStyle::Minified);
+    assert!(!minified.contains('\n'), "{}", minified);
+    for printed in [&pretty, &minified] {
+        assert_eq!(
+            print_document(&parse_document(printed), PrintStyle::Pretty),
+            pretty,
+        );
+        assert_eq!(
+            print_document(&parse_document(printed), PrintStyle::Minified),
+            minified,
+        );
+    }
+}
+
+fn normalize(s: &str, operation_name: Option<&str>) -> Option<String> {
+    normalize_document(&parse_document(s), operation_name, &schema())
+        .map(|doc| print_document(&doc, PrintStyle::Minified))
+}
+
+#[test]
+fn prints_operations() {
+    assert_round_trip(
+        "{ dog { name, nickname } }",
+        "{\n  dog {\n    name\n    nickname\n  }\n}",
+    );
+    assert_round_trip(
+        r#"
+        query Q($a: Int = 1, $b: [String!]! = ["x", "y"]) @onQuery {
+            alias: dog { isHousetrained(atOtherHomes: $a) @include(if: true) }
+            complicatedArgs {
+                complexArgField(complexArg: { requiredField: true, stringListField: [null] })
+                floatArgField(floatArg: 1.0)
+                stringArgField(stringArg: "quote \" and \\ and \n\t and \u0001")
+                enumArgField(enumArg: BROWN)
+            }
+        }
+        "#,
+        r#"query Q($a: Int = 1, $b: [String!]! = ["x", "y"]) @onQuery {
+  alias: dog {
+    isHousetrained(atOtherHomes: $a) @include(if: true)
+  }
+  complicatedArgs {
+    complexArgField(complexArg: {requiredField: true, stringListField: [null]})
+    floatArgField(floatArg: 1.0)
+    stringArgField(stringArg: "quote \" and \\ and \n\t and \u0001")
+    enumArgField(enumArg: BROWN)
+  }
+}"#,
+    );
+    assert_round_trip(
+        "mutation { testInput } subscription S { name }",
+        "mutation {\n  testInput\n}\n\nsubscription S {\n  name\n}",
+    );
+}
+
+#[test]
+fn prints_fragments() {
+    assert_round_trip(
+        r#"
+        query { dog { ...F ... on Dog { name } ... @skip(if: false) { nickname } } }
+        fragment F on Dog @onFragmentDefinition { barkVolume }
+        "#,
+        r#"{
+  dog {
+    ...F
+    ... on Dog {
+      name
+    }
+    ... @skip(if: false) {
+      nickname
+    }
+  }
+}
+
+fragment F on Dog @onFragmentDefinition {
+  barkVolume
+}"#,
+    );
+}
+
+#[test]
+fn prints_minified() {
+    let doc = parse_document(
+        r#"
+        query Q($a: Int = 1) {
+            alias: dog { name ...F ... on Dog { nickname } }
+            complicatedArgs { multipleReqs(req1: 1, req2: -2) }
+        }
+        fragment F on Dog { barkVolume }
+        "#,
+    );
+
+    assert_eq!(
+        print_document(&doc, PrintStyle::Minified),
+        "query Q($a:Int=1){alias:dog{name...F...on Dog{nickname}}\
+         complicatedArgs{multipleReqs(req1:1,req2:-2)}}\
+         fragment F on Dog{barkVolume}",
+    );
+}
+
+#[test]
+fn normalizes_operations() {
+    let a = normalize(
+        r#"
+        query Q($b: Int, $a: Int = 3) {
+            complicatedArgs {
+                multipleReqs(req2: 2, req1: $a)
+                stringArgField(stringArg: "secret")
+            }
+            d: dog @skip(if: false) @include(if: true) { ...F name }
+        }
+        fragment F on Dog { nickname ...G }
+        fragment G on Dog { barkVolume }
+        fragment Unused on Dog { name }
+        "#,
+        None,
+    );
+    let b = normalize(
+        r#"
+        query Q($a: Int = 5, $b: Int) {
+            dog @include(if: true) @skip(if: false) { name ...F }
+            complicatedArgs {
+                stringArgField(stringArg: "other")
+                multipleReqs(req1: $a, req2: 7)
+            }
+        }
+        fragment G on Dog { barkVolume }
+        fragment F on Dog { ...G nickname }
+        "#,
+        None,
+    );
+
+    assert_eq!(a, b);
+    assert_eq!(
+        a.as_deref(),
+        Some(
+            "query Q($a:Int=0,$b:Int){\
+             complicatedArgs{multipleReqs(req1:$a,req2:0)stringArgField(stringArg:\"\")}\
+             dog@include(if:true)@skip(if:false){barkVolume name nickname}}",
+        ),
+    );
+}
+
+#[test]
+fn inlines_fragments() {
+    let inlined = normalize("{ dog { name } }", None);
+
+    assert_eq!(
+        normalize("{ ...F } fragment F on QueryRoot { dog { name } }", None),
+        inlined,
+    );
+    assert_eq!(
+        normalize("{ ... { dog { ... on Dog { name } } } }", None),
+        inlined,
+    );
+    assert_eq!(
+        normalize(
+            "{ dog { name } ...F } fragment F on QueryRoot { dog { name } }",
+            None
+        ),
+        inlined,
+    );
+    assert_eq!(inlined.as_deref(), Some("{dog{name}}"));
+
+    assert_eq!(
+        normalize(
+            "query Q($a: Boolean!) { pet { ...F @include(if: $a) ... on Pet { name } } }
+             fragment F on Dog { barkVolume }",
+            None,
+        )
+        .as_deref(),
+        Some("query Q($a:Boolean!){pet{name...on Dog@include(if:$a){barkVolume}}}"),
+    );
+}
+
+#[test]
+fn strips_literals() {
+    assert_eq!(
+        normalize(
+            r#"{
+                complicatedArgs {
+                    complexArgField(complexArg: { requiredField: true })
+                    stringListArgField(stringListArg: ["a"])
+                    floatArgField(floatArg: 1.5)
+                    booleanArgField(booleanArg: false)
+                    enumArgField(enumArg: BROWN)
+                    intArgField(intArg: null)
+                }
+            }"#,
+            None,
+        )
+        .as_deref(),
+        Some(
+            "{complicatedArgs{booleanArgField(booleanArg:false)complexArgField(complexArg:{})\
+             enumArgField(enumArg:BROWN)floatArgField(floatArg:0)intArgField(intArg:null)\
+             stringListArgField(stringListArg:[])}}",
+        ),
+    );
+}
+
+#[test]
+fn selects_operation() {
+    let doc = "query A { dog { name } } query B { cat { name } }";
+
+    assert_eq!(
+        normalize(doc, Some("B")).as_deref(),
+        Some("query B{cat{name}}"),
+    );
+    assert_eq!(normalize(doc, Some("C")), None);
+    assert_eq!(normalize(doc, None), None);
+}
@@ -0,0 +1,410 @@
+use crate::{
+    ast::{
+        Directive, DirectiveDefinition, FieldDefinition, InputValueDefinition, OperationType,
+        ScalarTypeDefinition, SchemaDocument, Type, TypeDefinition, TypeSystemDefinition,
+    },
+    graphql_input_value,
+    parser::{parse_schema_document, ParseError, SourcePosition, Spanning, Token},
+    schema::model::DirectiveLocation,
+    value::DefaultScalarValue,
+};
+
+fn parse_schema(s: &str) -> SchemaDocument<'_, DefaultScalarValue> {
+    parse_schema_document(s).expect(&format!("Parse error on input {:#?}", s))
+}
+
+fn parse_schema_error(s: &str) -> Spanning<ParseError<'_>> {
+    match parse_schema_document::<DefaultScalarValue>(s) {
+        Ok(doc) => panic!("*No* parse error on input {:#?} =>\n{:#?}", s, doc),
+        Err(err) => err,
+    }
+}
+
+/// Returns the type definitions (not extensions) of the provided `doc`.
+fn types<'a, 'd>(
+    doc: &'d SchemaDocument<'a, DefaultScalarValue>,
+) -> Vec<&'d TypeDefinition<'a, DefaultScalarValue>> {
+    doc.iter()
+        .filter_map(|def| match def {
+            TypeSystemDefinition::Type(t) => Some(&t.item),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn simple_ast() {
+    assert_eq!(
+        parse_schema(r#""Date" scalar Date @specifiedBy(url: "x")"#),
+        vec![TypeSystemDefinition::Type(Spanning::start_end(
+            &SourcePosition::new(0, 0, 0),
+            &SourcePosition::new(41, 0, 41),
+            TypeDefinition::Scalar(ScalarTypeDefinition {
+                description: Some(Spanning::start_end(
+                    &SourcePosition::new(0, 0, 0),
+                    &SourcePosition::new(6, 0, 6),
+                    "Date".into(),
+                )),
+                name: Spanning::start_end(
+                    &SourcePosition::new(14, 0, 14),
+                    &SourcePosition::new(18, 0, 18),
+                    "Date",
+                ),
+                directives: Some(vec![Spanning::start_end(
+                    &SourcePosition::new(19, 0, 19),
+                    &SourcePosition::new(41, 0, 41),
+                    Directive {
+                        name: Spanning::start_end(
+                            &SourcePosition::new(20, 0, 20),
+                            &SourcePosition::new(31, 0, 31),
+                            "specifiedBy",
+                        ),
+                        arguments: Some(Spanning::start_end(
+                            &SourcePosition::new(31, 0, 31),
+                            &SourcePosition::new(41, 0, 41),
+                            crate::ast::Arguments {
+                                items: vec![(
+                                    Spanning::start_end(
+                                        &SourcePosition::new(32, 0, 32),
+                                        &SourcePosition::new(35, 0, 35),
+                                        "url",
+                                    ),
+                                    Spanning::start_end(
+                                        &SourcePosition::new(37, 0, 37),
+                                        &SourcePosition::new(40, 0, 40),
+                                        graphql_input_value!("x"),
+                                    ),
+                                )],
+                            },
+                        )),
+                    },
+                )]),
+            }),
+        ))],
+    );
+}
+
+#[test]
+fn type_definitions() {
+    let doc = parse_schema(
+        r#"
+        """
+        Something having an ID.
+        """
+        interface Node { id: ID! }
+
+        type User implements Node & Named @key(fields: "id") {
+            id: ID!
+            "Escaped \"name\"."
+            name(short: Boolean = false @deprecated): String
+            friends(first: Int = 10, after: [ID!]): [User!]! @deprecated(reason: "No")
+        }
+
+        union Entity = | User | Post
+
+        enum Color { RED "Bluish." GREEN @deprecated BLUE }
+
+        input Filter { color: Color = RED, ids: [ID] = ["a", "b"] tags: [String] }
+        "#,
+    );
+    let types = types(&doc);
+    assert_eq!(types.len(), 5);
+
+    match types[0] {
+        TypeDefinition::Interface(i) => {
+            assert_eq!(i.name.item, "Node");
+            assert_eq!(
+                i.description.as_ref().map(|d| d.item.as_str()),
+                Some("Something having an ID."),
+            );
+            assert_eq!(i.fields.len(), 1);
+        }
+        t => panic!("Unexpected {:?}", t),
+    }
+
+    match types[1] {
+        TypeDefinition::Object(o) => {
+            assert_eq!(
+                o.interfaces.iter().map(|i| i.item).collect::<Vec<_>>(),
+                vec!["Node", "Named"],
+            );
+            assert_eq!(o.directives.as_ref().unwrap()[0].item.name.item, "key");
+
+            let fields = o.fields.iter().map(|f| &f.item).collect::<Vec<_>>();
+            assert_eq!(
+                fields.iter().map(|f| f.name.item).collect::<Vec<_>>(),
+                vec!["id", "name", "friends"],
+            );
+            assert_eq!(fields[0].field_type.item, Type::NonNullNamed("ID".into()));
+            assert_eq!(
+                fields[1].description.as_ref().unwrap().item,
+                "Escaped \"name\".",
+            );
+
+            let InputValueDefinition {
+                name,
+                value_type,
+                default_value,
+                directives,
+                ..
+            } = &fields[1].arguments[0].item;
+            assert_eq!(name.item, "short");
+            assert_eq!(value_type.item, Type::Named("Boolean".into()));
+            assert_eq!(
+                default_value.as_ref().map(|v| &v.item),
+                Some(&graphql_input_value!(false)),
+            );
+            assert_eq!(directives.as_ref().unwrap()[0].item.name.item, "deprecated");
+
+            let FieldDefinition {
+                arguments,
+                field_type,
+                directives,
+                ..
+            } = fields[2];
+            assert_eq!(
+                arguments[0].item.default_value.as_ref().map(|v| &v.item),
+                Some(&graphql_input_value!(10)),
+            );
+            assert_eq!(arguments[1].item.default_value, None);
+            assert_eq!(
+                field_type.item,
+                Type::NonNullList(Box::new(Type::NonNullNamed("User".into())), None),
+            );
+            assert!(directives.is_some());
+        }
+        t => panic!("Unexpected {:?}", t),
+    }
+
+    match types[2] {
+        TypeDefinition::Union(u) => assert_eq!(
+            u.members.iter().map(|m| m.item).collect::<Vec<_>>(),
+            vec!["User", "Post"],
+        ),
+        t => panic!("Unexpected {:?}", t),
+    }
+
+    match types[3] {
+        TypeDefinition::Enum(e) => {
+            assert_eq!(
+                e.values
+                    .iter()
+                    .map(|v| v.item.name.item)
+                    .collect::<Vec<_>>(),
+                vec!["RED", "GREEN", "BLUE"],
+            );
+            assert_eq!(
+                e.values[1].item.description.as_ref().unwrap().item,
+                "Bluish."
+            );
+            assert!(e.values[1].item.directives.is_some());
+        }
+        t => panic!("Unexpected {:?}", t),
+    }
+
+    match types[4] {
+        TypeDefinition::InputObject(i) => {
+            assert_eq!(i.name.item, "Filter");
+            assert_eq!(
+                i.fields[0].item.default_value.as_ref().map(|v| &v.item),
+                Some(&graphql_input_value!(RED)),
+            );
+            assert_eq!(
+                i.fields[1]
+                    .item
+                    .default_value
+                    .as_ref()
+                    .map(|v| v.item.to_string()),
+                Some(r#"["a", "b"]"#.into()),
+            );
+            assert_eq!(i.fields[2].item.default_value, None);
+        }
+        t => panic!("Unexpected {:?}", t),
+    }
+}
+
+#[test]
+fn schema_and_directive_definitions() {
+    let doc = parse_schema(
+        r#"
+        "The schema."
+        schema @link { query: Root, mutation: Mutation }
+
+        "Authorization."
+        directive @auth(role: String = "admin") repeatable on | FIELD_DEFINITION | OBJECT
+        directive @tag on SCHEMA
+        "#,
+    );
+    assert_eq!(doc.len(), 3);
+
+    match &doc[0] {
+        TypeSystemDefinition::Schema(s) => {
+            assert_eq!(s.start, SourcePosition::new(9, 1, 8));
+            assert_eq!(s.end, SourcePosition::new(79, 2, 56));
+            assert_eq!(s.item.description.as_ref().unwrap().item, "The schema.");
+            assert_eq!(
+                s.item
+                    .operation_types
+                    .iter()
+                    .map(|(op, name)| (op.item.clone(), name.item))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (OperationType::Query, "Root"),
+                    (OperationType::Mutation, "Mutation"),
+                ],
+            );
+        }
+        d => panic!("Unexpected {:?}", d),
+    }
+
+    match &doc[1] {
+        TypeSystemDefinition::Directive(d) => {
+            let DirectiveDefinition {
+                description,
+                name,
+                arguments,
+                is_repeatable,
+                locations,
+            } = &d.item;
+            assert_eq!(description.as_ref().unwrap().item, "Authorization.");
+            assert_eq!(name.item, "auth");
+            assert_eq!(
+                arguments[0].item.default_value.as_ref().map(|v| &v.item),
+                Some(&graphql_input_value!("admin")),
+            );
+            assert!(is_repeatable);
+            assert_eq!(
+                locations.iter().map(|l| l.item.clone()).collect::<Vec<_>>(),
+                vec![
+                    DirectiveLocation::FieldDefinition,
+                    DirectiveLocation::Object
+                ],
+            );
+        }
+        d => panic!("Unexpected {:?}", d),
+    }
+
+    match &doc[2] {
+        TypeSystemDefinition::Directive(d) => {
+            assert!(!d.item.is_repeatable);
+            assert!(d.item.arguments.is_empty());
+        }
+        d => panic!("Unexpected {:?}", d),
+    }
+}
+
+#[test]
+fn extensions() {
+    let doc = parse_schema(
+        r#"
+        extend schema @tag
+        extend schema { subscription: Subscription }
+        extend scalar Date @tag
+        extend type User implements Entity
+        extend interface Node @tag
+        extend union Entity = Tag
+        extend enum Color { PURPLE }
+        extend input Filter { limit: Int }
+        "#,
+    );
+    assert_eq!(doc.len(), 8);
+
+    match (&doc[0], &doc[1]) {
+        (TypeSystemDefinition::SchemaExtension(a), TypeSystemDefinition::SchemaExtension(b)) => {
+            assert!(a.item.operation_types.is_empty());
+            assert!(a.item.directives.is_some());
+            assert_eq!(b.item.operation_types.len(), 1);
+        }
+        d => panic!("Unexpected {:?}", d),
+    }
+
+    let extended = doc[2..]
+        .iter()
+        .map(|def| match def {
+            TypeSystemDefinition::TypeExtension(t) => t.item.name().item,
+            d => panic!("Unexpected {:?}", d),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        extended,
+        vec!["Date", "User", "Node", "Entity", "Color", "Filter"],
+    );
+
+    match &doc[3] {
+        TypeSystemDefinition::TypeExtension(Spanning {
+            item: TypeDefinition::Object(o),
+            end,
+            ..
+        }) => {
+            assert!(o.fields.is_empty());
+            assert_eq!(o.interfaces[0].item, "Entity");
+            assert_eq!(*end, o.interfaces[0].end);
+        }
+        d => panic!("Unexpected {:?}", d),
+    }
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        parse_schema_error("type Query {"),
+        Spanning::zero_width(
+            &SourcePosition::new(12, 0, 12),
+            ParseError::UnexpectedEndOfFile,
+        ),
+    );
+    assert_eq!(
+        parse_schema_error("type Query {}"),
+        Spanning::start_end(
+            &SourcePosition::new(12, 0, 12),
+            &SourcePosition::new(13, 0, 13),
+            ParseError::UnexpectedToken(Token::CurlyClose),
+        ),
+    );
+    assert_eq!(
+        parse_schema_error("query { a }"),
+        Spanning::start_end(
+            &SourcePosition::new(0, 0, 0),
+            &SourcePosition::new(5, 0, 5),
+            ParseError::UnexpectedToken(Token::Name("query")),
+        ),
+    );
+    assert_eq!(
+        parse_schema_error(r#""Doc" extend type A"#),
+        Spanning::start_end(
+            &SourcePosition::new(6, 0, 6),
+            &SourcePosition::new(12, 0, 12),
+            ParseError::UnexpectedToken(Token::Name("extend")),
+        ),
+    );
+    assert_eq!(
+        parse_schema_error("schema @tag"),
+        Spanning::zero_width(
+            &SourcePosition::new(11, 0, 11),
+            ParseError::UnexpectedEndOfFile,
+        ),
+    );
+    assert_eq!(
+        parse_schema_error("enum Bool { true }"),
+        Spanning::start_end(
+            &SourcePosition::new(12, 0, 12),
+            &SourcePosition::new(16, 0, 16),
+            ParseError::UnexpectedToken(Token::Name("true")),
+        ),
+    );
+    assert_eq!(
+        parse_schema_error("directive @a on ANYWHERE"),
+        Spanning::start_end(
+            &SourcePosition::new(16, 0, 16),
+            &SourcePosition::new(24, 0, 24),
+            ParseError::UnexpectedToken(Token::Name("ANYWHERE")),
+        ),
+    );
+    assert_eq!(
+        parse_schema_error("type A { a(x: Int = $var): Int }"),
+        Spanning::single_width(
+            &SourcePosition::new(20, 0, 20),
+            ParseError::UnexpectedToken(Token::Dollar),
+        ),
+    );
+}
@@ -114,6 +114,17 @@ fn input_value_literals() {
             graphql_input_value!("test"),
         ),
     );
+    assert_eq!(
+        parse_value::<DefaultScalarValue>(
+            "\"\"\"\n  block \"test\"\n\"\"\"",
+            &scalar_meta::<String>("String"),
+        ),
+        Spanning::start_end(
+            &SourcePosition::new(0, 0, 0),
+            &SourcePosition::new(22, 2, 3),
+            graphql_input_value!("block \"test\""),
+        ),
+    );
     let values = &[EnumValue::new("enum_value")];
     let e: EnumMeta<DefaultScalarValue> = EnumMeta::new::<Enum>("TestEnum".into(), values);
 
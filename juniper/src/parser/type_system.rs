@@ -0,0 +1,514 @@
+use crate::ast::{
+    DirectiveDefinition, EnumTypeDefinition, EnumValueDefinition, FieldDefinition, FromInputValue,
+    InputObjectTypeDefinition, InputValue, InputValueDefinition, ObjectTypeDefinition,
+    ScalarTypeDefinition, SchemaDefinition, SchemaDocument, TypeDefinition, TypeSystemDefinition,
+    UnionTypeDefinition,
+};
+
+use crate::{
+    parser::{
+        document::{parse_directives, parse_operation_type, parse_type},
+        lexer::block_string_value,
+        value::parse_value_literal,
+        Lexer, OptionParseResult, ParseError, ParseResult, Parser, ParserLimits, ScalarToken,
+        SourcePosition, Spanning, Token, UnlocatedParseResult,
+    },
+    schema::model::{DirectiveLocation, SchemaType},
+    value::{DefaultScalarValue, ParseScalarValue, ScalarValue},
+};
+
+/// Parses the provided GraphQL type system document (SDL) containing schema,
+/// type and directive definitions, and their extensions.
+///
+/// Literals are parsed as the built-in scalars, with the rest of their
+/// validation left to the consumer of the returned [`SchemaDocument`]. The
+/// document is restricted by the default [`ParserLimits`].
+pub fn parse_schema_document<'a, S>(s: &'a str) -> UnlocatedParseResult<'a, SchemaDocument<'a, S>>
+where
+    S: ScalarValue,
+{
+    let schema = SchemaType::builtin();
+    let mut lexer = Lexer::new(s);
+    let mut parser = Parser::new_with_limits(&mut lexer, &ParserLimits::default())?;
+
+    let mut defs = Vec::new();
+    loop {
+        defs.push(parse_definition(&mut parser, &schema)?);
+
+        if parser.peek().item == Token::EndOfFile {
+            return Ok(defs);
+        }
+    }
+}
+
+fn parse_definition<'a, 'b, S>(
+    parser: &mut Parser<'a>,
+    schema: &'b SchemaType<'b, S>,
+) -> UnlocatedParseResult<'a, TypeSystemDefinition<'a, S>>
+where
+    S: ScalarValue,
+{
+    let description = parse_description(parser)?;
+    match (parser.peek().item, &description) {
+        (Token::Name("schema"), _) => Ok(TypeSystemDefinition::Schema(parse_schema_definition(
+            parser,
+            schema,
+            description,
+            false,
+        )?)),
+        (Token::Name("directive"), _) => Ok(TypeSystemDefinition::Directive(
+            parse_directive_definition(parser, schema, description)?,
+        )),
+        (Token::Name("extend"), None) => {
+            parser.next_token()?;
+            if parser.peek().item == Token::Name("schema") {
+                Ok(TypeSystemDefinition::SchemaExtension(
+                    parse_schema_definition(parser, schema, None, true)?,
+                ))
+            } else {
+                Ok(TypeSystemDefinition::TypeExtension(parse_type_definition(
+                    parser, schema, None,
+                )?))
+            }
+        }
+        (Token::Name("extend"), Some(_)) => {
+            Err(parser.next_token()?.map(ParseError::UnexpectedToken))
+        }
+        _ => Ok(TypeSystemDefinition::Type(parse_type_definition(
+            parser,
+            schema,
+            description,
+        )?)),
+    }
+}
+
+fn parse_schema_definition<'a, 'b, S>(
+    parser: &mut Parser<'a>,
+    schema: &'b SchemaType<'b, S>,
+    description: Option<Spanning<String>>,
+    is_extension: bool,
+) -> ParseResult<'a, SchemaDefinition<'a, S>>
+where
+    S: ScalarValue,
+{
+    let keyword = parser.expect(&Token::Name("schema"))?;
+    let directives = parse_directives(parser, schema, true)?;
+    // Root operation types are optional in extensions only.
+    let operation_types = if is_extension && parser.peek().item != Token::CurlyOpen {
+        None
+    } else {
+        Some(parser.delimited_nonempty_list(
+            &Token::CurlyOpen,
+            |p| {
+                let operation_type = parse_operation_type(p)?;
+                p.expect(&Token::Colon)?;
+                let type_name = p.expect_name()?;
+                Ok(Spanning::start_end(
+                    &operation_type.start.clone(),
+                    &type_name.end.clone(),
+                    (operation_type, type_name),
+                ))
+            },
+            &Token::CurlyClose,
+        )?)
+    };
+
+    let end = end_of(
+        &keyword,
+        &[
+            directives.as_ref().map(|d| &d.end),
+            operation_types.as_ref().map(|t| &t.end),
+        ],
+    );
+    Ok(Spanning::start_end(
+        &start_of(&description, &keyword),
+        &end,
+        SchemaDefinition {
+            description,
+            directives: directives.map(|s| s.item),
+            operation_types: operation_types
+                .map(|s| s.item.into_iter().map(|t| t.item).collect())
+                .unwrap_or_default(),
+        },
+    ))
+}
+
+fn parse_type_definition<'a, 'b, S>(
+    parser: &mut Parser<'a>,
+    schema: &'b SchemaType<'b, S>,
+    description: Option<Spanning<String>>,
+) -> ParseResult<'a, TypeDefinition<'a, S>>
+where
+    S: ScalarValue,
+{
+    let keyword = parser.next_token()?;
+    if !matches!(
+        keyword.item,
+        Token::Name("scalar" | "type" | "interface" | "union" | "enum" | "input"),
+    ) {
+        return Err(keyword.map(ParseError::UnexpectedToken));
+    }
+    let start = start_of(&description, &keyword);
+    let name = parser.expect_name()?;
+
+    match keyword.item {
+        Token::Name("scalar") => {
+            let directives = parse_directives(parser, schema, true)?;
+
+            let end = end_of(&name, &[directives.as_ref().map(|d| &d.end)]);
+            Ok(Spanning::start_end(
+                &start,
+                &end,
+                TypeDefinition::Scalar(ScalarTypeDefinition {
+                    description,
+                    name,
+                    directives: directives.map(|s| s.item),
+                }),
+            ))
+        }
+        Token::Name(kind @ "type") | Token::Name(kind @ "interface") => {
+            let interfaces = parse_implements_interfaces(parser)?;
+            let directives = parse_directives(parser, schema, true)?;
+            let fields = parse_optional_definitions(parser, |p| parse_field_definition(p, schema))?;
+
+            let end = end_of(
+                &name,
+                &[
+                    interfaces.last().map(|i| &i.end),
+                    directives.as_ref().map(|d| &d.end),
+                    fields.as_ref().map(|f| &f.end),
+                ],
+            );
+            let definition = ObjectTypeDefinition {
+                description,
+                name,
+                interfaces,
+                directives: directives.map(|s| s.item),
+                fields: fields.map(|s| s.item).unwrap_or_default(),
+            };
+            Ok(Spanning::start_end(
+                &start,
+                &end,
+                if kind == "type" {
+                    TypeDefinition::Object(definition)
+                } else {
+                    TypeDefinition::Interface(definition)
+                },
+            ))
+        }
+        Token::Name("union") => {
+            let directives = parse_directives(parser, schema, true)?;
+            let mut members = Vec::new();
+            if skip(parser, &Token::Equals)? {
+                skip(parser, &Token::Pipe)?;
+                members.push(parser.expect_name()?);
+                while skip(parser, &Token::Pipe)? {
+                    members.push(parser.expect_name()?);
+                }
+            }
+
+            let end = end_of(
+                &name,
+                &[
+                    directives.as_ref().map(|d| &d.end),
+                    members.last().map(|m| &m.end),
+                ],
+            );
+            Ok(Spanning::start_end(
+                &start,
+                &end,
+                TypeDefinition::Union(UnionTypeDefinition {
+                    description,
+                    name,
+                    directives: directives.map(|s| s.item),
+                    members,
+                }),
+            ))
+        }
+        Token::Name("enum") => {
+            let directives = parse_directives(parser, schema, true)?;
+            let values =
+                parse_optional_definitions(parser, |p| parse_enum_value_definition(p, schema))?;
+
+            let end = end_of(
+                &name,
+                &[
+                    directives.as_ref().map(|d| &d.end),
+                    values.as_ref().map(|v| &v.end),
+                ],
+            );
+            Ok(Spanning::start_end(
+                &start,
+                &end,
+                TypeDefinition::Enum(EnumTypeDefinition {
+                    description,
+                    name,
+                    directives: directives.map(|s| s.item),
+                    values: values.map(|s| s.item).unwrap_or_default(),
+                }),
+            ))
+        }
+        Token::Name("input") => {
+            let directives = parse_directives(parser, schema, true)?;
+            let fields =
+                parse_optional_definitions(parser, |p| parse_input_value_definition(p, schema))?;
+
+            let end = end_of(
+                &name,
+                &[
+                    directives.as_ref().map(|d| &d.end),
+                    fields.as_ref().map(|f| &f.end),
+                ],
+            );
+            Ok(Spanning::start_end(
+                &start,
+                &end,
+                TypeDefinition::InputObject(InputObjectTypeDefinition {
+                    description,
+                    name,
+                    directives: directives.map(|s| s.item),
+                    fields: fields.map(|s| s.item).unwrap_or_default(),
+                }),
+            ))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn parse_implements_interfaces<'a>(
+    parser: &mut Parser<'a>,
+) -> Result<Vec<Spanning<&'a str>>, Spanning<ParseError<'a>>> {
+    let mut interfaces = Vec::new();
+    if skip(parser, &Token::Name("implements"))? {
+        skip(parser, &Token::Amp)?;
+        interfaces.push(parser.expect_name()?);
+        while skip(parser, &Token::Amp)? {
+            interfaces.push(parser.expect_name()?);
+        }
+    }
+    Ok(interfaces)
+}
+
+fn parse_optional_definitions<'a, T, F>(
+    parser: &mut Parser<'a>,
+    parse: F,
+) -> OptionParseResult<'a, Vec<Spanning<T>>>
+where
+    T: std::fmt::Debug,
+    F: Fn(&mut Parser<'a>) -> ParseResult<'a, T>,
+{
+    if parser.peek().item == Token::CurlyOpen {
+        Ok(Some(parser.delimited_nonempty_list(
+            &Token::CurlyOpen,
+            parse,
+            &Token::CurlyClose,
+        )?))
+    } else {
+        Ok(None)
+    }
+}
+
+fn parse_field_definition<'a, 'b, S>(
+    parser: &mut Parser<'a>,
+    schema: &'b SchemaType<'b, S>,
+) -> ParseResult<'a, FieldDefinition<'a, S>>
+where
+    S: ScalarValue,
+{
+    let description = parse_description(parser)?;
+    let name = parser.expect_name()?;
+    let arguments = parse_arguments_definition(parser, schema)?;
+    parser.expect(&Token::Colon)?;
+    let field_type = parse_type(parser)?;
+    let directives = parse_directives(parser, schema, true)?;
+
+    let end = end_of(&field_type, &[directives.as_ref().map(|d| &d.end)]);
+    Ok(Spanning::start_end(
+        &start_of(&description, &name),
+        &end,
+        FieldDefinition {
+            description,
+            name,
+            arguments: arguments.map(|s| s.item).unwrap_or_default(),
+            field_type,
+            directives: directives.map(|s| s.item),
+        },
+    ))
+}
+
+fn parse_arguments_definition<'a, 'b, S>(
+    parser: &mut Parser<'a>,
+    schema: &'b SchemaType<'b, S>,
+) -> OptionParseResult<'a, Vec<Spanning<InputValueDefinition<'a, S>>>>
+where
+    S: ScalarValue,
+{
+    if parser.peek().item == Token::ParenOpen {
+        Ok(Some(parser.delimited_nonempty_list(
+            &Token::ParenOpen,
+            |p| parse_input_value_definition(p, schema),
+            &Token::ParenClose,
+        )?))
+    } else {
+        Ok(None)
+    }
+}
+
+fn parse_input_value_definition<'a, 'b, S>(
+    parser: &mut Parser<'a>,
+    schema: &'b SchemaType<'b, S>,
+) -> ParseResult<'a, InputValueDefinition<'a, S>>
+where
+    S: ScalarValue,
+{
+    let description = parse_description(parser)?;
+    let name = parser.expect_name()?;
+    parser.expect(&Token::Colon)?;
+    let value_type = parse_type(parser)?;
+    let default_value = if skip(parser, &Token::Equals)? {
+        Some(parse_value_literal(
+            parser,
+            true,
+            schema,
+            schema.lookup_type(&value_type.item),
+        )?)
+    } else {
+        None
+    };
+    let directives = parse_directives(parser, schema, true)?;
+
+    let end = end_of(
+        &value_type,
+        &[
+            default_value.as_ref().map(|v| &v.end),
+            directives.as_ref().map(|d| &d.end),
+        ],
+    );
+    Ok(Spanning::start_end(
+        &start_of(&description, &name),
+        &end,
+        InputValueDefinition {
+            description,
+            name,
+            value_type,
+            default_value,
+            directives: directives.map(|s| s.item),
+        },
+    ))
+}
+
+fn parse_enum_value_definition<'a, 'b, S>(
+    parser: &mut Parser<'a>,
+    schema: &'b SchemaType<'b, S>,
+) -> ParseResult<'a, EnumValueDefinition<'a, S>>
+where
+    S: ScalarValue,
+{
+    let description = parse_description(parser)?;
+    let name = parser.expect_name()?;
+    if let "true" | "false" | "null" = name.item {
+        return Err(name.map(|n| ParseError::UnexpectedToken(Token::Name(n))));
+    }
+    let directives = parse_directives(parser, schema, true)?;
+
+    let end = end_of(&name, &[directives.as_ref().map(|d| &d.end)]);
+    Ok(Spanning::start_end(
+        &start_of(&description, &name),
+        &end,
+        EnumValueDefinition {
+            description,
+            name,
+            directives: directives.map(|s| s.item),
+        },
+    ))
+}
+
+fn parse_directive_definition<'a, 'b, S>(
+    parser: &mut Parser<'a>,
+    schema: &'b SchemaType<'b, S>,
+    description: Option<Spanning<String>>,
+) -> ParseResult<'a, DirectiveDefinition<'a, S>>
+where
+    S: ScalarValue,
+{
+    let keyword = parser.expect(&Token::Name("directive"))?;
+    parser.expect(&Token::At)?;
+    let name = parser.expect_name()?;
+    let arguments = parse_arguments_definition(parser, schema)?;
+    let is_repeatable = skip(parser, &Token::Name("repeatable"))?;
+    parser.expect(&Token::Name("on"))?;
+    skip(parser, &Token::Pipe)?;
+    let mut locations = vec![parse_directive_location(parser)?];
+    while skip(parser, &Token::Pipe)? {
+        locations.push(parse_directive_location(parser)?);
+    }
+
+    Ok(Spanning::start_end(
+        &start_of(&description, &keyword),
+        &locations.last().map(|l| l.end).unwrap_or(name.end),
+        DirectiveDefinition {
+            description,
+            name,
+            arguments: arguments.map(|s| s.item).unwrap_or_default(),
+            is_repeatable,
+            locations,
+        },
+    ))
+}
+
+fn parse_directive_location<'a>(parser: &mut Parser<'a>) -> ParseResult<'a, DirectiveLocation> {
+    let name = parser.expect_name()?;
+    match DirectiveLocation::from_input_value(&InputValue::<DefaultScalarValue>::enum_value(
+        name.item,
+    )) {
+        Ok(location) => Ok(Spanning::start_end(&name.start, &name.end, location)),
+        Err(_) => Err(name.map(|n| ParseError::UnexpectedToken(Token::Name(n)))),
+    }
+}
+
+/// Parses the description preceding a definition, if there is any.
+fn parse_description<'a>(parser: &mut Parser<'a>) -> OptionParseResult<'a, String> {
+    match parser.peek().item {
+        Token::Scalar(ScalarToken::String(_)) | Token::BlockString(_) => {}
+        _ => return Ok(None),
+    }
+
+    let Spanning { item, start, end } = parser.next_token()?;
+    let description = match item {
+        Token::Scalar(token) => <String as ParseScalarValue<DefaultScalarValue>>::from_str(token)
+            .map_err(|e| Spanning::start_end(&start, &end, e))?
+            .into_string()
+            .expect("`String` is parsed into a string"),
+        Token::BlockString(raw) => block_string_value(raw),
+        _ => unreachable!(),
+    };
+    Ok(Some(Spanning::start_end(&start, &end, description)))
+}
+
+/// Consumes the next token if it's the `expected` one, returning whether it
+/// was consumed.
+///
+/// Unlike [`Parser::skip()`], doesn't fail at the end of the document, as
+/// definitions may end with their optional parts.
+fn skip<'a>(parser: &mut Parser<'a>, expected: &Token) -> Result<bool, Spanning<ParseError<'a>>> {
+    if &parser.peek().item == expected {
+        parser.next_token()?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+/// Returns the start of a definition, which is its `description` if present.
+fn start_of<T>(description: &Option<Spanning<String>>, first: &Spanning<T>) -> SourcePosition {
+    description.as_ref().map_or(first.start, |d| d.start)
+}
+
+/// Returns the end of the last present part of a definition.
+fn end_of<T>(required: &Spanning<T>, optional: &[Option<&SourcePosition>]) -> SourcePosition {
+    optional
+        .iter()
+        .rev()
+        .find_map(|end| end.copied())
+        .unwrap_or(required.end)
+}
@@ -1,9 +1,12 @@
 use crate::ast::InputValue;
 
 use crate::{
-    parser::{ParseError, ParseResult, Parser, ScalarToken, SourcePosition, Spanning, Token},
+    parser::{
+        lexer::block_string_value, ParseError, ParseResult, Parser, ScalarToken, SourcePosition,
+        Spanning, Token,
+    },
     schema::{
-        meta::{InputObjectMeta, MetaType},
+        meta::{InputObjectMeta, MetaType, ScalarMeta},
         model::SchemaType,
     },
     value::ScalarValue,
@@ -38,8 +41,14 @@ where
                 item: Token::CurlyOpen,
                 ..
             },
-            Some(&MetaType::InputObject(ref o)),
-        ) => parse_object_literal(parser, is_const, schema, Some(o)),
+            Some(t @ &MetaType::InputObject(_))
+            | Some(
+                t @ &MetaType::Scalar(ScalarMeta {
+                    structured_input: true,
+                    ..
+                }),
+            ),
+        ) => parse_object_literal(parser, is_const, schema, Some(t)),
         (
             &Spanning {
                 item: Token::Dollar,
@@ -85,6 +94,16 @@ where
                 unreachable!()
             }
         }
+        (
+            &Spanning {
+                item: Token::BlockString(_),
+                ..
+            },
+            _,
+        ) => Ok(parser.next_token()?.map(|t| match t {
+            Token::BlockString(raw) => InputValue::scalar(block_string_value(raw)),
+            _ => unreachable!(),
+        })),
         (
             &Spanning {
                 item: Token::Name("true"),
@@ -139,7 +158,7 @@ fn parse_object_literal<'a, 'b, S>(
     parser: &mut Parser<'a>,
     is_const: bool,
     schema: &'b SchemaType<'b, S>,
-    object_tpe: Option<&InputObjectMeta<'b, S>>,
+    object_tpe: Option<&MetaType<'b, S>>,
 ) -> ParseResult<'a, InputValue<S>>
 where
     S: ScalarValue,
@@ -153,20 +172,35 @@ where
         .map(|items| InputValue::parsed_object(items.into_iter().map(|s| s.item).collect())))
 }
 
-fn parse_object_field<'a, 'b, S>(
+fn parse_object_field<'a, 'b, 'c, S>(
     parser: &mut Parser<'a>,
     is_const: bool,
     schema: &'b SchemaType<'b, S>,
-    object_meta: Option<&InputObjectMeta<'b, S>>,
+    object_tpe: Option<&'c MetaType<'b, S>>,
 ) -> ParseResult<'a, (Spanning<String>, Spanning<InputValue<S>>)>
 where
     S: ScalarValue,
+    'b: 'c,
 {
     let key = parser.expect_name()?;
 
-    let tpe = object_meta
-        .and_then(|o| o.input_fields.iter().find(|f| f.name == key.item))
-        .and_then(|f| schema.lookup_type(&f.arg_type));
+    let tpe = match object_tpe {
+        Some(&MetaType::InputObject(InputObjectMeta {
+            ref input_fields, ..
+        })) => input_fields
+            .iter()
+            .find(|f| f.name == key.item)
+            .and_then(|f| schema.lookup_type(&f.arg_type)),
+        // Fields of an object literal given for a scalar (like a `JSON` one)
+        // are parsed as values of the same scalar.
+        Some(
+            t @ &MetaType::Scalar(ScalarMeta {
+                structured_input: true,
+                ..
+            }),
+        ) => Some(t),
+        _ => None,
+    };
 
     parser.expect(&Token::Colon)?;
 
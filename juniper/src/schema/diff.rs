@@ -0,0 +1,1231 @@
+//! Detection of changes between two GraphQL schemas.
+
+use std::{collections::BTreeMap, fmt};
+
+use crate::{
+    ast::{
+        Directive, DirectiveDefinition, EnumValueDefinition, FieldDefinition, InputValue,
+        InputValueDefinition, OperationType, SchemaDocument, Type, TypeDefinition,
+        TypeSystemDefinition,
+    },
+    parser::{parse_schema_document, ParseError, Spanning},
+    schema::{
+        model::{RootNode, SchemaType},
+        translate::{ast::AstTranslator, SchemaTranslator as _},
+    },
+    DefaultScalarValue, GraphQLType, ScalarValue,
+};
+
+/// GraphQL schema, which can be compared with another one via [`diff()`].
+pub trait IntoSchemaDocument<'a> {
+    /// Converts this GraphQL schema into a [`SchemaDocument`].
+    ///
+    /// # Errors
+    ///
+    /// If this GraphQL schema is represented in the [GraphQL Schema Language][0]
+    /// and fails to be parsed.
+    ///
+    /// [0]: https://graphql.org/learn/schema/#type-language
+    fn into_schema_document(self) -> Result<SchemaDocument<'a>, Spanning<ParseError<'a>>>;
+}
+
+impl<'a> IntoSchemaDocument<'a> for SchemaDocument<'a> {
+    fn into_schema_document(self) -> Result<SchemaDocument<'a>, Spanning<ParseError<'a>>> {
+        Ok(self)
+    }
+}
+
+impl<'a> IntoSchemaDocument<'a> for &'a str {
+    fn into_schema_document(self) -> Result<SchemaDocument<'a>, Spanning<ParseError<'a>>> {
+        parse_schema_document(self)
+    }
+}
+
+impl<'a> IntoSchemaDocument<'a> for &'a String {
+    fn into_schema_document(self) -> Result<SchemaDocument<'a>, Spanning<ParseError<'a>>> {
+        parse_schema_document(self)
+    }
+}
+
+impl<'a, S> IntoSchemaDocument<'a> for &'a SchemaType<'a, S>
+where
+    S: ScalarValue + 'a,
+{
+    fn into_schema_document(self) -> Result<SchemaDocument<'a>, Spanning<ParseError<'a>>> {
+        Ok(AstTranslator::translate_schema(self))
+    }
+}
+
+impl<'a, QueryT, MutationT, SubscriptionT, S> IntoSchemaDocument<'a>
+    for &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>
+where
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S>,
+    SubscriptionT: GraphQLType<S>,
+    S: ScalarValue + 'a,
+{
+    fn into_schema_document(self) -> Result<SchemaDocument<'a>, Spanning<ParseError<'a>>> {
+        Ok(AstTranslator::translate_schema(&self.schema))
+    }
+}
+
+/// Compares the `old` GraphQL schema with the `new` one, classifying each
+/// detected [`Change`] by its [`Criticality`] for the existing clients.
+///
+/// Any of the schemas may be either a [`SchemaType`], a [`RootNode`] or a
+/// [GraphQL Schema Language][0] source (like the one returned by the
+/// [`RootNode::as_schema_language()`] method).
+///
+/// # Errors
+///
+/// If any of the schemas is represented in the [GraphQL Schema Language][0] and
+/// fails to be parsed.
+///
+/// [0]: https://graphql.org/learn/schema/#type-language
+pub fn diff<'a, Old, New>(old: Old, new: New) -> Result<SchemaDiff, Spanning<ParseError<'a>>>
+where
+    Old: IntoSchemaDocument<'a>,
+    New: IntoSchemaDocument<'a>,
+{
+    let (old, new) = (old.into_schema_document()?, new.into_schema_document()?);
+
+    let mut differ = Differ::default();
+    differ.diff_schemas(&Schema::new(&old), &Schema::new(&new));
+    Ok(SchemaDiff {
+        changes: differ.changes,
+    })
+}
+
+/// Result of comparing two GraphQL schemas via [`diff()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SchemaDiff {
+    /// All the detected [`Change`]s.
+    pub changes: Vec<Change>,
+}
+
+impl SchemaDiff {
+    /// Indicates whether no [`Change`]s have been detected.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Indicates whether any [`Criticality::Breaking`] [`Change`] has been
+    /// detected.
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        self.breaking().next().is_some()
+    }
+
+    /// Returns an [`Iterator`] over the [`Criticality::Breaking`] [`Change`]s.
+    pub fn breaking(&self) -> impl Iterator<Item = &Change> {
+        self.with_criticality(Criticality::Breaking)
+    }
+
+    /// Returns an [`Iterator`] over the [`Criticality::Dangerous`] [`Change`]s.
+    pub fn dangerous(&self) -> impl Iterator<Item = &Change> {
+        self.with_criticality(Criticality::Dangerous)
+    }
+
+    /// Returns an [`Iterator`] over the [`Criticality::Safe`] [`Change`]s.
+    pub fn safe(&self) -> impl Iterator<Item = &Change> {
+        self.with_criticality(Criticality::Safe)
+    }
+
+    fn with_criticality(&self, criticality: Criticality) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(move |c| c.criticality == criticality)
+    }
+}
+
+impl fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// Single change between two GraphQL schemas.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Change {
+    /// Kind of this [`Change`].
+    pub kind: ChangeKind,
+
+    /// [`Criticality`] of this [`Change`] for the existing clients.
+    pub criticality: Criticality,
+
+    /// [Schema coordinate][0] of the changed schema element (like `User`,
+    /// `User.name`, `Query.user(id:)` or `@auth(role:)`), or the operation
+    /// name for a [`ChangeKind::RootTypeChanged`].
+    ///
+    /// [0]: https://github.com/graphql/graphql-spec/pull/794
+    pub path: String,
+
+    /// Human-readable description of this [`Change`].
+    pub message: String,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.criticality, self.message)
+    }
+}
+
+/// Criticality of a [`Change`] for the existing clients of a GraphQL schema.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Criticality {
+    /// [`Change`] breaking the existing queries (like a removed field).
+    Breaking,
+
+    /// [`Change`] not breaking the existing queries, but possibly breaking the
+    /// clients not prepared for it (like a new enum value).
+    Dangerous,
+
+    /// [`Change`] safe for all the existing clients (like a new field).
+    Safe,
+}
+
+impl fmt::Display for Criticality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Breaking => "BREAKING",
+            Self::Dangerous => "DANGEROUS",
+            Self::Safe => "SAFE",
+        })
+    }
+}
+
+/// Kind of a [`Change`] between two GraphQL schemas.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ChangeKind {
+    TypeAdded,
+    TypeRemoved,
+    TypeKindChanged,
+    TypeDescriptionChanged,
+    RootTypeChanged,
+    FieldAdded,
+    FieldRemoved,
+    FieldTypeChanged,
+    FieldDescriptionChanged,
+    FieldDeprecationChanged,
+    ArgumentAdded,
+    ArgumentRemoved,
+    ArgumentTypeChanged,
+    ArgumentDefaultValueChanged,
+    ArgumentDescriptionChanged,
+    InputFieldAdded,
+    InputFieldRemoved,
+    InputFieldTypeChanged,
+    InputFieldDefaultValueChanged,
+    InputFieldDescriptionChanged,
+    EnumValueAdded,
+    EnumValueRemoved,
+    EnumValueDescriptionChanged,
+    EnumValueDeprecationChanged,
+    UnionMemberAdded,
+    UnionMemberRemoved,
+    InterfaceImplementationAdded,
+    InterfaceImplementationRemoved,
+    DirectiveAdded,
+    DirectiveRemoved,
+    DirectiveDescriptionChanged,
+    DirectiveLocationAdded,
+    DirectiveLocationRemoved,
+    DirectiveRepeatableChanged,
+}
+
+/// Named definitions of a [`SchemaDocument`].
+struct Schema<'d, 'a> {
+    types: BTreeMap<&'a str, &'d TypeDefinition<'a, DefaultScalarValue>>,
+    directives: BTreeMap<&'a str, &'d DirectiveDefinition<'a, DefaultScalarValue>>,
+    roots: [(&'static str, Option<&'a str>); 3],
+}
+
+impl<'d, 'a> Schema<'d, 'a> {
+    fn new(doc: &'d SchemaDocument<'a>) -> Self {
+        let mut types = BTreeMap::new();
+        let mut directives = BTreeMap::new();
+        let mut operation_types = None;
+        for def in doc {
+            match def {
+                TypeSystemDefinition::Schema(def) | TypeSystemDefinition::SchemaExtension(def) => {
+                    operation_types
+                        .get_or_insert_with(Vec::new)
+                        .extend(&def.item.operation_types);
+                }
+                TypeSystemDefinition::Type(def) => {
+                    types.insert(def.item.name().item, &def.item);
+                }
+                TypeSystemDefinition::Directive(def) => {
+                    directives.insert(def.item.name.item, &def.item);
+                }
+                TypeSystemDefinition::TypeExtension(_) => {}
+            }
+        }
+
+        let roots = if let Some(ops) = operation_types {
+            let by_op = |op| {
+                ops.iter()
+                    .find(|(o, _)| o.item == op)
+                    .map(|(_, name)| name.item)
+            };
+            [
+                ("query", by_op(OperationType::Query)),
+                ("mutation", by_op(OperationType::Mutation)),
+                ("subscription", by_op(OperationType::Subscription)),
+            ]
+        } else {
+            // Without a `schema` definition, the root operation types are
+            // determined by their conventional names.
+            let by_name = |name| Some(name).filter(|n| types.contains_key(n));
+            [
+                ("query", by_name("Query")),
+                ("mutation", by_name("Mutation")),
+                ("subscription", by_name("Subscription")),
+            ]
+        };
+
+        Self {
+            types,
+            directives,
+            roots,
+        }
+    }
+}
+
+/// Position of an [`InputValue`] in a GraphQL schema.
+#[derive(Clone, Copy)]
+enum InputPosition {
+    /// Argument of a field or a directive.
+    Argument,
+
+    /// Field of an input object.
+    InputField,
+}
+
+impl InputPosition {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Argument => "argument",
+            Self::InputField => "input field",
+        }
+    }
+
+    fn path(self, owner: &str, name: &str) -> String {
+        match self {
+            Self::Argument => format!("{}({}:)", owner, name),
+            Self::InputField => format!("{}.{}", owner, name),
+        }
+    }
+
+    fn added(self) -> ChangeKind {
+        match self {
+            Self::Argument => ChangeKind::ArgumentAdded,
+            Self::InputField => ChangeKind::InputFieldAdded,
+        }
+    }
+
+    fn removed(self) -> ChangeKind {
+        match self {
+            Self::Argument => ChangeKind::ArgumentRemoved,
+            Self::InputField => ChangeKind::InputFieldRemoved,
+        }
+    }
+
+    fn type_changed(self) -> ChangeKind {
+        match self {
+            Self::Argument => ChangeKind::ArgumentTypeChanged,
+            Self::InputField => ChangeKind::InputFieldTypeChanged,
+        }
+    }
+
+    fn default_value_changed(self) -> ChangeKind {
+        match self {
+            Self::Argument => ChangeKind::ArgumentDefaultValueChanged,
+            Self::InputField => ChangeKind::InputFieldDefaultValueChanged,
+        }
+    }
+
+    fn description_changed(self) -> ChangeKind {
+        match self {
+            Self::Argument => ChangeKind::ArgumentDescriptionChanged,
+            Self::InputField => ChangeKind::InputFieldDescriptionChanged,
+        }
+    }
+}
+
+/// Collector of [`Change`]s between two GraphQL schemas.
+#[derive(Default)]
+struct Differ {
+    changes: Vec<Change>,
+}
+
+impl Differ {
+    fn push(&mut self, kind: ChangeKind, criticality: Criticality, path: String, message: String) {
+        self.changes.push(Change {
+            kind,
+            criticality,
+            path,
+            message,
+        })
+    }
+
+    fn diff_schemas<'a>(&mut self, old: &Schema<'_, 'a>, new: &Schema<'_, 'a>) {
+        for ((op, old_root), (_, new_root)) in old.roots.iter().zip(&new.roots) {
+            let (criticality, message) = match (old_root, new_root) {
+                (Some(o), Some(n)) if o != n => (
+                    Criticality::Breaking,
+                    format!("Root `{}` type changed from `{}` to `{}`", op, o, n),
+                ),
+                (Some(o), None) => (
+                    Criticality::Breaking,
+                    format!("Root `{}` type `{}` was removed", op, o),
+                ),
+                (None, Some(n)) => (
+                    Criticality::Safe,
+                    format!("Root `{}` type `{}` was added", op, n),
+                ),
+                _ => continue,
+            };
+            self.push(
+                ChangeKind::RootTypeChanged,
+                criticality,
+                op.to_string(),
+                message,
+            );
+        }
+
+        for (name, old_ty) in &old.types {
+            match new.types.get(name) {
+                Some(new_ty) => self.diff_types(old_ty, new_ty),
+                None => self.push(
+                    ChangeKind::TypeRemoved,
+                    Criticality::Breaking,
+                    name.to_string(),
+                    format!("Type `{}` was removed", name),
+                ),
+            }
+        }
+        for name in new.types.keys().filter(|n| !old.types.contains_key(*n)) {
+            self.push(
+                ChangeKind::TypeAdded,
+                Criticality::Safe,
+                name.to_string(),
+                format!("Type `{}` was added", name),
+            );
+        }
+
+        for (name, old_dir) in &old.directives {
+            match new.directives.get(name) {
+                Some(new_dir) => self.diff_directives(old_dir, new_dir),
+                None => self.push(
+                    ChangeKind::DirectiveRemoved,
+                    Criticality::Breaking,
+                    format!("@{}", name),
+                    format!("Directive `@{}` was removed", name),
+                ),
+            }
+        }
+        for name in new
+            .directives
+            .keys()
+            .filter(|n| !old.directives.contains_key(*n))
+        {
+            self.push(
+                ChangeKind::DirectiveAdded,
+                Criticality::Safe,
+                format!("@{}", name),
+                format!("Directive `@{}` was added", name),
+            );
+        }
+    }
+
+    fn diff_types(
+        &mut self,
+        old: &TypeDefinition<'_, DefaultScalarValue>,
+        new: &TypeDefinition<'_, DefaultScalarValue>,
+    ) {
+        let name = old.name().item;
+
+        if type_kind(old) != type_kind(new) {
+            self.push(
+                ChangeKind::TypeKindChanged,
+                Criticality::Breaking,
+                name.into(),
+                format!(
+                    "Type `{}` changed kind from `{}` to `{}`",
+                    name,
+                    type_kind(old),
+                    type_kind(new),
+                ),
+            );
+            return;
+        }
+
+        self.diff_description(
+            ChangeKind::TypeDescriptionChanged,
+            "type",
+            name,
+            type_description(old),
+            type_description(new),
+        );
+
+        match (old, new) {
+            (TypeDefinition::Object(o), TypeDefinition::Object(n)) => {
+                self.diff_interfaces(name, &o.interfaces, &n.interfaces);
+                self.diff_fields(name, &o.fields, &n.fields);
+            }
+            (TypeDefinition::Interface(o), TypeDefinition::Interface(n)) => {
+                self.diff_interfaces(name, &o.interfaces, &n.interfaces);
+                self.diff_fields(name, &o.fields, &n.fields);
+            }
+            (TypeDefinition::Union(o), TypeDefinition::Union(n)) => {
+                self.diff_union_members(name, &o.members, &n.members);
+            }
+            (TypeDefinition::Enum(o), TypeDefinition::Enum(n)) => {
+                self.diff_enum_values(name, &o.values, &n.values);
+            }
+            (TypeDefinition::InputObject(o), TypeDefinition::InputObject(n)) => {
+                self.diff_input_values(InputPosition::InputField, name, &o.fields, &n.fields);
+            }
+            _ => {}
+        }
+    }
+
+    fn diff_interfaces(&mut self, ty: &str, old: &[Spanning<&str>], new: &[Spanning<&str>]) {
+        let (old, new) = (unspanned(old), unspanned(new));
+        for iface in old.iter().filter(|i| !new.contains(i)) {
+            self.push(
+                ChangeKind::InterfaceImplementationRemoved,
+                Criticality::Breaking,
+                ty.into(),
+                format!("Type `{}` no longer implements interface `{}`", ty, iface),
+            );
+        }
+        for iface in new.iter().filter(|i| !old.contains(i)) {
+            self.push(
+                ChangeKind::InterfaceImplementationAdded,
+                Criticality::Dangerous,
+                ty.into(),
+                format!("Type `{}` now implements interface `{}`", ty, iface),
+            );
+        }
+    }
+
+    fn diff_union_members(&mut self, union: &str, old: &[Spanning<&str>], new: &[Spanning<&str>]) {
+        let (old, new) = (unspanned(old), unspanned(new));
+        for member in old.iter().filter(|m| !new.contains(m)) {
+            self.push(
+                ChangeKind::UnionMemberRemoved,
+                Criticality::Breaking,
+                union.into(),
+                format!("Type `{}` was removed from union `{}`", member, union),
+            );
+        }
+        for member in new.iter().filter(|m| !old.contains(m)) {
+            self.push(
+                ChangeKind::UnionMemberAdded,
+                Criticality::Dangerous,
+                union.into(),
+                format!("Type `{}` was added to union `{}`", member, union),
+            );
+        }
+    }
+
+    fn diff_fields(
+        &mut self,
+        ty: &str,
+        old: &[Spanning<FieldDefinition<'_, DefaultScalarValue>>],
+        new: &[Spanning<FieldDefinition<'_, DefaultScalarValue>>],
+    ) {
+        for Spanning {
+            item: old_field, ..
+        } in old
+        {
+            let path = format!("{}.{}", ty, old_field.name.item);
+            let new_field = match new.iter().find(|f| f.item.name.item == old_field.name.item) {
+                Some(f) => &f.item,
+                None => {
+                    self.push(
+                        ChangeKind::FieldRemoved,
+                        Criticality::Breaking,
+                        path.clone(),
+                        format!("Field `{}` was removed", path),
+                    );
+                    continue;
+                }
+            };
+
+            let (old_type, new_type) = (&old_field.field_type.item, &new_field.field_type.item);
+            if old_type != new_type {
+                let criticality = if is_safe_output_type_change(old_type, new_type) {
+                    Criticality::Safe
+                } else {
+                    Criticality::Breaking
+                };
+                self.push(
+                    ChangeKind::FieldTypeChanged,
+                    criticality,
+                    path.clone(),
+                    format!(
+                        "Field `{}` changed type from `{}` to `{}`",
+                        path, old_type, new_type,
+                    ),
+                );
+            }
+            self.diff_description(
+                ChangeKind::FieldDescriptionChanged,
+                "field",
+                &path,
+                description(&old_field.description),
+                description(&new_field.description),
+            );
+            self.diff_deprecation(
+                ChangeKind::FieldDeprecationChanged,
+                "Field",
+                &path,
+                &old_field.directives,
+                &new_field.directives,
+            );
+            self.diff_input_values(
+                InputPosition::Argument,
+                &path,
+                &old_field.arguments,
+                &new_field.arguments,
+            );
+        }
+        for new_field in new
+            .iter()
+            .filter(|f| !old.iter().any(|o| o.item.name.item == f.item.name.item))
+        {
+            let path = format!("{}.{}", ty, new_field.item.name.item);
+            self.push(
+                ChangeKind::FieldAdded,
+                Criticality::Safe,
+                path.clone(),
+                format!("Field `{}` was added", path),
+            );
+        }
+    }
+
+    fn diff_input_values(
+        &mut self,
+        position: InputPosition,
+        owner: &str,
+        old: &[Spanning<InputValueDefinition<'_, DefaultScalarValue>>],
+        new: &[Spanning<InputValueDefinition<'_, DefaultScalarValue>>],
+    ) {
+        let label = position.label();
+
+        for Spanning { item: old_val, .. } in old {
+            let path = position.path(owner, old_val.name.item);
+            let new_val = match new.iter().find(|v| v.item.name.item == old_val.name.item) {
+                Some(v) => &v.item,
+                None => {
+                    self.push(
+                        position.removed(),
+                        Criticality::Breaking,
+                        path.clone(),
+                        format!("{} `{}` was removed", capitalize(label), path),
+                    );
+                    continue;
+                }
+            };
+
+            let (old_type, new_type) = (&old_val.value_type.item, &new_val.value_type.item);
+            if old_type != new_type {
+                let criticality = if is_safe_input_type_change(old_type, new_type) {
+                    Criticality::Safe
+                } else {
+                    Criticality::Breaking
+                };
+                self.push(
+                    position.type_changed(),
+                    criticality,
+                    path.clone(),
+                    format!(
+                        "{} `{}` changed type from `{}` to `{}`",
+                        capitalize(label),
+                        path,
+                        old_type,
+                        new_type,
+                    ),
+                );
+            }
+            if !same_default(&old_val.default_value, &new_val.default_value) {
+                self.push(
+                    position.default_value_changed(),
+                    Criticality::Dangerous,
+                    path.clone(),
+                    format!(
+                        "Default value of {} `{}` changed from `{}` to `{}`",
+                        label,
+                        path,
+                        display_default(&old_val.default_value),
+                        display_default(&new_val.default_value),
+                    ),
+                );
+            }
+            self.diff_description(
+                position.description_changed(),
+                label,
+                &path,
+                description(&old_val.description),
+                description(&new_val.description),
+            );
+        }
+        for Spanning { item: new_val, .. } in new
+            .iter()
+            .filter(|v| !old.iter().any(|o| o.item.name.item == v.item.name.item))
+        {
+            let path = position.path(owner, new_val.name.item);
+            let (criticality, required) = if is_required(new_val) {
+                (Criticality::Breaking, "Required")
+            } else {
+                (Criticality::Dangerous, "Optional")
+            };
+            self.push(
+                position.added(),
+                criticality,
+                path.clone(),
+                format!("{} {} `{}` was added", required, label, path),
+            );
+        }
+    }
+
+    fn diff_enum_values(
+        &mut self,
+        ty: &str,
+        old: &[Spanning<EnumValueDefinition<'_, DefaultScalarValue>>],
+        new: &[Spanning<EnumValueDefinition<'_, DefaultScalarValue>>],
+    ) {
+        for Spanning { item: old_val, .. } in old {
+            let path = format!("{}.{}", ty, old_val.name.item);
+            let new_val = match new.iter().find(|v| v.item.name.item == old_val.name.item) {
+                Some(v) => &v.item,
+                None => {
+                    self.push(
+                        ChangeKind::EnumValueRemoved,
+                        Criticality::Breaking,
+                        path.clone(),
+                        format!("Enum value `{}` was removed", path),
+                    );
+                    continue;
+                }
+            };
+
+            self.diff_description(
+                ChangeKind::EnumValueDescriptionChanged,
+                "enum value",
+                &path,
+                description(&old_val.description),
+                description(&new_val.description),
+            );
+            self.diff_deprecation(
+                ChangeKind::EnumValueDeprecationChanged,
+                "Enum value",
+                &path,
+                &old_val.directives,
+                &new_val.directives,
+            );
+        }
+        for new_val in new
+            .iter()
+            .filter(|v| !old.iter().any(|o| o.item.name.item == v.item.name.item))
+        {
+            let path = format!("{}.{}", ty, new_val.item.name.item);
+            self.push(
+                ChangeKind::EnumValueAdded,
+                Criticality::Dangerous,
+                path.clone(),
+                format!("Enum value `{}` was added", path),
+            );
+        }
+    }
+
+    fn diff_directives(
+        &mut self,
+        old: &DirectiveDefinition<'_, DefaultScalarValue>,
+        new: &DirectiveDefinition<'_, DefaultScalarValue>,
+    ) {
+        let path = format!("@{}", old.name.item);
+
+        self.diff_description(
+            ChangeKind::DirectiveDescriptionChanged,
+            "directive",
+            &path,
+            description(&old.description),
+            description(&new.description),
+        );
+
+        let (old_locations, new_locations) = (unspanned(&old.locations), unspanned(&new.locations));
+        for loc in old_locations.iter().filter(|l| !new_locations.contains(l)) {
+            self.push(
+                ChangeKind::DirectiveLocationRemoved,
+                Criticality::Breaking,
+                path.clone(),
+                format!("Location `{}` was removed from directive `{}`", loc, path,),
+            );
+        }
+        for loc in new_locations.iter().filter(|l| !old_locations.contains(l)) {
+            self.push(
+                ChangeKind::DirectiveLocationAdded,
+                Criticality::Safe,
+                path.clone(),
+                format!("Location `{}` was added to directive `{}`", loc, path,),
+            );
+        }
+
+        if old.is_repeatable != new.is_repeatable {
+            let (criticality, message) = if old.is_repeatable {
+                (Criticality::Breaking, "is no longer repeatable")
+            } else {
+                (Criticality::Safe, "became repeatable")
+            };
+            self.push(
+                ChangeKind::DirectiveRepeatableChanged,
+                criticality,
+                path.clone(),
+                format!("Directive `{}` {}", path, message),
+            );
+        }
+
+        self.diff_input_values(
+            InputPosition::Argument,
+            &path,
+            &old.arguments,
+            &new.arguments,
+        );
+    }
+
+    fn diff_description(
+        &mut self,
+        kind: ChangeKind,
+        label: &str,
+        path: &str,
+        old: Option<&str>,
+        new: Option<&str>,
+    ) {
+        if old != new {
+            self.push(
+                kind,
+                Criticality::Safe,
+                path.into(),
+                format!("Description of {} `{}` changed", label, path),
+            );
+        }
+    }
+
+    fn diff_deprecation(
+        &mut self,
+        kind: ChangeKind,
+        label: &str,
+        path: &str,
+        old: &Option<Vec<Spanning<Directive<'_, DefaultScalarValue>>>>,
+        new: &Option<Vec<Spanning<Directive<'_, DefaultScalarValue>>>>,
+    ) {
+        let message = match (deprecation(old), deprecation(new)) {
+            (None, Some(_)) => "was deprecated",
+            (Some(_), None) => "is no longer deprecated",
+            (Some(o), Some(n)) if o != n => "changed its deprecation reason",
+            _ => return,
+        };
+        self.push(
+            kind,
+            Criticality::Safe,
+            path.into(),
+            format!("{} `{}` {}", label, path, message),
+        );
+    }
+}
+
+/// Returns the [introspection kind][0] of the given [`TypeDefinition`].
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Type-Kinds
+fn type_kind<S>(def: &TypeDefinition<'_, S>) -> &'static str {
+    match def {
+        TypeDefinition::Scalar(_) => "SCALAR",
+        TypeDefinition::Object(_) => "OBJECT",
+        TypeDefinition::Interface(_) => "INTERFACE",
+        TypeDefinition::Union(_) => "UNION",
+        TypeDefinition::Enum(_) => "ENUM",
+        TypeDefinition::InputObject(_) => "INPUT_OBJECT",
+    }
+}
+
+/// Returns the description of the given [`TypeDefinition`], if any.
+fn type_description<'d, S>(def: &'d TypeDefinition<'_, S>) -> Option<&'d str> {
+    description(match def {
+        TypeDefinition::Scalar(t) => &t.description,
+        TypeDefinition::Object(t) | TypeDefinition::Interface(t) => &t.description,
+        TypeDefinition::Union(t) => &t.description,
+        TypeDefinition::Enum(t) => &t.description,
+        TypeDefinition::InputObject(t) => &t.description,
+    })
+}
+
+fn description(description: &Option<Spanning<String>>) -> Option<&str> {
+    description.as_ref().map(|d| d.item.as_str())
+}
+
+/// Strips the [`Spanning`]s off the given items, so they can be compared
+/// regardless of their positions in the source.
+fn unspanned<T: Clone>(items: &[Spanning<T>]) -> Vec<T> {
+    items.iter().map(|i| i.item.clone()).collect()
+}
+
+/// Returns the reason of the `@deprecated` directive among the given ones, if
+/// any is applied.
+fn deprecation(
+    directives: &Option<Vec<Spanning<Directive<'_, DefaultScalarValue>>>>,
+) -> Option<Option<String>> {
+    directives
+        .iter()
+        .flatten()
+        .find(|d| d.item.name.item == "deprecated")
+        .map(|d| {
+            d.item
+                .arguments
+                .iter()
+                .flat_map(|args| &args.item.items)
+                .find(|(name, _)| name.item == "reason")
+                .map(|(_, reason)| match reason.item.as_string_value() {
+                    Some(s) => s.into(),
+                    None => reason.item.to_string(),
+                })
+        })
+}
+
+/// Indicates whether the given [`InputValueDefinition`] must be provided by
+/// clients.
+fn is_required<S>(val: &InputValueDefinition<'_, S>) -> bool {
+    val.value_type.item.is_non_null() && val.default_value.is_none()
+}
+
+/// Indicates whether changing the type of an output field from `old` to `new`
+/// keeps the existing clients working.
+fn is_safe_output_type_change(old: &Type<'_>, new: &Type<'_>) -> bool {
+    match (old, new) {
+        (Type::Named(o) | Type::NonNullNamed(o), Type::NonNullNamed(n))
+        | (Type::Named(o), Type::Named(n)) => o == n,
+        (Type::List(o, _) | Type::NonNullList(o, _), Type::NonNullList(n, _))
+        | (Type::List(o, _), Type::List(n, _)) => is_safe_output_type_change(o, n),
+        _ => false,
+    }
+}
+
+/// Indicates whether changing the type of an argument or an input field from
+/// `old` to `new` keeps the existing clients working.
+fn is_safe_input_type_change(old: &Type<'_>, new: &Type<'_>) -> bool {
+    match (old, new) {
+        (Type::NonNullNamed(o), Type::Named(n) | Type::NonNullNamed(n))
+        | (Type::Named(o), Type::Named(n)) => o == n,
+        (Type::NonNullList(o, _), Type::List(n, _) | Type::NonNullList(n, _))
+        | (Type::List(o, _), Type::List(n, _)) => is_safe_input_type_change(o, n),
+        _ => false,
+    }
+}
+
+/// Indicates whether the given default values are the same, regardless of
+/// their positions in the source.
+fn same_default(
+    old: &Option<Spanning<InputValue<DefaultScalarValue>>>,
+    new: &Option<Spanning<InputValue<DefaultScalarValue>>>,
+) -> bool {
+    match (old, new) {
+        (Some(o), Some(n)) => o.item.unlocated_eq(&n.item),
+        (o, n) => o.is_none() && n.is_none(),
+    }
+}
+
+/// Displays the given default value of an [`InputValueDefinition`].
+fn display_default(val: &Option<Spanning<InputValue<DefaultScalarValue>>>) -> String {
+    val.as_ref()
+        .map(|v| v.item.to_string())
+        .unwrap_or_else(|| "none".into())
+}
+
+/// Capitalizes the first letter of the given `label`.
+fn capitalize(label: &str) -> String {
+    let mut chars = label.chars();
+    chars
+        .next()
+        .map(|c| c.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{graphql_object, EmptyMutation, EmptySubscription, GraphQLEnum, RootNode};
+
+    use super::{diff, ChangeKind, Criticality};
+
+    const OLD: &str = r#"
+        schema { query: Query }
+
+        type Query {
+            user(id: ID!, first: Int = 10): User
+            users(filter: UserFilter): [User!]!
+            search(text: String): SearchResult
+        }
+
+        type User implements Node {
+            id: ID!
+            name: String
+            email: String!
+            role: Role!
+            nickname: String
+        }
+
+        interface Node {
+            id: ID!
+        }
+
+        input UserFilter {
+            name: String
+            role: Role
+        }
+
+        enum Role {
+            ADMIN
+            USER
+        }
+
+        union SearchResult = User
+
+        scalar Legacy
+    "#;
+
+    const NEW: &str = r#"
+        schema { query: Query }
+
+        type Query {
+            user(id: ID, first: Int = 20, verbose: Boolean): User
+            users(filter: UserFilter, sort: String!): [User!]!
+            search(text: String): SearchResult
+        }
+
+        type User implements Node {
+            id: ID!
+            "Full name."
+            name: String!
+            email: String
+            role: Role!
+            nickname: String @deprecated(reason: "Use `name`.")
+            age: Int
+        }
+
+        interface Node {
+            id: ID!
+        }
+
+        type Post {
+            title: String!
+        }
+
+        input UserFilter {
+            name: String!
+            role: Role
+        }
+
+        enum Role {
+            ADMIN
+            USER
+            GUEST
+        }
+
+        union SearchResult = User | Post
+    "#;
+
+    fn changes(criticality: Criticality) -> Vec<(ChangeKind, String)> {
+        diff(OLD, NEW)
+            .unwrap()
+            .changes
+            .into_iter()
+            .filter(|c| c.criticality == criticality)
+            .map(|c| (c.kind, c.path))
+            .collect()
+    }
+
+    #[test]
+    fn detects_breaking_changes() {
+        assert_eq!(
+            changes(Criticality::Breaking),
+            [
+                (ChangeKind::TypeRemoved, "Legacy".into()),
+                (ChangeKind::ArgumentAdded, "Query.users(sort:)".into()),
+                (ChangeKind::FieldTypeChanged, "User.email".into()),
+                (ChangeKind::InputFieldTypeChanged, "UserFilter.name".into()),
+            ],
+        );
+    }
+
+    #[test]
+    fn detects_dangerous_changes() {
+        assert_eq!(
+            changes(Criticality::Dangerous),
+            [
+                (
+                    ChangeKind::ArgumentDefaultValueChanged,
+                    "Query.user(first:)".into()
+                ),
+                (ChangeKind::ArgumentAdded, "Query.user(verbose:)".into()),
+                (ChangeKind::EnumValueAdded, "Role.GUEST".into()),
+                (ChangeKind::UnionMemberAdded, "SearchResult".into()),
+            ],
+        );
+    }
+
+    #[test]
+    fn detects_safe_changes() {
+        assert_eq!(
+            changes(Criticality::Safe),
+            [
+                (ChangeKind::ArgumentTypeChanged, "Query.user(id:)".into()),
+                (ChangeKind::FieldTypeChanged, "User.name".into()),
+                (ChangeKind::FieldDescriptionChanged, "User.name".into()),
+                (ChangeKind::FieldDeprecationChanged, "User.nickname".into()),
+                (ChangeKind::FieldAdded, "User.age".into()),
+                (ChangeKind::TypeAdded, "Post".into()),
+            ],
+        );
+    }
+
+    #[test]
+    fn detects_removals() {
+        let diff = diff(NEW, OLD).unwrap();
+
+        assert!(diff.is_breaking());
+        assert_eq!(
+            diff.breaking().map(|c| c.to_string()).collect::<Vec<_>>(),
+            [
+                "BREAKING: Type `Post` was removed",
+                "BREAKING: Argument `Query.user(id:)` changed type from `ID` to `ID!`",
+                "BREAKING: Argument `Query.user(verbose:)` was removed",
+                "BREAKING: Argument `Query.users(sort:)` was removed",
+                "BREAKING: Enum value `Role.GUEST` was removed",
+                "BREAKING: Type `Post` was removed from union `SearchResult`",
+                "BREAKING: Field `User.name` changed type from `String!` to `String`",
+                "BREAKING: Field `User.age` was removed",
+            ]
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn detects_type_kind_and_root_changes() {
+        let diff = diff(
+            "type Query { a: Int } type Mutation { b: Int } scalar Foo",
+            "schema { query: Root } type Root { a: Int } type Query { a: Int } enum Foo { X }",
+        )
+        .unwrap();
+
+        assert_eq!(
+            diff.breaking().map(|c| &c.message).collect::<Vec<_>>(),
+            [
+                "Root `query` type changed from `Query` to `Root`",
+                "Root `mutation` type `Mutation` was removed",
+                "Type `Foo` changed kind from `SCALAR` to `ENUM`",
+                "Type `Mutation` was removed",
+            ],
+        );
+    }
+
+    #[test]
+    fn compares_schema_with_sdl() {
+        #[derive(GraphQLEnum)]
+        enum Episode {
+            NewHope,
+            #[graphql(deprecated = "Too dark.")]
+            Empire,
+        }
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            /// Hero of the episode.
+            fn hero(
+                episode: Option<Episode>,
+                #[graphql(default = vec![1, 2])] ids: Vec<i32>,
+            ) -> Option<String> {
+                episode.map(|_| format!("Luke {:?}", ids))
+            }
+        }
+
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let sdl = r#"
+            enum Episode {
+                NEW_HOPE
+                EMPIRE @deprecated(reason: "Too dark.")
+            }
+
+            type Query {
+                "Hero of the episode."
+                hero(episode: Episode, ids: [Int!] = [1, 2]): String
+            }
+        "#;
+
+        assert!(diff(sdl, &schema).unwrap().is_empty());
+
+        let changed = sdl.replace("= [1, 2]", "= [1]");
+        assert_eq!(
+            diff(&schema, &changed).unwrap().to_string(),
+            "DANGEROUS: Default value of argument `Query.hero(ids:)` changed \
+             from `[1, 2]` to `[1]`\n",
+        );
+    }
+
+    #[cfg(feature = "schema-language")]
+    #[test]
+    fn compares_schema_with_its_sdl() {
+        #[derive(GraphQLEnum)]
+        enum Episode {
+            NewHope,
+            Empire,
+        }
+
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            /// Hero of the episode.
+            fn hero(episode: Option<Episode>) -> Option<String> {
+                episode.map(|_| "Luke".into())
+            }
+        }
+
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let sdl = schema.as_schema_language();
+
+        assert!(diff(&sdl, &schema).unwrap().is_empty());
+        assert!(diff(&schema.schema, &schema.schema).unwrap().is_empty());
+
+        let changed = sdl.replace("  EMPIRE\n", "");
+        let diff = diff(&schema, &changed).unwrap();
+        assert!(diff.is_breaking());
+        assert_eq!(
+            diff.to_string(),
+            "BREAKING: Enum value `Episode.EMPIRE` was removed\n",
+        );
+    }
+
+    #[test]
+    fn errors_on_invalid_sdl() {
+        assert!(diff("type Query { a: Int }", "type Query {").is_err());
+    }
+}
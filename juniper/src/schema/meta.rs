@@ -42,6 +42,23 @@ impl DeprecationStatus {
     }
 }
 
+/// [Type system directive][0] applied to a schema element, along with its
+/// arguments.
+///
+/// Unlike executable directives (like `@skip` or `@include`), these are not
+/// evaluated by the executor, but are only recorded in the schema, so can be
+/// consumed by the tools reading its [SDL][1].
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+/// [1]: https://graphql.org/learn/schema/#type-language
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedDirective<S> {
+    /// Name of the applied directive (without the leading `@`).
+    pub name: String,
+    /// Arguments the directive is applied with, in their declaration order.
+    pub arguments: Vec<(String, InputValue<S>)>,
+}
+
 /// Scalar type metadata
 pub struct ScalarMeta<'a, S> {
     #[doc(hidden)]
@@ -50,6 +67,10 @@ pub struct ScalarMeta<'a, S> {
     pub description: Option<String>,
     #[doc(hidden)]
     pub specified_by_url: Option<Cow<'a, str>>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective<S>>,
+    #[doc(hidden)]
+    pub structured_input: bool,
     pub(crate) try_parse_fn: InputValueParseFn<S>,
     pub(crate) parse_fn: ScalarTokenParseFn<S>,
 }
@@ -88,6 +109,8 @@ pub struct ObjectMeta<'a, S> {
     pub fields: Vec<Field<'a, S>>,
     #[doc(hidden)]
     pub interface_names: Vec<String>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective<S>>,
 }
 
 /// Enum type metadata
@@ -97,7 +120,9 @@ pub struct EnumMeta<'a, S> {
     #[doc(hidden)]
     pub description: Option<String>,
     #[doc(hidden)]
-    pub values: Vec<EnumValue>,
+    pub values: Vec<EnumValue<S>>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective<S>>,
     pub(crate) try_parse_fn: InputValueParseFn<S>,
 }
 
@@ -110,17 +135,21 @@ pub struct InterfaceMeta<'a, S> {
     pub description: Option<String>,
     #[doc(hidden)]
     pub fields: Vec<Field<'a, S>>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective<S>>,
 }
 
 /// Union type metadata
 #[derive(Debug)]
-pub struct UnionMeta<'a> {
+pub struct UnionMeta<'a, S> {
     #[doc(hidden)]
     pub name: Cow<'a, str>,
     #[doc(hidden)]
     pub description: Option<String>,
     #[doc(hidden)]
     pub of_type_names: Vec<String>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective<S>>,
 }
 
 /// Input object metadata
@@ -131,6 +160,8 @@ pub struct InputObjectMeta<'a, S> {
     pub description: Option<String>,
     #[doc(hidden)]
     pub input_fields: Vec<Argument<'a, S>>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective<S>>,
     pub(crate) try_parse_fn: InputValueParseFn<S>,
 }
 
@@ -160,7 +191,7 @@ pub enum MetaType<'a, S = DefaultScalarValue> {
     #[doc(hidden)]
     Interface(InterfaceMeta<'a, S>),
     #[doc(hidden)]
-    Union(UnionMeta<'a>),
+    Union(UnionMeta<'a, S>),
     #[doc(hidden)]
     InputObject(InputObjectMeta<'a, S>),
     #[doc(hidden)]
@@ -180,6 +211,8 @@ pub struct Field<'a, S> {
     pub field_type: Type<'a>,
     #[doc(hidden)]
     pub deprecation_status: DeprecationStatus,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective<S>>,
 }
 
 impl<'a, S> Field<'a, S> {
@@ -201,6 +234,8 @@ pub struct Argument<'a, S> {
     pub arg_type: Type<'a>,
     #[doc(hidden)]
     pub default_value: Option<InputValue<S>>,
+    #[doc(hidden)]
+    pub directives: Vec<AppliedDirective<S>>,
 }
 
 impl<'a, S> Argument<'a, S> {
@@ -213,7 +248,7 @@ impl<'a, S> Argument<'a, S> {
 
 /// Metadata for a single value in an enum
 #[derive(Debug, Clone)]
-pub struct EnumValue {
+pub struct EnumValue<S = DefaultScalarValue> {
     /// The name of the enum value
     ///
     /// This is the string literal representation of the enum in responses.
@@ -225,6 +260,10 @@ pub struct EnumValue {
     pub description: Option<String>,
     /// Whether the field is deprecated or not, with an optional reason.
     pub deprecation_status: DeprecationStatus,
+    /// [Type system directives][0] applied to the enum value.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub directives: Vec<AppliedDirective<S>>,
 }
 
 impl<'a, S> MetaType<'a, S> {
@@ -272,6 +311,24 @@ impl<'a, S> MetaType<'a, S> {
         }
     }
 
+    /// Accesses the [type system directives][0] applied to this type, if
+    /// applicable.
+    ///
+    /// Lists, nullable wrappers, and placeholders don't have directives.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub fn directives(&self) -> &[AppliedDirective<S>] {
+        match self {
+            Self::Scalar(ScalarMeta { directives, .. })
+            | Self::Object(ObjectMeta { directives, .. })
+            | Self::Enum(EnumMeta { directives, .. })
+            | Self::Interface(InterfaceMeta { directives, .. })
+            | Self::Union(UnionMeta { directives, .. })
+            | Self::InputObject(InputObjectMeta { directives, .. }) => directives,
+            _ => &[],
+        }
+    }
+
     /// Construct a `TypeKind` for a given type
     ///
     /// # Panics
@@ -430,6 +487,62 @@ impl<'a, S> MetaType<'a, S> {
                 _ => None,
             })
     }
+
+    /// Indicates whether this [`MetaType`] defines the same GraphQL type as
+    /// the `other` one, so both can be registered under the same name.
+    ///
+    /// Only the shape of types is compared (their kinds, fields, arguments,
+    /// values and members), ignoring descriptions, deprecations and directives.
+    pub(crate) fn is_same_definition(&self, other: &Self) -> bool {
+        fn same_args<S>(l: &[Argument<'_, S>], r: &[Argument<'_, S>]) -> bool {
+            l.len() == r.len()
+                && l.iter()
+                    .zip(r)
+                    .all(|(l, r)| l.name == r.name && l.arg_type == r.arg_type)
+        }
+
+        fn same_fields<S>(l: &[Field<'_, S>], r: &[Field<'_, S>]) -> bool {
+            l.len() == r.len()
+                && l.iter().zip(r).all(|(l, r)| {
+                    l.name == r.name
+                        && l.field_type == r.field_type
+                        && same_args(
+                            l.arguments.as_deref().unwrap_or_default(),
+                            r.arguments.as_deref().unwrap_or_default(),
+                        )
+                })
+        }
+
+        match (self, other) {
+            (Self::Scalar(l), Self::Scalar(r)) => l.name == r.name,
+            (Self::List(l), Self::List(r)) => l.of_type == r.of_type,
+            (Self::Nullable(l), Self::Nullable(r)) => l.of_type == r.of_type,
+            (Self::Object(l), Self::Object(r)) => {
+                l.name == r.name
+                    && l.interface_names == r.interface_names
+                    && same_fields(&l.fields, &r.fields)
+            }
+            (Self::Enum(l), Self::Enum(r)) => {
+                l.name == r.name
+                    && l.values.len() == r.values.len()
+                    && l.values
+                        .iter()
+                        .zip(&r.values)
+                        .all(|(l, r)| l.name == r.name)
+            }
+            (Self::Interface(l), Self::Interface(r)) => {
+                l.name == r.name && same_fields(&l.fields, &r.fields)
+            }
+            (Self::Union(l), Self::Union(r)) => {
+                l.name == r.name && l.of_type_names == r.of_type_names
+            }
+            (Self::InputObject(l), Self::InputObject(r)) => {
+                l.name == r.name && same_args(&l.input_fields, &r.input_fields)
+            }
+            (Self::Placeholder(_), _) | (_, Self::Placeholder(_)) => true,
+            _ => false,
+        }
+    }
 }
 
 impl<'a, S> ScalarMeta<'a, S> {
@@ -443,6 +556,8 @@ impl<'a, S> ScalarMeta<'a, S> {
             name,
             description: None,
             specified_by_url: None,
+            directives: vec![],
+            structured_input: false,
             try_parse_fn: try_parse_fn::<S, T>,
             parse_fn: <T as ParseScalarValue<S>>::from_str,
         }
@@ -468,6 +583,28 @@ impl<'a, S> ScalarMeta<'a, S> {
         self
     }
 
+    /// Applies the given [type system directive][0] to this [`ScalarMeta`] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective<S>) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
+    /// Makes this [`ScalarMeta`] type accept lists and objects as its input
+    /// values (like a `JSON` scalar does).
+    ///
+    /// By default, a scalar accepts neither list nor object values, as
+    /// [required by the specification][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Scalars.Input-Coercion
+    #[must_use]
+    pub fn structured_input(mut self) -> Self {
+        self.structured_input = true;
+        self
+    }
+
     /// Wraps this [`ScalarMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Scalar(self)
@@ -515,6 +652,7 @@ impl<'a, S> ObjectMeta<'a, S> {
             description: None,
             fields: fields.to_vec(),
             interface_names: vec![],
+            directives: vec![],
         }
     }
 
@@ -539,6 +677,15 @@ impl<'a, S> ObjectMeta<'a, S> {
         self
     }
 
+    /// Applies the given [type system directive][0] to this [`ObjectMeta`] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective<S>) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
     /// Wraps this [`ObjectMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Object(self)
@@ -548,15 +695,17 @@ impl<'a, S> ObjectMeta<'a, S> {
 impl<'a, S> EnumMeta<'a, S> {
     /// Build a new [`EnumMeta`] type with the specified `name` and possible
     /// `values`.
-    pub fn new<T>(name: Cow<'a, str>, values: &[EnumValue]) -> Self
+    pub fn new<T>(name: Cow<'a, str>, values: &[EnumValue<S>]) -> Self
     where
         T: FromInputValue<S>,
         T::Error: IntoFieldError<S>,
+        S: Clone,
     {
         Self {
             name,
             description: None,
             values: values.to_owned(),
+            directives: vec![],
             try_parse_fn: try_parse_fn::<S, T>,
         }
     }
@@ -570,6 +719,15 @@ impl<'a, S> EnumMeta<'a, S> {
         self
     }
 
+    /// Applies the given [type system directive][0] to this [`EnumMeta`] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective<S>) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
     /// Wraps this [`EnumMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Enum(self)
@@ -587,6 +745,7 @@ impl<'a, S> InterfaceMeta<'a, S> {
             name,
             description: None,
             fields: fields.to_vec(),
+            directives: vec![],
         }
     }
 
@@ -599,13 +758,22 @@ impl<'a, S> InterfaceMeta<'a, S> {
         self
     }
 
+    /// Applies the given [type system directive][0] to this [`InterfaceMeta`] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective<S>) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
     /// Wraps this [`InterfaceMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Interface(self)
     }
 }
 
-impl<'a> UnionMeta<'a> {
+impl<'a, S> UnionMeta<'a, S> {
     /// Build a new [`UnionMeta`] type with the specified `name` and possible
     /// [`Type`]s.
     pub fn new(name: Cow<'a, str>, of_types: &[Type]) -> Self {
@@ -616,6 +784,7 @@ impl<'a> UnionMeta<'a> {
                 .iter()
                 .map(|t| t.innermost_name().to_owned())
                 .collect(),
+            directives: vec![],
         }
     }
 
@@ -628,8 +797,17 @@ impl<'a> UnionMeta<'a> {
         self
     }
 
+    /// Applies the given [type system directive][0] to this [`UnionMeta`] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective<S>) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
     /// Wraps this [`UnionMeta`] type into a generic [`MetaType`].
-    pub fn into_meta<S>(self) -> MetaType<'a, S> {
+    pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::Union(self)
     }
 }
@@ -647,6 +825,7 @@ impl<'a, S> InputObjectMeta<'a, S> {
             name,
             description: None,
             input_fields: input_fields.to_vec(),
+            directives: vec![],
             try_parse_fn: try_parse_fn::<S, T>,
         }
     }
@@ -660,6 +839,15 @@ impl<'a, S> InputObjectMeta<'a, S> {
         self
     }
 
+    /// Applies the given [type system directive][0] to this [`InputObjectMeta`] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective<S>) -> Self {
+        self.directives.push(directive);
+        self
+    }
+
     /// Wraps this [`InputObjectMeta`] type into a generic [`MetaType`].
     pub fn into_meta(self) -> MetaType<'a, S> {
         MetaType::InputObject(self)
@@ -700,6 +888,15 @@ impl<'a, S> Field<'a, S> {
         self.deprecation_status = DeprecationStatus::Deprecated(reason.map(ToOwned::to_owned));
         self
     }
+
+    /// Applies the given [type system directive][0] to this [`Field`].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective<S>) -> Self {
+        self.directives.push(directive);
+        self
+    }
 }
 
 impl<'a, S> Argument<'a, S> {
@@ -710,6 +907,7 @@ impl<'a, S> Argument<'a, S> {
             description: None,
             arg_type,
             default_value: None,
+            directives: vec![],
         }
     }
 
@@ -730,15 +928,25 @@ impl<'a, S> Argument<'a, S> {
         self.default_value = Some(val);
         self
     }
+
+    /// Applies the given [type system directive][0] to this [`Argument`].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective<S>) -> Self {
+        self.directives.push(directive);
+        self
+    }
 }
 
-impl EnumValue {
+impl<S> EnumValue<S> {
     /// Constructs a new [`EnumValue`] with the provided `name`.
     pub fn new(name: &str) -> Self {
         Self {
             name: name.to_owned(),
             description: None,
             deprecation_status: DeprecationStatus::Current,
+            directives: vec![],
         }
     }
 
@@ -759,6 +967,34 @@ impl EnumValue {
         self.deprecation_status = DeprecationStatus::Deprecated(reason.map(ToOwned::to_owned));
         self
     }
+
+    /// Applies the given [type system directive][0] to this [`EnumValue`].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    #[must_use]
+    pub fn directive(mut self, directive: AppliedDirective<S>) -> Self {
+        self.directives.push(directive);
+        self
+    }
+}
+
+impl<S> AppliedDirective<S> {
+    /// Constructs a new [`AppliedDirective`] with the provided `name` and no
+    /// arguments.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            arguments: vec![],
+        }
+    }
+
+    /// Adds an argument with the provided `name` and `value` to this
+    /// [`AppliedDirective`].
+    #[must_use]
+    pub fn argument(mut self, name: &str, value: InputValue<S>) -> Self {
+        self.arguments.push((name.to_owned(), value));
+        self
+    }
 }
 
 impl<'a, S: fmt::Debug> fmt::Debug for ScalarMeta<'a, S> {
@@ -766,6 +1002,7 @@ impl<'a, S: fmt::Debug> fmt::Debug for ScalarMeta<'a, S> {
         fmt.debug_struct("ScalarMeta")
             .field("name", &self.name)
             .field("description", &self.description)
+            .field("directives", &self.directives)
             .finish()
     }
 }
@@ -776,6 +1013,7 @@ impl<'a, S: fmt::Debug> fmt::Debug for EnumMeta<'a, S> {
             .field("name", &self.name)
             .field("description", &self.description)
             .field("values", &self.values)
+            .field("directives", &self.directives)
             .finish()
     }
 }
@@ -786,6 +1024,7 @@ impl<'a, S: fmt::Debug> fmt::Debug for InputObjectMeta<'a, S> {
             .field("name", &self.name)
             .field("description", &self.description)
             .field("input_fields", &self.input_fields)
+            .field("directives", &self.directives)
             .finish()
     }
 }
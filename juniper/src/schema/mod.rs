@@ -1,6 +1,12 @@
+//! GraphQL schema definitions and tooling.
+
 #![allow(clippy::module_inception)]
 
+mod diff;
 pub mod meta;
-pub mod model;
-pub mod schema;
-pub mod translate;
+pub(crate) mod model;
+pub(crate) mod remote;
+pub(crate) mod schema;
+pub(crate) mod translate;
+
+pub use self::diff::{diff, Change, ChangeKind, Criticality, IntoSchemaDocument, SchemaDiff};
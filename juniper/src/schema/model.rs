@@ -5,14 +5,23 @@ use fnv::FnvHashMap;
 use graphql_parser::schema::Document;
 
 use crate::{
-    ast::Type,
-    executor::{Context, Registry},
-    schema::meta::{Argument, InterfaceMeta, MetaType, ObjectMeta, PlaceholderMeta, UnionMeta},
+    ast::{OwnedDocument, Type},
+    executor::{
+        cache_control, get_operation, validators, CacheScope, Context, Registry, Variables,
+    },
+    http::cache::{ResponseCache, SchemaResponseCache},
+    parser::{parse_document_source, ParserLimits, SourcePosition},
+    schema::meta::{
+        AppliedDirective, Argument, InterfaceMeta, MetaType, ObjectMeta, PlaceholderMeta, UnionMeta,
+    },
     types::{base::GraphQLType, name::Name},
+    validation::{validate_input_values, visit_all_rules, RuleError, ValidatorContext},
     value::{DefaultScalarValue, ScalarValue},
     GraphQLEnum,
 };
 
+#[cfg(feature = "trusted-documents")]
+use crate::http::trusted_documents::TrustedDocuments;
 #[cfg(feature = "graphql-parser")]
 use crate::schema::translate::{graphql_parser::GraphQLParserTranslator, SchemaTranslator};
 
@@ -44,6 +53,9 @@ pub struct RootNode<
     pub subscription_info: SubscriptionT::TypeInfo,
     #[doc(hidden)]
     pub schema: SchemaType<'a, S>,
+    #[cfg(feature = "trusted-documents")]
+    trusted_documents: Option<TrustedDocuments<S>>,
+    response_cache: Option<SchemaResponseCache<S>>,
 }
 
 /// Metadata for a schema
@@ -54,7 +66,9 @@ pub struct SchemaType<'a, S> {
     pub(crate) query_type_name: String,
     pub(crate) mutation_type_name: Option<String>,
     pub(crate) subscription_type_name: Option<String>,
-    directives: FnvHashMap<String, DirectiveType<'a, S>>,
+    pub(crate) directives: FnvHashMap<String, DirectiveType<'a, S>>,
+    pub(crate) applied_directives: Vec<AppliedDirective<S>>,
+    pub(crate) parser_limits: ParserLimits,
 }
 
 impl<'a, S> Context for SchemaType<'a, S> {}
@@ -66,17 +80,27 @@ pub enum TypeType<'a, S: 'a> {
     List(Box<TypeType<'a, S>>, Option<usize>),
 }
 
+/// Definition of a [GraphQL directive][0].
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
 #[derive(Debug)]
 pub struct DirectiveType<'a, S> {
+    /// Name of this directive (without the leading `@`).
     pub name: String,
+    /// Optional description of this directive.
     pub description: Option<String>,
+    /// Locations this directive may be placed at.
     pub locations: Vec<DirectiveLocation>,
+    /// Arguments accepted by this directive.
     pub arguments: Vec<Argument<'a, S>>,
+    /// Indicator whether this directive may be applied more than once at the
+    /// same location.
     pub is_repeatable: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, GraphQLEnum)]
 #[graphql(name = "__DirectiveLocation", internal)]
+#[allow(missing_docs)]
 pub enum DirectiveLocation {
     Query,
     Mutation,
@@ -95,6 +119,17 @@ pub enum DirectiveLocation {
     InlineFragment,
     #[graphql(name = "ENUM_VALUE")]
     EnumValue,
+    Schema,
+    Object,
+    #[graphql(name = "ARGUMENT_DEFINITION")]
+    ArgumentDefinition,
+    Interface,
+    Union,
+    Enum,
+    #[graphql(name = "INPUT_OBJECT")]
+    InputObject,
+    #[graphql(name = "INPUT_FIELD_DEFINITION")]
+    InputFieldDefinition,
 }
 
 impl<'a, QueryT, MutationT, SubscriptionT>
@@ -159,9 +194,117 @@ where
             query_info,
             mutation_info,
             subscription_info,
+            #[cfg(feature = "trusted-documents")]
+            trusted_documents: None,
+            response_cache: None,
         }
     }
 
+    /// Registers the given custom [`DirectiveType`] in this schema.
+    ///
+    /// Registered directives are exposed via introspection and printed in
+    /// [`RootNode::as_schema_language()`] along with their usages.
+    #[must_use]
+    pub fn with_directive(mut self, directive: DirectiveType<'a, S>) -> Self {
+        self.schema.add_directive(directive);
+        self
+    }
+
+    /// Applies the given [type system directive][0] to the schema definition
+    /// itself.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    #[must_use]
+    pub fn with_schema_directive(mut self, directive: AppliedDirective<S>) -> Self {
+        self.schema.apply_directive(directive);
+        self
+    }
+
+    /// Sets the [`ParserLimits`] restricting the documents executed or
+    /// validated against this schema.
+    ///
+    /// Documents exceeding them fail with [`ParseError::LimitExceeded`].
+    ///
+    /// [`ParseError::LimitExceeded`]: crate::ParseError::LimitExceeded
+    #[must_use]
+    pub fn with_parser_limits(mut self, limits: ParserLimits) -> Self {
+        self.schema.set_parser_limits(limits);
+        self
+    }
+
+    /// Restricts the documents of the [`GraphQLRequest`]s executed against
+    /// this schema to the provided [`TrustedDocuments`].
+    ///
+    /// Any other requested document is rejected with
+    /// [`GraphQLError::UnknownDocument`] before being parsed, while the trusted
+    /// ones are executed without being parsed and validated again. Documents
+    /// executed directly (like by [`introspect()`]) aren't restricted.
+    ///
+    /// [`GraphQLError::UnknownDocument`]: crate::GraphQLError::UnknownDocument
+    /// [`GraphQLRequest`]: crate::http::GraphQLRequest
+    /// [`introspect()`]: crate::introspect
+    #[cfg(feature = "trusted-documents")]
+    #[must_use]
+    pub fn with_trusted_documents(mut self, documents: TrustedDocuments<S>) -> Self {
+        self.trusted_documents = Some(documents);
+        self
+    }
+
+    /// Returns the [`TrustedDocuments`] executed against this schema, if it's
+    /// restricted to them.
+    #[cfg(feature = "trusted-documents")]
+    pub fn trusted_documents(&self) -> Option<&TrustedDocuments<S>> {
+        self.trusted_documents.as_ref()
+    }
+
+    /// Sets the [`ResponseCache`] reusing the responses to the cacheable
+    /// queries executed against this schema.
+    ///
+    /// See the [`http::cache`] module for details.
+    ///
+    /// [`http::cache`]: crate::http::cache
+    #[must_use]
+    pub fn with_response_cache(mut self, cache: impl ResponseCache<S> + 'static) -> Self {
+        self.response_cache = Some(SchemaResponseCache(Box::new(cache)));
+        self
+    }
+
+    /// Returns the [`ResponseCache`] of this schema, if any.
+    pub fn response_cache(&self) -> Option<&dyn ResponseCache<S>> {
+        self.response_cache.as_ref().map(|c| &*c.0)
+    }
+
+    /// Validates the provided GraphQL document against this schema, without
+    /// executing it.
+    ///
+    /// See [`SchemaType::validate_document()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// All the syntax and validation errors found in the document.
+    pub fn validate_document(&self, source: &str) -> Result<(), Vec<RuleError>> {
+        self.schema.validate_document(source)
+    }
+
+    /// Validates the provided GraphQL document against this schema, along with
+    /// the operation to be executed and its `variables`, without executing it.
+    ///
+    /// See [`SchemaType::validate_operation()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// All the syntax and validation errors found in the document, or the
+    /// errors of selecting the operation and checking its `variables`.
+    pub fn validate_operation(
+        &self,
+        source: &str,
+        operation_name: Option<&str>,
+        variables: &Variables<S>,
+    ) -> Result<(), Vec<RuleError>> {
+        self.schema
+            .validate_operation(source, operation_name, variables)
+    }
+
     #[cfg(feature = "schema-language")]
     /// The schema definition as a `String` in the
     /// [GraphQL Schema Language](https://graphql.org/learn/schema/#type-language)
@@ -223,6 +366,18 @@ impl<'a, S> SchemaType<'a, S> {
             "specifiedBy".to_owned(),
             DirectiveType::new_specified_by(&mut registry),
         );
+        if cache_control::is_used(registry.types.values()) {
+            directives.insert(
+                cache_control::DIRECTIVE_NAME.to_owned(),
+                DirectiveType::new_cache_control(&mut registry),
+            );
+        }
+        if validators::is_used(registry.types.values()) {
+            directives.insert(
+                validators::DIRECTIVE_NAME.to_owned(),
+                DirectiveType::new_constraint(&mut registry),
+            );
+        }
 
         let mut meta_fields = vec![
             registry.field::<SchemaType<S>>("__schema", &()),
@@ -261,6 +416,8 @@ impl<'a, S> SchemaType<'a, S> {
                 None
             },
             directives,
+            applied_directives: vec![],
+            parser_limits: ParserLimits::default(),
         }
     }
 
@@ -274,6 +431,34 @@ impl<'a, S> SchemaType<'a, S> {
         self.directives.insert(directive.name.clone(), directive);
     }
 
+    /// Applies the given [type system directive][0] to the schema definition
+    /// itself.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub fn apply_directive(&mut self, directive: AppliedDirective<S>) {
+        self.applied_directives.push(directive);
+    }
+
+    /// Sets the [`ParserLimits`] restricting the documents executed or
+    /// validated against this schema.
+    pub fn set_parser_limits(&mut self, limits: ParserLimits) {
+        self.parser_limits = limits;
+    }
+
+    /// Get the [`ParserLimits`] restricting the documents executed or
+    /// validated against this schema.
+    pub fn parser_limits(&self) -> &ParserLimits {
+        &self.parser_limits
+    }
+
+    /// Get a list of [type system directives][0] applied to the schema
+    /// definition itself.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub fn applied_directives(&self) -> &[AppliedDirective<S>] {
+        &self.applied_directives
+    }
+
     /// Get a type by name.
     pub fn type_by_name(&self, name: &str) -> Option<TypeType<S>> {
         self.types.get(name).map(|t| TypeType::Concrete(t))
@@ -467,6 +652,77 @@ impl<'a, S> SchemaType<'a, S> {
     }
 }
 
+impl<'a, S: ScalarValue> SchemaType<'a, S> {
+    /// Validates the provided GraphQL document against this schema, without
+    /// executing it.
+    ///
+    /// Runs the parser and all the [validation rules][0], so any document
+    /// passing it is accepted by the `execute*()` functions, unless an invalid
+    /// operation or variables are provided. Use
+    /// [`SchemaType::validate_operation()`] to check these too.
+    ///
+    /// # Errors
+    ///
+    /// All the syntax and validation errors found in the document.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Validation
+    pub fn validate_document(&self, source: &str) -> Result<(), Vec<RuleError>> {
+        self.parse_valid_document(source).map(drop)
+    }
+
+    /// Validates the provided GraphQL document against this schema, along with
+    /// the operation to be executed and its `variables`, without executing it.
+    ///
+    /// Performs exactly the same checks as the `execute*()` functions do
+    /// before executing an operation.
+    ///
+    /// # Errors
+    ///
+    /// All the syntax and validation errors found in the document, or the
+    /// errors of selecting the operation and checking its `variables`.
+    pub fn validate_operation(
+        &self,
+        source: &str,
+        operation_name: Option<&str>,
+        variables: &Variables<S>,
+    ) -> Result<(), Vec<RuleError>> {
+        let document = self.parse_valid_document(source)?;
+        let operation = get_operation(&document, operation_name).map_err(|e| {
+            vec![RuleError::new(
+                &e.to_string(),
+                &[SourcePosition::new_origin()],
+            )]
+        })?;
+
+        let errors = validate_input_values(variables, operation, self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Parses the provided GraphQL document and checks it with all the
+    /// validation rules.
+    pub(crate) fn parse_valid_document<'d>(
+        &self,
+        source: &'d str,
+    ) -> Result<OwnedDocument<'d, S>, Vec<RuleError>> {
+        let document = parse_document_source(source, self)
+            .map_err(|e| vec![RuleError::new(&e.item.to_string(), &[e.start])])?;
+
+        let mut ctx = ValidatorContext::new(self, &document);
+        visit_all_rules(&mut ctx, &document);
+
+        let errors = ctx.into_errors();
+        if errors.is_empty() {
+            Ok(document)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
 impl<'a, S> TypeType<'a, S> {
     #[inline]
     pub fn to_concrete(&self) -> Option<&'a MetaType<S>> {
@@ -503,6 +759,8 @@ impl<'a, S> DirectiveType<'a, S>
 where
     S: ScalarValue + 'a,
 {
+    /// Constructs a new [`DirectiveType`] with the provided `name`,
+    /// `locations` and `arguments`.
     pub fn new(
         name: &str,
         locations: &[DirectiveLocation],
@@ -518,7 +776,7 @@ where
         }
     }
 
-    fn new_skip(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    pub(crate) fn new_skip(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
     where
         S: ScalarValue,
     {
@@ -534,7 +792,7 @@ where
         )
     }
 
-    fn new_include(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    pub(crate) fn new_include(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
     where
         S: ScalarValue,
     {
@@ -550,7 +808,7 @@ where
         )
     }
 
-    fn new_deprecated(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    pub(crate) fn new_deprecated(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
     where
         S: ScalarValue,
     {
@@ -565,7 +823,7 @@ where
         )
     }
 
-    fn new_specified_by(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    pub(crate) fn new_specified_by(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
     where
         S: ScalarValue,
     {
@@ -577,6 +835,65 @@ where
         )
     }
 
+    pub(crate) fn new_cache_control(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    where
+        S: ScalarValue,
+    {
+        Self::new(
+            cache_control::DIRECTIVE_NAME,
+            &[
+                DirectiveLocation::FieldDefinition,
+                DirectiveLocation::Object,
+                DirectiveLocation::Interface,
+                DirectiveLocation::Union,
+            ],
+            &[
+                registry.arg::<Option<i32>>("maxAge", &()),
+                registry.arg::<Option<CacheScope>>("scope", &()),
+            ],
+            false,
+        )
+        .description("Caching hint of a field or a type, limiting the caching of the responses.")
+    }
+
+    pub(crate) fn new_constraint(registry: &mut Registry<'a, S>) -> DirectiveType<'a, S>
+    where
+        S: ScalarValue,
+    {
+        Self::new(
+            validators::DIRECTIVE_NAME,
+            &[
+                DirectiveLocation::ArgumentDefinition,
+                DirectiveLocation::InputFieldDefinition,
+            ],
+            &[
+                registry.arg::<Option<f64>>("min", &()),
+                registry.arg::<Option<f64>>("max", &()),
+                registry.arg::<Option<i32>>("minLength", &()),
+                registry.arg::<Option<i32>>("maxLength", &()),
+                registry.arg::<Option<String>>("pattern", &()),
+                registry.arg::<Option<String>>("format", &()),
+                registry.arg::<Option<i32>>("minItems", &()),
+                registry.arg::<Option<i32>>("maxItems", &()),
+            ],
+            false,
+        )
+        .description("Constraints validating the value of an argument or an input field.")
+    }
+
+    /// Indicates whether this [`DirectiveType`] is one of the built-in
+    /// directives defined by the GraphQL specification.
+    pub fn is_builtin(&self) -> bool {
+        matches!(
+            self.name.as_str(),
+            "skip" | "include" | "deprecated" | "specifiedBy",
+        )
+    }
+
+    /// Sets the `description` of this [`DirectiveType`].
+    ///
+    /// Overwrites any previously set description.
+    #[must_use]
     pub fn description(mut self, description: &str) -> DirectiveType<'a, S> {
         self.description = Some(description.to_owned());
         self
@@ -597,6 +914,14 @@ impl fmt::Display for DirectiveLocation {
             Self::VariableDefinition => "variable definition",
             Self::Scalar => "scalar",
             Self::EnumValue => "enum value",
+            Self::Schema => "schema",
+            Self::Object => "object",
+            Self::ArgumentDefinition => "argument definition",
+            Self::Interface => "interface",
+            Self::Union => "union",
+            Self::Enum => "enum",
+            Self::InputObject => "input object",
+            Self::InputFieldDefinition => "input field definition",
         })
     }
 }
@@ -654,8 +979,11 @@ mod test {
     #[cfg(feature = "schema-language")]
     mod schema_language {
         use crate::{
-            graphql_object, EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject,
-            GraphQLObject, GraphQLUnion, RootNode,
+            ast::Type,
+            graphql_input_value, graphql_object,
+            meta::{AppliedDirective, Argument},
+            DirectiveLocation, DirectiveType, EmptyMutation, EmptySubscription, GraphQLEnum,
+            GraphQLInputObject, GraphQLObject, GraphQLUnion, RootNode,
         };
 
         #[test]
@@ -756,5 +1084,123 @@ mod test {
             .unwrap();
             assert_eq!(format!("{}", ast), schema.as_schema_language());
         }
+
+        #[test]
+        fn schema_language_with_directives() {
+            struct Query;
+            #[graphql_object(directive = @tag(name: "query"))]
+            impl Query {
+                #[graphql(directive = @tag(name: "field"), directive = @internal)]
+                fn blah(#[graphql(directive = @tag(name: "arg"))] value: i32) -> i32 {
+                    value
+                }
+            }
+
+            let schema = RootNode::new(
+                Query,
+                EmptyMutation::<()>::new(),
+                EmptySubscription::<()>::new(),
+            )
+            .with_directive(DirectiveType::new(
+                "internal",
+                &[DirectiveLocation::FieldDefinition],
+                &[],
+                false,
+            ))
+            .with_directive(DirectiveType::new(
+                "tag",
+                &[
+                    DirectiveLocation::Schema,
+                    DirectiveLocation::Object,
+                    DirectiveLocation::FieldDefinition,
+                    DirectiveLocation::ArgumentDefinition,
+                ],
+                &[Argument::new("name", Type::NonNullNamed("String".into()))],
+                true,
+            ))
+            .with_schema_directive(
+                AppliedDirective::new("tag").argument("name", graphql_input_value!("schema")),
+            );
+            let ast = graphql_parser::parse_schema::<&str>(
+                r#"
+                type Query @tag(name: "query") {
+                  blah(value: Int! @tag(name: "arg")): Int! @tag(name: "field") @internal
+                }
+                directive @internal on FIELD_DEFINITION
+                directive @tag(name: String!) repeatable on SCHEMA | OBJECT | FIELD_DEFINITION | ARGUMENT_DEFINITION
+                schema @tag(name: "schema") {
+                  query: Query
+                }
+            "#,
+            )
+            .unwrap();
+            assert_eq!(format!("{}", ast), schema.as_schema_language());
+        }
+    }
+
+    mod validate {
+        use crate::{
+            graphql_vars,
+            tests::fixtures::starwars::schema::{Database, Query},
+            types::scalars::{EmptyMutation, EmptySubscription},
+            RootNode,
+        };
+
+        fn schema() -> RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>>
+        {
+            RootNode::new(
+                Query,
+                EmptyMutation::<Database>::new(),
+                EmptySubscription::<Database>::new(),
+            )
+        }
+
+        #[test]
+        fn document() {
+            let schema = schema();
+
+            assert_eq!(schema.validate_document("{ hero { name } }"), Ok(()));
+
+            let errors = schema
+                .validate_document("{ hero { unknown } droid { name } }")
+                .unwrap_err();
+            assert_eq!(errors.len(), 2);
+            assert_eq!(
+                errors[0].message(),
+                r#"Unknown field "unknown" on type "Character""#,
+            );
+
+            let errors = schema.validate_document("{ hero").unwrap_err();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].message(), "Unexpected end of input");
+        }
+
+        #[test]
+        fn operation() {
+            let schema = schema();
+            let doc = r#"
+                query Hero { hero { name } }
+                query Human($id: String!) { human(id: $id) { name } }
+            "#;
+
+            assert_eq!(schema.validate_document(doc), Ok(()));
+            assert_eq!(
+                schema.validate_operation(doc, Some("Human"), &graphql_vars! {"id": "1000"}),
+                Ok(()),
+            );
+
+            let errors = schema
+                .validate_operation(doc, Some("Human"), &graphql_vars! {})
+                .unwrap_err();
+            assert_eq!(
+                errors[0].message(),
+                r#"Variable "$id" of required type "String!" was not provided."#,
+            );
+
+            let errors = schema
+                .validate_operation(doc, None, &graphql_vars! {})
+                .unwrap_err();
+            assert_eq!(errors[0].message(), "Multiple operations provided");
+        }
     }
 }
@@ -0,0 +1,1071 @@
+//! Resolver-less [`SchemaType`]s describing remote GraphQL schemas.
+//!
+//! Such schemas are built from an [introspection] result or from an [SDL]
+//! document instead of Rust types. They cannot execute anything, but can be
+//! used to validate documents with [`ValidatorContext`] and
+//! [`visit_all_rules()`].
+//!
+//! [`ValidatorContext`]: crate::validation::ValidatorContext
+//! [`visit_all_rules()`]: crate::validation::visit_all_rules
+//! [introspection]: https://spec.graphql.org/October2021#sec-Introspection
+//! [SDL]: https://graphql.org/learn/schema/#type-language
+
+use std::{convert::Infallible, error::Error, fmt};
+
+use fnv::FnvHashMap;
+
+use crate::{
+    ast::{
+        Directive, DirectiveDefinition, EnumValueDefinition, FieldDefinition, FromInputValue,
+        InputValue, InputValueDefinition, OperationType, Type, TypeDefinition,
+        TypeSystemDefinition,
+    },
+    executor::Registry,
+    parser::{
+        parse_schema_document, parse_value_literal, print_value, Lexer, Parser, ParserLimits,
+        ScalarToken, Spanning, Token,
+    },
+    schema::{
+        meta::{
+            Argument, DeprecationStatus, EnumMeta, EnumValue, Field, InputObjectMeta,
+            InterfaceMeta, MetaType, ObjectMeta, ScalarMeta, UnionMeta,
+        },
+        model::{DirectiveLocation, DirectiveType, SchemaType, TypeType},
+    },
+    types::{name::Name, scalars::ID},
+    value::{Object, ParseScalarResult, ParseScalarValue, ScalarValue, Value},
+};
+
+/// Error building a [`SchemaType`] from an introspection result or an SDL
+/// document.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SchemaBuildError {
+    /// SDL document cannot be parsed.
+    Syntax(String),
+
+    /// Introspection result misses a required entry or has a mistyped one.
+    Malformed(String),
+
+    /// Type with the same name is defined more than once.
+    DuplicateType(String),
+
+    /// Referenced type is not defined.
+    UnknownType(String),
+
+    /// Root operation type is missing or is not an object type.
+    InvalidRootType(String),
+
+    /// Default value of an argument or an input field cannot be parsed.
+    InvalidDefaultValue(String),
+}
+
+impl fmt::Display for SchemaBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Syntax(e) => write!(f, "Invalid SDL document: {}", e),
+            Self::Malformed(e) => write!(f, "Malformed introspection result: {}", e),
+            Self::DuplicateType(name) => write!(f, "Type `{}` is defined more than once", name),
+            Self::UnknownType(name) => write!(f, "Unknown type `{}`", name),
+            Self::InvalidRootType(name) => {
+                write!(f, "Root type `{}` is missing or is not an object", name)
+            }
+            Self::InvalidDefaultValue(e) => write!(f, "Invalid default value: {}", e),
+        }
+    }
+}
+
+impl Error for SchemaBuildError {}
+
+impl<S: ScalarValue> SchemaType<'static, S> {
+    /// Creates a [`SchemaType`] containing only the built-in scalars,
+    /// introspection types and directives.
+    ///
+    /// Its `Query` root type is not defined, so it may only be used for
+    /// parsing literals of the built-in types.
+    pub(crate) fn builtin() -> Self {
+        let mut registry = Registry::new(FnvHashMap::default());
+        registry.get_type::<SchemaType<S>>(&());
+        registry.get_type::<i32>(&());
+        registry.get_type::<f64>(&());
+        registry.get_type::<String>(&());
+        registry.get_type::<bool>(&());
+        registry.get_type::<ID>(&());
+
+        let mut directives = FnvHashMap::default();
+        for directive in [
+            DirectiveType::new_skip(&mut registry),
+            DirectiveType::new_include(&mut registry),
+            DirectiveType::new_deprecated(&mut registry),
+            DirectiveType::new_specified_by(&mut registry),
+        ] {
+            directives.insert(directive.name.clone(), directive);
+        }
+
+        SchemaType {
+            description: None,
+            types: registry.types,
+            query_type_name: "Query".into(),
+            mutation_type_name: None,
+            subscription_type_name: None,
+            directives,
+            applied_directives: vec![],
+            parser_limits: ParserLimits::default(),
+        }
+    }
+
+    /// Builds a resolver-less [`SchemaType`] from the provided [introspection]
+    /// result, as returned by [`introspect()`].
+    ///
+    /// Both the bare result (having a `__schema` field) and the whole
+    /// response (having it under `data`) are accepted.
+    ///
+    /// Custom scalars accept any input, while enums and input objects are
+    /// validated by their values and fields only.
+    ///
+    /// # Errors
+    ///
+    /// If the `result` is malformed or describes an invalid schema.
+    ///
+    /// [`introspect()`]: crate::introspect
+    /// [introspection]: https://spec.graphql.org/October2021#sec-Introspection
+    pub fn from_introspection(result: &Value<S>) -> Result<Self, SchemaBuildError> {
+        let result = as_object(result, "result")?;
+        let result = match value_of(result, "data") {
+            Some(data) => as_object(data, "data")?,
+            None => result,
+        };
+        let schema = as_object(required(result, "__schema")?, "__schema")?;
+
+        let mut builder = Builder::default();
+        for ty in list_of(schema, "types")? {
+            builder.add_introspected_type(as_object(ty, "types")?)?;
+        }
+        for directive in list_of(schema, "directives")? {
+            builder.add_introspected_directive(as_object(directive, "directives")?)?;
+        }
+
+        let root_name = |field: &str| -> Result<Option<String>, SchemaBuildError> {
+            value_of(schema, field)
+                .map(|root| required_string(as_object(root, field)?, "name"))
+                .transpose()
+        };
+        let query = root_name("queryType")?
+            .ok_or_else(|| SchemaBuildError::Malformed("missing `queryType`".into()))?;
+
+        builder.finish(
+            optional_string(schema, "description")?,
+            query,
+            root_name("mutationType")?,
+            root_name("subscriptionType")?,
+        )
+    }
+
+    /// Builds a resolver-less [`SchemaType`] from the provided JSON
+    /// [introspection] result.
+    ///
+    /// See [`SchemaType::from_introspection()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// If the `result` is malformed or describes an invalid schema.
+    ///
+    /// [introspection]: https://spec.graphql.org/October2021#sec-Introspection
+    #[cfg(feature = "json")]
+    pub fn from_introspection_json(result: &serde_json::Value) -> Result<Self, SchemaBuildError> {
+        Self::from_introspection(&crate::integrations::json::json_to_value(result))
+    }
+
+    /// Builds a resolver-less [`SchemaType`] from the provided [SDL] document.
+    ///
+    /// If the document has no `schema` definition, the `Query`, `Mutation` and
+    /// `Subscription` types are used as root operation types. Type extensions
+    /// are merged into their types. Type system directives other than
+    /// `@deprecated` and `@specifiedBy` are not preserved.
+    ///
+    /// Custom scalars accept any input, while enums and input objects are
+    /// validated by their values and fields only.
+    ///
+    /// # Errors
+    ///
+    /// If the `sdl` cannot be parsed or describes an invalid schema.
+    ///
+    /// [SDL]: https://graphql.org/learn/schema/#type-language
+    pub fn from_sdl(sdl: &str) -> Result<Self, SchemaBuildError> {
+        let doc =
+            parse_schema_document::<S>(sdl).map_err(|e| SchemaBuildError::Syntax(e.to_string()))?;
+
+        let mut builder = Builder::default();
+        let mut description = None;
+        let mut roots = None;
+        let mut extensions = vec![];
+        for def in doc {
+            match def {
+                TypeSystemDefinition::Schema(def) => {
+                    description = def.item.description.map(|d| d.item);
+                    roots
+                        .get_or_insert_with(Vec::new)
+                        .extend(def.item.operation_types);
+                }
+                TypeSystemDefinition::SchemaExtension(ext) => roots
+                    .get_or_insert_with(Vec::new)
+                    .extend(ext.item.operation_types),
+                TypeSystemDefinition::Type(def) => builder.add_sdl_type(def.item)?,
+                TypeSystemDefinition::TypeExtension(ext) => extensions.push(ext.item),
+                TypeSystemDefinition::Directive(def) => builder.add_sdl_directive(def.item),
+            }
+        }
+        for ext in extensions {
+            builder.extend_sdl_type(ext)?;
+        }
+
+        let (query, mutation, subscription) = match roots {
+            Some(roots) => {
+                let root = |op| {
+                    roots
+                        .iter()
+                        .find(|(o, _)| o.item == op)
+                        .map(|(_, name)| name.item.to_owned())
+                };
+                (
+                    root(OperationType::Query).unwrap_or_else(|| "Query".into()),
+                    root(OperationType::Mutation),
+                    root(OperationType::Subscription),
+                )
+            }
+            None => {
+                let defined = |name: &str| builder.position(name).map(|_| name.to_owned());
+                ("Query".into(), defined("Mutation"), defined("Subscription"))
+            }
+        };
+
+        builder.finish(description, query, mutation, subscription)
+    }
+}
+
+/// Marker of values of remote input types, accepting any input.
+///
+/// Remote types have no Rust counterpart to parse their values into, so only
+/// their structure (described by their [`MetaType`]s) is validated.
+struct Remote;
+
+impl<S> FromInputValue<S> for Remote {
+    type Error = Infallible;
+
+    fn from_input_value(_: &InputValue<S>) -> Result<Self, Self::Error> {
+        Ok(Self)
+    }
+}
+
+impl<S: ScalarValue> ParseScalarValue<S> for Remote {
+    fn from_str(value: ScalarToken<'_>) -> ParseScalarResult<'_, S> {
+        match value {
+            // Integers not fitting into the `ScalarValue` are parsed as floats.
+            ScalarToken::Int(v) => match v.parse::<i128>().ok().and_then(S::from_big_int) {
+                Some(s) => Ok(s),
+                None => <f64 as ParseScalarValue<S>>::from_str(ScalarToken::Float(v)),
+            },
+            ScalarToken::Float(_) => <f64 as ParseScalarValue<S>>::from_str(value),
+            ScalarToken::String(_) => <String as ParseScalarValue<S>>::from_str(value),
+        }
+    }
+}
+
+/// Location of an [`Argument`] having a default value to be parsed.
+enum ArgumentPath {
+    /// Argument of a field of an object or an interface type.
+    Field {
+        type_name: String,
+        field: String,
+        argument: String,
+    },
+
+    /// Field of an input object type.
+    InputField { type_name: String, field: String },
+
+    /// Argument of a directive.
+    Directive { directive: String, argument: String },
+}
+
+impl ArgumentPath {
+    /// Looks up the [`Argument`] at this [`ArgumentPath`] in the provided
+    /// [`SchemaType`].
+    fn lookup<'s, S>(
+        &self,
+        schema: &'s mut SchemaType<'static, S>,
+    ) -> Option<&'s mut Argument<'static, S>> {
+        match self {
+            Self::Field {
+                type_name,
+                field,
+                argument,
+            } => match schema.types.get_mut(type_name.as_str())? {
+                MetaType::Object(ObjectMeta { fields, .. })
+                | MetaType::Interface(InterfaceMeta { fields, .. }) => fields
+                    .iter_mut()
+                    .find(|f| f.name.as_str() == field)?
+                    .arguments
+                    .as_mut()?
+                    .iter_mut()
+                    .find(|a| &a.name == argument),
+                _ => None,
+            },
+            Self::InputField { type_name, field } => {
+                match schema.types.get_mut(type_name.as_str())? {
+                    MetaType::InputObject(InputObjectMeta { input_fields, .. }) => {
+                        input_fields.iter_mut().find(|f| &f.name == field)
+                    }
+                    _ => None,
+                }
+            }
+            Self::Directive {
+                directive,
+                argument,
+            } => schema
+                .directives
+                .get_mut(directive)?
+                .arguments
+                .iter_mut()
+                .find(|a| &a.name == argument),
+        }
+    }
+}
+
+impl fmt::Display for ArgumentPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Field {
+                type_name,
+                field,
+                argument,
+            } => write!(f, "{}.{}({})", type_name, field, argument),
+            Self::InputField { type_name, field } => write!(f, "{}.{}", type_name, field),
+            Self::Directive {
+                directive,
+                argument,
+            } => write!(f, "@{}({})", directive, argument),
+        }
+    }
+}
+
+/// Collector of the remote types and directives of a [`SchemaType`] being
+/// built.
+struct Builder<S> {
+    types: Vec<MetaType<'static, S>>,
+    directives: Vec<DirectiveType<'static, S>>,
+
+    /// Default values, as GraphQL literals, to be parsed once all the types
+    /// are known.
+    defaults: Vec<(ArgumentPath, String)>,
+}
+
+impl<S> Default for Builder<S> {
+    fn default() -> Self {
+        Self {
+            types: vec![],
+            directives: vec![],
+            defaults: vec![],
+        }
+    }
+}
+
+impl<S: ScalarValue> Builder<S> {
+    /// Returns the position of the type with the provided `name` among the
+    /// collected ones.
+    fn position(&self, name: &str) -> Option<usize> {
+        self.types.iter().position(|t| t.name() == Some(name))
+    }
+
+    /// Builds a [`SchemaType`] out of the collected types and directives,
+    /// along with the built-in scalars, introspection types and directives.
+    fn finish(
+        self,
+        description: Option<String>,
+        query_type_name: String,
+        mutation_type_name: Option<String>,
+        subscription_type_name: Option<String>,
+    ) -> Result<SchemaType<'static, S>, SchemaBuildError> {
+        let SchemaType {
+            mut types,
+            mut directives,
+            ..
+        } = SchemaType::builtin();
+
+        let mut registry = Registry::new(FnvHashMap::default());
+        let mut meta_fields = vec![
+            registry.field::<SchemaType<S>>("__schema", &()),
+            registry
+                .field::<TypeType<S>>("__type", &())
+                .argument(registry.arg::<String>("name", &())),
+        ];
+
+        for ty in self.types {
+            let name = ty.name().unwrap_or_default().to_owned();
+            if name.starts_with("__") || matches!(ty, MetaType::Scalar(_)) && ty.is_builtin() {
+                continue;
+            }
+            let key = name
+                .parse::<Name>()
+                .map_err(|e| SchemaBuildError::Malformed(e.to_string()))?;
+            if types.insert(key, ty).is_some() {
+                return Err(SchemaBuildError::DuplicateType(name));
+            }
+        }
+        for directive in self.directives {
+            if !directive.is_builtin() {
+                directives.insert(directive.name.clone(), directive);
+            }
+        }
+
+        for name in std::iter::once(&query_type_name)
+            .chain(&mutation_type_name)
+            .chain(&subscription_type_name)
+        {
+            if !matches!(types.get(name.as_str()), Some(MetaType::Object(_))) {
+                return Err(SchemaBuildError::InvalidRootType(name.clone()));
+            }
+        }
+        check_references(&types, &directives)?;
+
+        if let Some(MetaType::Object(ObjectMeta { fields, .. })) =
+            types.get_mut(query_type_name.as_str())
+        {
+            fields.append(&mut meta_fields);
+        }
+
+        let mut schema = SchemaType {
+            description: description.map(Into::into),
+            types,
+            query_type_name,
+            mutation_type_name,
+            subscription_type_name,
+            directives,
+            applied_directives: vec![],
+            parser_limits: ParserLimits::default(),
+        };
+
+        for (path, literal) in self.defaults {
+            let arg_type = match path.lookup(&mut schema) {
+                Some(arg) => arg.arg_type.clone(),
+                None => continue,
+            };
+            let value = parse_default_value(&schema, &arg_type, &literal).map_err(|e| {
+                SchemaBuildError::InvalidDefaultValue(format!("{} = {}: {}", path, literal, e))
+            })?;
+            if let Some(arg) = path.lookup(&mut schema) {
+                arg.default_value = Some(value);
+            }
+        }
+
+        Ok(schema)
+    }
+
+    fn add_introspected_type(&mut self, ty: &Object<S>) -> Result<(), SchemaBuildError> {
+        let kind = required_string(ty, "kind")?;
+        let name = required_string(ty, "name")?;
+        let description = optional_string(ty, "description")?;
+
+        let meta = match kind.as_str() {
+            "SCALAR" => {
+                let mut meta = ScalarMeta::new::<Remote>(name.into());
+                // Older introspection queries spell it as `specifiedByUrl`.
+                let url = match optional_string(ty, "specifiedByURL")? {
+                    Some(url) => Some(url),
+                    None => optional_string(ty, "specifiedByUrl")?,
+                };
+                if let Some(url) = url {
+                    meta = meta.specified_by_url(url);
+                }
+                meta.into_meta()
+            }
+            "OBJECT" => {
+                let fields = self.introspected_fields(&name, ty)?;
+                let interfaces = list_of(ty, "interfaces")?
+                    .iter()
+                    .map(|i| {
+                        Ok(Type::Named(
+                            required_string(as_object(i, "interfaces")?, "name")?.into(),
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, SchemaBuildError>>()?;
+                ObjectMeta::new(name.into(), &fields)
+                    .interfaces(&interfaces)
+                    .into_meta()
+            }
+            "INTERFACE" => {
+                let fields = self.introspected_fields(&name, ty)?;
+                InterfaceMeta::new(name.into(), &fields).into_meta()
+            }
+            "UNION" => {
+                let members = list_of(ty, "possibleTypes")?
+                    .iter()
+                    .map(|t| {
+                        Ok(Type::Named(
+                            required_string(as_object(t, "possibleTypes")?, "name")?.into(),
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, SchemaBuildError>>()?;
+                UnionMeta::new(name.into(), &members).into_meta()
+            }
+            "ENUM" => {
+                let values = list_of(ty, "enumValues")?
+                    .iter()
+                    .map(|v| {
+                        let v = as_object(v, "enumValues")?;
+                        let mut value = EnumValue::new(&required_string(v, "name")?);
+                        if let Some(d) = optional_string(v, "description")? {
+                            value = value.description(&d);
+                        }
+                        value.deprecation_status = introspected_deprecation(v)?;
+                        Ok(value)
+                    })
+                    .collect::<Result<Vec<_>, SchemaBuildError>>()?;
+                EnumMeta::new::<Remote>(name.into(), &values).into_meta()
+            }
+            "INPUT_OBJECT" => {
+                let fields = list_of(ty, "inputFields")?
+                    .iter()
+                    .map(|f| {
+                        let f = as_object(f, "inputFields")?;
+                        self.introspected_argument(f, |field| ArgumentPath::InputField {
+                            type_name: name.clone(),
+                            field,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                InputObjectMeta::new::<Remote>(name.into(), &fields).into_meta()
+            }
+            kind => {
+                return Err(SchemaBuildError::Malformed(format!(
+                    "unexpected `kind` of type `{}`: {}",
+                    name, kind,
+                )))
+            }
+        };
+
+        self.push_type(meta, description.as_deref())
+    }
+
+    fn introspected_fields(
+        &mut self,
+        type_name: &str,
+        ty: &Object<S>,
+    ) -> Result<Vec<Field<'static, S>>, SchemaBuildError> {
+        list_of(ty, "fields")?
+            .iter()
+            .map(|f| {
+                let f = as_object(f, "fields")?;
+                let name = required_string(f, "name")?;
+                let arguments = list_of(f, "args")?
+                    .iter()
+                    .map(|a| {
+                        self.introspected_argument(as_object(a, "args")?, |argument| {
+                            ArgumentPath::Field {
+                                type_name: type_name.into(),
+                                field: name.clone(),
+                                argument,
+                            }
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Field {
+                    field_type: introspected_type_ref(required(f, "type")?)?,
+                    description: optional_string(f, "description")?,
+                    arguments: if arguments.is_empty() {
+                        None
+                    } else {
+                        Some(arguments)
+                    },
+                    deprecation_status: introspected_deprecation(f)?,
+                    directives: vec![],
+                    name: name.into(),
+                })
+            })
+            .collect()
+    }
+
+    /// Converts the provided introspected input value into an [`Argument`],
+    /// deferring the parsing of its default value to the [`ArgumentPath`]
+    /// returned by the `path` function.
+    fn introspected_argument(
+        &mut self,
+        arg: &Object<S>,
+        path: impl FnOnce(String) -> ArgumentPath,
+    ) -> Result<Argument<'static, S>, SchemaBuildError> {
+        let name = required_string(arg, "name")?;
+        let mut argument = Argument::new(&name, introspected_type_ref(required(arg, "type")?)?);
+        if let Some(d) = optional_string(arg, "description")? {
+            argument = argument.description(&d);
+        }
+        if let Some(default) = optional_string(arg, "defaultValue")? {
+            self.defaults.push((path(name), default));
+        }
+        Ok(argument)
+    }
+
+    fn add_introspected_directive(
+        &mut self,
+        directive: &Object<S>,
+    ) -> Result<(), SchemaBuildError> {
+        let name = required_string(directive, "name")?;
+        let locations = list_of(directive, "locations")?
+            .iter()
+            .map(|l| {
+                l.as_scalar()
+                    .and_then(ScalarValue::as_str)
+                    .and_then(|l| {
+                        DirectiveLocation::from_input_value(&InputValue::<S>::enum_value(l)).ok()
+                    })
+                    .ok_or_else(|| {
+                        SchemaBuildError::Malformed(format!(
+                            "invalid location of directive `@{}`: {}",
+                            name, l,
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let arguments = list_of(directive, "args")?
+            .iter()
+            .map(|a| {
+                self.introspected_argument(as_object(a, "args")?, |argument| {
+                    ArgumentPath::Directive {
+                        directive: name.clone(),
+                        argument,
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let is_repeatable = value_of(directive, "isRepeatable")
+            .and_then(Value::as_scalar)
+            .and_then(ScalarValue::as_bool)
+            .unwrap_or_default();
+
+        let mut directive_type = DirectiveType::new(&name, &locations, &arguments, is_repeatable);
+        directive_type.description = optional_string(directive, "description")?;
+        self.directives.push(directive_type);
+        Ok(())
+    }
+
+    /// Adds the provided remote type, failing if it has been added already.
+    fn push_type(
+        &mut self,
+        mut meta: MetaType<'static, S>,
+        description: Option<&str>,
+    ) -> Result<(), SchemaBuildError> {
+        let name = meta.name().unwrap_or_default();
+        if self.position(name).is_some() {
+            return Err(SchemaBuildError::DuplicateType(name.into()));
+        }
+        if let Some(d) = description {
+            meta = match meta {
+                MetaType::Scalar(m) => m.description(d).into_meta(),
+                MetaType::Object(m) => m.description(d).into_meta(),
+                MetaType::Interface(m) => m.description(d).into_meta(),
+                MetaType::Union(m) => m.description(d).into_meta(),
+                MetaType::Enum(m) => m.description(d).into_meta(),
+                MetaType::InputObject(m) => m.description(d).into_meta(),
+                meta => meta,
+            };
+        }
+        self.types.push(meta);
+        Ok(())
+    }
+}
+
+impl<S: ScalarValue> Builder<S> {
+    fn add_sdl_type(&mut self, def: TypeDefinition<'_, S>) -> Result<(), SchemaBuildError> {
+        let (meta, description) = match def {
+            TypeDefinition::Scalar(s) => {
+                let mut meta = ScalarMeta::new::<Remote>(s.name.item.to_owned().into());
+                if let Some(url) = sdl_directive_argument(&s.directives, "specifiedBy", "url") {
+                    meta = meta.specified_by_url(url);
+                }
+                (meta.into_meta(), s.description)
+            }
+            TypeDefinition::Object(o) => {
+                let fields = self.sdl_fields(o.name.item, o.fields);
+                let interfaces = o
+                    .interfaces
+                    .into_iter()
+                    .map(|i| Type::Named(i.item.to_owned().into()))
+                    .collect::<Vec<_>>();
+                let meta =
+                    ObjectMeta::new(o.name.item.to_owned().into(), &fields).interfaces(&interfaces);
+                (meta.into_meta(), o.description)
+            }
+            TypeDefinition::Interface(i) => {
+                let fields = self.sdl_fields(i.name.item, i.fields);
+                let meta = InterfaceMeta::new(i.name.item.to_owned().into(), &fields);
+                (meta.into_meta(), i.description)
+            }
+            TypeDefinition::Union(u) => {
+                let members = u
+                    .members
+                    .into_iter()
+                    .map(|t| Type::Named(t.item.to_owned().into()))
+                    .collect::<Vec<_>>();
+                let meta = UnionMeta::new(u.name.item.to_owned().into(), &members);
+                (meta.into_meta(), u.description)
+            }
+            TypeDefinition::Enum(e) => {
+                let values = e
+                    .values
+                    .into_iter()
+                    .map(|v| sdl_enum_value(v.item))
+                    .collect::<Vec<_>>();
+                let meta = EnumMeta::new::<Remote>(e.name.item.to_owned().into(), &values);
+                (meta.into_meta(), e.description)
+            }
+            TypeDefinition::InputObject(i) => {
+                let fields = self.sdl_input_fields(i.name.item, i.fields);
+                let meta = InputObjectMeta::new::<Remote>(i.name.item.to_owned().into(), &fields);
+                (meta.into_meta(), i.description)
+            }
+        };
+
+        self.push_type(meta, description.as_ref().map(|d| d.item.as_str()))
+    }
+
+    fn extend_sdl_type(&mut self, ext: TypeDefinition<'_, S>) -> Result<(), SchemaBuildError> {
+        let name = ext.name().item;
+        let idx = self
+            .position(name)
+            .ok_or_else(|| SchemaBuildError::UnknownType(name.into()))?;
+
+        match ext {
+            TypeDefinition::Scalar(_) => {}
+            TypeDefinition::Object(o) => {
+                let mut fields = self.sdl_fields(name, o.fields);
+                if let MetaType::Object(meta) = &mut self.types[idx] {
+                    meta.fields.append(&mut fields);
+                    meta.interface_names
+                        .extend(o.interfaces.into_iter().map(|i| i.item.to_owned()));
+                }
+            }
+            TypeDefinition::Interface(i) => {
+                let mut fields = self.sdl_fields(name, i.fields);
+                if let MetaType::Interface(meta) = &mut self.types[idx] {
+                    meta.fields.append(&mut fields);
+                }
+            }
+            TypeDefinition::Union(u) => {
+                if let MetaType::Union(meta) = &mut self.types[idx] {
+                    meta.of_type_names
+                        .extend(u.members.into_iter().map(|t| t.item.to_owned()));
+                }
+            }
+            TypeDefinition::Enum(e) => {
+                if let MetaType::Enum(meta) = &mut self.types[idx] {
+                    meta.values
+                        .extend(e.values.into_iter().map(|v| sdl_enum_value(v.item)));
+                }
+            }
+            TypeDefinition::InputObject(i) => {
+                let mut fields = self.sdl_input_fields(name, i.fields);
+                if let MetaType::InputObject(meta) = &mut self.types[idx] {
+                    meta.input_fields.append(&mut fields);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn sdl_fields(
+        &mut self,
+        type_name: &str,
+        fields: Vec<Spanning<FieldDefinition<'_, S>>>,
+    ) -> Vec<Field<'static, S>> {
+        fields
+            .into_iter()
+            .map(|f| {
+                let FieldDefinition {
+                    name,
+                    description,
+                    arguments,
+                    field_type,
+                    directives,
+                } = f.item;
+                let arguments = arguments
+                    .into_iter()
+                    .map(|a| {
+                        self.sdl_argument(a.item, |argument| ArgumentPath::Field {
+                            type_name: type_name.into(),
+                            field: name.item.into(),
+                            argument,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                Field {
+                    field_type: sdl_type(&field_type.item),
+                    description: description.map(|d| d.item),
+                    arguments: if arguments.is_empty() {
+                        None
+                    } else {
+                        Some(arguments)
+                    },
+                    deprecation_status: sdl_deprecation(&directives),
+                    directives: vec![],
+                    name: name.item.into(),
+                }
+            })
+            .collect()
+    }
+
+    fn sdl_input_fields(
+        &mut self,
+        type_name: &str,
+        fields: Vec<Spanning<InputValueDefinition<'_, S>>>,
+    ) -> Vec<Argument<'static, S>> {
+        fields
+            .into_iter()
+            .map(|f| {
+                self.sdl_argument(f.item, |field| ArgumentPath::InputField {
+                    type_name: type_name.into(),
+                    field,
+                })
+            })
+            .collect()
+    }
+
+    /// Converts the provided SDL input value into an [`Argument`], deferring
+    /// the parsing of its default value to the [`ArgumentPath`] returned by
+    /// the `path` function.
+    fn sdl_argument(
+        &mut self,
+        arg: InputValueDefinition<'_, S>,
+        path: impl FnOnce(String) -> ArgumentPath,
+    ) -> Argument<'static, S> {
+        let mut argument = Argument::new(arg.name.item, sdl_type(&arg.value_type.item));
+        argument.description = arg.description.map(|d| d.item);
+        if let Some(default) = arg.default_value {
+            self.defaults
+                .push((path(arg.name.item.into()), print_value(&default.item)));
+        }
+        argument
+    }
+
+    fn add_sdl_directive(&mut self, def: DirectiveDefinition<'_, S>) {
+        let locations = def
+            .locations
+            .into_iter()
+            .map(|l| l.item)
+            .collect::<Vec<_>>();
+        let name = def.name.item;
+        let arguments = def
+            .arguments
+            .into_iter()
+            .map(|a| {
+                self.sdl_argument(a.item, |argument| ArgumentPath::Directive {
+                    directive: name.into(),
+                    argument,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut directive = DirectiveType::new(name, &locations, &arguments, def.is_repeatable);
+        directive.description = def.description.map(|d| d.item);
+        self.directives.push(directive);
+    }
+}
+
+/// Checks that all the types referenced by the provided `types` and
+/// `directives` are defined.
+fn check_references<S>(
+    types: &FnvHashMap<Name, MetaType<'static, S>>,
+    directives: &FnvHashMap<String, DirectiveType<'static, S>>,
+) -> Result<(), SchemaBuildError> {
+    let check = |name: &str| {
+        if types.contains_key(name) {
+            Ok(())
+        } else {
+            Err(SchemaBuildError::UnknownType(name.into()))
+        }
+    };
+    let check_args = |args: &[Argument<'static, S>]| {
+        args.iter()
+            .try_for_each(|a| check(a.arg_type.innermost_name()))
+    };
+    let check_fields = |fields: &[Field<'static, S>]| {
+        fields.iter().try_for_each(|f| {
+            check(f.field_type.innermost_name())?;
+            check_args(f.arguments.as_deref().unwrap_or_default())
+        })
+    };
+
+    for ty in types.values() {
+        match ty {
+            MetaType::Object(ObjectMeta {
+                fields,
+                interface_names,
+                ..
+            }) => {
+                check_fields(fields)?;
+                interface_names.iter().try_for_each(|i| check(i))?;
+            }
+            MetaType::Interface(InterfaceMeta { fields, .. }) => check_fields(fields)?,
+            MetaType::Union(UnionMeta { of_type_names, .. }) => {
+                of_type_names.iter().try_for_each(|t| check(t))?
+            }
+            MetaType::InputObject(InputObjectMeta { input_fields, .. }) => {
+                check_args(input_fields)?
+            }
+            _ => {}
+        }
+    }
+    directives
+        .values()
+        .try_for_each(|d| check_args(&d.arguments))
+}
+
+/// Parses the provided default value `literal` of the `arg_type`.
+fn parse_default_value<S: ScalarValue>(
+    schema: &SchemaType<'static, S>,
+    arg_type: &Type<'static>,
+    literal: &str,
+) -> Result<InputValue<S>, String> {
+    let mut lexer = Lexer::new(literal);
+    let mut parser = Parser::new(&mut lexer).map_err(|e| e.item.to_string())?;
+    let value = parse_value_literal(&mut parser, true, schema, schema.lookup_type(arg_type))
+        .map_err(|e| e.item.to_string())?;
+    match &parser.peek().item {
+        Token::EndOfFile => Ok(value.item),
+        t => Err(format!("Unexpected \"{}\"", t)),
+    }
+}
+
+/// Converts the provided introspected type reference into a [`Type`].
+fn introspected_type_ref<S: ScalarValue>(
+    type_ref: &Value<S>,
+) -> Result<Type<'static>, SchemaBuildError> {
+    let type_ref = as_object(type_ref, "type")?;
+    let of_type = || introspected_type_ref(required(type_ref, "ofType")?);
+    match required_string(type_ref, "kind")?.as_str() {
+        "NON_NULL" => Ok(non_null(of_type()?)),
+        "LIST" => Ok(Type::List(Box::new(of_type()?), None)),
+        _ => Ok(Type::Named(required_string(type_ref, "name")?.into())),
+    }
+}
+
+fn introspected_deprecation<S: ScalarValue>(
+    obj: &Object<S>,
+) -> Result<DeprecationStatus, SchemaBuildError> {
+    let is_deprecated = value_of(obj, "isDeprecated")
+        .and_then(Value::as_scalar)
+        .and_then(ScalarValue::as_bool)
+        .unwrap_or_default();
+    Ok(if is_deprecated {
+        DeprecationStatus::Deprecated(optional_string(obj, "deprecationReason")?)
+    } else {
+        DeprecationStatus::Current
+    })
+}
+
+/// Makes the provided [`Type`] non-null.
+fn non_null(ty: Type<'static>) -> Type<'static> {
+    match ty {
+        Type::Named(n) => Type::NonNullNamed(n),
+        Type::List(t, size) => Type::NonNullList(t, size),
+        ty => ty,
+    }
+}
+
+/// Returns the value of the provided `field` of the `obj`, unless it's absent
+/// or `null`.
+fn value_of<'v, S>(obj: &'v Object<S>, field: &str) -> Option<&'v Value<S>> {
+    obj.get_field_value(field).filter(|v| !v.is_null())
+}
+
+fn required<'v, S>(obj: &'v Object<S>, field: &str) -> Result<&'v Value<S>, SchemaBuildError> {
+    value_of(obj, field).ok_or_else(|| SchemaBuildError::Malformed(format!("missing `{}`", field)))
+}
+
+fn as_object<'v, S>(value: &'v Value<S>, field: &str) -> Result<&'v Object<S>, SchemaBuildError> {
+    value
+        .as_object_value()
+        .ok_or_else(|| SchemaBuildError::Malformed(format!("`{}` is not an object", field)))
+}
+
+/// Returns the items of the provided list `field` of the `obj`, treating an
+/// absent or `null` one as empty.
+fn list_of<'v, S>(obj: &'v Object<S>, field: &str) -> Result<&'v [Value<S>], SchemaBuildError> {
+    match value_of(obj, field) {
+        Some(v) => v
+            .as_list_value()
+            .map(Vec::as_slice)
+            .ok_or_else(|| SchemaBuildError::Malformed(format!("`{}` is not a list", field))),
+        None => Ok(&[]),
+    }
+}
+
+fn optional_string<S: ScalarValue>(
+    obj: &Object<S>,
+    field: &str,
+) -> Result<Option<String>, SchemaBuildError> {
+    value_of(obj, field)
+        .map(|v| {
+            v.as_scalar()
+                .and_then(ScalarValue::as_str)
+                .map(str::to_owned)
+                .ok_or_else(|| SchemaBuildError::Malformed(format!("`{}` is not a string", field)))
+        })
+        .transpose()
+}
+
+fn required_string<S: ScalarValue>(
+    obj: &Object<S>,
+    field: &str,
+) -> Result<String, SchemaBuildError> {
+    optional_string(obj, field)?
+        .ok_or_else(|| SchemaBuildError::Malformed(format!("missing `{}`", field)))
+}
+
+/// Converts the provided SDL type reference into an owned [`Type`].
+fn sdl_type(ty: &Type<'_>) -> Type<'static> {
+    match ty {
+        Type::Named(n) => Type::Named(n.to_string().into()),
+        Type::NonNullNamed(n) => Type::NonNullNamed(n.to_string().into()),
+        Type::List(t, size) => Type::List(Box::new(sdl_type(t)), *size),
+        Type::NonNullList(t, size) => Type::NonNullList(Box::new(sdl_type(t)), *size),
+    }
+}
+
+fn sdl_enum_value<S: ScalarValue>(value: EnumValueDefinition<'_, S>) -> EnumValue<S> {
+    let mut v = EnumValue::new(value.name.item);
+    v.description = value.description.map(|d| d.item);
+    v.deprecation_status = sdl_deprecation(&value.directives);
+    v
+}
+
+/// Returns the [`DeprecationStatus`] specified by the `@deprecated` directive
+/// among the provided ones.
+fn sdl_deprecation<S: ScalarValue>(
+    directives: &Option<Vec<Spanning<Directive<'_, S>>>>,
+) -> DeprecationStatus {
+    if directives
+        .iter()
+        .flatten()
+        .any(|d| d.item.name.item == "deprecated")
+    {
+        DeprecationStatus::Deprecated(sdl_directive_argument(directives, "deprecated", "reason"))
+    } else {
+        DeprecationStatus::Current
+    }
+}
+
+/// Returns the string value of the `argument` of the `directive` among the
+/// provided ones, if any.
+fn sdl_directive_argument<S: ScalarValue>(
+    directives: &Option<Vec<Spanning<Directive<'_, S>>>>,
+    directive: &str,
+    argument: &str,
+) -> Option<String> {
+    directives
+        .iter()
+        .flatten()
+        .filter(|d| d.item.name.item == directive)
+        .filter_map(|d| d.item.arguments.as_ref())
+        .flat_map(|args| &args.item.items)
+        .find_map(|(name, value)| match &value.item {
+            InputValue::Scalar(s) if name.item == argument => s.as_str().map(str::to_owned),
+            _ => None,
+        })
+}
@@ -296,7 +296,10 @@ impl<'a, S: ScalarValue + 'a> TypeType<'a, S> {
         }
     }
 
-    fn enum_values(&self, #[graphql(default)] include_deprecated: bool) -> Option<Vec<&EnumValue>> {
+    fn enum_values(
+        &self,
+        #[graphql(default)] include_deprecated: bool,
+    ) -> Option<Vec<&EnumValue<S>>> {
         match self {
             TypeType::Concrete(&MetaType::Enum(EnumMeta { ref values, .. })) => Some(
                 values
@@ -372,8 +375,8 @@ impl<'a, S: ScalarValue + 'a> Argument<'a, S> {
     }
 }
 
-#[graphql_object(name = "__EnumValue", internal)]
-impl EnumValue {
+#[graphql_object(name = "__EnumValue", scalar = S, internal)]
+impl<S: ScalarValue> EnumValue<S> {
     fn name(&self) -> &str {
         &self.name
     }
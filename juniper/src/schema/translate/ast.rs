@@ -0,0 +1,336 @@
+use crate::{
+    ast::{
+        Arguments, Directive, DirectiveDefinition, EnumTypeDefinition, EnumValueDefinition,
+        FieldDefinition, InputObjectTypeDefinition, InputValue, InputValueDefinition,
+        ObjectTypeDefinition, OperationType, ScalarTypeDefinition, SchemaDefinition,
+        SchemaDocument, TypeDefinition, TypeSystemDefinition, UnionTypeDefinition,
+    },
+    parser::Spanning,
+    schema::{
+        meta::{AppliedDirective, Argument, DeprecationStatus, EnumValue, Field, MetaType},
+        model::{DirectiveType, SchemaType},
+        translate::SchemaTranslator,
+    },
+    value::{DefaultScalarValue, ScalarValue},
+};
+
+/// Translator of a [`SchemaType`] into juniper's own [`SchemaDocument`].
+///
+/// Scalar values (of default values and applied directives' arguments) are
+/// converted into the [`DefaultScalarValue`].
+pub struct AstTranslator;
+
+impl<'a> SchemaTranslator<'a, SchemaDocument<'a>> for AstTranslator {
+    fn translate_schema<S: 'a + ScalarValue>(input: &'a SchemaType<S>) -> SchemaDocument<'a> {
+        // Translate type defs.
+        let mut doc = input
+            .types
+            .values()
+            .filter(|meta| !meta.is_builtin())
+            .filter_map(AstTranslator::translate_meta)
+            .map(|def| TypeSystemDefinition::Type(Spanning::unlocated(def)))
+            .collect::<SchemaDocument<'a>>();
+
+        // Translate custom directive defs.
+        let mut directives = input
+            .directive_list()
+            .into_iter()
+            .filter(|d| !d.is_builtin())
+            .collect::<Vec<_>>();
+        directives.sort_by(|a, b| a.name.cmp(&b.name));
+        doc.extend(
+            directives
+                .into_iter()
+                .map(AstTranslator::translate_directive_type)
+                .map(|def| TypeSystemDefinition::Directive(Spanning::unlocated(def))),
+        );
+
+        let operation_types = vec![
+            (OperationType::Query, Some(&input.query_type_name)),
+            (OperationType::Mutation, input.mutation_type_name.as_ref()),
+            (
+                OperationType::Subscription,
+                input.subscription_type_name.as_ref(),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(op, name)| {
+            name.map(|n| (Spanning::unlocated(op), Spanning::unlocated(n.as_str())))
+        })
+        .collect();
+        doc.push(TypeSystemDefinition::Schema(Spanning::unlocated(
+            SchemaDefinition {
+                description: input
+                    .description
+                    .as_ref()
+                    .map(|d| Spanning::unlocated(d.to_string())),
+                directives: translate_directives(None, input.applied_directives()),
+                operation_types,
+            },
+        )));
+
+        doc
+    }
+}
+
+impl AstTranslator {
+    fn translate_meta<'a, S>(
+        input: &'a MetaType<S>,
+    ) -> Option<TypeDefinition<'a, DefaultScalarValue>>
+    where
+        S: ScalarValue,
+    {
+        Some(match input {
+            MetaType::Scalar(x) => TypeDefinition::Scalar(ScalarTypeDefinition {
+                description: unlocated_description(&x.description),
+                name: Spanning::unlocated(x.name.as_ref()),
+                directives: translate_directives(
+                    x.specified_by_url
+                        .as_ref()
+                        .map(|url| directive("specifiedBy", "url", url.to_string())),
+                    &x.directives,
+                ),
+            }),
+            MetaType::Enum(x) => TypeDefinition::Enum(EnumTypeDefinition {
+                description: unlocated_description(&x.description),
+                name: Spanning::unlocated(x.name.as_ref()),
+                directives: translate_directives(None, &x.directives),
+                values: x
+                    .values
+                    .iter()
+                    .map(|v| Spanning::unlocated(AstTranslator::translate_enum_value(v)))
+                    .collect(),
+            }),
+            MetaType::Union(x) => TypeDefinition::Union(UnionTypeDefinition {
+                description: unlocated_description(&x.description),
+                name: Spanning::unlocated(x.name.as_ref()),
+                directives: translate_directives(None, &x.directives),
+                members: x
+                    .of_type_names
+                    .iter()
+                    .map(|n| Spanning::unlocated(n.as_str()))
+                    .collect(),
+            }),
+            MetaType::Interface(x) => TypeDefinition::Interface(ObjectTypeDefinition {
+                description: unlocated_description(&x.description),
+                name: Spanning::unlocated(x.name.as_ref()),
+                // TODO: Support this with GraphQL October 2021 Edition.
+                interfaces: vec![],
+                directives: translate_directives(None, &x.directives),
+                fields: AstTranslator::translate_fields(&x.fields),
+            }),
+            MetaType::InputObject(x) => TypeDefinition::InputObject(InputObjectTypeDefinition {
+                description: unlocated_description(&x.description),
+                name: Spanning::unlocated(x.name.as_ref()),
+                directives: translate_directives(None, &x.directives),
+                fields: x
+                    .input_fields
+                    .iter()
+                    .filter(|f| !f.is_builtin())
+                    .map(|f| Spanning::unlocated(AstTranslator::translate_argument(f)))
+                    .collect(),
+            }),
+            MetaType::Object(x) => TypeDefinition::Object(ObjectTypeDefinition {
+                description: unlocated_description(&x.description),
+                name: Spanning::unlocated(x.name.as_ref()),
+                interfaces: x
+                    .interface_names
+                    .iter()
+                    .map(|n| Spanning::unlocated(n.as_str()))
+                    .collect(),
+                directives: translate_directives(None, &x.directives),
+                fields: AstTranslator::translate_fields(&x.fields),
+            }),
+            MetaType::List(..) | MetaType::Nullable(..) | MetaType::Placeholder(..) => return None,
+        })
+    }
+
+    fn translate_fields<'a, S>(
+        input: &'a [Field<S>],
+    ) -> Vec<Spanning<FieldDefinition<'a, DefaultScalarValue>>>
+    where
+        S: ScalarValue,
+    {
+        input
+            .iter()
+            .filter(|f| !f.is_builtin())
+            .map(|f| {
+                Spanning::unlocated(FieldDefinition {
+                    description: unlocated_description(&f.description),
+                    name: Spanning::unlocated(f.name.as_str()),
+                    arguments: f
+                        .arguments
+                        .iter()
+                        .flatten()
+                        .filter(|a| !a.is_builtin())
+                        .map(|a| Spanning::unlocated(AstTranslator::translate_argument(a)))
+                        .collect(),
+                    field_type: Spanning::unlocated(f.field_type.clone()),
+                    directives: translate_directives(
+                        deprecation_to_directive(&f.deprecation_status),
+                        &f.directives,
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    fn translate_argument<'a, S>(
+        input: &'a Argument<S>,
+    ) -> InputValueDefinition<'a, DefaultScalarValue>
+    where
+        S: ScalarValue,
+    {
+        InputValueDefinition {
+            description: unlocated_description(&input.description),
+            name: Spanning::unlocated(input.name.as_str()),
+            value_type: Spanning::unlocated(input.arg_type.clone()),
+            default_value: input
+                .default_value
+                .as_ref()
+                .map(|v| Spanning::unlocated(translate_value(v))),
+            directives: translate_directives(None, &input.directives),
+        }
+    }
+
+    fn translate_enum_value<'a, S>(
+        input: &'a EnumValue<S>,
+    ) -> EnumValueDefinition<'a, DefaultScalarValue>
+    where
+        S: ScalarValue,
+    {
+        EnumValueDefinition {
+            description: unlocated_description(&input.description),
+            name: Spanning::unlocated(input.name.as_str()),
+            directives: translate_directives(
+                deprecation_to_directive(&input.deprecation_status),
+                &input.directives,
+            ),
+        }
+    }
+
+    fn translate_directive_type<'a, S>(
+        input: &'a DirectiveType<S>,
+    ) -> DirectiveDefinition<'a, DefaultScalarValue>
+    where
+        S: ScalarValue,
+    {
+        DirectiveDefinition {
+            description: unlocated_description(&input.description),
+            name: Spanning::unlocated(input.name.as_str()),
+            arguments: input
+                .arguments
+                .iter()
+                .map(|a| Spanning::unlocated(AstTranslator::translate_argument(a)))
+                .collect(),
+            is_repeatable: input.is_repeatable,
+            locations: input
+                .locations
+                .iter()
+                .cloned()
+                .map(Spanning::unlocated)
+                .collect(),
+        }
+    }
+}
+
+fn unlocated_description(description: &Option<String>) -> Option<Spanning<String>> {
+    description.clone().map(Spanning::unlocated)
+}
+
+/// Converts the provided [`InputValue`] into the one of [`DefaultScalarValue`].
+fn translate_value<S: ScalarValue>(input: &InputValue<S>) -> InputValue<DefaultScalarValue> {
+    match input {
+        InputValue::Null => InputValue::Null,
+        InputValue::Scalar(s) => InputValue::Scalar(s.clone().into_another()),
+        InputValue::Enum(e) => InputValue::Enum(e.clone()),
+        InputValue::Variable(v) => InputValue::Variable(v.clone()),
+        InputValue::List(l) => InputValue::List(
+            l.iter()
+                .map(|v| Spanning::unlocated(translate_value(&v.item)))
+                .collect(),
+        ),
+        InputValue::Object(o) => InputValue::Object(
+            o.iter()
+                .map(|(k, v)| {
+                    (
+                        Spanning::unlocated(k.item.clone()),
+                        Spanning::unlocated(translate_value(&v.item)),
+                    )
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn deprecation_to_directive(
+    status: &DeprecationStatus,
+) -> Option<Directive<'static, DefaultScalarValue>> {
+    match status {
+        DeprecationStatus::Current => None,
+        DeprecationStatus::Deprecated(None) => Some(Directive {
+            name: Spanning::unlocated("deprecated"),
+            arguments: None,
+        }),
+        DeprecationStatus::Deprecated(Some(reason)) => {
+            Some(directive("deprecated", "reason", reason.clone()))
+        }
+    }
+}
+
+/// Creates a [`Directive`] with the provided `name` and a single string
+/// `argument`.
+fn directive(
+    name: &'static str,
+    argument: &'static str,
+    value: String,
+) -> Directive<'static, DefaultScalarValue> {
+    Directive {
+        name: Spanning::unlocated(name),
+        arguments: Some(Spanning::unlocated(Arguments {
+            items: vec![(
+                Spanning::unlocated(argument),
+                Spanning::unlocated(InputValue::scalar(value)),
+            )],
+        })),
+    }
+}
+
+// Besides the applied type system directives, only `@deprecated` and
+// `@specifiedBy` are generated here, as the `implied` one.
+// <https://facebook.github.io/graphql/draft/#sec-Type-System.Directives>
+fn translate_directives<'a, S>(
+    implied: Option<Directive<'a, DefaultScalarValue>>,
+    applied: &'a [AppliedDirective<S>],
+) -> Option<Vec<Spanning<Directive<'a, DefaultScalarValue>>>>
+where
+    S: ScalarValue,
+{
+    let directives = implied
+        .into_iter()
+        .chain(applied.iter().map(|d| {
+            Directive {
+                name: Spanning::unlocated(d.name.as_str()),
+                arguments: Some(Spanning::unlocated(Arguments {
+                    items: d
+                        .arguments
+                        .iter()
+                        .map(|(name, value)| {
+                            (
+                                Spanning::unlocated(name.as_str()),
+                                Spanning::unlocated(translate_value(value)),
+                            )
+                        })
+                        .collect(),
+                }))
+                .filter(|args| !args.item.items.is_empty()),
+            }
+        }))
+        .map(Spanning::unlocated)
+        .collect::<Vec<_>>();
+    if directives.is_empty() {
+        None
+    } else {
+        Some(directives)
+    }
+}
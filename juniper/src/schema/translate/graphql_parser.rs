@@ -3,10 +3,12 @@ use std::{boxed::Box, collections::BTreeMap};
 use graphql_parser::{
     query::{Directive as ExternalDirective, Number as ExternalNumber, Type as ExternalType},
     schema::{
-        Definition, Document, EnumType as ExternalEnum, EnumValue as ExternalEnumValue,
-        Field as ExternalField, InputObjectType as ExternalInputObjectType,
-        InputValue as ExternalInputValue, InterfaceType as ExternalInterfaceType,
-        ObjectType as ExternalObjectType, ScalarType as ExternalScalarType, SchemaDefinition, Text,
+        Definition, DirectiveDefinition as ExternalDirectiveDefinition,
+        DirectiveLocation as ExternalDirectiveLocation, Document, EnumType as ExternalEnum,
+        EnumValue as ExternalEnumValue, Field as ExternalField,
+        InputObjectType as ExternalInputObjectType, InputValue as ExternalInputValue,
+        InterfaceType as ExternalInterfaceType, ObjectType as ExternalObjectType,
+        ScalarType as ExternalScalarType, SchemaDefinition, Text,
         TypeDefinition as ExternalTypeDefinition, UnionType as ExternalUnionType,
         Value as ExternalValue,
     },
@@ -16,8 +18,8 @@ use graphql_parser::{
 use crate::{
     ast::{InputValue, Type},
     schema::{
-        meta::{Argument, DeprecationStatus, EnumValue, Field, MetaType},
-        model::SchemaType,
+        meta::{AppliedDirective, Argument, DeprecationStatus, EnumValue, Field, MetaType},
+        model::{DirectiveLocation, DirectiveType, SchemaType},
         translate::SchemaTranslator,
     },
     value::ScalarValue,
@@ -56,10 +58,28 @@ where
             .collect();
         doc.definitions.append(&mut types);
 
+        // Translate custom directive defs.
+        let mut directives = input
+            .directive_list()
+            .into_iter()
+            .filter(|d| !d.is_builtin())
+            .collect::<Vec<_>>();
+        directives.sort_by(|a, b| a.name.cmp(&b.name));
+        doc.definitions.extend(
+            directives
+                .into_iter()
+                .map(GraphQLParserTranslator::translate_directive_type)
+                .map(Definition::DirectiveDefinition),
+        );
+
         doc.definitions
             .push(Definition::SchemaDefinition(SchemaDefinition {
                 position: Pos::default(),
-                directives: vec![],
+                directives: input
+                    .applied_directives()
+                    .iter()
+                    .map(GraphQLParserTranslator::translate_applied_directive)
+                    .collect(),
                 query: Some(From::from(input.query_type_name.as_str())),
                 mutation: input
                     .mutation_type_name
@@ -90,7 +110,85 @@ impl GraphQLParserTranslator {
                 .default_value
                 .as_ref()
                 .map(|x| GraphQLParserTranslator::translate_value(x)),
-            directives: vec![],
+            directives: input
+                .directives
+                .iter()
+                .map(GraphQLParserTranslator::translate_applied_directive)
+                .collect(),
+        }
+    }
+
+    fn translate_applied_directive<'a, S, T>(
+        input: &'a AppliedDirective<S>,
+    ) -> ExternalDirective<'a, T>
+    where
+        S: ScalarValue,
+        T: Text<'a>,
+    {
+        ExternalDirective {
+            position: Pos::default(),
+            name: From::from(input.name.as_str()),
+            arguments: input
+                .arguments
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        From::from(name.as_str()),
+                        GraphQLParserTranslator::translate_value(value),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn translate_directive_type<'a, S, T>(
+        input: &'a DirectiveType<S>,
+    ) -> ExternalDirectiveDefinition<'a, T>
+    where
+        S: ScalarValue,
+        T: Text<'a>,
+    {
+        ExternalDirectiveDefinition {
+            position: Pos::default(),
+            description: input.description.clone(),
+            name: From::from(input.name.as_str()),
+            arguments: input
+                .arguments
+                .iter()
+                .map(GraphQLParserTranslator::translate_argument)
+                .collect(),
+            repeatable: input.is_repeatable,
+            locations: input
+                .locations
+                .iter()
+                .map(GraphQLParserTranslator::translate_directive_location)
+                .collect(),
+        }
+    }
+
+    fn translate_directive_location(input: &DirectiveLocation) -> ExternalDirectiveLocation {
+        match input {
+            DirectiveLocation::Query => ExternalDirectiveLocation::Query,
+            DirectiveLocation::Mutation => ExternalDirectiveLocation::Mutation,
+            DirectiveLocation::Subscription => ExternalDirectiveLocation::Subscription,
+            DirectiveLocation::Field => ExternalDirectiveLocation::Field,
+            DirectiveLocation::Scalar => ExternalDirectiveLocation::Scalar,
+            DirectiveLocation::FragmentDefinition => ExternalDirectiveLocation::FragmentDefinition,
+            DirectiveLocation::FieldDefinition => ExternalDirectiveLocation::FieldDefinition,
+            DirectiveLocation::VariableDefinition => ExternalDirectiveLocation::VariableDefinition,
+            DirectiveLocation::FragmentSpread => ExternalDirectiveLocation::FragmentSpread,
+            DirectiveLocation::InlineFragment => ExternalDirectiveLocation::InlineFragment,
+            DirectiveLocation::EnumValue => ExternalDirectiveLocation::EnumValue,
+            DirectiveLocation::Schema => ExternalDirectiveLocation::Schema,
+            DirectiveLocation::Object => ExternalDirectiveLocation::Object,
+            DirectiveLocation::ArgumentDefinition => ExternalDirectiveLocation::ArgumentDefinition,
+            DirectiveLocation::Interface => ExternalDirectiveLocation::Interface,
+            DirectiveLocation::Union => ExternalDirectiveLocation::Union,
+            DirectiveLocation::Enum => ExternalDirectiveLocation::Enum,
+            DirectiveLocation::InputObject => ExternalDirectiveLocation::InputObject,
+            DirectiveLocation::InputFieldDefinition => {
+                ExternalDirectiveLocation::InputFieldDefinition
+            }
         }
     }
 
@@ -162,13 +260,21 @@ impl GraphQLParserTranslator {
                 position: Pos::default(),
                 description: x.description.as_ref().map(From::from),
                 name: From::from(x.name.as_ref()),
-                directives: vec![],
+                directives: x
+                    .directives
+                    .iter()
+                    .map(GraphQLParserTranslator::translate_applied_directive)
+                    .collect(),
             }),
             MetaType::Enum(x) => ExternalTypeDefinition::Enum(ExternalEnum {
                 position: Pos::default(),
                 description: x.description.as_ref().map(|s| From::from(s.as_str())),
                 name: From::from(x.name.as_ref()),
-                directives: vec![],
+                directives: x
+                    .directives
+                    .iter()
+                    .map(GraphQLParserTranslator::translate_applied_directive)
+                    .collect(),
                 values: x
                     .values
                     .iter()
@@ -179,7 +285,11 @@ impl GraphQLParserTranslator {
                 position: Pos::default(),
                 description: x.description.as_ref().map(|s| From::from(s.as_str())),
                 name: From::from(x.name.as_ref()),
-                directives: vec![],
+                directives: x
+                    .directives
+                    .iter()
+                    .map(GraphQLParserTranslator::translate_applied_directive)
+                    .collect(),
                 types: x
                     .of_type_names
                     .iter()
@@ -192,7 +302,11 @@ impl GraphQLParserTranslator {
                 name: From::from(x.name.as_ref()),
                 // TODO: Support this with GraphQL October 2021 Edition.
                 implements_interfaces: vec![],
-                directives: vec![],
+                directives: x
+                    .directives
+                    .iter()
+                    .map(GraphQLParserTranslator::translate_applied_directive)
+                    .collect(),
                 fields: x
                     .fields
                     .iter()
@@ -205,7 +319,11 @@ impl GraphQLParserTranslator {
                     position: Pos::default(),
                     description: x.description.as_ref().map(|s| From::from(s.as_str())),
                     name: From::from(x.name.as_ref()),
-                    directives: vec![],
+                    directives: x
+                        .directives
+                        .iter()
+                        .map(GraphQLParserTranslator::translate_applied_directive)
+                        .collect(),
                     fields: x
                         .input_fields
                         .iter()
@@ -218,7 +336,11 @@ impl GraphQLParserTranslator {
                 position: Pos::default(),
                 description: x.description.as_ref().map(|s| From::from(s.as_str())),
                 name: From::from(x.name.as_ref()),
-                directives: vec![],
+                directives: x
+                    .directives
+                    .iter()
+                    .map(GraphQLParserTranslator::translate_applied_directive)
+                    .collect(),
                 fields: x
                     .fields
                     .iter()
@@ -235,15 +357,16 @@ impl GraphQLParserTranslator {
         }
     }
 
-    fn translate_enum_value<'a, T>(input: &'a EnumValue) -> ExternalEnumValue<'a, T>
+    fn translate_enum_value<'a, S, T>(input: &'a EnumValue<S>) -> ExternalEnumValue<'a, T>
     where
+        S: ScalarValue,
         T: Text<'a>,
     {
         ExternalEnumValue {
             position: Pos::default(),
             name: From::from(input.name.as_ref()),
             description: input.description.as_ref().map(|s| From::from(s.as_str())),
-            directives: generate_directives(&input.deprecation_status),
+            directives: generate_directives(&input.deprecation_status, &input.directives),
         }
     }
 
@@ -267,7 +390,7 @@ impl GraphQLParserTranslator {
             position: Pos::default(),
             name: From::from(input.name.as_str()),
             description: input.description.as_ref().map(|s| From::from(s.as_str())),
-            directives: generate_directives(&input.deprecation_status),
+            directives: generate_directives(&input.deprecation_status, &input.directives),
             field_type: GraphQLParserTranslator::translate_type(&input.field_type),
             arguments,
         }
@@ -295,16 +418,23 @@ where
     }
 }
 
-// Right now the only directive supported is `@deprecated`. `@skip` and `@include`
-// are dealt with elsewhere.
+// Besides the applied type system directives, only `@deprecated` is generated
+// here. `@skip` and `@include` are dealt with elsewhere.
 // <https://facebook.github.io/graphql/draft/#sec-Type-System.Directives>
-fn generate_directives<'a, T>(status: &DeprecationStatus) -> Vec<ExternalDirective<'a, T>>
+fn generate_directives<'a, S, T>(
+    status: &DeprecationStatus,
+    applied: &'a [AppliedDirective<S>],
+) -> Vec<ExternalDirective<'a, T>>
 where
+    S: ScalarValue,
     T: Text<'a>,
 {
-    if let Some(d) = deprecation_to_directive(status) {
-        vec![d]
-    } else {
-        vec![]
-    }
+    deprecation_to_directive(status)
+        .into_iter()
+        .chain(
+            applied
+                .iter()
+                .map(GraphQLParserTranslator::translate_applied_directive),
+        )
+        .collect()
 }
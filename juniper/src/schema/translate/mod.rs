@@ -4,5 +4,6 @@ pub trait SchemaTranslator<'a, T> {
     fn translate_schema<S: 'a + ScalarValue>(s: &'a SchemaType<S>) -> T;
 }
 
+pub mod ast;
 #[cfg(feature = "graphql-parser")]
 pub mod graphql_parser;
@@ -0,0 +1,446 @@
+//! Client for testing schemas, executing operations against a [`RootNode`]
+//! and asserting on their results as Rust types.
+//!
+//! ```
+//! # use juniper::{
+//! #     graphql_object, testing::TestClient, EmptyMutation, EmptySubscription, FieldResult,
+//! #     RootNode,
+//! # };
+//! # use serde::Deserialize;
+//! #
+//! struct Query;
+//!
+//! #[graphql_object]
+//! impl Query {
+//!     fn hero(id: i32) -> FieldResult<String> {
+//!         match id {
+//!             1 => Ok("Luke".into()),
+//!             _ => Err("Unknown hero".into()),
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Hero {
+//!     hero: String,
+//! }
+//!
+//! let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+//! let client = TestClient::new(&schema, &());
+//!
+//! let res = client
+//!     .operation("query Hero($id: Int!) { hero(id: $id) }")
+//!     .with_variable("id", 1)
+//!     .execute_sync();
+//! assert_eq!(res.data::<Hero>().hero, "Luke");
+//!
+//! client
+//!     .operation("{ hero(id: 2) }")
+//!     .execute_sync()
+//!     .assert_error(&["hero"], "Unknown hero");
+//! ```
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    ast::ToInputValue,
+    executor::{ExecutionError, Variables},
+    schema::model::RootNode,
+    types::{async_await::GraphQLTypeAsync, base::GraphQLType},
+    value::{DefaultScalarValue, ScalarValue, Value},
+    GraphQLError,
+};
+
+/// Client executing operations against a [`RootNode`] with a context.
+pub struct TestClient<'a, QueryT, MutationT, SubscriptionT, S = DefaultScalarValue>
+where
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S>,
+    SubscriptionT: GraphQLType<S>,
+    S: ScalarValue,
+{
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    context: &'a QueryT::Context,
+}
+
+impl<'a, QueryT, MutationT, SubscriptionT, S> TestClient<'a, QueryT, MutationT, SubscriptionT, S>
+where
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S, Context = QueryT::Context>,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+    S: ScalarValue,
+{
+    /// Creates a new [`TestClient`] executing operations against the provided
+    /// `root_node` with the provided `context`.
+    pub fn new(
+        root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+        context: &'a QueryT::Context,
+    ) -> Self {
+        Self { root_node, context }
+    }
+
+    /// Prepares the execution of the provided GraphQL document `source`.
+    pub fn operation(
+        &self,
+        source: &'a str,
+    ) -> TestOperation<'a, QueryT, MutationT, SubscriptionT, S> {
+        TestOperation {
+            root_node: self.root_node,
+            context: self.context,
+            source,
+            operation_name: None,
+            variables: Variables::new(),
+        }
+    }
+}
+
+/// Operation prepared by a [`TestClient`], along with its variables.
+pub struct TestOperation<'a, QueryT, MutationT, SubscriptionT, S = DefaultScalarValue>
+where
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S>,
+    SubscriptionT: GraphQLType<S>,
+    S: ScalarValue,
+{
+    root_node: &'a RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    context: &'a QueryT::Context,
+    source: &'a str,
+    operation_name: Option<&'a str>,
+    variables: Variables<S>,
+}
+
+impl<'a, QueryT, MutationT, SubscriptionT, S> TestOperation<'a, QueryT, MutationT, SubscriptionT, S>
+where
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S, Context = QueryT::Context>,
+    SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+    S: ScalarValue,
+{
+    /// Sets the name of the operation to execute, if the document contains
+    /// multiple ones.
+    #[must_use]
+    pub fn with_operation_name(mut self, operation_name: &'a str) -> Self {
+        self.operation_name = Some(operation_name);
+        self
+    }
+
+    /// Sets the variable with the provided `name` to the provided `value`.
+    #[must_use]
+    pub fn with_variable(mut self, name: impl Into<String>, value: impl ToInputValue<S>) -> Self {
+        self.variables.insert(name.into(), value.to_input_value());
+        self
+    }
+
+    /// Sets all the provided `variables` (as made by [`graphql_vars!`] macro).
+    ///
+    /// [`graphql_vars!`]: crate::graphql_vars
+    #[must_use]
+    pub fn with_variables(mut self, variables: Variables<S>) -> Self {
+        self.variables.extend(variables);
+        self
+    }
+
+    /// Executes this operation synchronously.
+    pub fn execute_sync(self) -> TestResponse<'a, S> {
+        TestResponse(crate::execute_sync(
+            self.source,
+            self.operation_name,
+            self.root_node,
+            &self.variables,
+            self.context,
+        ))
+    }
+
+    /// Executes this operation asynchronously.
+    pub async fn execute(self) -> TestResponse<'a, S>
+    where
+        QueryT: GraphQLTypeAsync<S>,
+        QueryT::TypeInfo: Sync,
+        QueryT::Context: Sync,
+        MutationT: GraphQLTypeAsync<S>,
+        MutationT::TypeInfo: Sync,
+        SubscriptionT: Sync,
+        SubscriptionT::TypeInfo: Sync,
+        S: Send + Sync,
+    {
+        TestResponse(
+            crate::execute(
+                self.source,
+                self.operation_name,
+                self.root_node,
+                &self.variables,
+                self.context,
+            )
+            .await,
+        )
+    }
+}
+
+/// Result of an operation executed by a [`TestClient`].
+///
+/// Assertions panic with the whole result, for diagnosing failed tests.
+#[derive(Debug)]
+pub struct TestResponse<'a, S = DefaultScalarValue>(
+    Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError<'a>>,
+);
+
+impl<'a, S: ScalarValue> TestResponse<'a, S> {
+    /// Deserializes the `data` of this response into the type `T`.
+    ///
+    /// # Panics
+    ///
+    /// If the operation failed, or resulted in any field errors, or its `data`
+    /// cannot be deserialized into `T`.
+    pub fn data<T: DeserializeOwned>(&self) -> T {
+        self.assert_no_errors().partial_data()
+    }
+
+    /// Deserializes the `data` of this response into the type `T`, even if the
+    /// operation resulted in field errors.
+    ///
+    /// # Panics
+    ///
+    /// If the operation failed, or its `data` cannot be deserialized into `T`.
+    pub fn partial_data<T: DeserializeOwned>(&self) -> T {
+        T::deserialize(self.value().clone()).unwrap_or_else(|e| {
+            panic!(
+                "Cannot deserialize `{}` from data: {}\n{:#?}",
+                std::any::type_name::<T>(),
+                e,
+                self.0,
+            )
+        })
+    }
+
+    /// Returns the `data` of this response as a [`Value`].
+    ///
+    /// # Panics
+    ///
+    /// If the operation failed.
+    pub fn value(&self) -> &Value<S> {
+        &self.result().0
+    }
+
+    /// Returns the field errors of this response.
+    ///
+    /// # Panics
+    ///
+    /// If the operation failed.
+    pub fn errors(&self) -> &[ExecutionError<S>] {
+        &self.result().1
+    }
+
+    /// Returns the field error at the provided `path` of response keys, if
+    /// any.
+    ///
+    /// # Panics
+    ///
+    /// If the operation failed.
+    pub fn error_at(&self, path: &[&str]) -> Option<&ExecutionError<S>> {
+        self.errors().iter().find(|e| e.path() == path)
+    }
+
+    /// Asserts that this response has a field error with the provided
+    /// `message` at the provided `path` of response keys.
+    pub fn assert_error(&self, path: &[&str], message: &str) -> &Self {
+        match self.error_at(path) {
+            Some(e) if e.error().message() == message => self,
+            _ => panic!(
+                "Expected error `{}` at path {:?}, got:\n{:#?}",
+                message, path, self.0,
+            ),
+        }
+    }
+
+    /// Asserts that this response has no field errors.
+    pub fn assert_no_errors(&self) -> &Self {
+        if !self.errors().is_empty() {
+            panic!("Expected no errors, got:\n{:#?}", self.0);
+        }
+        self
+    }
+
+    /// Returns the error the operation failed with, if any.
+    pub fn graphql_error(&self) -> Option<&GraphQLError<'a>> {
+        self.0.as_ref().err()
+    }
+
+    /// Returns the whole result of the operation.
+    pub fn into_result(self) -> Result<(Value<S>, Vec<ExecutionError<S>>), GraphQLError<'a>> {
+        self.0
+    }
+
+    fn result(&self) -> &(Value<S>, Vec<ExecutionError<S>>) {
+        self.0
+            .as_ref()
+            .unwrap_or_else(|e| panic!("Operation failed: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::{
+        graphql_object,
+        tests::fixtures::starwars::schema::{Database, Query},
+        EmptyMutation, EmptySubscription, FieldResult, GraphQLError, RootNode,
+    };
+
+    use super::TestClient;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Character {
+        name: String,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Characters {
+        human: Option<Character>,
+        droid: Option<Character>,
+    }
+
+    fn schema() -> RootNode<'static, Query, EmptyMutation<Database>, EmptySubscription<Database>> {
+        RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        )
+    }
+
+    const CHARACTERS: &str = r#"
+        query Characters($human: String!, $droid: String!) {
+            human(id: $human) { name }
+            droid(id: $droid) { name }
+        }
+    "#;
+
+    #[test]
+    fn deserializes_data() {
+        let (schema, db) = (schema(), Database::new());
+        let client = TestClient::new(&schema, &db);
+
+        assert_eq!(
+            client
+                .operation(CHARACTERS)
+                .with_variable("human", "1000")
+                .with_variable("droid", "9999")
+                .execute_sync()
+                .data::<Characters>(),
+            Characters {
+                human: Some(Character {
+                    name: "Luke Skywalker".into(),
+                }),
+                droid: None,
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn deserializes_data_async() {
+        let (schema, db) = (schema(), Database::new());
+        let client = TestClient::new(&schema, &db);
+
+        let res = client
+            .operation(CHARACTERS)
+            .with_operation_name("Characters")
+            .with_variables(graphql_vars! {"human": "1001", "droid": "2001"})
+            .execute()
+            .await;
+
+        assert_eq!(
+            res.data::<Characters>(),
+            Characters {
+                human: Some(Character {
+                    name: "Darth Vader".into(),
+                }),
+                droid: Some(Character {
+                    name: "R2-D2".into(),
+                }),
+            },
+        );
+    }
+
+    struct Fallible;
+
+    #[graphql_object]
+    impl Fallible {
+        fn ok() -> i32 {
+            1
+        }
+
+        fn failing() -> FieldResult<Option<i32>> {
+            Err("Failed".into())
+        }
+
+        fn nested() -> Fallible {
+            Fallible
+        }
+    }
+
+    #[test]
+    fn asserts_errors_by_path() {
+        #[derive(Deserialize)]
+        struct Data {
+            ok: i32,
+            nested: Nested,
+        }
+
+        #[derive(Deserialize)]
+        struct Nested {
+            failing: Option<i32>,
+        }
+
+        let schema = RootNode::new(
+            Fallible,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let client = TestClient::new(&schema, &());
+
+        let res = client.operation("{ ok nested { failing } }").execute_sync();
+        res.assert_error(&["nested", "failing"], "Failed");
+        assert!(res.error_at(&["failing"]).is_none());
+
+        let data = res.partial_data::<Data>();
+        assert_eq!(data.ok, 1);
+        assert_eq!(data.nested.failing, None);
+
+        let res = client.operation("{ ok nested { ok } }").execute_sync();
+        assert_eq!(
+            res.assert_no_errors().value(),
+            &graphql_value!({"ok": 1, "nested": {"ok": 1}})
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected no errors")]
+    fn data_panics_on_errors() {
+        let schema = RootNode::new(
+            Fallible,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        TestClient::new(&schema, &())
+            .operation("{ failing }")
+            .execute_sync()
+            .data::<Option<()>>();
+    }
+
+    #[test]
+    fn returns_graphql_errors() {
+        let (schema, db) = (schema(), Database::new());
+        let client = TestClient::new(&schema, &db);
+
+        let res = client
+            .operation(CHARACTERS)
+            .with_operation_name("Unknown")
+            .execute_sync();
+
+        assert_eq!(
+            res.graphql_error(),
+            Some(&GraphQLError::UnknownOperationName)
+        );
+    }
+}
@@ -6,6 +6,8 @@ mod introspection_tests;
 #[cfg(test)]
 mod query_tests;
 #[cfg(test)]
+mod remote_schema_tests;
+#[cfg(test)]
 mod schema_introspection;
 #[cfg(test)]
 mod subscriptions;
@@ -0,0 +1,207 @@
+use crate::{
+    introspection::IntrospectionFormat,
+    parser::parse_document_source,
+    schema::{
+        diff,
+        model::{RootNode, SchemaType},
+        remote::SchemaBuildError,
+        SchemaDiff,
+    },
+    tests::fixtures::starwars::schema::{Database, Query},
+    types::scalars::{EmptyMutation, EmptySubscription},
+    validation::{visit_all_rules, RuleError, ValidatorContext},
+    value::DefaultScalarValue,
+};
+
+fn validate(schema: &SchemaType<DefaultScalarValue>, query: &str) -> Vec<RuleError> {
+    let doc = parse_document_source(query, schema).expect("Failed to parse document");
+    let mut ctx = ValidatorContext::new(schema, &doc);
+    visit_all_rules(&mut ctx, &doc);
+    ctx.into_errors()
+}
+
+fn starwars_introspection() -> crate::Value {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    );
+    let (result, errors) =
+        crate::introspect(&schema, &Database::new(), IntrospectionFormat::All).unwrap();
+    assert_eq!(errors, vec![]);
+    result
+}
+
+#[test]
+fn builds_from_introspection() {
+    let original = RootNode::new(
+        Query,
+        EmptyMutation::<Database>::new(),
+        EmptySubscription::<Database>::new(),
+    );
+    let remote = SchemaType::from_introspection(&starwars_introspection()).unwrap();
+
+    assert_eq!(
+        diff(&remote, &original.schema).unwrap(),
+        SchemaDiff::default()
+    );
+}
+
+#[test]
+fn accepts_whole_response() {
+    let response = graphql_value!({ "data": (starwars_introspection()) });
+
+    assert!(SchemaType::from_introspection(&response).is_ok());
+}
+
+#[test]
+fn validates_against_introspected_schema() {
+    let schema = SchemaType::from_introspection(&starwars_introspection()).unwrap();
+
+    assert_eq!(
+        validate(
+            &schema,
+            r#"query ($id: String!) {
+                hero(episode: EMPIRE) { name ... on Human { homePlanet } }
+                droid(id: $id) { friends { name } }
+                __schema { queryType { name } }
+            }"#,
+        ),
+        vec![],
+    );
+    assert_eq!(validate(&schema, "{ hero { unknown } }").len(), 1);
+    assert_eq!(
+        validate(&schema, "{ hero(episode: PLUTO) { name } }").len(),
+        1
+    );
+    assert_eq!(validate(&schema, r#"{ human(id: 1) { name } }"#).len(), 1);
+}
+
+const SDL: &str = r#"
+    "A moment in time."
+    scalar DateTime @specifiedBy(url: "https://tools.ietf.org/html/rfc3339")
+
+    enum Color { RED GREEN BLUE @deprecated(reason: "Too cold") }
+
+    input Filter {
+        color: Color = RED
+        limit: Int = 10
+        since: DateTime
+    }
+
+    interface Node { id: ID! }
+
+    type Item implements Node {
+        id: ID!
+        color: Color
+        createdAt: DateTime
+    }
+
+    type Tag implements Node { id: ID! }
+
+    union SearchResult = Item
+
+    type Query {
+        items(filter: Filter = { color: GREEN }, first: Int = 20): [Item!]!
+        node(id: ID!): Node
+    }
+
+    extend union SearchResult = Tag
+
+    extend type Query {
+        search(text: String!): [SearchResult!]!
+    }
+
+    directive @auth(role: String = "admin") repeatable on FIELD | QUERY
+"#;
+
+#[test]
+fn builds_from_sdl() {
+    let schema = SchemaType::<DefaultScalarValue>::from_sdl(SDL).unwrap();
+
+    assert!(schema.mutation_type().is_none());
+    assert_eq!(
+        schema
+            .concrete_type_by_name("DateTime")
+            .and_then(|t| t.specified_by_url()),
+        Some("https://tools.ietf.org/html/rfc3339"),
+    );
+
+    let query = schema.concrete_type_by_name("Query").unwrap();
+    let items = query.field_by_name("items").unwrap();
+    let args = items.arguments.as_ref().unwrap();
+    assert_eq!(
+        args[0].default_value.as_ref().map(ToString::to_string),
+        Some("{color: GREEN}".into()),
+    );
+    assert_eq!(args[1].default_value, Some(graphql_input_value!(20)));
+    assert!(query.field_by_name("search").is_some());
+    assert!(query.field_by_name("__schema").is_some());
+
+    let filter = schema.concrete_type_by_name("Filter").unwrap();
+    assert_eq!(
+        filter.input_field_by_name("limit").unwrap().default_value,
+        Some(graphql_input_value!(10)),
+    );
+
+    let auth = schema.directive_by_name("auth").unwrap();
+    assert!(auth.is_repeatable);
+    assert_eq!(
+        auth.arguments[0].default_value,
+        Some(graphql_input_value!("admin")),
+    );
+}
+
+#[test]
+fn validates_against_sdl_schema() {
+    let schema = SchemaType::<DefaultScalarValue>::from_sdl(SDL).unwrap();
+
+    assert_eq!(
+        validate(
+            &schema,
+            r#"query @auth @auth(role: "user") {
+                items(filter: { color: BLUE, since: "2022-01-01T00:00:00Z" }) {
+                    id color createdAt
+                }
+                search(text: "x") { ... on Tag { id } ... on Node { id } }
+            }"#,
+        ),
+        vec![],
+    );
+    assert_eq!(
+        validate(&schema, "{ items(filter: { color: PURPLE }) { id } }").len(),
+        1,
+    );
+    assert_eq!(
+        validate(&schema, "{ items(filter: { unknown: 1 }) { id } }").len(),
+        1,
+    );
+    assert_eq!(validate(&schema, "{ node { id } }").len(), 1);
+    assert_eq!(validate(&schema, "{ search(text: \"x\") { id } }").len(), 1);
+}
+
+#[test]
+fn rejects_invalid_schemas() {
+    let build = SchemaType::<DefaultScalarValue>::from_sdl;
+
+    assert!(matches!(
+        build("type Query {"),
+        Err(SchemaBuildError::Syntax(_))
+    ));
+    assert_eq!(
+        build("type Query { a: Unknown }").unwrap_err(),
+        SchemaBuildError::UnknownType("Unknown".into()),
+    );
+    assert_eq!(
+        build("type Query { a: Int } enum Query { A }").unwrap_err(),
+        SchemaBuildError::DuplicateType("Query".into()),
+    );
+    assert_eq!(
+        build("type Foo { a: Int }").unwrap_err(),
+        SchemaBuildError::InvalidRootType("Query".into()),
+    );
+    assert!(matches!(
+        SchemaType::<DefaultScalarValue>::from_introspection(&graphql_value!({ "__schema": {} })),
+        Err(SchemaBuildError::Malformed(_)),
+    ));
+}
@@ -0,0 +1,402 @@
+//! GraphQL scalars for integer types not fitting into the [`Int` scalar][0].
+//!
+//! # Serialization
+//!
+//! The [`Int` scalar][0] is limited to 32-bit signed integers, while JSON
+//! numbers are commonly parsed into double-precision floats by clients, which
+//! represent integers exactly only up to 2<sup>53</sup>. That's why the values
+//! of these scalars follow explicit rules:
+//! - On output, a value is serialized as a number if the used [`ScalarValue`]
+//!   is able to hold it losslessly (see [`ScalarValue::from_big_int()`]), and
+//!   as a decimal string otherwise. With [`DefaultScalarValue`] this means that
+//!   only values fitting into [`i32`] are serialized as numbers, while with
+//!   [`LongScalarValue`] all the [`i64`] values are.
+//! - On input, both forms are accepted: an integer number (or a float without
+//!   a fractional part not exceeding 2<sup>53</sup> by its absolute value) and
+//!   a string containing a decimal integer.
+//!
+//! `std::num::NonZero*` types are represented with the same GraphQL scalars as
+//! their primitive counterparts, additionally rejecting `0` on input.
+//!
+//! [`DefaultScalarValue`]: crate::DefaultScalarValue
+//! [`LongScalarValue`]: crate::LongScalarValue
+//! [0]: https://spec.graphql.org/October2021#sec-Int
+
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+    num::{NonZeroI128, NonZeroI32, NonZeroI64, NonZeroU128, NonZeroU32, NonZeroU64},
+    str::FromStr,
+};
+
+use crate::{
+    ast::InputValue,
+    graphql_scalar,
+    parser::{ParseError, ScalarToken, Token},
+    value::{ParseScalarResult, ParseScalarValue, ScalarValue, Value},
+};
+
+/// Maximum absolute value of an integer representable by [`f64`] exactly.
+const MAX_SAFE_FLOAT_INT: f64 = 9_007_199_254_740_992.0;
+
+/// Converts the given integer into a [`ScalarValue`], falling back to its
+/// decimal [`String`] representation if the [`ScalarValue`] cannot hold it
+/// losslessly.
+fn to_scalar<S, T>(n: T) -> S
+where
+    S: ScalarValue,
+    T: TryInto<i128> + Copy + fmt::Display,
+{
+    n.try_into()
+        .ok()
+        .and_then(S::from_big_int)
+        .unwrap_or_else(|| S::from(n.to_string()))
+}
+
+/// Extracts an integer of the given type from the provided [`InputValue`],
+/// accepting both numbers and decimal strings.
+fn from_input<S, T>(v: &InputValue<S>) -> Option<T>
+where
+    S: ScalarValue,
+    T: TryFrom<i128> + FromStr,
+{
+    let s = v.as_scalar_value()?;
+    if let Some(n) = s.as_big_int() {
+        T::try_from(n).ok()
+    } else if let Some(s) = s.as_str() {
+        s.parse().ok()
+    } else {
+        s.as_float()
+            .filter(|f| f.fract() == 0.0 && f.abs() <= MAX_SAFE_FLOAT_INT)
+            .and_then(|f| T::try_from(f as i128).ok())
+    }
+}
+
+/// Parses the given [`ScalarToken`] as an integer of the given type.
+///
+/// [`ScalarToken::String`]s are parsed "as is", so the value is validated
+/// later, when converted from an [`InputValue`].
+fn parse_token<S, T>(value: ScalarToken<'_>) -> ParseScalarResult<'_, S>
+where
+    S: ScalarValue,
+    T: TryInto<i128> + FromStr + Copy + fmt::Display,
+{
+    match value {
+        ScalarToken::Int(v) => v
+            .parse::<T>()
+            .map(to_scalar)
+            .map_err(|_| ParseError::UnexpectedToken(Token::Scalar(value))),
+        ScalarToken::String(_) => <String as ParseScalarValue<S>>::from_str(value),
+        ScalarToken::Float(_) => Err(ParseError::UnexpectedToken(Token::Scalar(value))),
+    }
+}
+
+/// Defines a GraphQL scalar for the given primitive integer type.
+macro_rules! integer_scalar {
+    ($name:ident = $ty:ty, $module:ident, $doc:literal $(,)?) => {
+        #[doc = $doc]
+        #[graphql_scalar(with = $module)]
+        type $name = $ty;
+
+        mod $module {
+            use super::*;
+
+            pub(super) fn to_output<S: ScalarValue>(v: &$name) -> Value<S> {
+                Value::Scalar(to_scalar(*v))
+            }
+
+            pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<$name, String> {
+                super::from_input(v)
+                    .ok_or_else(|| format!("Expected `{}`, found: {}", stringify!($name), v))
+            }
+
+            pub(super) fn parse_token<S: ScalarValue>(
+                value: ScalarToken<'_>,
+            ) -> ParseScalarResult<'_, S> {
+                super::parse_token::<S, $name>(value)
+            }
+        }
+    };
+}
+
+/// Defines a GraphQL scalar for the given `std::num::NonZero*` type,
+/// represented with the same GraphQL scalar as its primitive counterpart.
+macro_rules! non_zero_scalar {
+    (
+        $alias:ident = $ty:ty,
+        $module:ident,
+        $name:literal($base:ty),
+        $parse_token:path,
+        $doc:literal $(,)?
+    ) => {
+        #[doc = $doc]
+        #[graphql_scalar(name = $name, with = $module)]
+        type $alias = $ty;
+
+        mod $module {
+            use super::*;
+
+            pub(super) fn to_output<S: ScalarValue>(v: &$alias) -> Value<S> {
+                Value::Scalar(to_scalar(<$base>::from(*v)))
+            }
+
+            pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<$alias, String> {
+                super::from_input::<S, $base>(v)
+                    .ok_or_else(|| format!("Expected `{}`, found: {}", $name, v))
+                    .and_then(|n| {
+                        <$alias>::new(n)
+                            .ok_or_else(|| format!("Expected non-zero `{}`, found: {}", $name, v))
+                    })
+            }
+
+            pub(super) fn parse_token<S: ScalarValue>(
+                value: ScalarToken<'_>,
+            ) -> ParseScalarResult<'_, S> {
+                $parse_token(value)
+            }
+        }
+    };
+}
+
+integer_scalar!(
+    UnsignedInt = u32,
+    impl_unsigned_int_scalar,
+    "32-bit unsigned integer.\n\nSerialized as a number if the server is able to \
+     represent it losslessly, or as a decimal string otherwise.",
+);
+
+integer_scalar!(
+    Long = i64,
+    impl_long_scalar,
+    "64-bit signed integer.\n\nSerialized as a number if the server is able to \
+     represent it losslessly, or as a decimal string otherwise.",
+);
+
+integer_scalar!(
+    UnsignedLong = u64,
+    impl_unsigned_long_scalar,
+    "64-bit unsigned integer.\n\nSerialized as a number if the server is able to \
+     represent it losslessly, or as a decimal string otherwise.",
+);
+
+integer_scalar!(
+    BigInt = i128,
+    impl_big_int_scalar,
+    "128-bit signed integer.\n\nSerialized as a number if the server is able to \
+     represent it losslessly, or as a decimal string otherwise.",
+);
+
+integer_scalar!(
+    UnsignedBigInt = u128,
+    impl_unsigned_big_int_scalar,
+    "128-bit unsigned integer.\n\nSerialized as a number if the server is able to \
+     represent it losslessly, or as a decimal string otherwise.",
+);
+
+// No doc comment here, as it would become a description of the `Int` scalar.
+#[graphql_scalar(name = "Int", with = impl_non_zero_int_scalar)]
+type NonZeroInt = NonZeroI32;
+
+mod impl_non_zero_int_scalar {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(v: &NonZeroInt) -> Value<S> {
+        Value::scalar(v.get())
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<NonZeroInt, String> {
+        v.as_int_value()
+            .ok_or_else(|| format!("Expected `Int`, found: {}", v))
+            .and_then(|n| {
+                NonZeroInt::new(n).ok_or_else(|| format!("Expected non-zero `Int`, found: {}", v))
+            })
+    }
+
+    pub(super) fn parse_token<S: ScalarValue>(value: ScalarToken<'_>) -> ParseScalarResult<'_, S> {
+        <i32 as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+non_zero_scalar!(
+    NonZeroUnsignedInt = NonZeroU32,
+    impl_non_zero_unsigned_int_scalar,
+    "UnsignedInt"(u32),
+    impl_unsigned_int_scalar::parse_token,
+    "32-bit unsigned integer.\n\nSerialized as a number if the server is able to \
+     represent it losslessly, or as a decimal string otherwise.",
+);
+
+non_zero_scalar!(
+    NonZeroLong = NonZeroI64,
+    impl_non_zero_long_scalar,
+    "Long"(i64),
+    impl_long_scalar::parse_token,
+    "64-bit signed integer.\n\nSerialized as a number if the server is able to \
+     represent it losslessly, or as a decimal string otherwise.",
+);
+
+non_zero_scalar!(
+    NonZeroUnsignedLong = NonZeroU64,
+    impl_non_zero_unsigned_long_scalar,
+    "UnsignedLong"(u64),
+    impl_unsigned_long_scalar::parse_token,
+    "64-bit unsigned integer.\n\nSerialized as a number if the server is able to \
+     represent it losslessly, or as a decimal string otherwise.",
+);
+
+non_zero_scalar!(
+    NonZeroBigInt = NonZeroI128,
+    impl_non_zero_big_int_scalar,
+    "BigInt"(i128),
+    impl_big_int_scalar::parse_token,
+    "128-bit signed integer.\n\nSerialized as a number if the server is able to \
+     represent it losslessly, or as a decimal string otherwise.",
+);
+
+non_zero_scalar!(
+    NonZeroUnsignedBigInt = NonZeroU128,
+    impl_non_zero_unsigned_big_int_scalar,
+    "UnsignedBigInt"(u128),
+    impl_unsigned_big_int_scalar::parse_token,
+    "128-bit unsigned integer.\n\nSerialized as a number if the server is able to \
+     represent it losslessly, or as a decimal string otherwise.",
+);
+
+#[cfg(test)]
+mod tests {
+    use std::num::{NonZeroI64, NonZeroU64};
+
+    use crate::{
+        graphql_input_value, graphql_object, graphql_value, graphql_vars,
+        parser::ScalarToken,
+        value::{DefaultScalarValue, LongScalarValue, ParseScalarValue},
+        EmptyMutation, EmptySubscription, FromInputValue, InputValue, RootNode, ScalarValue as _,
+        ToInputValue,
+    };
+
+    #[test]
+    fn outputs_numbers_only_when_lossless() {
+        let small: InputValue = 42_i64.to_input_value();
+        assert_eq!(small, graphql_input_value!(42));
+
+        let big: InputValue = i64::MAX.to_input_value();
+        assert_eq!(big, graphql_input_value!("9223372036854775807"));
+
+        let big: InputValue<LongScalarValue> = i64::MAX.to_input_value();
+        assert_eq!(big, InputValue::scalar(LongScalarValue::Long(i64::MAX)));
+
+        let huge: InputValue<LongScalarValue> = u64::MAX.to_input_value();
+        assert_eq!(huge, InputValue::scalar("18446744073709551615"));
+    }
+
+    #[test]
+    fn accepts_numbers_and_strings() {
+        for (input, expected) in [
+            (graphql_input_value!(42), Some(42)),
+            (graphql_input_value!("9223372036854775807"), Some(i64::MAX)),
+            (
+                graphql_input_value!(123_567_890_123.0),
+                Some(123_567_890_123),
+            ),
+            (graphql_input_value!(1.5), None),
+            (graphql_input_value!(1e300), None),
+            (graphql_input_value!("9223372036854775808"), None),
+            (graphql_input_value!("abc"), None),
+            (graphql_input_value!(true), None),
+        ] {
+            let actual = <i64 as FromInputValue>::from_input_value(&input).ok();
+            assert_eq!(actual, expected, "input: {}", input);
+        }
+
+        let input = InputValue::<LongScalarValue>::scalar(LongScalarValue::Long(i64::MIN));
+        assert_eq!(i64::from_input_value(&input), Ok(i64::MIN));
+        assert!(u64::from_input_value(&input).is_err());
+    }
+
+    #[test]
+    fn rejects_zero_for_non_zero() {
+        assert_eq!(
+            <NonZeroU64 as FromInputValue>::from_input_value(&graphql_input_value!("7")).ok(),
+            NonZeroU64::new(7),
+        );
+        assert_eq!(
+            <NonZeroI64 as FromInputValue>::from_input_value(&graphql_input_value!(0))
+                .unwrap_err()
+                .message(),
+            "Expected non-zero `Long`, found: 0",
+        );
+    }
+
+    #[test]
+    fn parses_tokens() {
+        let n = <i64 as ParseScalarValue<DefaultScalarValue>>::from_str(ScalarToken::Int(
+            "9223372036854775807",
+        ));
+        assert_eq!(n, Ok(DefaultScalarValue::from("9223372036854775807")));
+
+        let n = <i64 as ParseScalarValue<LongScalarValue>>::from_str(ScalarToken::Int(
+            "9223372036854775807",
+        ));
+        assert_eq!(n, Ok(LongScalarValue::Long(i64::MAX)));
+
+        let n = <u32 as ParseScalarValue<DefaultScalarValue>>::from_str(ScalarToken::Int("-1"));
+        assert!(n.is_err());
+    }
+
+    #[test]
+    fn converts_scalar_values_losslessly() {
+        let v: DefaultScalarValue = LongScalarValue::Long(i64::MAX).into_another();
+        assert_eq!(v, DefaultScalarValue::from("9223372036854775807"));
+
+        let v: LongScalarValue = DefaultScalarValue::from(i32::MIN).into_another();
+        assert_eq!(v, LongScalarValue::Int(i32::MIN));
+
+        let v: DefaultScalarValue = LongScalarValue::Float(1.5).into_another();
+        assert_eq!(v, DefaultScalarValue::Float(1.5));
+    }
+
+    #[tokio::test]
+    async fn resolves_in_query() {
+        struct Query;
+
+        #[graphql_object]
+        impl Query {
+            fn echo(value: u64) -> u64 {
+                value
+            }
+
+            fn non_zero(value: NonZeroI64) -> NonZeroI64 {
+                value
+            }
+        }
+
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+
+        let query = r#"{
+            small: echo(value: 7)
+            big: echo(value: 18446744073709551615)
+            str: echo(value: "18446744073709551615")
+            nonZero(value: -3)
+        }"#;
+        assert_eq!(
+            crate::execute(query, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "small": 7,
+                    "big": "18446744073709551615",
+                    "str": "18446744073709551615",
+                    "nonZero": -3,
+                }),
+                vec![],
+            )),
+        );
+
+        let vars = graphql_vars! {};
+        let res = crate::execute("{ nonZero(value: 0) }", None, &schema, &vars, &()).await;
+        assert!(res.is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     ast::InputValue,
     schema::{
-        meta::{EnumMeta, InputObjectMeta, MetaType},
+        meta::{EnumMeta, InputObjectMeta, MetaType, ScalarMeta},
         model::{SchemaType, TypeType},
     },
     value::ScalarValue,
@@ -53,6 +53,16 @@ where
                 return false;
             }
 
+            // Enums not backed by a Rust type (like the ones of a remote
+            // schema) don't check their values while parsing.
+            if let (InputValue::Enum(name), MetaType::Enum(EnumMeta { values, .. })) =
+                (arg_value, t)
+            {
+                if !values.iter().any(|v| &v.name == name) {
+                    return false;
+                }
+            }
+
             match *arg_value {
                 InputValue::Null | InputValue::Variable(_) => true,
                 ref v @ InputValue::Scalar(_) | ref v @ InputValue::Enum(_) => {
@@ -62,7 +72,20 @@ where
                         false
                     }
                 }
-                InputValue::List(_) => false,
+                ref v @ InputValue::List(_) => {
+                    // Only scalars opted into structured input may accept lists
+                    // in place of a single value (like a `JSON` scalar does).
+                    if let MetaType::Scalar(ScalarMeta {
+                        try_parse_fn,
+                        structured_input: true,
+                        ..
+                    }) = *t
+                    {
+                        try_parse_fn(v).is_ok()
+                    } else {
+                        false
+                    }
+                }
                 InputValue::Object(ref obj) => {
                     if let MetaType::InputObject(InputObjectMeta {
                         ref input_fields, ..
@@ -94,6 +117,13 @@ where
                         });
 
                         all_types_ok && remaining_required_fields.is_empty()
+                    } else if let MetaType::Scalar(ScalarMeta {
+                        try_parse_fn,
+                        structured_input: true,
+                        ..
+                    }) = *t
+                    {
+                        try_parse_fn(arg_value).is_ok()
                     } else {
                         false
                     }
@@ -7,7 +7,7 @@ use crate::ast::{Definition, Document, Type};
 
 use crate::schema::{meta::MetaType, model::SchemaType};
 
-use crate::parser::SourcePosition;
+use crate::parser::{Diagnostic, SourcePosition};
 
 /// Query validation error
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -66,6 +66,20 @@ impl fmt::Display for RuleError {
 
 impl std::error::Error for RuleError {}
 
+impl From<&RuleError> for Diagnostic {
+    /// Points the [`Diagnostic`] to the first location of the [`RuleError`],
+    /// with the other ones being related.
+    fn from(error: &RuleError) -> Self {
+        let mut locations = error.locations.iter();
+        let diagnostic = Diagnostic::new(error.message.clone());
+        let diagnostic = match locations.next() {
+            Some(first) => diagnostic.at(*first, None),
+            None => diagnostic,
+        };
+        locations.fold(diagnostic, |d, l| d.with_related(*l, "related location"))
+    }
+}
+
 impl<'a, S: Debug> ValidatorContext<'a, S> {
     #[doc(hidden)]
     pub fn new(schema: &'a SchemaType<S>, document: &Document<'a, S>) -> ValidatorContext<'a, S> {
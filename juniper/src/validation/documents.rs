@@ -0,0 +1,165 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{schema::model::SchemaType, validation::RuleError, value::ScalarValue};
+
+/// Extensions of the files considered to contain GraphQL documents.
+const EXTENSIONS: &[&str] = &["graphql", "gql"];
+
+/// Failed validation of a GraphQL document stored in a file.
+#[derive(Debug, PartialEq)]
+pub struct DocumentFailure {
+    /// Path to the file containing the document.
+    pub path: PathBuf,
+
+    /// Errors found in the document.
+    pub errors: Vec<RuleError>,
+}
+
+impl fmt::Display for DocumentFailure {
+    /// Formats every error on its own line, prefixed with the file path and
+    /// the one-based line and column of its first location.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for error in &self.errors {
+            write!(f, "{}", self.path.display())?;
+            if let Some(pos) = error.locations().first() {
+                write!(f, ":{}:{}", pos.line() + 1, pos.column() + 1)?;
+            }
+            writeln!(f, ": {}", error.message())?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates all the GraphQL documents (files with `.graphql` or `.gql`
+/// extension) in the provided `dir` and its subdirectories against the
+/// provided `schema`.
+///
+/// Returns the failures sorted by file path, so an empty [`Vec`] means that
+/// all the documents are valid. See [`SchemaType::validate_document()`] for
+/// the performed checks.
+///
+/// # Errors
+///
+/// If the `dir` or any of the documents cannot be read.
+pub fn validate_documents_in_dir<S: ScalarValue>(
+    schema: &SchemaType<S>,
+    dir: impl AsRef<Path>,
+) -> io::Result<Vec<DocumentFailure>> {
+    let mut paths = vec![];
+    collect_documents(dir.as_ref(), &mut paths)?;
+    paths.sort();
+
+    let mut failures = vec![];
+    for path in paths {
+        let source = fs::read_to_string(&path)?;
+        if let Err(errors) = schema.validate_document(&source) {
+            failures.push(DocumentFailure { path, errors });
+        }
+    }
+    Ok(failures)
+}
+
+/// Collects the paths of all the GraphQL documents in the provided `dir`,
+/// recursively.
+fn collect_documents(dir: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_documents(&path, paths)?;
+        } else if matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some(ext) if EXTENSIONS.contains(&ext),
+        ) {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::{
+        schema::model::RootNode,
+        tests::fixtures::starwars::schema::{Database, Query},
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::validate_documents_in_dir;
+
+    /// Creates a fresh temporary directory with the provided `files`.
+    fn documents_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("juniper-documents-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (path, content) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn reports_invalid_documents() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+        let dir = documents_dir(
+            "invalid",
+            &[
+                ("valid.graphql", "{ hero { name } }"),
+                ("nested/invalid.gql", "query {\n  hero { unknown }\n}"),
+                ("nested/broken.graphql", "{ hero {"),
+                ("ignored.txt", "{ unknown }"),
+            ],
+        );
+
+        let failures = validate_documents_in_dir(&schema.schema, &dir).unwrap();
+        let rendered = failures.iter().map(ToString::to_string).collect::<String>();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].path, dir.join("nested/broken.graphql"));
+        assert_eq!(failures[1].path, dir.join("nested/invalid.gql"));
+        assert_eq!(
+            rendered,
+            format!(
+                "{}:1:9: Unexpected end of input\n\
+                 {}:2:10: Unknown field \"unknown\" on type \"Character\"\n",
+                dir.join("nested/broken.graphql").display(),
+                dir.join("nested/invalid.gql").display(),
+            ),
+        );
+    }
+
+    #[test]
+    fn passes_valid_documents() {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+        let dir = documents_dir(
+            "valid",
+            &[
+                ("a.graphql", "{ hero { name } }"),
+                (
+                    "b/c.gql",
+                    "query ($id: String!) { human(id: $id) { name } }",
+                ),
+            ],
+        );
+
+        let failures = validate_documents_in_dir(&schema.schema, &dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(failures, vec![]);
+    }
+}
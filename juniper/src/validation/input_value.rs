@@ -214,14 +214,16 @@ where
         )];
     }
 
+    // Lists and objects are accepted only by the scalars opted into them (like
+    // a `JSON` scalar).
     match *value {
-        InputValue::List(_) => errors.push(unification_error(
+        InputValue::List(_) if !meta.structured_input => errors.push(unification_error(
             var_name,
             var_pos,
             path,
             &format!(r#"Expected "{}", found list"#, meta.name),
         )),
-        InputValue::Object(_) => errors.push(unification_error(
+        InputValue::Object(_) if !meta.structured_input => errors.push(unification_error(
             var_name,
             var_pos,
             path,
@@ -1,6 +1,7 @@
 //! Query validation related methods and data structures
 
 mod context;
+mod documents;
 mod input_value;
 mod multi_visitor;
 mod rules;
@@ -12,6 +13,7 @@ pub(crate) mod test_harness;
 
 pub use self::{
     context::{RuleError, ValidatorContext},
+    documents::{validate_documents_in_dir, DocumentFailure},
     input_value::validate_input_values,
     multi_visitor::MultiVisitorNil,
     rules::visit_all_rules,
@@ -10,7 +10,9 @@ use crate::{
 
 pub use self::{
     object::Object,
-    scalar::{DefaultScalarValue, ParseScalarResult, ParseScalarValue, ScalarValue},
+    scalar::{
+        DefaultScalarValue, LongScalarValue, ParseScalarResult, ParseScalarValue, ScalarValue,
+    },
 };
 
 /// Serializable value returned from query and field execution.
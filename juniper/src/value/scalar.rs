@@ -1,4 +1,4 @@
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, convert::TryFrom as _, fmt};
 
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -44,6 +44,7 @@ pub trait ParseScalarValue<S = DefaultScalarValue> {
 /// enum MyScalarValue {
 ///     #[value(as_float, as_int)]
 ///     Int(i32),
+///     #[value(as_big_int)]
 ///     Long(i64),
 ///     #[value(as_float)]
 ///     Float(f64),
@@ -208,10 +209,39 @@ pub trait ScalarValue:
     /// [`GraphQLValue`]: crate::GraphQLValue
     fn as_bool(&self) -> Option<bool>;
 
+    /// Represents this [`ScalarValue`] as an integer value wider than 32 bits.
+    ///
+    /// This function is used for implementing [`GraphQLValue`] for [`i64`],
+    /// [`u64`], [`i128`] and [`u128`] for all possible [`ScalarValue`]s. The
+    /// default implementation falls back to [`ScalarValue::as_int()`], so
+    /// implementations storing wider integers should override it.
+    ///
+    /// [`GraphQLValue`]: crate::GraphQLValue
+    #[must_use]
+    fn as_big_int(&self) -> Option<i128> {
+        self.as_int().map(i128::from)
+    }
+
+    /// Constructs a new [`ScalarValue`] holding the provided integer value
+    /// losslessly, if this [`ScalarValue`] is able to represent it.
+    ///
+    /// This function is used for resolving 64-bit (and wider) integer GraphQL
+    /// scalars. If [`None`] is returned, the value is represented as a decimal
+    /// [`String`] instead. The default implementation is only able to
+    /// represent values fitting into [`i32`].
+    #[must_use]
+    fn from_big_int(n: i128) -> Option<Self> {
+        i32::try_from(n).ok().map(Self::from)
+    }
+
     /// Converts this [`ScalarValue`] into another one.
     fn into_another<S: ScalarValue>(self) -> S {
         if let Some(i) = self.as_int() {
             S::from(i)
+        } else if let Some(n) = self.as_big_int() {
+            // Wide integers are also representable as floats, but lossy, so
+            // fall back to a decimal string instead.
+            S::from_big_int(n).unwrap_or_else(|| S::from(n.to_string()))
         } else if let Some(f) = self.as_float() {
             S::from(f)
         } else if let Some(b) = self.as_bool() {
@@ -260,6 +290,62 @@ pub enum DefaultScalarValue {
     Boolean(bool),
 }
 
+/// [`ScalarValue`] representation storing 64-bit integers losslessly.
+///
+/// Mirrors [`DefaultScalarValue`], but additionally holds integers not fitting
+/// into the [`Int` scalar][0] in a [`LongScalarValue::Long`] variant, so
+/// [`i64`] (and [`u64`] up to [`i64::MAX`]) values are resolved and serialized
+/// as plain numbers rather than decimal strings.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Int
+#[derive(Clone, Debug, PartialEq, ScalarValue, Serialize)]
+#[serde(untagged)]
+pub enum LongScalarValue {
+    /// [`Int` scalar][0] as a signed 32‐bit numeric non‐fractional value.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Int
+    #[value(as_float, as_int)]
+    Int(i32),
+
+    /// Signed 64‐bit numeric non‐fractional value not fitting into
+    /// [`LongScalarValue::Int`].
+    #[value(as_float = long_as_float, as_big_int)]
+    Long(i64),
+
+    /// [`Float` scalar][0] as a signed double‐precision fractional values as
+    /// specified by [IEEE 754].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Float
+    /// [IEEE 754]: https://en.wikipedia.org/wiki/IEEE_floating_point
+    #[value(as_float)]
+    Float(f64),
+
+    /// [`String` scalar][0] as a textual data, represented as UTF‐8 character
+    /// sequences.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-String
+    #[value(as_str, as_string, into_string)]
+    String(String),
+
+    /// [`Boolean` scalar][0] as a `true` or `false` value.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Boolean
+    #[value(as_bool)]
+    Boolean(bool),
+}
+
+/// Converts the provided [`LongScalarValue::Long`] into a float, as required
+/// by [`ScalarValue::as_float()`] contract.
+fn long_as_float(v: &i64) -> f64 {
+    *v as f64
+}
+
+impl<'a> From<&'a str> for LongScalarValue {
+    fn from(s: &'a str) -> Self {
+        Self::String(s.into())
+    }
+}
+
 impl<'a> From<&'a str> for DefaultScalarValue {
     fn from(s: &'a str) -> Self {
         Self::String(s.into())
@@ -4,8 +4,9 @@
 #![deny(warnings)]
 
 use actix_web::{
-    error::JsonPayloadError, http::Method, web, Error, FromRequest, HttpMessage, HttpRequest,
-    HttpResponse,
+    error::JsonPayloadError,
+    http::{header::CACHE_CONTROL, Method},
+    web, Error, FromRequest, HttpMessage, HttpRequest, HttpResponse,
 };
 use juniper::{
     http::{
@@ -16,13 +17,21 @@ use juniper::{
 };
 use serde::Deserialize;
 
+#[cfg(feature = "sse")]
+use juniper::http::sse;
+#[cfg(feature = "sse")]
+use std::{collections::HashMap, sync::Arc};
+
 #[derive(Deserialize, Clone, PartialEq, Debug)]
 #[serde(deny_unknown_fields)]
 struct GetGraphQLRequest {
+    #[serde(default)]
     query: String,
     #[serde(rename = "operationName")]
     operation_name: Option<String>,
     variables: Option<String>,
+    #[serde(rename = "documentId")]
+    document_id: Option<String>,
 }
 
 impl<S> From<GetGraphQLRequest> for GraphQLRequest<S>
@@ -34,9 +43,14 @@ where
             query,
             operation_name,
             variables,
+            document_id,
         } = get_req;
         let variables = variables.map(|s| serde_json::from_str(&s).unwrap());
-        Self::new(query, operation_name, variables)
+        let req = Self::new(query, operation_name, variables);
+        match document_id {
+            Some(id) => req.with_document_id(id),
+            None => req,
+        }
     }
 }
 
@@ -87,6 +101,9 @@ where
         true => HttpResponse::Ok(),
         false => HttpResponse::BadRequest(),
     };
+    if let Some(policy) = gql_response.cache_policy() {
+        response.insert_header((CACHE_CONTROL, policy.header_value()));
+    }
     Ok(response
         .content_type("application/json")
         .body(body_response))
@@ -129,9 +146,157 @@ where
         true => HttpResponse::Ok(),
         false => HttpResponse::BadRequest(),
     };
+    if let Some(policy) = gql_batch_response.cache_policy() {
+        response.insert_header((CACHE_CONTROL, policy.header_value()));
+    }
     Ok(response.content_type("application/json").body(gql_response))
 }
 
+/// Actix Web GraphQL Handler serving operations over [Server-Sent Events][1], following the
+/// [GraphQL over SSE protocol][2] in both "distinct connections" and "single connection" modes.
+///
+/// Requests carrying an event stream token are served in "single connection" mode via the
+/// provided `connections` registry, as well as `PUT` requests reserving such tokens. Any other
+/// `GET` or `POST` request is served in "distinct connections" mode.
+///
+/// See [`juniper::http::sse`] module for details.
+///
+/// [1]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+/// [2]: https://github.com/enisdenjo/graphql-sse/blob/master/PROTOCOL.md
+#[cfg(feature = "sse")]
+pub async fn graphql_sse_handler<Query, Mutation, Subscription, CtxT, S>(
+    schema: Arc<juniper::RootNode<'static, Query, Mutation, Subscription, S>>,
+    context: Arc<CtxT>,
+    connections: sse::SingleConnections<S>,
+    req: HttpRequest,
+    payload: actix_web::web::Payload,
+) -> Result<HttpResponse, Error>
+where
+    Query: juniper::GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Query::TypeInfo: Send + Sync,
+    Mutation: juniper::GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Mutation::TypeInfo: Send + Sync,
+    Subscription: juniper::GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    Subscription::TypeInfo: Send + Sync,
+    CtxT: Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+{
+    let qry = web::Query::<HashMap<String, String>>::from_query(req.query_string())?.into_inner();
+    let token = req
+        .headers()
+        .get(sse::TOKEN_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_owned)
+        .or_else(|| qry.get(sse::TOKEN_PARAM).cloned());
+
+    match (req.method().clone(), token) {
+        (Method::PUT, _) => Ok(match connections.reserve() {
+            Ok(token) => HttpResponse::Created()
+                .content_type("text/plain; charset=utf-8")
+                .body(token),
+            Err(e) => sse_error_response(e),
+        }),
+        (Method::GET, Some(token)) => Ok(match connections.open(&token) {
+            Ok(events) => sse_response(events),
+            Err(e) => sse_error_response(e),
+        }),
+        (Method::POST, Some(token)) => {
+            let body = String::from_request(&req, &mut payload.into_inner()).await?;
+            Ok(
+                match connections.execute(&token, schema, context, &body).await {
+                    Ok(()) => HttpResponse::Accepted().finish(),
+                    Err(e) => sse_error_response(e),
+                },
+            )
+        }
+        (Method::DELETE, Some(token)) => {
+            let id = qry
+                .get(sse::OPERATION_ID_PARAM)
+                .map(String::as_str)
+                .unwrap_or_default();
+            Ok(match connections.stop(&token, id) {
+                Ok(()) => HttpResponse::Ok().finish(),
+                Err(e) => sse_error_response(e),
+            })
+        }
+        (Method::GET, None) => {
+            let get_req = web::Query::<GetGraphQLRequest>::from_query(req.query_string())?;
+            sse_distinct_response(schema, context, get_req.into_inner().into()).await
+        }
+        (Method::POST, None) => {
+            let req = match req.content_type() {
+                "application/json" => {
+                    let body = String::from_request(&req, &mut payload.into_inner()).await?;
+                    match serde_json::from_str::<GraphQLBatchRequest<S>>(&body)
+                        .map_err(JsonPayloadError::Deserialize)?
+                    {
+                        GraphQLBatchRequest::Single(req) => req,
+                        GraphQLBatchRequest::Batch(_) => {
+                            return Ok(HttpResponse::BadRequest()
+                                .body("Batch requests are not supported over SSE"))
+                        }
+                    }
+                }
+                "application/graphql" => {
+                    let body = String::from_request(&req, &mut payload.into_inner()).await?;
+                    GraphQLRequest::new(body, None, None)
+                }
+                _ => return Err(JsonPayloadError::ContentType.into()),
+            };
+            sse_distinct_response(schema, context, req).await
+        }
+        _ => Err(actix_web::error::UrlGenerationError::ResourceNotFound.into()),
+    }
+}
+
+#[cfg(feature = "sse")]
+async fn sse_distinct_response<Query, Mutation, Subscription, CtxT, S>(
+    schema: Arc<juniper::RootNode<'static, Query, Mutation, Subscription, S>>,
+    context: Arc<CtxT>,
+    req: GraphQLRequest<S>,
+) -> Result<HttpResponse, Error>
+where
+    Query: juniper::GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Query::TypeInfo: Send + Sync,
+    Mutation: juniper::GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Mutation::TypeInfo: Send + Sync,
+    Subscription: juniper::GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    Subscription::TypeInfo: Send + Sync,
+    CtxT: Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+{
+    Ok(match sse::subscribe(schema, context, req).await {
+        Ok(events) => sse_response(events),
+        Err(rejection) => HttpResponse::BadRequest()
+            .content_type("application/json")
+            .body(rejection.into_body()),
+    })
+}
+
+#[cfg(feature = "sse")]
+fn sse_response<S: ScalarValue>(events: sse::EventStream<S>) -> HttpResponse {
+    use futures::StreamExt as _;
+
+    HttpResponse::Ok()
+        .content_type(sse::CONTENT_TYPE)
+        .insert_header(("cache-control", "no-cache"))
+        .streaming(events.map(|e| Ok::<_, Error>(web::Bytes::from(e.to_string()))))
+}
+
+#[cfg(feature = "sse")]
+fn sse_error_response(err: sse::SingleConnectionError) -> HttpResponse {
+    let code =
+        actix_web::http::StatusCode::from_u16(err.status_code()).expect("status code is valid");
+    match err {
+        sse::SingleConnectionError::Rejected(rejection) => HttpResponse::build(code)
+            .content_type("application/json")
+            .body(rejection.into_body()),
+        err => HttpResponse::build(code)
+            .content_type("text/plain; charset=utf-8")
+            .body(err.to_string()),
+    }
+}
+
 /// Create a handler that replies with an HTML page containing GraphiQL. This does not handle routing, so you can mount it on any endpoint
 ///
 /// For example:
@@ -442,7 +607,10 @@ mod tests {
     };
     use futures::future;
     use juniper::{
-        http::tests::{run_http_test_suite, HttpIntegration, TestResponse},
+        http::tests::{
+            run_http_test_suite, run_trusted_documents_test_suite, with_trusted_documents,
+            HttpIntegration, TestResponse,
+        },
         tests::fixtures::starwars::schema::{Database, Query},
         EmptyMutation, EmptySubscription, RootNode,
     };
@@ -679,7 +847,9 @@ mod tests {
         assert!(result.is_err());
     }
 
-    pub struct TestActixWebIntegration;
+    pub struct TestActixWebIntegration {
+        is_trusted: bool,
+    }
 
     impl TestActixWebIntegration {
         fn make_request(&self, req: TestRequest) -> TestResponse {
@@ -689,6 +859,11 @@ mod tests {
                     EmptyMutation::<Database>::new(),
                     EmptySubscription::<Database>::new(),
                 );
+                let schema = if self.is_trusted {
+                    with_trusted_documents(schema)
+                } else {
+                    schema
+                };
 
                 let mut app = test::init_service(
                     App::new()
@@ -746,7 +921,108 @@ mod tests {
 
     #[test]
     fn test_actix_web_integration() {
-        run_http_test_suite(&TestActixWebIntegration);
+        run_http_test_suite(&TestActixWebIntegration { is_trusted: false });
+    }
+
+    #[test]
+    fn test_trusted_actix_web_integration() {
+        run_trusted_documents_test_suite(&TestActixWebIntegration { is_trusted: true });
+    }
+}
+
+#[cfg(feature = "sse")]
+#[cfg(test)]
+mod sse_tests {
+    use std::{pin::Pin, sync::Arc};
+
+    use actix_http::body::MessageBody;
+    use actix_web::{http::header::CONTENT_TYPE, test::TestRequest, FromRequest, HttpResponse};
+    use futures::{stream, StreamExt as _};
+    use juniper::{
+        http::{
+            sse::SingleConnections,
+            tests::{run_sse_test_suite, SseIntegration, SseTestResponse},
+        },
+        tests::fixtures::starwars::schema::{Database, Query, Subscription},
+        EmptyMutation, LocalBoxFuture, RootNode,
+    };
+
+    use super::*;
+
+    type Schema = RootNode<'static, Query, EmptyMutation<Database>, Subscription>;
+
+    struct TestActixWebSseIntegration {
+        schema: Arc<Schema>,
+        context: Arc<Database>,
+        connections: SingleConnections,
+    }
+
+    impl SseIntegration for TestActixWebSseIntegration {
+        fn request<'a>(
+            &'a self,
+            method: &'a str,
+            url: &'a str,
+            headers: &'a [(&'a str, &'a str)],
+            body: Option<&'a str>,
+        ) -> LocalBoxFuture<'a, SseTestResponse> {
+            let mut req = TestRequest::default()
+                .method(method.parse().unwrap())
+                .uri(url);
+            for header in headers {
+                req = req.append_header(*header);
+            }
+            if let Some(body) = body {
+                req = req.set_payload(body.to_owned());
+            }
+
+            Box::pin(async move {
+                let (req, mut payload) = req.to_http_parts();
+                let payload = web::Payload::from_request(&req, &mut payload)
+                    .await
+                    .unwrap();
+                let resp = graphql_sse_handler(
+                    self.schema.clone(),
+                    self.context.clone(),
+                    self.connections.clone(),
+                    req,
+                    payload,
+                )
+                .await
+                .unwrap_or_else(|e| HttpResponse::from_error(e));
+
+                let status_code = resp.status().as_u16() as i32;
+                let content_type = resp
+                    .headers()
+                    .get(CONTENT_TYPE)
+                    .map(|ct| ct.to_str().unwrap().to_owned())
+                    .unwrap_or_default();
+                let mut body = resp.into_body();
+                let body = stream::poll_fn(move |cx| Pin::new(&mut body).poll_next(cx))
+                    .map(|chunk| String::from_utf8(chunk.unwrap().to_vec()).unwrap())
+                    .boxed_local();
+
+                SseTestResponse {
+                    status_code,
+                    content_type,
+                    body,
+                }
+            })
+        }
+    }
+
+    #[actix_web::rt::test]
+    async fn test_actix_web_sse_integration() {
+        let integration = TestActixWebSseIntegration {
+            schema: Arc::new(Schema::new(
+                Query,
+                EmptyMutation::<Database>::new(),
+                Subscription,
+            )),
+            context: Arc::new(Database::new()),
+            connections: SingleConnections::new(),
+        };
+
+        run_sse_test_suite(&integration).await;
     }
 }
 
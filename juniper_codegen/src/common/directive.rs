@@ -0,0 +1,157 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of applied [GraphQL type system directives][1].
+//!
+//! [1]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream},
+    token,
+};
+
+use crate::common::parse::ParseBufferExt as _;
+
+/// [GraphQL type system directive][1] applied to a schema element via
+/// `#[graphql(directive = ...)]` attribute argument.
+///
+/// Mirrors [SDL] syntax, so it's parsed from the following forms:
+/// - `directive = name`;
+/// - `directive = @name`;
+/// - `directive = name(arg: value, ...)`, where `value` is any input accepted
+///   by the `graphql_input_value!` macro.
+///
+/// [SDL]: https://graphql.org/learn/schema/#type-language
+/// [1]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+#[derive(Clone, Debug)]
+pub(crate) struct Directive {
+    /// Name of this [GraphQL directive][1] (without the leading `@`).
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) name: syn::Ident,
+
+    /// Arguments this [GraphQL directive][1] is applied with, as names and
+    /// `graphql_input_value!` macro inputs.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) arguments: Vec<(syn::Ident, TokenStream)>,
+}
+
+impl Parse for Directive {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        input.try_parse::<token::At>()?;
+        let name = input.parse_any_ident()?;
+
+        let mut arguments = vec![];
+        if input.is_next::<token::Paren>() {
+            let args;
+            let _ = syn::parenthesized!(args in input);
+            while !args.is_empty() {
+                let arg = args.parse_any_ident()?;
+                args.parse::<token::Colon>()?;
+                let value = args.step(|cursor| {
+                    let mut value = TokenStream::new();
+                    let mut rest = *cursor;
+                    while let Some((tt, next)) = rest.token_tree() {
+                        match &tt {
+                            TokenTree::Punct(p) if p.as_char() == ',' => break,
+                            _ => {
+                                value.extend(Some(tt));
+                                rest = next;
+                            }
+                        }
+                    }
+                    Ok((value, rest))
+                })?;
+                if value.is_empty() {
+                    return Err(syn::Error::new(
+                        arg.span(),
+                        "directive argument value is expected",
+                    ));
+                }
+                arguments.push((arg, value));
+                args.try_parse::<token::Comma>()?;
+            }
+        }
+
+        Ok(Self { name, arguments })
+    }
+}
+
+impl Directive {
+    /// Parses the `@cacheControl` [`Directive`] from the
+    /// `#[graphql(cache_control(max_age = 60, scope = "private"))]` attribute
+    /// argument, where both `max_age` and `scope` are optional, and `scope` is
+    /// either `"public"` or `"private"`.
+    pub(crate) fn parse_cache_control(input: ParseStream<'_>) -> syn::Result<Self> {
+        let name = syn::Ident::new("cacheControl", input.span());
+
+        let mut arguments = vec![];
+        let args;
+        let _ = syn::parenthesized!(args in input);
+        while !args.is_empty() {
+            let arg = args.parse_any_ident()?;
+            args.parse::<token::Eq>()?;
+            match arg.to_string().as_str() {
+                "max_age" => {
+                    let lit = args.parse::<syn::LitInt>()?;
+                    let _ = lit.base10_parse::<u32>()?;
+                    arguments.push((syn::Ident::new("maxAge", arg.span()), quote! { #lit }));
+                }
+                "scope" => {
+                    let lit = args.parse::<syn::LitStr>()?;
+                    let scope = match lit.value().as_str() {
+                        "public" => quote! { PUBLIC },
+                        "private" => quote! { PRIVATE },
+                        _ => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "expected \"public\" or \"private\" cache scope",
+                            ))
+                        }
+                    };
+                    arguments.push((syn::Ident::new("scope", arg.span()), scope));
+                }
+                name => {
+                    return Err(syn::Error::new(
+                        arg.span(),
+                        format!("unknown `cache_control` argument: {}", name),
+                    ))
+                }
+            }
+            args.try_parse::<token::Comma>()?;
+        }
+
+        Ok(Self { name, arguments })
+    }
+
+    /// Returns generated code applying this [`Directive`] to a schema element
+    /// being built in the [`GraphQLType::meta`] method.
+    ///
+    /// [`GraphQLType::meta`]: juniper::GraphQLType::meta
+    #[must_use]
+    pub(crate) fn method_meta_tokens(&self) -> TokenStream {
+        let name = self.name.unraw().to_string();
+        let args = self.arguments.iter().map(|(arg, value)| {
+            let arg = arg.unraw().to_string();
+            quote! { .argument(#arg, ::juniper::graphql_input_value!(#value)) }
+        });
+
+        quote! {
+            .directive(::juniper::meta::AppliedDirective::new(#name)#( #args )*)
+        }
+    }
+}
+
+/// Returns generated code applying all the given [`Directive`]s to a schema
+/// element being built in the [`GraphQLType::meta`] method.
+///
+/// [`GraphQLType::meta`]: juniper::GraphQLType::meta
+#[must_use]
+pub(crate) fn meta_tokens(directives: &[Directive]) -> TokenStream {
+    directives
+        .iter()
+        .map(Directive::method_meta_tokens)
+        .collect()
+}
@@ -5,7 +5,7 @@
 
 use std::mem;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
 use syn::{
     ext::IdentExt as _,
@@ -16,11 +16,13 @@ use syn::{
 
 use crate::{
     common::{
+        directive::{self, Directive},
         parse::{
             attr::{err, OptionExt as _},
             ParseBufferExt as _, TypeExt as _,
         },
         scalar,
+        validator::{self, Validator},
     },
     result::GraphQLScope,
     util::{filter_attrs, path_eq_single, span_container::SpanContainer, RenameRule},
@@ -58,6 +60,18 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/June2018/#sec-Required-Arguments
     pub(crate) default: Option<SpanContainer<Option<syn::Expr>>>,
 
+    /// Explicitly specified [type system directives][2] applied to this
+    /// [GraphQL argument][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Vec<Directive>,
+
+    /// Explicitly specified [`Validator`]s of this [GraphQL argument][1] value.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
+    pub(crate) validators: Vec<(Span, Validator)>,
+
     /// Explicitly specified marker indicating that this method argument doesn't
     /// represent a [GraphQL argument][1], but is a [`Context`] being injected
     /// into a [GraphQL field][2] resolving function.
@@ -121,6 +135,13 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse::<Directive>()?);
+                }
+                "validate" => {
+                    out.validators.extend(Validator::parse_list(input)?);
+                }
                 "ctx" | "context" | "Context" => {
                     let span = ident.span();
                     out.context
@@ -151,6 +172,16 @@ impl Attr {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
             default: try_merge_opt!(default: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            validators: self
+                .validators
+                .into_iter()
+                .chain(another.validators)
+                .collect(),
             context: try_merge_opt!(context: self, another),
             executor: try_merge_opt!(executor: self, another),
         })
@@ -167,6 +198,8 @@ impl Attr {
             if attr.name.is_some()
                 || attr.description.is_some()
                 || attr.default.is_some()
+                || !attr.directives.is_empty()
+                || !attr.validators.is_empty()
                 || attr.executor.is_some()
             {
                 return Err(syn::Error::new(
@@ -180,6 +213,8 @@ impl Attr {
             if attr.name.is_some()
                 || attr.description.is_some()
                 || attr.default.is_some()
+                || !attr.directives.is_empty()
+                || !attr.validators.is_empty()
                 || attr.context.is_some()
             {
                 return Err(syn::Error::new(
@@ -204,6 +239,12 @@ impl Attr {
         if let Some(span) = &self.default {
             return Err(Self::err_disallowed(&span, "default"));
         }
+        if let Some(d) = self.directives.first() {
+            return Err(Self::err_disallowed(&d.name, "directive"));
+        }
+        if let Some((span, _)) = self.validators.first() {
+            return Err(Self::err_disallowed(span, "validate"));
+        }
         Ok(())
     }
 
@@ -224,7 +265,7 @@ impl Attr {
 /// Representation of a [GraphQL field argument][1] for code generation.
 ///
 /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct OnField {
     /// Rust type that this [GraphQL field argument][1] is represented by.
     ///
@@ -253,10 +294,22 @@ pub(crate) struct OnField {
     /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
     /// [2]: https://spec.graphql.org/June2018/#sec-Required-Arguments
     pub(crate) default: Option<Option<syn::Expr>>,
+
+    /// [Type system directives][2] applied to this [GraphQL field argument][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Vec<Directive>,
+
+    /// [`Validator`]s of this [GraphQL field argument][1] value, checked
+    /// before resolving the field.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Arguments
+    pub(crate) validators: Vec<(Span, Validator)>,
 }
 
 /// Possible kinds of Rust method arguments for code generation.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) enum OnMethod {
     /// Regular [GraphQL field argument][1].
     ///
@@ -340,7 +393,9 @@ impl OnMethod {
             quote! { .arg::<#ty>(#name, info) }
         };
 
-        Some(quote! { .argument(registry#method#description) })
+        let directives = directive::meta_tokens(&arg.directives);
+
+        Some(quote! { .argument(registry#method#description#directives) })
     }
 
     /// Returns generated code for the [`GraphQLValue::resolve_field`] method,
@@ -356,10 +411,10 @@ impl OnMethod {
     ) -> TokenStream {
         match self {
             Self::Regular(arg) => {
-                let (name, ty) = (&arg.name, &arg.ty);
+                let (name, ty, validators) = (&arg.name, &arg.ty, &arg.validators);
                 let err_text = format!("Missing argument `{}`: {{}}", &name);
 
-                let arg = quote! {
+                let mut arg = quote! {
                     args.get::<#ty>(#name).and_then(|opt| opt.map_or_else(|| {
                         <#ty as ::juniper::FromInputValue<#scalar>>::from_implicit_null()
                             .map_err(|e| {
@@ -368,6 +423,15 @@ impl OnMethod {
                             })
                     }, Ok))
                 };
+                if !validators.is_empty() {
+                    let validate = validator::validate_argument_tokens(name, validators);
+                    arg = quote! {
+                        #arg.and_then(|v| {
+                            #validate
+                            Ok(v)
+                        })
+                    };
+                }
                 if for_async {
                     quote! {
                         match #arg {
@@ -459,11 +523,16 @@ impl OnMethod {
             return None;
         }
 
+        let mut directives = attr.directives;
+        directives.extend(validator::constraint_directive(&attr.validators));
+
         Some(Self::Regular(Box::new(OnField {
             name,
             ty: argument.ty.as_ref().clone(),
             description: attr.description.as_ref().map(|d| d.as_ref().value()),
             default: attr.default.as_ref().map(|v| v.as_ref().clone()),
+            directives,
+            validators: attr.validators,
         })))
     }
 }
@@ -16,6 +16,7 @@ use syn::{
 
 use crate::{
     common::{
+        directive::{self, Directive},
         parse::{
             attr::{err, OptionExt as _},
             ParseBufferExt as _,
@@ -58,12 +59,45 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/June2018/#sec-Deprecation
     pub(crate) deprecated: Option<SpanContainer<Option<syn::LitStr>>>,
 
+    /// Explicitly specified [type system directives][2] applied to this
+    /// [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Vec<Directive>,
+
+    /// Explicitly specified [`Guard`] checked before resolving this
+    /// [GraphQL field][1].
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) guard: Option<SpanContainer<syn::Expr>>,
+
     /// Explicitly specified marker indicating that this method (or struct
     /// field) should be omitted by code generation and not considered as the
     /// [GraphQL field][1] definition.
     ///
     /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
     pub(crate) ignore: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified marker indicating that the [GraphQL fields][1] of
+    /// this struct field's type should be inlined into the parent
+    /// [GraphQL object][2], rather than this struct field being considered as
+    /// a [GraphQL field][1] itself.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) flatten: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified function resolving this [GraphQL field][1] from
+    /// the struct field's value, instead of using the value as is.
+    ///
+    /// The [GraphQL type][2] of the field is inferred from the return type of
+    /// this function.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/June2018/#sec-Types
+    pub(crate) with: Option<SpanContainer<syn::Expr>>,
 }
 
 impl Parse for Attr {
@@ -100,10 +134,35 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse::<Directive>()?);
+                }
+                "cache_control" => {
+                    out.directives.push(Directive::parse_cache_control(input)?);
+                }
+                "guard" => {
+                    input.parse::<token::Eq>()?;
+                    let guard = input.parse::<syn::Expr>()?;
+                    out.guard
+                        .replace(SpanContainer::new(ident.span(), Some(guard.span()), guard))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "with" | "resolve" => {
+                    input.parse::<token::Eq>()?;
+                    let with = input.parse::<syn::Expr>()?;
+                    out.with
+                        .replace(SpanContainer::new(ident.span(), Some(with.span()), with))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ignore" | "skip" => out
                     .ignore
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
                     .none_or_else(|_| err::dup_arg(&ident))?,
+                "flatten" => out
+                    .flatten
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -122,7 +181,15 @@ impl Attr {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
             deprecated: try_merge_opt!(deprecated: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            guard: try_merge_opt!(guard: self, another),
             ignore: try_merge_opt!(ignore: self, another),
+            flatten: try_merge_opt!(flatten: self, another),
+            with: try_merge_opt!(with: self, another),
         })
     }
 
@@ -136,7 +203,14 @@ impl Attr {
             .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
 
         if let Some(ignore) = &attr.ignore {
-            if attr.name.is_some() || attr.description.is_some() || attr.deprecated.is_some() {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.deprecated.is_some()
+                || !attr.directives.is_empty()
+                || attr.guard.is_some()
+                || attr.flatten.is_some()
+                || attr.with.is_some()
+            {
                 return Err(syn::Error::new(
                     ignore.span(),
                     "`ignore` attribute argument is not composable with any other arguments",
@@ -144,6 +218,21 @@ impl Attr {
             }
         }
 
+        if let Some(flatten) = &attr.flatten {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.deprecated.is_some()
+                || !attr.directives.is_empty()
+                || attr.guard.is_some()
+                || attr.with.is_some()
+            {
+                return Err(syn::Error::new(
+                    flatten.span(),
+                    "`flatten` attribute argument is not composable with any other arguments",
+                ));
+            }
+        }
+
         if attr.description.is_none() {
             attr.description = get_doc_comment(attrs).map(|sc| {
                 let span = sc.span_ident();
@@ -165,7 +254,7 @@ impl Attr {
 /// Representation of a [GraphQL field][1] for code generation.
 ///
 /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct Definition {
     /// Rust type that this [GraphQL field][1] is represented by (method return
     /// type or struct field type).
@@ -193,6 +282,19 @@ pub(crate) struct Definition {
     /// [2]: https://spec.graphql.org/June2018/#sec-Deprecation
     pub(crate) deprecated: Option<Option<String>>,
 
+    /// [Type system directives][2] applied to this [GraphQL field][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    pub(crate) directives: Vec<Directive>,
+
+    /// [`Guard`]s checked (in order) before resolving this
+    /// [GraphQL field][1].
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) guards: Vec<syn::Expr>,
+
     /// Ident of the Rust method (or struct field) representing this
     /// [GraphQL field][1].
     ///
@@ -219,6 +321,17 @@ pub(crate) struct Definition {
     ///
     /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
     pub(crate) is_async: bool,
+
+    /// Function resolving this [GraphQL field][1] from the value of the struct
+    /// field representing it.
+    ///
+    /// If [`Some`], then the [GraphQL type][2] of this [GraphQL field][1] is
+    /// inferred from the return type of this function, rather than from the
+    /// [`Definition::ty`].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/June2018/#sec-Types
+    pub(crate) with: Option<syn::Expr>,
 }
 
 impl Definition {
@@ -231,6 +344,55 @@ impl Definition {
         self.arguments.is_some()
     }
 
+    /// Returns the provided generated code resolving this [GraphQL field][1]
+    /// synchronously, preceded by the checks of its [`Guard`]s.
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    #[must_use]
+    pub(crate) fn guarded_resolve_tokens(
+        &self,
+        scalar: &scalar::Type,
+        resolve: TokenStream,
+    ) -> TokenStream {
+        let guards = &self.guards;
+        quote! {
+            #( ::juniper::Guard::<_, #scalar>::check(&(#guards), executor.context(), args)?; )*
+            #resolve
+        }
+    }
+
+    /// Returns the provided generated code resolving this [GraphQL field][1]
+    /// asynchronously into a [`BoxFuture`], preceded by the checks of its
+    /// [`Guard`]s.
+    ///
+    /// [`BoxFuture`]: juniper::BoxFuture
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    #[must_use]
+    pub(crate) fn guarded_resolve_async_tokens(
+        &self,
+        scalar: &scalar::Type,
+        resolve: TokenStream,
+    ) -> TokenStream {
+        if self.guards.is_empty() {
+            return resolve;
+        }
+
+        let guards = &self.guards;
+        quote! {
+            Box::pin(async move {
+                #( ::juniper::Guard::<_, #scalar>::check_async(
+                    &(#guards), executor.context(), args,
+                ).await?; )*
+                let resolve = || -> ::juniper::BoxFuture<'_, ::juniper::ExecutionResult<#scalar>> {
+                    #resolve
+                };
+                resolve().await
+            })
+        }
+    }
+
     /// Returns generated code that errors about unknown [GraphQL field][1]
     /// tried to be resolved in the [`GraphQLValue::resolve_field`] method.
     ///
@@ -268,6 +430,14 @@ impl Definition {
             .flat_map(|args| args.iter().filter_map(|a| a.method_mark_tokens(scalar)));
 
         let ty = &self.ty;
+        if let Some(with) = &self.with {
+            return quote_spanned! { with.span() =>
+                ::juniper::macros::helper::resolver::mark_output::<
+                    _, #ty, <Self as ::juniper::GraphQLValue<#scalar>>::Context, #scalar, _, _,
+                >(&(#with));
+            };
+        }
+
         let mut ty = quote! { #ty };
         if infer_result {
             ty = quote! {
@@ -323,11 +493,24 @@ impl Definition {
             .iter()
             .flat_map(|args| args.iter().filter_map(MethodArgument::method_meta_tokens));
 
+        let directives = directive::meta_tokens(&self.directives);
+
+        let field = if let Some(with) = &self.with {
+            quote! {
+                ::juniper::macros::helper::resolver::output_field::<
+                    _, #ty, Self::Context, _, _, _,
+                >(&(#with), #name, info, registry)
+            }
+        } else {
+            quote! { registry.field_convert::<#ty, _, Self::Context>(#name, info) }
+        };
+
         quote! {
-            registry.field_convert::<#ty, _, Self::Context>(#name, info)
+            #field
                 #( #args )*
                 #description
                 #deprecated
+                #directives
         }
     }
 
@@ -366,9 +549,14 @@ impl Definition {
             fut = quote! { ::juniper::futures::future::ready(#fut) };
         }
 
+        let guards = &self.guards;
+
         quote! {
             #name => {
                 ::juniper::futures::FutureExt::boxed(async move {
+                    #( ::juniper::Guard::<_, #scalar>::check_async(
+                        &(#guards), executor.context(), &args,
+                    ).await?; )*
                     let res: #ty = #fut.await;
                     let res = ::juniper::IntoFieldResult::<_, #scalar>::into_result(res)?;
                     let executor = executor.as_owned_executor();
@@ -408,3 +596,16 @@ pub(crate) fn all_different(fields: &[Definition]) -> bool {
     names.dedup();
     names.len() == fields.len()
 }
+
+/// Prepends the provided [`Guard`], specified on a whole GraphQL type, to the
+/// ones of each of its [GraphQL fields][1], so it's checked first.
+///
+/// [`Guard`]: juniper::Guard
+/// [1]: https://spec.graphql.org/June2018/#sec-Language.Fields
+pub(crate) fn prepend_guard(fields: &mut [Definition], guard: Option<&syn::Expr>) {
+    if let Some(guard) = guard {
+        for f in fields {
+            f.guards.insert(0, guard.clone());
+        }
+    }
+}
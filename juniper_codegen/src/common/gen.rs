@@ -1,7 +1,7 @@
 //! Common code generated parts, used by this crate.
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 /// Generate the code resolving some [GraphQL type][1] in a synchronous manner.
 ///
@@ -45,3 +45,44 @@ pub(crate) fn async_resolving_code(ty: Option<&syn::Type>) -> TokenStream {
         }))
     }
 }
+
+/// Generate the code concatenating the given `parts` into a single [`Names`]
+/// in a `const` context.
+///
+/// Each of the `parts` should be a `const` expression of [`Names`] type, not
+/// depending on any generic parameters.
+///
+/// [`Names`]: juniper::macros::reflect::Names
+pub(crate) fn concat_names(parts: Vec<TokenStream>) -> TokenStream {
+    if parts.len() < 2 {
+        return parts.into_iter().next().unwrap_or_else(|| quote! { &[] });
+    }
+
+    let last = format_ident!("NAMES_{}", parts.len() - 1);
+    let consts = parts.into_iter().enumerate().map(|(i, part)| {
+        let names = format_ident!("NAMES_{}", i);
+        if i == 0 {
+            return quote! {
+                const #names: ::juniper::macros::reflect::Names = #part;
+            };
+        }
+        let (prev, part_ident, all) = (
+            format_ident!("NAMES_{}", i - 1),
+            format_ident!("PART_{}", i),
+            format_ident!("ALL_{}", i),
+        );
+        quote! {
+            const #part_ident: ::juniper::macros::reflect::Names = #part;
+            const #all: [
+                ::juniper::macros::reflect::Name;
+                #prev.len() + #part_ident.len()
+            ] = ::juniper::macros::reflect::concat_names(#prev, #part_ident);
+            const #names: ::juniper::macros::reflect::Names = &#all;
+        }
+    });
+
+    quote! {{
+        #( #consts )*
+        #last
+    }}
+}
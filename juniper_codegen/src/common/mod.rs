@@ -1,6 +1,9 @@
 //! Common functions, definitions and extensions for code generation, used by this crate.
 
+pub(crate) mod directive;
 pub(crate) mod field;
 pub(crate) mod gen;
 pub(crate) mod parse;
+pub(crate) mod remote;
 pub(crate) mod scalar;
+pub(crate) mod validator;
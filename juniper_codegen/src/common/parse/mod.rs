@@ -318,6 +318,23 @@ impl GenericsExt for syn::Generics {
     }
 }
 
+/// Replaces type parameters with the provided concrete [`syn::Type`]s.
+pub(crate) struct ReplaceTypeParams<'a>(pub(crate) &'a [(syn::Ident, syn::Type)]);
+
+impl<'a> VisitMut for ReplaceTypeParams<'a> {
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if let syn::Type::Path(p) = ty {
+            if let Some(ident) = p.path.get_ident().filter(|_| p.qself.is_none()) {
+                if let Some((_, concrete)) = self.0.iter().find(|(param, _)| param == ident) {
+                    *ty = concrete.clone();
+                    return;
+                }
+            }
+        }
+        syn::visit_mut::visit_type_mut(self, ty)
+    }
+}
+
 /// Replaces [`Generics`] with default values:
 /// - `'static` for [`Lifetime`]s;
 /// - `::juniper::DefaultScalarValue` for [`Type`]s.
@@ -0,0 +1,115 @@
+//! Common functions, definitions and extensions for code generation of local
+//! proxies mirroring remote (foreign) types, used by this crate.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse::ParseStream, spanned::Spanned as _};
+
+/// Parses the value of a `remote` attribute's argument, being either a
+/// [`syn::Type`], or a string literal containing it.
+pub(crate) fn parse_type(input: ParseStream<'_>) -> syn::Result<syn::Type> {
+    if input.peek(syn::LitStr) {
+        input.parse::<syn::LitStr>()?.parse()
+    } else {
+        input.parse()
+    }
+}
+
+/// Generates code of [`From`] conversions between the `remote` type and the
+/// local type, mirroring it with the same shape of the provided `data`.
+///
+/// Each field is converted with [`Into`], so a field of a remote type may be
+/// mirrored by a field of its local proxy.
+#[must_use]
+pub(crate) fn impl_from_tokens(
+    local: &syn::Ident,
+    generics: &syn::Generics,
+    remote: &syn::Type,
+    data: &syn::Data,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let local_ty = quote! { #local#ty_generics };
+
+    let (from_remote, into_remote) = match data {
+        syn::Data::Struct(data) => {
+            let members: Vec<_> = members(&data.fields).collect();
+            (
+                quote! {
+                    Self { #( #members: ::std::convert::Into::into(remote.#members), )* }
+                },
+                quote! {
+                    Self { #( #members: ::std::convert::Into::into(local.#members), )* }
+                },
+            )
+        }
+        syn::Data::Enum(data) => {
+            let (from_remote, into_remote): (Vec<_>, Vec<_>) = data
+                .variants
+                .iter()
+                .map(|v| {
+                    let variant = &v.ident;
+                    let members: Vec<_> = members(&v.fields).collect();
+                    let bindings: Vec<_> = (0..members.len())
+                        .map(|i| format_ident!("__field{}", i))
+                        .collect();
+                    (
+                        quote! {
+                            __Remote::#variant { #( #members: #bindings, )* } => Self::#variant {
+                                #( #members: ::std::convert::Into::into(#bindings), )*
+                            },
+                        },
+                        quote! {
+                            #local::#variant { #( #members: #bindings, )* } => Self::#variant {
+                                #( #members: ::std::convert::Into::into(#bindings), )*
+                            },
+                        },
+                    )
+                })
+                .unzip();
+            (
+                quote! {
+                    type __Remote = #remote;
+                    match remote {
+                        #( #from_remote )*
+                    }
+                },
+                quote! {
+                    match local {
+                        #( #into_remote )*
+                    }
+                },
+            )
+        }
+        syn::Data::Union(_) => {
+            return syn::Error::new(remote.span(), "remote unions are not supported")
+                .to_compile_error()
+        }
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl#impl_generics ::std::convert::From<#remote> for #local_ty #where_clause {
+            fn from(remote: #remote) -> Self {
+                #from_remote
+            }
+        }
+
+        #[automatically_derived]
+        impl#impl_generics ::std::convert::From<#local_ty> for #remote #where_clause {
+            fn from(local: #local_ty) -> Self {
+                #into_remote
+            }
+        }
+    }
+}
+
+/// Returns [`syn::Member`]s of the provided [`syn::Fields`], both named and
+/// unnamed ones.
+fn members(fields: &syn::Fields) -> impl Iterator<Item = syn::Member> + '_ {
+    fields.iter().enumerate().map(|(i, f)| {
+        f.ident
+            .clone()
+            .map(syn::Member::Named)
+            .unwrap_or_else(|| syn::Member::Unnamed(i.into()))
+    })
+}
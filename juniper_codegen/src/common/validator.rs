@@ -0,0 +1,228 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of validators of [GraphQL arguments][1] and [input object fields][2].
+//!
+//! [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+//! [2]: https://spec.graphql.org/October2021#sec-Input-Objects
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
+use syn::{parse::ParseStream, token};
+
+use crate::common::{directive::Directive, parse::ParseBufferExt as _};
+
+/// Validator of a [GraphQL argument][1] or an [input object field][2] value,
+/// specified in a `#[graphql(validate(...))]` attribute argument.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+/// [2]: https://spec.graphql.org/October2021#sec-Input-Objects
+#[derive(Clone, Debug)]
+pub(crate) enum Validator {
+    /// `min = <number>` validator.
+    Min(f64),
+
+    /// `max = <number>` validator.
+    Max(f64),
+
+    /// `min_length = <int>` validator.
+    MinLength(syn::LitInt),
+
+    /// `max_length = <int>` validator.
+    MaxLength(syn::LitInt),
+
+    /// `regex = "<pattern>"` validator.
+    Regex(syn::LitStr),
+
+    /// `email` validator.
+    Email,
+
+    /// `url` validator.
+    Url,
+
+    /// `min_items = <int>` validator.
+    MinItems(syn::LitInt),
+
+    /// `max_items = <int>` validator.
+    MaxItems(syn::LitInt),
+
+    /// `custom = <function>` validator.
+    Custom(Box<syn::Expr>),
+}
+
+impl Validator {
+    /// Parses the [`Validator`]s listed in the parentheses of the
+    /// `#[graphql(validate(...))]` attribute argument.
+    pub(crate) fn parse_list(input: ParseStream<'_>) -> syn::Result<Vec<(Span, Self)>> {
+        let mut out = vec![];
+        let list;
+        let _ = syn::parenthesized!(list in input);
+        while !list.is_empty() {
+            let ident = list.parse_any_ident()?;
+            let validator = match ident.to_string().as_str() {
+                "email" => Self::Email,
+                "url" => Self::Url,
+                name => {
+                    list.parse::<token::Eq>()?;
+                    match name {
+                        "min" => Self::Min(parse_number(&list)?),
+                        "max" => Self::Max(parse_number(&list)?),
+                        "min_length" => Self::MinLength(parse_count(&list)?),
+                        "max_length" => Self::MaxLength(parse_count(&list)?),
+                        "regex" => Self::Regex(list.parse()?),
+                        "min_items" => Self::MinItems(parse_count(&list)?),
+                        "max_items" => Self::MaxItems(parse_count(&list)?),
+                        "custom" => Self::Custom(list.parse()?),
+                        _ => {
+                            return Err(syn::Error::new(
+                                ident.span(),
+                                format!("unknown validator: {}", name),
+                            ))
+                        }
+                    }
+                }
+            };
+            if out.iter().any(|(_, v)| Self::same_kind(v, &validator)) {
+                return Err(syn::Error::new(ident.span(), "duplicated validator"));
+            }
+            out.push((ident.span(), validator));
+            list.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+
+    /// Indicates whether the provided [`Validator`]s are of the same kind.
+    fn same_kind(a: &Self, b: &Self) -> bool {
+        std::mem::discriminant(a) == std::mem::discriminant(b)
+    }
+
+    /// Returns generated code of a reference to the `juniper::Validator`
+    /// represented by this [`Validator`].
+    #[must_use]
+    pub(crate) fn expr_tokens(&self) -> TokenStream {
+        let module = quote! { ::juniper::executor::validators };
+        match self {
+            Self::Min(n) => {
+                let n = float_tokens(*n);
+                quote! { &#module::min(#n) }
+            }
+            Self::Max(n) => {
+                let n = float_tokens(*n);
+                quote! { &#module::max(#n) }
+            }
+            Self::MinLength(n) => quote! { &#module::min_length(#n) },
+            Self::MaxLength(n) => quote! { &#module::max_length(#n) },
+            Self::Regex(pattern) => quote! {{
+                static PATTERN: #module::Pattern = #module::regex(#pattern);
+                &PATTERN
+            }},
+            Self::Email => quote! { &#module::email() },
+            Self::Url => quote! { &#module::url() },
+            Self::MinItems(n) => quote! { &#module::min_items(#n) },
+            Self::MaxItems(n) => quote! { &#module::max_items(#n) },
+            Self::Custom(f) => quote! { &#module::custom(#f) },
+        }
+    }
+
+    /// Returns the `@constraint` argument exposing this [`Validator`] in the
+    /// schema, if it's a built-in one.
+    fn constraint_argument(&self) -> Option<(&'static str, TokenStream)> {
+        Some(match self {
+            Self::Min(n) => ("min", float_tokens(*n)),
+            Self::Max(n) => ("max", float_tokens(*n)),
+            Self::MinLength(n) => ("minLength", quote! { #n }),
+            Self::MaxLength(n) => ("maxLength", quote! { #n }),
+            Self::Regex(pattern) => ("pattern", quote! { #pattern }),
+            Self::Email => ("format", quote! { "email" }),
+            Self::Url => ("format", quote! { "url" }),
+            Self::MinItems(n) => ("minItems", quote! { #n }),
+            Self::MaxItems(n) => ("maxItems", quote! { #n }),
+            Self::Custom(_) => return None,
+        })
+    }
+}
+
+/// Returns the `@constraint` [`Directive`] exposing the provided built-in
+/// [`Validator`]s in the schema, if there are any.
+///
+/// As `@constraint` has a single `format` argument, only the first of the
+/// `email` and `url` validators is exposed.
+#[must_use]
+pub(crate) fn constraint_directive(validators: &[(Span, Validator)]) -> Option<Directive> {
+    let mut arguments: Vec<(syn::Ident, TokenStream)> = vec![];
+    for (span, v) in validators {
+        if let Some((name, value)) = v.constraint_argument() {
+            if arguments.iter().all(|(arg, _)| arg != name) {
+                arguments.push((syn::Ident::new(name, *span), value));
+            }
+        }
+    }
+    let span = validators.first()?.0;
+    (!arguments.is_empty()).then(|| Directive {
+        name: syn::Ident::new("constraint", span),
+        arguments,
+    })
+}
+
+/// Returns generated code validating the `v` variable holding the value of
+/// the [GraphQL argument][1] with the provided `name`, failing with a
+/// `juniper::FieldError`.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+#[must_use]
+pub(crate) fn validate_argument_tokens(
+    name: &str,
+    validators: &[(Span, Validator)],
+) -> TokenStream {
+    let validators = validators.iter().map(|(_, v)| v.expr_tokens());
+    quote! {
+        #( ::juniper::executor::validators::validate_argument(#name, &v, #validators)?; )*
+    }
+}
+
+/// Returns generated code validating the `v` variable holding the value of
+/// the [input object field][1] with the provided `name`, failing with a
+/// `juniper::FieldError`.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+#[must_use]
+pub(crate) fn validate_input_field_tokens(
+    name: &str,
+    validators: &[(Span, Validator)],
+) -> TokenStream {
+    let validators = validators.iter().map(|(_, v)| v.expr_tokens());
+    quote! {
+        #( ::juniper::executor::validators::validate_input_field(#name, &v, #validators)?; )*
+    }
+}
+
+/// Parses a (possibly negative) number literal.
+fn parse_number(input: ParseStream<'_>) -> syn::Result<f64> {
+    let neg = input.try_parse::<token::Sub>()?.is_some();
+    let n = if input.peek(syn::LitFloat) {
+        input.parse::<syn::LitFloat>()?.base10_parse::<f64>()?
+    } else {
+        input.parse::<syn::LitInt>()?.base10_parse::<f64>()?
+    };
+    Ok(if neg { -n } else { n })
+}
+
+/// Parses a non-negative integer literal fitting into a GraphQL `Int`.
+fn parse_count(input: ParseStream<'_>) -> syn::Result<syn::LitInt> {
+    let lit = input.parse::<syn::LitInt>()?;
+    if lit.base10_parse::<i32>()? < 0 {
+        return Err(syn::Error::new(
+            lit.span(),
+            "expected a non-negative number",
+        ));
+    }
+    Ok(syn::LitInt::new(lit.base10_digits(), lit.span()))
+}
+
+/// Returns the provided number as an unsuffixed `f64` literal.
+fn float_tokens(n: f64) -> TokenStream {
+    let lit = Literal::f64_unsuffixed(n.abs());
+    if n.is_sign_negative() {
+        quote! { -#lit }
+    } else {
+        quote! { #lit }
+    }
+}
@@ -0,0 +1,144 @@
+//! Code generation for `#[derive(GraphQLEnum)]` macro.
+
+use proc_macro2::TokenStream;
+use proc_macro_error::ResultExt as _;
+use quote::ToTokens as _;
+use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _};
+
+use crate::{
+    common::{remote, scalar},
+    result::GraphQLScope,
+    util::{span_container::SpanContainer, RenameRule},
+};
+
+use super::{Attr, Definition, ValueDefinition, VariantAttr};
+
+/// [`GraphQLScope`] of errors for `#[derive(GraphQLEnum)]` macro.
+const ERR: GraphQLScope = GraphQLScope::EnumDerive;
+
+/// Expands `#[derive(GraphQLEnum)]` macro into generated code.
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let ast = syn::parse2::<syn::DeriveInput>(input).unwrap_or_abort();
+
+    let attr = Attr::from_attrs("graphql", &ast.attrs)?;
+
+    let data = if let syn::Data::Enum(data) = &ast.data {
+        data
+    } else {
+        return Err(ERR.custom_error(ast.span(), "can only be derived for enums"));
+    };
+
+    let enum_ident = &ast.ident;
+
+    let name = attr
+        .name
+        .clone()
+        .map(SpanContainer::into_inner)
+        .unwrap_or_else(|| enum_ident.unraw().to_string());
+    if !attr.is_internal && name.starts_with("__") {
+        ERR.no_double_underscore(
+            attr.name
+                .as_ref()
+                .map(SpanContainer::span_ident)
+                .unwrap_or_else(|| enum_ident.span()),
+        );
+    }
+
+    let rename_rule = attr
+        .rename_values
+        .as_deref()
+        .copied()
+        .unwrap_or(RenameRule::ScreamingSnakeCase);
+
+    let mut has_ignored_variants = false;
+    let values: Vec<_> = data
+        .variants
+        .iter()
+        .filter_map(|v| {
+            let v_attr = VariantAttr::from_attrs("graphql", &v.attrs)
+                .map_err(|e| proc_macro_error::emit_error!(e))
+                .ok()?;
+            if v_attr.ignore.is_some() {
+                has_ignored_variants = true;
+                return None;
+            }
+
+            if !matches!(v.fields, syn::Fields::Unit) {
+                ERR.emit_custom(
+                    v.fields.span(),
+                    "only unit variants are allowed, consider ignoring other ones with \
+                     `#[graphql(ignore)]` attribute",
+                );
+                return None;
+            }
+
+            let name = v_attr
+                .name
+                .as_ref()
+                .map(|n| n.as_ref().clone())
+                .unwrap_or_else(|| rename_rule.apply(&v.ident.unraw().to_string()));
+            if name.starts_with("__") {
+                ERR.no_double_underscore(
+                    v_attr
+                        .name
+                        .as_ref()
+                        .map(SpanContainer::span_ident)
+                        .unwrap_or_else(|| v.ident.span()),
+                );
+            }
+
+            Some(ValueDefinition {
+                ident: v.ident.clone(),
+                name,
+                description: v_attr.description.map(SpanContainer::into_inner),
+                deprecated: v_attr
+                    .deprecated
+                    .map(|desc| desc.into_inner().as_ref().map(syn::LitStr::value)),
+                directives: v_attr.directives,
+            })
+        })
+        .collect();
+
+    proc_macro_error::abort_if_dirty();
+
+    if values.is_empty() {
+        ERR.emit_custom(ast.span(), "must have at least one value");
+    }
+    let mut names: Vec<_> = values.iter().map(|v| &v.name).collect();
+    names.sort();
+    names.dedup();
+    if names.len() != values.len() {
+        ERR.emit_custom(ast.span(), "must have a different name for each value");
+    }
+
+    proc_macro_error::abort_if_dirty();
+
+    let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
+
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let definition = Definition {
+        name,
+        ty: parse_quote! { #enum_ident#ty_generics },
+        generics: ast.generics.clone(),
+        description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        context: attr
+            .context
+            .map(SpanContainer::into_inner)
+            .unwrap_or_else(|| parse_quote! { () }),
+        scalar,
+        values,
+        has_ignored_variants,
+    };
+
+    let mut tokens = definition.into_token_stream();
+    if let Some(remote) = &attr.remote {
+        tokens.extend(remote::impl_from_tokens(
+            enum_ident,
+            &ast.generics,
+            remote,
+            &ast.data,
+        ));
+    }
+    Ok(tokens)
+}
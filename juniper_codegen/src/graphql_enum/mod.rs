@@ -0,0 +1,845 @@
+//! Code generation for [GraphQL enum][1].
+//!
+//! [1]: https://spec.graphql.org/June2018/#sec-Enums
+
+pub mod derive;
+
+use std::convert::TryInto as _;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::{
+    common::{
+        directive::{self, Directive},
+        parse::{
+            attr::{err, OptionExt as _},
+            ParseBufferExt as _, TypeExt as _,
+        },
+        remote, scalar,
+    },
+    util::{
+        filter_attrs, get_deprecated, get_doc_comment, span_container::SpanContainer, RenameRule,
+    },
+};
+
+/// Available arguments behind `#[graphql]` attribute placed on a Rust enum
+/// definition, when generating code for [GraphQL enum][1] type.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Enums
+#[derive(Debug, Default)]
+struct Attr {
+    /// Explicitly specified name of this [GraphQL enum][1] type.
+    ///
+    /// If [`None`], then Rust type name is used by default.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    name: Option<SpanContainer<String>>,
+
+    /// Explicitly specified [description][2] of this [GraphQL enum][1] type.
+    ///
+    /// If [`None`], then Rust doc comment is used as [description][2], if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    /// [2]: https://spec.graphql.org/June2018/#sec-Descriptions
+    description: Option<SpanContainer<String>>,
+
+    /// Explicitly specified [type system directives][0] applied to this
+    /// [GraphQL enum][1] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    directives: Vec<Directive>,
+
+    /// Explicitly specified type of [`Context`] to use for resolving this
+    /// [GraphQL enum][1] type with.
+    ///
+    /// If [`None`], then unit type `()` is assumed as a type of [`Context`].
+    ///
+    /// [`Context`]: juniper::Context
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    context: Option<SpanContainer<syn::Type>>,
+
+    /// Explicitly specified type (or type parameter with its bounds) of
+    /// [`ScalarValue`] to use for resolving this [GraphQL enum][1] type with.
+    ///
+    /// If [`None`], then generated code will be generic over any
+    /// [`ScalarValue`] type.
+    ///
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    scalar: Option<SpanContainer<scalar::AttrValue>>,
+
+    /// Explicitly specified [`RenameRule`] for all [values][2] of this
+    /// [GraphQL enum][1].
+    ///
+    /// If [`None`], then the [`RenameRule::ScreamingSnakeCase`] rule is
+    /// applied by default.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    /// [2]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+    rename_values: Option<SpanContainer<RenameRule>>,
+
+    /// Explicitly specified remote (foreign) Rust enum, mirrored by the enum
+    /// this [GraphQL enum][1] type is derived for.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    remote: Option<SpanContainer<syn::Type>>,
+
+    /// Indicator whether the generated code is intended to be used only inside
+    /// the [`juniper`] library.
+    is_internal: bool,
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            match ident.to_string().as_str() {
+                "name" => {
+                    input.parse::<token::Eq>()?;
+                    let name = input.parse::<syn::LitStr>()?;
+                    out.name
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(name.span()),
+                            name.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "desc" | "description" => {
+                    input.parse::<token::Eq>()?;
+                    let desc = input.parse::<syn::LitStr>()?;
+                    out.description
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(desc.span()),
+                            desc.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse::<Directive>()?);
+                }
+                "ctx" | "context" | "Context" => {
+                    input.parse::<token::Eq>()?;
+                    let ctx = input.parse::<syn::Type>()?;
+                    out.context
+                        .replace(SpanContainer::new(ident.span(), Some(ctx.span()), ctx))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "scalar" | "Scalar" | "ScalarValue" => {
+                    input.parse::<token::Eq>()?;
+                    let scl = input.parse::<scalar::AttrValue>()?;
+                    out.scalar
+                        .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "rename_all" => {
+                    input.parse::<token::Eq>()?;
+                    let val = input.parse::<syn::LitStr>()?;
+                    out.rename_values
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(val.span()),
+                            val.try_into()?,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?;
+                }
+                "remote" => {
+                    input.parse::<token::Eq>()?;
+                    let remote = remote::parse_type(input)?;
+                    out.remote
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(remote.span()),
+                            remote,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "internal" => {
+                    out.is_internal = true;
+                }
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl Attr {
+    /// Tries to merge two [`Attr`]s into a single one, reporting about
+    /// duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            name: try_merge_opt!(name: self, another),
+            description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            context: try_merge_opt!(context: self, another),
+            scalar: try_merge_opt!(scalar: self, another),
+            rename_values: try_merge_opt!(rename_values: self, another),
+            remote: try_merge_opt!(remote: self, another),
+            is_internal: self.is_internal || another.is_internal,
+        })
+    }
+
+    /// Parses [`Attr`] from the given multiple `name`d
+    /// [`syn::Attribute`]s placed on a type definition.
+    fn from_attrs(name: &str, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut attr = filter_attrs(name, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
+
+        if attr.description.is_none() {
+            attr.description = get_doc_comment(attrs);
+        }
+
+        Ok(attr)
+    }
+}
+
+/// Available arguments behind `#[graphql]` attribute placed on a Rust enum
+/// variant, when generating code for [GraphQL enum][1]'s [value][2].
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Enums
+/// [2]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+#[derive(Debug, Default)]
+struct VariantAttr {
+    /// Explicitly specified name of this [GraphQL enum value][1].
+    ///
+    /// If [`None`], then the Rust variant name renamed by the
+    /// [`Attr::rename_values`] rule is used by default.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+    name: Option<SpanContainer<String>>,
+
+    /// Explicitly specified [description][2] of this [GraphQL enum value][1].
+    ///
+    /// If [`None`], then Rust doc comment is used as [description][2], if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+    /// [2]: https://spec.graphql.org/June2018/#sec-Descriptions
+    description: Option<SpanContainer<String>>,
+
+    /// Explicitly specified [deprecation][2] of this [GraphQL enum value][1].
+    ///
+    /// If [`None`], then Rust `#[deprecated]` attribute is used as the
+    /// [deprecation][2], if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+    /// [2]: https://spec.graphql.org/June2018/#sec-Deprecation
+    deprecated: Option<SpanContainer<Option<syn::LitStr>>>,
+
+    /// Explicitly specified [type system directives][0] applied to this
+    /// [GraphQL enum value][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+    directives: Vec<Directive>,
+
+    /// Explicitly specified marker for the Rust enum variant being ignored and
+    /// not included into [GraphQL enum][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    ignore: Option<SpanContainer<syn::Ident>>,
+}
+
+impl Parse for VariantAttr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            match ident.to_string().as_str() {
+                "name" => {
+                    input.parse::<token::Eq>()?;
+                    let name = input.parse::<syn::LitStr>()?;
+                    out.name
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(name.span()),
+                            name.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "desc" | "description" => {
+                    input.parse::<token::Eq>()?;
+                    let desc = input.parse::<syn::LitStr>()?;
+                    out.description
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(desc.span()),
+                            desc.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "deprecated" => {
+                    let mut reason = None;
+                    if input.is_next::<token::Eq>() {
+                        input.parse::<token::Eq>()?;
+                        reason = Some(input.parse::<syn::LitStr>()?);
+                    }
+                    out.deprecated
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            reason.as_ref().map(|r| r.span()),
+                            reason,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse::<Directive>()?);
+                }
+                "ignore" | "skip" => out
+                    .ignore
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl VariantAttr {
+    /// Tries to merge two [`VariantAttr`]s into a single one, reporting about
+    /// duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            name: try_merge_opt!(name: self, another),
+            description: try_merge_opt!(description: self, another),
+            deprecated: try_merge_opt!(deprecated: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            ignore: try_merge_opt!(ignore: self, another),
+        })
+    }
+
+    /// Parses [`VariantAttr`] from the given multiple `name`d
+    /// [`syn::Attribute`]s placed on a enum variant.
+    fn from_attrs(name: &str, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut attr = filter_attrs(name, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
+
+        if let Some(ignore) = &attr.ignore {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.deprecated.is_some()
+                || !attr.directives.is_empty()
+            {
+                return Err(syn::Error::new(
+                    ignore.span(),
+                    "`ignore` attribute argument is not composable with any other arguments",
+                ));
+            }
+        }
+
+        if attr.description.is_none() {
+            attr.description = get_doc_comment(attrs);
+        }
+
+        if attr.deprecated.is_none() {
+            attr.deprecated = get_deprecated(attrs).map(|sc| {
+                let span = sc.span_ident();
+                sc.map(|depr| depr.reason.map(|rsn| syn::LitStr::new(&rsn, span)))
+            });
+        }
+
+        Ok(attr)
+    }
+}
+
+/// Representation of a [GraphQL enum value][1] for code generation.
+///
+/// [1]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+#[derive(Debug)]
+struct ValueDefinition {
+    /// [`syn::Ident`] of the Rust enum variant representing this
+    /// [GraphQL enum value][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+    ident: syn::Ident,
+
+    /// Name of this [GraphQL enum value][1] in GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+    name: String,
+
+    /// [Description][2] of this [GraphQL enum value][1] to put into GraphQL
+    /// schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+    /// [2]: https://spec.graphql.org/June2018/#sec-Descriptions
+    description: Option<String>,
+
+    /// [Deprecation][2] of this [GraphQL enum value][1] to put into GraphQL
+    /// schema.
+    ///
+    /// If inner [`Option`] is [`None`], then deprecation has no message
+    /// attached.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+    /// [2]: https://spec.graphql.org/June2018/#sec-Deprecation
+    deprecated: Option<Option<String>>,
+
+    /// [Type system directives][0] applied to this [GraphQL enum value][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+    directives: Vec<Directive>,
+}
+
+/// Definition of [GraphQL enum][1] for code generation.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Enums
+struct Definition {
+    /// Name of this [GraphQL enum][1] in GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    name: String,
+
+    /// Rust type that this [GraphQL enum][1] is represented with.
+    ///
+    /// It should contain all its generics, if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    ty: syn::Type,
+
+    /// Generics of the Rust enum that this [GraphQL enum][1] is implemented
+    /// for.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    generics: syn::Generics,
+
+    /// Description of this [GraphQL enum][1] to put into GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    description: Option<String>,
+
+    /// [Type system directives][0] applied to this [GraphQL enum][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    directives: Vec<Directive>,
+
+    /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
+    /// for this [GraphQL enum][1].
+    ///
+    /// [`Context`]: juniper::Context
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    context: syn::Type,
+
+    /// [`ScalarValue`] parametrization to generate [`GraphQLType`]
+    /// implementation with for this [GraphQL enum][1].
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    scalar: scalar::Type,
+
+    /// [Values][2] of this [GraphQL enum][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    /// [2]: https://spec.graphql.org/June2018/#EnumValuesDefinition
+    values: Vec<ValueDefinition>,
+
+    /// Indicator whether the Rust enum has variants ignored by this
+    /// [GraphQL enum][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    has_ignored_variants: bool,
+}
+
+impl ToTokens for Definition {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        self.impl_input_and_output_type_tokens().to_tokens(into);
+        self.impl_graphql_type_tokens().to_tokens(into);
+        self.impl_graphql_value_tokens().to_tokens(into);
+        self.impl_graphql_value_async_tokens().to_tokens(into);
+        self.impl_from_input_value_tokens().to_tokens(into);
+        self.impl_to_input_value_tokens().to_tokens(into);
+        self.impl_reflection_traits_tokens().to_tokens(into);
+    }
+}
+
+impl Definition {
+    /// Returns prepared [`syn::Generics::split_for_impl`] for [`GraphQLType`]
+    /// trait (and similar) implementation of this [GraphQL enum][1].
+    ///
+    /// If `for_async` is `true`, then additional predicates are added to suit
+    /// the [`GraphQLAsyncValue`] trait (and similar) requirements.
+    ///
+    /// [`GraphQLAsyncValue`]: juniper::GraphQLAsyncValue
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    #[must_use]
+    fn impl_generics(&self, for_async: bool) -> (TokenStream, Option<syn::WhereClause>) {
+        let mut generics = self.generics.clone();
+
+        let scalar = &self.scalar;
+        if scalar.is_implicit_generic() {
+            generics.params.push(parse_quote! { #scalar });
+        }
+        if scalar.is_generic() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #scalar: ::juniper::ScalarValue });
+        }
+        if let Some(bound) = scalar.bounds() {
+            generics.make_where_clause().predicates.push(bound);
+        }
+
+        if for_async {
+            let self_ty = if self.generics.lifetimes().next().is_some() {
+                let mut lifetimes = vec![];
+
+                // Modify lifetime names to omit "lifetime name `'a` shadows a
+                // lifetime name that is already in scope" error.
+                let mut ty = self.ty.clone();
+                ty.lifetimes_iter_mut(&mut |lt| {
+                    let ident = lt.ident.unraw();
+                    lt.ident = format_ident!("__fa__{}", ident);
+                    lifetimes.push(lt.clone());
+                });
+
+                quote! { for<#( #lifetimes ),*> #ty }
+            } else {
+                quote! { Self }
+            };
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #self_ty: Sync });
+
+            if scalar.is_generic() {
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! { #scalar: Send + Sync });
+            }
+        }
+
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        (quote! { #impl_generics }, where_clause.cloned())
+    }
+
+    /// Returns generated code of a `match` arm panicking on the Rust enum
+    /// variants ignored by this [GraphQL enum][1], if there are any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    #[must_use]
+    fn ignored_variants_arm_tokens(&self) -> Option<TokenStream> {
+        let name = &self.name;
+        self.has_ignored_variants.then(|| {
+            quote! {
+                _ => panic!(
+                    "ignored Rust enum variant cannot be represented by GraphQL enum `{}`",
+                    #name,
+                ),
+            }
+        })
+    }
+
+    /// Returns generated code implementing [`marker::IsInputType`] and
+    /// [`marker::IsOutputType`] traits for this [GraphQL enum][1].
+    ///
+    /// [`marker::IsInputType`]: juniper::marker::IsInputType
+    /// [`marker::IsOutputType`]: juniper::marker::IsOutputType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    #[must_use]
+    fn impl_input_and_output_type_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::marker::IsInputType<#scalar> for #ty #where_clause {}
+
+            #[automatically_derived]
+            impl#impl_generics ::juniper::marker::IsOutputType<#scalar> for #ty #where_clause {}
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLType`] trait for this
+    /// [GraphQL enum][1].
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    #[must_use]
+    fn impl_graphql_type_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let name = &self.name;
+        let description = self
+            .description
+            .as_ref()
+            .map(|desc| quote! { .description(#desc) });
+        let directives = directive::meta_tokens(&self.directives);
+
+        let values_meta = self.values.iter().map(|v| {
+            let name = &v.name;
+            let description = v
+                .description
+                .as_ref()
+                .map(|desc| quote! { .description(#desc) });
+            let deprecated = v.deprecated.as_ref().map(|reason| {
+                let reason = reason
+                    .as_ref()
+                    .map(|rsn| quote! { Some(#rsn) })
+                    .unwrap_or_else(|| quote! { None });
+                quote! { .deprecated(#reason) }
+            });
+            let directives = directive::meta_tokens(&v.directives);
+
+            quote! {
+                ::juniper::meta::EnumValue::new(#name)
+                    #description
+                    #deprecated
+                    #directives
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::GraphQLType<#scalar> for #ty #where_clause
+            {
+                fn name(_ : &Self::TypeInfo) -> Option<&'static str> {
+                    Some(#name)
+                }
+
+                fn meta<'r>(
+                    info: &Self::TypeInfo,
+                    registry: &mut ::juniper::Registry<'r, #scalar>
+                ) -> ::juniper::meta::MetaType<'r, #scalar>
+                where #scalar: 'r,
+                {
+                    let values = [#( #values_meta ),*];
+                    registry.build_enum_type::<#ty>(info, &values)
+                        #description
+                        #directives
+                        .into_meta()
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLValue`] trait for this
+    /// [GraphQL enum][1].
+    ///
+    /// [`GraphQLValue`]: juniper::GraphQLValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    #[must_use]
+    fn impl_graphql_value_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+        let context = &self.context;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let variants = self.values.iter().map(|v| {
+            let (ident, name) = (&v.ident, &v.name);
+            quote! {
+                Self::#ident => Ok(::juniper::Value::scalar(String::from(#name))),
+            }
+        });
+        let ignored = self.ignored_variants_arm_tokens();
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::GraphQLValue<#scalar> for #ty #where_clause
+            {
+                type Context = #context;
+                type TypeInfo = ();
+
+                fn type_name<'__i>(&self, info: &'__i Self::TypeInfo) -> Option<&'__i str> {
+                    <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+                }
+
+                fn resolve(
+                    &self,
+                    _: &(),
+                    _: Option<&[::juniper::Selection<#scalar>]>,
+                    _: &::juniper::Executor<Self::Context, #scalar>,
+                ) -> ::juniper::ExecutionResult<#scalar> {
+                    match self {
+                        #( #variants )*
+                        #ignored
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLValueAsync`] trait for this
+    /// [GraphQL enum][1].
+    ///
+    /// [`GraphQLValueAsync`]: juniper::GraphQLValueAsync
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    #[must_use]
+    fn impl_graphql_value_async_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(true);
+        let ty = &self.ty;
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::GraphQLValueAsync<#scalar> for #ty #where_clause
+            {
+                fn resolve_async<'__a>(
+                    &'__a self,
+                    info: &'__a Self::TypeInfo,
+                    selection_set: Option<&'__a [::juniper::Selection<#scalar>]>,
+                    executor: &'__a ::juniper::Executor<Self::Context, #scalar>,
+                ) -> ::juniper::BoxFuture<'__a, ::juniper::ExecutionResult<#scalar>> {
+                    let v = ::juniper::GraphQLValue::resolve(self, info, selection_set, executor);
+                    Box::pin(::juniper::futures::future::ready(v))
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`FromInputValue`] trait for this
+    /// [GraphQL enum][1].
+    ///
+    /// [`FromInputValue`]: juniper::FromInputValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    #[must_use]
+    fn impl_from_input_value_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let variants = self.values.iter().map(|v| {
+            let (ident, name) = (&v.ident, &v.name);
+            quote! {
+                Some(#name) => Ok(Self::#ident),
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::FromInputValue<#scalar> for #ty #where_clause
+            {
+                type Error = ::std::string::String;
+
+                fn from_input_value(
+                    v: &::juniper::InputValue<#scalar>,
+                ) -> Result<Self, Self::Error> {
+                    match v.as_enum_value().or_else(|| v.as_string_value()) {
+                        #( #variants )*
+                        _ => Err(format!("Unknown enum value: {}", v)),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`ToInputValue`] trait for this
+    /// [GraphQL enum][1].
+    ///
+    /// [`ToInputValue`]: juniper::ToInputValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    #[must_use]
+    fn impl_to_input_value_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let variants = self.values.iter().map(|v| {
+            let (ident, name) = (&v.ident, &v.name);
+            quote! {
+                Self::#ident => ::juniper::InputValue::<#scalar>::scalar(String::from(#name)),
+            }
+        });
+        let ignored = self.ignored_variants_arm_tokens();
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::ToInputValue<#scalar> for #ty #where_clause
+            {
+                fn to_input_value(&self) -> ::juniper::InputValue<#scalar> {
+                    match self {
+                        #( #variants )*
+                        #ignored
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`BaseType`], [`BaseSubTypes`] and
+    /// [`WrappedType`] traits for this [GraphQL enum][1].
+    ///
+    /// [`BaseSubTypes`]: juniper::macros::reflect::BaseSubTypes
+    /// [`BaseType`]: juniper::macros::reflect::BaseType
+    /// [`WrappedType`]: juniper::macros::reflect::WrappedType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Enums
+    #[must_use]
+    fn impl_reflection_traits_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+        let name = &self.name;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::macros::reflect::BaseType<#scalar>
+                for #ty
+                #where_clause
+            {
+                const NAME: ::juniper::macros::reflect::Type = #name;
+            }
+
+            #[automatically_derived]
+            impl#impl_generics ::juniper::macros::reflect::BaseSubTypes<#scalar>
+                for #ty
+                #where_clause
+            {
+                const NAMES: ::juniper::macros::reflect::Types =
+                    &[<Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME];
+            }
+
+            #[automatically_derived]
+            impl#impl_generics ::juniper::macros::reflect::WrappedType<#scalar>
+                for #ty
+                #where_clause
+            {
+                const VALUE: ::juniper::macros::reflect::WrappedValue = 1;
+            }
+        }
+    }
+}
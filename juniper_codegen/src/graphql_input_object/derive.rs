@@ -0,0 +1,142 @@
+//! Code generation for `#[derive(GraphQLInputObject)]` macro.
+
+use proc_macro2::TokenStream;
+use proc_macro_error::ResultExt as _;
+use quote::ToTokens as _;
+use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _};
+
+use crate::{
+    common::{remote, scalar},
+    result::GraphQLScope,
+    util::{span_container::SpanContainer, RenameRule},
+};
+
+use super::{Attr, Definition, FieldAttr, FieldDefinition};
+
+/// [`GraphQLScope`] of errors for `#[derive(GraphQLInputObject)]` macro.
+const ERR: GraphQLScope = GraphQLScope::InputObjectDerive;
+
+/// Expands `#[derive(GraphQLInputObject)]` macro into generated code.
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let ast = syn::parse2::<syn::DeriveInput>(input).unwrap_or_abort();
+
+    let attr = Attr::from_attrs("graphql", &ast.attrs)?;
+
+    let fields = match &ast.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(ERR.custom_error(
+                ast.span(),
+                "can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let struct_ident = &ast.ident;
+
+    let name = attr
+        .name
+        .clone()
+        .map(SpanContainer::into_inner)
+        .unwrap_or_else(|| struct_ident.unraw().to_string());
+    if !attr.is_internal && name.starts_with("__") {
+        ERR.no_double_underscore(
+            attr.name
+                .as_ref()
+                .map(SpanContainer::span_ident)
+                .unwrap_or_else(|| struct_ident.span()),
+        );
+    }
+
+    let rename_rule = attr
+        .rename_fields
+        .as_deref()
+        .copied()
+        .unwrap_or(RenameRule::CamelCase);
+
+    let fields: Vec<_> = fields
+        .iter()
+        .filter_map(|f| {
+            let f_attr = FieldAttr::from_attrs("graphql", &f.attrs)
+                .map_err(|e| proc_macro_error::emit_error!(e))
+                .ok()?;
+
+            let ident = f.ident.clone().unwrap();
+            let name = f_attr
+                .name
+                .as_ref()
+                .map(|n| n.as_ref().clone())
+                .unwrap_or_else(|| rename_rule.apply(&ident.unraw().to_string()));
+            if f_attr.ignore.is_none() && f_attr.flatten.is_none() && name.starts_with("__") {
+                ERR.no_double_underscore(
+                    f_attr
+                        .name
+                        .as_ref()
+                        .map(SpanContainer::span_ident)
+                        .unwrap_or_else(|| ident.span()),
+                );
+            }
+
+            Some(FieldDefinition {
+                ident,
+                ty: f.ty.clone(),
+                name,
+                description: f_attr.description.map(SpanContainer::into_inner),
+                default: f_attr.default.map(SpanContainer::into_inner),
+                directives: f_attr.directives,
+                validators: f_attr.validators,
+                ignored: f_attr.ignore.is_some(),
+                flattened: f_attr.flatten.is_some(),
+            })
+        })
+        .collect();
+
+    proc_macro_error::abort_if_dirty();
+
+    if fields.iter().all(|f| f.ignored) {
+        ERR.emit_custom(ast.span(), "must have at least one field");
+    }
+    let graphql_fields: Vec<_> = fields
+        .iter()
+        .filter(|f| !f.ignored && !f.flattened)
+        .collect();
+    let mut names: Vec<_> = graphql_fields.iter().map(|f| &f.name).collect();
+    names.sort();
+    names.dedup();
+    if names.len() != graphql_fields.len() {
+        ERR.emit_custom(ast.span(), "must have a different name for each field");
+    }
+
+    proc_macro_error::abort_if_dirty();
+
+    let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
+
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let definition = Definition {
+        name,
+        ty: parse_quote! { #struct_ident#ty_generics },
+        generics: ast.generics.clone(),
+        description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        context: attr
+            .context
+            .map(SpanContainer::into_inner)
+            .unwrap_or_else(|| parse_quote! { () }),
+        scalar,
+        fields,
+    };
+
+    let mut tokens = definition.into_token_stream();
+    if let Some(remote) = &attr.remote {
+        tokens.extend(remote::impl_from_tokens(
+            struct_ident,
+            &ast.generics,
+            remote,
+            &ast.data,
+        ));
+    }
+    Ok(tokens)
+}
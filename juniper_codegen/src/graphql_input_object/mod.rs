@@ -0,0 +1,1045 @@
+//! Code generation for [GraphQL input object][1].
+//!
+//! [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+
+pub mod derive;
+
+use std::{convert::TryInto as _, iter};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::{
+    common::{
+        directive::{self, Directive},
+        gen,
+        parse::{
+            attr::{err, OptionExt as _},
+            GenericsExt as _, ParseBufferExt as _, TypeExt as _,
+        },
+        remote, scalar,
+        validator::{self, Validator},
+    },
+    util::{filter_attrs, get_doc_comment, span_container::SpanContainer, RenameRule},
+};
+
+/// Available arguments behind `#[graphql]` attribute placed on a Rust struct
+/// definition, when generating code for [GraphQL input object][1] type.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+#[derive(Debug, Default)]
+struct Attr {
+    /// Explicitly specified name of this [GraphQL input object][1] type.
+    ///
+    /// If [`None`], then Rust type name is used by default.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    name: Option<SpanContainer<String>>,
+
+    /// Explicitly specified [description][2] of this
+    /// [GraphQL input object][1] type.
+    ///
+    /// If [`None`], then Rust doc comment is used as [description][2], if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    /// [2]: https://spec.graphql.org/June2018/#sec-Descriptions
+    description: Option<SpanContainer<String>>,
+
+    /// Explicitly specified [type system directives][0] applied to this
+    /// [GraphQL input object][1] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    directives: Vec<Directive>,
+
+    /// Explicitly specified type of [`Context`] to use for resolving this
+    /// [GraphQL input object][1] type with.
+    ///
+    /// If [`None`], then unit type `()` is assumed as a type of [`Context`].
+    ///
+    /// [`Context`]: juniper::Context
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    context: Option<SpanContainer<syn::Type>>,
+
+    /// Explicitly specified type (or type parameter with its bounds) of
+    /// [`ScalarValue`] to use for resolving this [GraphQL input object][1]
+    /// type with.
+    ///
+    /// If [`None`], then generated code will be generic over any
+    /// [`ScalarValue`] type.
+    ///
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    scalar: Option<SpanContainer<scalar::AttrValue>>,
+
+    /// Explicitly specified [`RenameRule`] for all fields of this
+    /// [GraphQL input object][1].
+    ///
+    /// If [`None`], then the [`RenameRule::CamelCase`] rule is applied by
+    /// default.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    rename_fields: Option<SpanContainer<RenameRule>>,
+
+    /// Explicitly specified remote (foreign) Rust struct, mirrored by the
+    /// struct this [GraphQL input object][1] type is derived for.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    remote: Option<SpanContainer<syn::Type>>,
+
+    /// Indicator whether the generated code is intended to be used only inside
+    /// the [`juniper`] library.
+    is_internal: bool,
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            match ident.to_string().as_str() {
+                "name" => {
+                    input.parse::<token::Eq>()?;
+                    let name = input.parse::<syn::LitStr>()?;
+                    out.name
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(name.span()),
+                            name.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "desc" | "description" => {
+                    input.parse::<token::Eq>()?;
+                    let desc = input.parse::<syn::LitStr>()?;
+                    out.description
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(desc.span()),
+                            desc.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse::<Directive>()?);
+                }
+                "ctx" | "context" | "Context" => {
+                    input.parse::<token::Eq>()?;
+                    let ctx = input.parse::<syn::Type>()?;
+                    out.context
+                        .replace(SpanContainer::new(ident.span(), Some(ctx.span()), ctx))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "scalar" | "Scalar" | "ScalarValue" => {
+                    input.parse::<token::Eq>()?;
+                    let scl = input.parse::<scalar::AttrValue>()?;
+                    out.scalar
+                        .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "rename_all" => {
+                    input.parse::<token::Eq>()?;
+                    let val = input.parse::<syn::LitStr>()?;
+                    out.rename_fields
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(val.span()),
+                            val.try_into()?,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?;
+                }
+                "remote" => {
+                    input.parse::<token::Eq>()?;
+                    let remote = remote::parse_type(input)?;
+                    out.remote
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(remote.span()),
+                            remote,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "internal" => {
+                    out.is_internal = true;
+                }
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl Attr {
+    /// Tries to merge two [`Attr`]s into a single one, reporting about
+    /// duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            name: try_merge_opt!(name: self, another),
+            description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            context: try_merge_opt!(context: self, another),
+            scalar: try_merge_opt!(scalar: self, another),
+            rename_fields: try_merge_opt!(rename_fields: self, another),
+            remote: try_merge_opt!(remote: self, another),
+            is_internal: self.is_internal || another.is_internal,
+        })
+    }
+
+    /// Parses [`Attr`] from the given multiple `name`d [`syn::Attribute`]s
+    /// placed on a type definition.
+    fn from_attrs(name: &str, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut attr = filter_attrs(name, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
+
+        if attr.description.is_none() {
+            attr.description = get_doc_comment(attrs);
+        }
+
+        Ok(attr)
+    }
+}
+
+/// Available arguments behind `#[graphql]` attribute placed on a Rust struct
+/// field, when generating code for [GraphQL input object][1]'s [field][2].
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+/// [2]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+#[derive(Debug, Default)]
+struct FieldAttr {
+    /// Explicitly specified name of this [GraphQL input object field][1].
+    ///
+    /// If [`None`], then the Rust field name renamed by the
+    /// [`Attr::rename_fields`] rule is used by default.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    name: Option<SpanContainer<String>>,
+
+    /// Explicitly specified [description][2] of this
+    /// [GraphQL input object field][1].
+    ///
+    /// If [`None`], then Rust doc comment is used as [description][2], if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    /// [2]: https://spec.graphql.org/June2018/#sec-Descriptions
+    description: Option<SpanContainer<String>>,
+
+    /// Explicitly specified [default value][2] of this
+    /// [GraphQL input object field][1].
+    ///
+    /// If the exact default expression is not specified, then the [`Default`]
+    /// value is used.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    /// [2]: https://spec.graphql.org/June2018/#DefaultValue
+    default: Option<SpanContainer<Option<syn::Expr>>>,
+
+    /// Explicitly specified [type system directives][0] applied to this
+    /// [GraphQL input object field][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    directives: Vec<Directive>,
+
+    /// Explicitly specified [`Validator`]s of this
+    /// [GraphQL input object field][1] value.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    validators: Vec<(Span, Validator)>,
+
+    /// Explicitly specified marker for the Rust struct field being ignored and
+    /// not included into [GraphQL input object][1].
+    ///
+    /// Ignored field is initialized with its [default value][2] (if specified)
+    /// or with its [`Default`] value.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    /// [2]: https://spec.graphql.org/June2018/#DefaultValue
+    ignore: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified marker for the [fields][2] of the Rust struct
+    /// field's type being inlined into [GraphQL input object][1], rather than
+    /// the Rust struct field being its [field][2] itself.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    /// [2]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    flatten: Option<SpanContainer<syn::Ident>>,
+}
+
+impl Parse for FieldAttr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            match ident.to_string().as_str() {
+                "name" => {
+                    input.parse::<token::Eq>()?;
+                    let name = input.parse::<syn::LitStr>()?;
+                    out.name
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(name.span()),
+                            name.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "desc" | "description" => {
+                    input.parse::<token::Eq>()?;
+                    let desc = input.parse::<syn::LitStr>()?;
+                    out.description
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(desc.span()),
+                            desc.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "default" => {
+                    let mut expr = None;
+                    if input.is_next::<token::Eq>() {
+                        input.parse::<token::Eq>()?;
+                        expr = Some(input.parse::<syn::Expr>()?);
+                    } else if input.is_next::<token::Paren>() {
+                        let inner;
+                        let _ = syn::parenthesized!(inner in input);
+                        expr = Some(inner.parse::<syn::Expr>()?);
+                    }
+                    out.default
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            expr.as_ref().map(|e| e.span()),
+                            expr,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse::<Directive>()?);
+                }
+                "validate" => {
+                    out.validators.extend(Validator::parse_list(input)?);
+                }
+                "ignore" | "skip" => out
+                    .ignore
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "flatten" => out
+                    .flatten
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl FieldAttr {
+    /// Tries to merge two [`FieldAttr`]s into a single one, reporting about
+    /// duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            name: try_merge_opt!(name: self, another),
+            description: try_merge_opt!(description: self, another),
+            default: try_merge_opt!(default: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            validators: self
+                .validators
+                .into_iter()
+                .chain(another.validators)
+                .collect(),
+            ignore: try_merge_opt!(ignore: self, another),
+            flatten: try_merge_opt!(flatten: self, another),
+        })
+    }
+
+    /// Parses [`FieldAttr`] from the given multiple `name`d
+    /// [`syn::Attribute`]s placed on a struct field.
+    fn from_attrs(name: &str, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut attr = filter_attrs(name, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
+
+        if let Some(ignore) = &attr.ignore {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || !attr.directives.is_empty()
+                || !attr.validators.is_empty()
+                || attr.flatten.is_some()
+            {
+                return Err(syn::Error::new(
+                    ignore.span(),
+                    "`ignore` attribute argument is not composable with any other arguments, \
+                     except `default`",
+                ));
+            }
+        }
+
+        if let Some(flatten) = &attr.flatten {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.default.is_some()
+                || !attr.directives.is_empty()
+                || !attr.validators.is_empty()
+            {
+                return Err(syn::Error::new(
+                    flatten.span(),
+                    "`flatten` attribute argument is not composable with any other arguments",
+                ));
+            }
+        }
+
+        if attr.description.is_none() {
+            attr.description = get_doc_comment(attrs);
+        }
+
+        Ok(attr)
+    }
+}
+
+/// Representation of a [GraphQL input object field][1] for code generation.
+///
+/// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+#[derive(Debug)]
+struct FieldDefinition {
+    /// [`syn::Ident`] of the Rust struct field representing this
+    /// [GraphQL input object field][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    ident: syn::Ident,
+
+    /// Rust type that this [GraphQL input object field][1] is represented by.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    ty: syn::Type,
+
+    /// Name of this [GraphQL input object field][1] in GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    name: String,
+
+    /// [Description][2] of this [GraphQL input object field][1] to put into
+    /// GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    /// [2]: https://spec.graphql.org/June2018/#sec-Descriptions
+    description: Option<String>,
+
+    /// [Default value][2] of this [GraphQL input object field][1].
+    ///
+    /// If inner [`Option`] is [`None`], then the [`Default`] value is used.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    /// [2]: https://spec.graphql.org/June2018/#DefaultValue
+    default: Option<Option<syn::Expr>>,
+
+    /// [Type system directives][0] applied to this
+    /// [GraphQL input object field][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    directives: Vec<Directive>,
+
+    /// [`Validator`]s of this [GraphQL input object field][1] value.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    validators: Vec<(Span, Validator)>,
+
+    /// Indicator whether the Rust struct field is ignored and not included
+    /// into [GraphQL input object][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    ignored: bool,
+
+    /// Indicator whether the Rust struct field is flattened, so its type's
+    /// [fields][2] are inlined into [GraphQL input object][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    /// [2]: https://spec.graphql.org/June2018/#InputFieldsDefinition
+    flattened: bool,
+}
+
+impl FieldDefinition {
+    /// Returns generated code of the [default value][1] of this field, if it
+    /// has any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#DefaultValue
+    #[must_use]
+    fn default_tokens(&self) -> Option<TokenStream> {
+        let ty = &self.ty;
+        self.default.as_ref().map(|default| {
+            default
+                .as_ref()
+                .map(|expr| quote! { ::std::convert::Into::<#ty>::into(#expr) })
+                .unwrap_or_else(|| quote! { <#ty as Default>::default() })
+        })
+    }
+}
+
+/// Definition of [GraphQL input object][1] for code generation.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+struct Definition {
+    /// Name of this [GraphQL input object][1] in GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    name: String,
+
+    /// Rust type that this [GraphQL input object][1] is represented with.
+    ///
+    /// It should contain all its generics, if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    ty: syn::Type,
+
+    /// Generics of the Rust struct that this [GraphQL input object][1] is
+    /// implemented for.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    generics: syn::Generics,
+
+    /// Description of this [GraphQL input object][1] to put into GraphQL
+    /// schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    description: Option<String>,
+
+    /// [Type system directives][0] applied to this [GraphQL input object][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    directives: Vec<Directive>,
+
+    /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
+    /// for this [GraphQL input object][1].
+    ///
+    /// [`Context`]: juniper::Context
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    context: syn::Type,
+
+    /// [`ScalarValue`] parametrization to generate [`GraphQLType`]
+    /// implementation with for this [GraphQL input object][1].
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    scalar: scalar::Type,
+
+    /// Fields of this [GraphQL input object][1], including the ignored ones.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    fields: Vec<FieldDefinition>,
+}
+
+impl ToTokens for Definition {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        self.impl_input_type_tokens().to_tokens(into);
+        self.impl_graphql_type_tokens().to_tokens(into);
+        self.impl_graphql_value_tokens().to_tokens(into);
+        self.impl_graphql_value_async_tokens().to_tokens(into);
+        self.impl_from_input_value_tokens().to_tokens(into);
+        self.impl_to_input_value_tokens().to_tokens(into);
+        self.impl_reflection_traits_tokens().to_tokens(into);
+    }
+}
+
+impl Definition {
+    /// Returns prepared [`syn::Generics::split_for_impl`] for [`GraphQLType`]
+    /// trait (and similar) implementation of this [GraphQL input object][1].
+    ///
+    /// If `for_async` is `true`, then additional predicates are added to suit
+    /// the [`GraphQLAsyncValue`] trait (and similar) requirements.
+    ///
+    /// [`GraphQLAsyncValue`]: juniper::GraphQLAsyncValue
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    #[must_use]
+    fn impl_generics(&self, for_async: bool) -> (TokenStream, Option<syn::WhereClause>) {
+        let mut generics = self.generics.clone();
+
+        let scalar = &self.scalar;
+        if scalar.is_implicit_generic() {
+            generics.params.push(parse_quote! { #scalar });
+        }
+        if scalar.is_generic() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #scalar: ::juniper::ScalarValue });
+        }
+        if let Some(bound) = scalar.bounds() {
+            generics.make_where_clause().predicates.push(bound);
+        }
+
+        if for_async {
+            let self_ty = if self.generics.lifetimes().next().is_some() {
+                let mut lifetimes = vec![];
+
+                // Modify lifetime names to omit "lifetime name `'a` shadows a
+                // lifetime name that is already in scope" error.
+                let mut ty = self.ty.clone();
+                ty.lifetimes_iter_mut(&mut |lt| {
+                    let ident = lt.ident.unraw();
+                    lt.ident = format_ident!("__fa__{}", ident);
+                    lifetimes.push(lt.clone());
+                });
+
+                quote! { for<#( #lifetimes ),*> #ty }
+            } else {
+                quote! { Self }
+            };
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #self_ty: Sync });
+
+            if scalar.is_generic() {
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! { #scalar: Send + Sync });
+            }
+        }
+
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        (quote! { #impl_generics }, where_clause.cloned())
+    }
+
+    /// Returns iterator over the fields of this [GraphQL input object][1],
+    /// which are neither ignored nor flattened.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    fn graphql_fields(&self) -> impl Iterator<Item = &FieldDefinition> {
+        self.fields.iter().filter(|f| !f.ignored && !f.flattened)
+    }
+
+    /// Returns iterator over the flattened fields of this
+    /// [GraphQL input object][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    fn flattened_fields(&self) -> impl Iterator<Item = &FieldDefinition> {
+        self.fields.iter().filter(|f| f.flattened)
+    }
+
+    /// Returns the given [`syn::Type`] with generic parameters of this
+    /// [GraphQL input object][1] replaced with the default ones, so it could be
+    /// used in a `const` context.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    #[must_use]
+    fn const_ty(&self, ty: &syn::Type) -> syn::Type {
+        let mut ty = ty.clone();
+        self.generics.replace_type_with_defaults(&mut ty);
+        ty
+    }
+
+    /// Returns generated code implementing [`marker::IsInputType`] trait for
+    /// this [GraphQL input object][1].
+    ///
+    /// [`marker::IsInputType`]: juniper::marker::IsInputType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    #[must_use]
+    fn impl_input_type_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let marks = self.fields.iter().filter(|f| !f.ignored).map(|f| {
+            let field_ty = &f.ty;
+            quote_spanned! { field_ty.span() =>
+                <#field_ty as ::juniper::marker::IsInputType<#scalar>>::mark();
+            }
+        });
+
+        let assert_flattened = (self.flattened_fields().next().is_some()).then(|| {
+            let const_scalar = scalar.default_ty();
+            let const_ty = self.const_ty(ty);
+            let fields = self.graphql_fields().map(|f| &f.name);
+            let const_flattened_tys = self.flattened_fields().map(|f| self.const_ty(&f.ty));
+            quote! {
+                ::juniper::assert_flattened_fields!(
+                    #const_scalar, #const_ty, &[#(#fields),*], #(#const_flattened_tys),*
+                );
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::marker::IsInputType<#scalar> for #ty #where_clause
+            {
+                fn mark() {
+                    #( #marks )*
+                    #assert_flattened
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLType`] trait for this
+    /// [GraphQL input object][1].
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    #[must_use]
+    fn impl_graphql_type_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let name = &self.name;
+        let description = self
+            .description
+            .as_ref()
+            .map(|desc| quote! { .description(#desc) });
+        let directives = directive::meta_tokens(&self.directives);
+
+        let fields_meta = self.graphql_fields().map(|f| {
+            let (field_ty, name) = (&f.ty, &f.name);
+
+            let arg = if let Some(default) = f.default_tokens() {
+                quote! { registry.arg_with_default::<#field_ty>(#name, &#default, info) }
+            } else {
+                quote! { registry.arg::<#field_ty>(#name, info) }
+            };
+            let description = f
+                .description
+                .as_ref()
+                .map(|desc| quote! { .description(#desc) });
+            let directives = directive::meta_tokens(
+                &f.directives
+                    .iter()
+                    .cloned()
+                    .chain(validator::constraint_directive(&f.validators))
+                    .collect::<Vec<_>>(),
+            );
+
+            quote! {
+                #arg
+                    #description
+                    #directives
+            }
+        });
+
+        let flattened_fields = (self.flattened_fields().next().is_some()).then(|| {
+            let flattened = self.flattened_fields().map(|f| {
+                let field_ty = &f.ty;
+                quote! {
+                    fields.extend(::juniper::macros::helper::flattened_input_object_fields(
+                        <#field_ty as ::juniper::GraphQLType<#scalar>>::meta(info, registry),
+                        #name,
+                    ));
+                }
+            });
+            quote! {
+                let mut fields = ::std::vec::Vec::from(fields);
+                #( #flattened )*
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::GraphQLType<#scalar> for #ty #where_clause
+            {
+                fn name(_: &Self::TypeInfo) -> Option<&'static str> {
+                    Some(#name)
+                }
+
+                fn meta<'r>(
+                    info: &Self::TypeInfo,
+                    registry: &mut ::juniper::Registry<'r, #scalar>,
+                ) -> ::juniper::meta::MetaType<'r, #scalar>
+                where #scalar: 'r,
+                {
+                    let fields = [#( #fields_meta ),*];
+                    #flattened_fields
+                    registry.build_input_object_type::<#ty>(info, &fields)
+                        #description
+                        #directives
+                        .into_meta()
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLValue`] trait for this
+    /// [GraphQL input object][1].
+    ///
+    /// [`GraphQLValue`]: juniper::GraphQLValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    #[must_use]
+    fn impl_graphql_value_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+        let context = &self.context;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::GraphQLValue<#scalar> for #ty #where_clause
+            {
+                type Context = #context;
+                type TypeInfo = ();
+
+                fn type_name<'__i>(&self, info: &'__i Self::TypeInfo) -> Option<&'__i str> {
+                    <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLValueAsync`] trait for this
+    /// [GraphQL input object][1].
+    ///
+    /// [`GraphQLValueAsync`]: juniper::GraphQLValueAsync
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    #[must_use]
+    fn impl_graphql_value_async_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(true);
+        let ty = &self.ty;
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::GraphQLValueAsync<#scalar> for #ty #where_clause {}
+        }
+    }
+
+    /// Returns generated code implementing [`FromInputValue`] trait for this
+    /// [GraphQL input object][1].
+    ///
+    /// [`FromInputValue`]: juniper::FromInputValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    #[must_use]
+    fn impl_from_input_value_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let fields = self.fields.iter().map(|f| {
+            let (ident, field_ty, name) = (&f.ident, &f.ty, &f.name);
+
+            let value = if f.ignored {
+                f.default_tokens()
+                    .unwrap_or_else(|| quote! { <#field_ty as Default>::default() })
+            } else if f.flattened {
+                quote! {
+                    <#field_ty as ::juniper::FromInputValue<#scalar>>::from_input_value(value)
+                        .map_err(::juniper::IntoFieldError::into_field_error)?
+                }
+            } else {
+                let parse = quote! {
+                    ::juniper::FromInputValue::<#scalar>::from_input_value(v)
+                        .map_err(::juniper::IntoFieldError::into_field_error)?
+                };
+                let value = if let Some(default) = f.default_tokens() {
+                    quote! {
+                        match obj.get(#name) {
+                            Some(v) if !v.is_null() => #parse,
+                            _ => #default,
+                        }
+                    }
+                } else {
+                    quote! {
+                        match obj.get(#name) {
+                            Some(v) => #parse,
+                            None => ::juniper::FromInputValue::<#scalar>::from_implicit_null()
+                                .map_err(::juniper::IntoFieldError::into_field_error)?,
+                        }
+                    }
+                };
+                let validate = validator::validate_input_field_tokens(name, &f.validators);
+
+                quote! {{
+                    let v: #field_ty = #value;
+                    #validate
+                    v
+                }}
+            };
+
+            quote! { #ident: #value, }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::FromInputValue<#scalar> for #ty #where_clause
+            {
+                type Error = ::juniper::FieldError<#scalar>;
+
+                fn from_input_value(
+                    value: &::juniper::InputValue<#scalar>,
+                ) -> Result<Self, Self::Error> {
+                    let obj = value
+                        .to_object_value()
+                        .ok_or_else(|| ::juniper::FieldError::<#scalar>::from(
+                            format!("Expected input object, found: {}", value),
+                        ))?;
+
+                    Ok(Self {
+                        #( #fields )*
+                    })
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`ToInputValue`] trait for this
+    /// [GraphQL input object][1].
+    ///
+    /// [`ToInputValue`]: juniper::ToInputValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    #[must_use]
+    fn impl_to_input_value_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let value = if self.flattened_fields().next().is_some() {
+            let name = &self.name;
+            let fields = self.fields.iter().filter(|f| !f.ignored).map(|f| {
+                let ident = &f.ident;
+                let value = quote! {
+                    ::juniper::ToInputValue::<#scalar>::to_input_value(&self.#ident)
+                };
+                if f.flattened {
+                    quote! {
+                        fields.extend(::juniper::macros::helper::flattened_input_object_value(
+                            #value,
+                            #name,
+                        ));
+                    }
+                } else {
+                    let field_name = &f.name;
+                    quote! { fields.push((#field_name.into(), #value)); }
+                }
+            });
+            quote! {
+                let mut fields = ::std::vec::Vec::<(
+                    ::std::string::String,
+                    ::juniper::InputValue<#scalar>,
+                )>::new();
+                #( #fields )*
+                ::juniper::InputValue::object(fields.into_iter().collect())
+            }
+        } else {
+            let fields = self.graphql_fields().map(|f| {
+                let (ident, name) = (&f.ident, &f.name);
+                quote! {
+                    (#name, ::juniper::ToInputValue::<#scalar>::to_input_value(&self.#ident)),
+                }
+            });
+            quote! {
+                ::juniper::InputValue::object(
+                    IntoIterator::into_iter(vec![#( #fields )*]).collect(),
+                )
+            }
+        };
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::ToInputValue<#scalar> for #ty #where_clause
+            {
+                fn to_input_value(&self) -> ::juniper::InputValue<#scalar> {
+                    #value
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`BaseType`], [`BaseSubTypes`],
+    /// [`WrappedType`] and [`Fields`] traits for this
+    /// [GraphQL input object][1].
+    ///
+    /// [`BaseSubTypes`]: juniper::macros::reflect::BaseSubTypes
+    /// [`BaseType`]: juniper::macros::reflect::BaseType
+    /// [`Fields`]: juniper::macros::reflect::Fields
+    /// [`WrappedType`]: juniper::macros::reflect::WrappedType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+    #[must_use]
+    fn impl_reflection_traits_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+        let name = &self.name;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let fields_names = {
+            let const_scalar = scalar.default_ty();
+            let fields = self.graphql_fields().map(|f| &f.name);
+            let flattened = self.flattened_fields().map(|f| {
+                let const_ty = self.const_ty(&f.ty);
+                quote! {
+                    <#const_ty as ::juniper::macros::reflect::Fields<#const_scalar>>::NAMES
+                }
+            });
+            gen::concat_names(
+                iter::once(quote! { &[#(#fields),*] })
+                    .chain(flattened)
+                    .collect(),
+            )
+        };
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::macros::reflect::BaseType<#scalar>
+                for #ty
+                #where_clause
+            {
+                const NAME: ::juniper::macros::reflect::Type = #name;
+            }
+
+            #[automatically_derived]
+            impl#impl_generics ::juniper::macros::reflect::BaseSubTypes<#scalar>
+                for #ty
+                #where_clause
+            {
+                const NAMES: ::juniper::macros::reflect::Types =
+                    &[<Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME];
+            }
+
+            #[automatically_derived]
+            impl#impl_generics ::juniper::macros::reflect::WrappedType<#scalar>
+                for #ty
+                #where_clause
+            {
+                const VALUE: ::juniper::macros::reflect::WrappedValue = 1;
+            }
+
+            #[automatically_derived]
+            impl#impl_generics ::juniper::macros::reflect::Fields<#scalar>
+                for #ty
+                #where_clause
+            {
+                const NAMES: ::juniper::macros::reflect::Names = #fields_names;
+            }
+        }
+    }
+}
@@ -75,7 +75,7 @@ fn expand_on_trait(
         .copied()
         .unwrap_or(RenameRule::CamelCase);
 
-    let fields = ast
+    let mut fields = ast
         .items
         .iter_mut()
         .filter_map(|item| {
@@ -85,6 +85,7 @@ fn expand_on_trait(
             None
         })
         .collect::<Vec<_>>();
+    field::prepend_guard(&mut fields, attr.guard.as_deref());
 
     proc_macro_error::abort_if_dirty();
 
@@ -121,6 +122,7 @@ fn expand_on_trait(
         enum_alias_ident,
         name,
         description: attr.description.as_deref().cloned(),
+        directives: attr.directives,
         context,
         scalar,
         fields,
@@ -162,6 +164,20 @@ fn parse_trait_method(
     if attr.ignore.is_some() {
         return None;
     }
+    if let Some(flatten) = &attr.flatten {
+        ERR.emit_custom(
+            flatten.span_ident(),
+            "`flatten` argument is supported only by `#[derive(GraphQLObject)]`",
+        );
+        return None;
+    }
+    if let Some(with) = &attr.with {
+        ERR.emit_custom(
+            with.span_ident(),
+            "`with` argument is supported only by `#[derive(GraphQLObject)]`",
+        );
+        return None;
+    }
 
     if method.default.is_some() {
         return err_default_impl_block(&method.default);
@@ -209,10 +225,17 @@ fn parse_trait_method(
         ty,
         description,
         deprecated,
+        directives: attr.directives,
+        guards: attr
+            .guard
+            .map(SpanContainer::into_inner)
+            .into_iter()
+            .collect(),
         ident: method_ident.clone(),
         arguments: Some(arguments),
         has_receiver: method.sig.receiver().is_some(),
         is_async: method.sig.asyncness.is_some(),
+        with: None,
     })
 }
 
@@ -261,11 +284,12 @@ fn expand_on_derive_input(
         .copied()
         .unwrap_or(RenameRule::CamelCase);
 
-    let fields = data
+    let mut fields = data
         .fields
         .iter_mut()
         .filter_map(|f| parse_struct_field(f, &renaming))
         .collect::<Vec<_>>();
+    field::prepend_guard(&mut fields, attr.guard.as_deref());
 
     proc_macro_error::abort_if_dirty();
 
@@ -301,6 +325,7 @@ fn expand_on_derive_input(
         enum_alias_ident,
         name,
         description: attr.description.as_deref().cloned(),
+        directives: attr.directives,
         context,
         scalar,
         fields,
@@ -340,6 +365,20 @@ fn parse_struct_field(field: &mut syn::Field, renaming: &RenameRule) -> Option<f
     if attr.ignore.is_some() {
         return None;
     }
+    if let Some(flatten) = &attr.flatten {
+        ERR.emit_custom(
+            flatten.span_ident(),
+            "`flatten` argument is supported only by `#[derive(GraphQLObject)]`",
+        );
+        return None;
+    }
+    if let Some(with) = &attr.with {
+        ERR.emit_custom(
+            with.span_ident(),
+            "`with` argument is supported only by `#[derive(GraphQLObject)]`",
+        );
+        return None;
+    }
 
     let name = attr
         .name
@@ -370,10 +409,17 @@ fn parse_struct_field(field: &mut syn::Field, renaming: &RenameRule) -> Option<f
         ty,
         description,
         deprecated,
+        directives: attr.directives,
+        guards: attr
+            .guard
+            .map(SpanContainer::into_inner)
+            .into_iter()
+            .collect(),
         ident: field_ident.clone(),
         arguments: None,
         has_receiver: false,
         is_async: false,
+        with: None,
     })
 }
 
@@ -53,11 +53,12 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         .copied()
         .unwrap_or(RenameRule::CamelCase);
 
-    let fields = data
+    let mut fields = data
         .fields
         .iter()
         .filter_map(|f| parse_field(f, &renaming))
         .collect::<Vec<_>>();
+    field::prepend_guard(&mut fields, attr.guard.as_deref());
 
     proc_macro_error::abort_if_dirty();
 
@@ -94,6 +95,7 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         enum_alias_ident,
         name,
         description: attr.description.as_deref().cloned(),
+        directives: attr.directives,
         context,
         scalar,
         fields,
@@ -121,6 +123,20 @@ fn parse_field(field: &syn::Field, renaming: &RenameRule) -> Option<field::Defin
     if attr.ignore.is_some() {
         return None;
     }
+    if let Some(flatten) = &attr.flatten {
+        ERR.emit_custom(
+            flatten.span_ident(),
+            "`flatten` argument is supported only by `#[derive(GraphQLObject)]`",
+        );
+        return None;
+    }
+    if let Some(with) = &attr.with {
+        ERR.emit_custom(
+            with.span_ident(),
+            "`with` argument is supported only by `#[derive(GraphQLObject)]`",
+        );
+        return None;
+    }
 
     let name = attr
         .name
@@ -151,9 +167,16 @@ fn parse_field(field: &syn::Field, renaming: &RenameRule) -> Option<field::Defin
         ty,
         description,
         deprecated,
+        directives: attr.directives,
+        guards: attr
+            .guard
+            .map(SpanContainer::into_inner)
+            .into_iter()
+            .collect(),
         ident: field_ident.clone(),
         arguments: None,
         has_receiver: false,
         is_async: false,
+        with: None,
     })
 }
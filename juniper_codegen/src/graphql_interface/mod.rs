@@ -21,6 +21,7 @@ use syn::{
 
 use crate::{
     common::{
+        directive::{self, Directive},
         field, gen,
         parse::{
             attr::{err, OptionExt as _},
@@ -72,6 +73,20 @@ struct Attr {
     /// [2]: https://spec.graphql.org/June2018/#sec-Descriptions
     description: Option<SpanContainer<String>>,
 
+    /// Explicitly specified [type system directives][0] applied to this
+    /// [GraphQL interface][1] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Interfaces
+    directives: Vec<Directive>,
+
+    /// Explicitly specified [`Guard`] checked before resolving any field of
+    /// this [GraphQL interface][1] type.
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/June2018/#sec-Interfaces
+    guard: Option<SpanContainer<syn::Expr>>,
+
     /// Explicitly specified identifier of the type alias of Rust enum type
     /// behind the trait or struct, being an actual implementation of a
     /// [GraphQL interface][1] type.
@@ -159,6 +174,20 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse::<Directive>()?);
+                }
+                "cache_control" => {
+                    out.directives.push(Directive::parse_cache_control(input)?);
+                }
+                "guard" => {
+                    input.parse::<token::Eq>()?;
+                    let guard = input.parse::<syn::Expr>()?;
+                    out.guard
+                        .replace(SpanContainer::new(ident.span(), Some(guard.span()), guard))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ctx" | "context" | "Context" => {
                     input.parse::<token::Eq>()?;
                     let ctx = input.parse::<syn::Type>()?;
@@ -229,6 +258,12 @@ impl Attr {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            guard: try_merge_opt!(guard: self, another),
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             implemented_for: try_merge_hashset!(implemented_for: self, another => span_joined),
@@ -293,6 +328,12 @@ struct Definition {
     /// [1]: https://spec.graphql.org/June2018/#sec-Interfaces
     description: Option<String>,
 
+    /// [Type system directives][0] applied to this [GraphQL interface][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Interfaces
+    directives: Vec<Directive>,
+
     /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
     /// for this [GraphQL interface][1].
     ///
@@ -593,6 +634,7 @@ impl Definition {
         });
 
         let fields_meta = self.fields.iter().map(|f| f.method_meta_tokens(None));
+        let directives = directive::meta_tokens(&self.directives);
 
         quote! {
             #[automatically_derived]
@@ -618,6 +660,7 @@ impl Definition {
                     ];
                     registry.build_interface_type::<#ty#ty_generics>(info, &fields)
                         #description
+                        #directives
                         .into_meta()
                 }
             }
@@ -932,6 +975,28 @@ impl Definition {
                     quote! { _ => unreachable!() }
                 });
 
+                let resolve = field.guarded_resolve_tokens(
+                    scalar,
+                    quote_spanned! { field.ident.span() =>
+                        match self {
+                            #( #ty::#implemented_for_idents(v) => {
+                                ::juniper::assert_field!(
+                                    #ty#const_ty_generics,
+                                    #const_implemented_for,
+                                    #const_scalar,
+                                    #field_name,
+                                );
+
+                                <_ as ::juniper::macros::reflect::Field::<
+                                    #scalar,
+                                    { ::juniper::macros::reflect::fnv1a128(#field_name) },
+                                >>::call(v, info, args, executor)
+                            } )*
+                            #unreachable_arm
+                        }
+                    },
+                );
+
                 quote_spanned! { field.ident.span() =>
                     #[allow(non_snake_case)]
                     #[automatically_derived]
@@ -945,22 +1010,7 @@ impl Definition {
                             args: &::juniper::Arguments<#scalar>,
                             executor: &::juniper::Executor<Self::Context, #scalar>,
                         ) -> ::juniper::ExecutionResult<#scalar> {
-                            match self {
-                                #( #ty::#implemented_for_idents(v) => {
-                                    ::juniper::assert_field!(
-                                        #ty#const_ty_generics,
-                                        #const_implemented_for,
-                                        #const_scalar,
-                                        #field_name,
-                                    );
-
-                                    <_ as ::juniper::macros::reflect::Field::<
-                                        #scalar,
-                                        { ::juniper::macros::reflect::fnv1a128(#field_name) },
-                                    >>::call(v, info, args, executor)
-                                } )*
-                                #unreachable_arm
-                            }
+                            #resolve
                         }
                     }
                 }
@@ -1012,6 +1062,28 @@ impl Definition {
                     quote! { _ => unreachable!() }
                 });
 
+                let resolve = field.guarded_resolve_async_tokens(
+                    scalar,
+                    quote_spanned! { field.ident.span() =>
+                        match self {
+                            #( #ty::#implemented_for_idents(v) => {
+                                ::juniper::assert_field!(
+                                    #ty#const_ty_generics,
+                                    #const_implemented_for,
+                                    #const_scalar,
+                                    #field_name,
+                                );
+
+                                <_ as ::juniper::macros::reflect::AsyncField<
+                                    #scalar,
+                                    { ::juniper::macros::reflect::fnv1a128(#field_name) },
+                                >>::call(v, info, args, executor)
+                            } )*
+                            #unreachable_arm
+                        }
+                    },
+                );
+
                 quote_spanned! { field.ident.span() =>
                     #[allow(non_snake_case)]
                     #[automatically_derived]
@@ -1025,22 +1097,7 @@ impl Definition {
                             args: &'b ::juniper::Arguments<#scalar>,
                             executor: &'b ::juniper::Executor<Self::Context, #scalar>,
                         ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
-                            match self {
-                                #( #ty::#implemented_for_idents(v) => {
-                                    ::juniper::assert_field!(
-                                        #ty#const_ty_generics,
-                                        #const_implemented_for,
-                                        #const_scalar,
-                                        #field_name,
-                                    );
-
-                                    <_ as ::juniper::macros::reflect::AsyncField<
-                                        #scalar,
-                                        { ::juniper::macros::reflect::fnv1a128(#field_name) },
-                                    >>::call(v, info, args, executor)
-                                } )*
-                                #unreachable_arm
-                            }
+                            #resolve
                         }
                     }
                 }
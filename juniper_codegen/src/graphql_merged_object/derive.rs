@@ -0,0 +1,87 @@
+//! Code generation for `#[derive(GraphQLMergedObject)]` macro.
+
+use proc_macro2::TokenStream;
+use proc_macro_error::ResultExt as _;
+use quote::ToTokens;
+use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _};
+
+use crate::{
+    common::{parse::TypeExt as _, scalar},
+    result::GraphQLScope,
+    util::span_container::SpanContainer,
+};
+
+use super::{Attr, Definition};
+
+/// [`GraphQLScope`] of errors for `#[derive(GraphQLMergedObject)]` macro.
+const ERR: GraphQLScope = GraphQLScope::MergedObjectDerive;
+
+/// Expands `#[derive(GraphQLMergedObject)]` macro into generated code.
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let ast = syn::parse2::<syn::DeriveInput>(input).unwrap_or_abort();
+
+    match &ast.data {
+        syn::Data::Struct(_) => expand_struct(ast),
+        _ => Err(ERR.custom_error(ast.span(), "can only be derived for structs")),
+    }
+    .map(ToTokens::into_token_stream)
+}
+
+/// Expands into generated code a `#[derive(GraphQLMergedObject)]` macro placed
+/// on a Rust struct.
+fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition> {
+    let attr = Attr::from_attrs("graphql", &ast.attrs)?;
+
+    let struct_span = ast.span();
+    let struct_ident = ast.ident;
+
+    let (_, struct_generics, _) = ast.generics.split_for_impl();
+    let ty = parse_quote! { #struct_ident#struct_generics };
+
+    let name = attr
+        .name
+        .clone()
+        .map(SpanContainer::into_inner)
+        .unwrap_or_else(|| struct_ident.unraw().to_string());
+    if !attr.is_internal && name.starts_with("__") {
+        ERR.no_double_underscore(
+            attr.name
+                .as_ref()
+                .map(SpanContainer::span_ident)
+                .unwrap_or_else(|| struct_ident.span()),
+        );
+    }
+
+    let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
+
+    let parts: Vec<_> = match ast.data {
+        syn::Data::Struct(data) => data.fields,
+        _ => unreachable!(),
+    }
+    .into_iter()
+    .enumerate()
+    .map(|(i, f)| {
+        let member = f
+            .ident
+            .map(syn::Member::Named)
+            .unwrap_or_else(|| syn::Member::Unnamed(i.into()));
+        (member, f.ty.unparenthesized().clone())
+    })
+    .collect();
+    if parts.is_empty() {
+        ERR.emit_custom(struct_span, "expects at least one object to merge");
+    }
+
+    proc_macro_error::abort_if_dirty();
+
+    Ok(Definition {
+        name,
+        ty,
+        generics: ast.generics,
+        description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
+        context: attr.context.map(SpanContainer::into_inner),
+        scalar,
+        parts,
+    })
+}
@@ -0,0 +1,597 @@
+//! Code generation for [GraphQL object][1] merged from multiple other ones.
+//!
+//! [1]: https://spec.graphql.org/June2018/#sec-Objects
+
+pub mod derive;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::{
+    common::{
+        directive::{self, Directive},
+        parse::{
+            attr::{err, OptionExt as _},
+            GenericsExt as _, ParseBufferExt as _, TypeExt as _,
+        },
+        scalar,
+    },
+    util::{filter_attrs, get_doc_comment, span_container::SpanContainer},
+};
+
+/// Available arguments behind `#[graphql]` attribute when generating code for
+/// a [GraphQL object][1] merged from multiple other ones.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Objects
+#[derive(Debug, Default)]
+struct Attr {
+    /// Explicitly specified name of this [GraphQL object][1] type.
+    ///
+    /// If [`None`], then Rust type name is used by default.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    name: Option<SpanContainer<String>>,
+
+    /// Explicitly specified [description][2] of this [GraphQL object][1] type.
+    ///
+    /// If [`None`], then Rust doc comment is used as [description][2], if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    /// [2]: https://spec.graphql.org/June2018/#sec-Descriptions
+    description: Option<SpanContainer<String>>,
+
+    /// Explicitly specified [type system directives][0] applied to this
+    /// [GraphQL object][1] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    directives: Vec<Directive>,
+
+    /// Explicitly specified type of [`Context`] to use for resolving this
+    /// [GraphQL object][1] type with.
+    ///
+    /// If [`None`], then the [`Context`] of the first merged object is used.
+    ///
+    /// [`Context`]: juniper::Context
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    context: Option<SpanContainer<syn::Type>>,
+
+    /// Explicitly specified type (or type parameter with its bounds) of
+    /// [`ScalarValue`] to resolve this [GraphQL object][1] type with.
+    ///
+    /// If [`None`], then generated code will be generic over any
+    /// [`ScalarValue`] type, which, in turn, requires all the merged objects
+    /// to be generic over any [`ScalarValue`] type too. That's why this type
+    /// should be specified only if one of the merged objects implements
+    /// [`GraphQLType`] in a non-generic way over [`ScalarValue`] type.
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    scalar: Option<SpanContainer<scalar::AttrValue>>,
+
+    /// Indicator whether the generated code is intended to be used only inside
+    /// the [`juniper`] library.
+    is_internal: bool,
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            match ident.to_string().as_str() {
+                "name" => {
+                    input.parse::<token::Eq>()?;
+                    let name = input.parse::<syn::LitStr>()?;
+                    out.name
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(name.span()),
+                            name.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "desc" | "description" => {
+                    input.parse::<token::Eq>()?;
+                    let desc = input.parse::<syn::LitStr>()?;
+                    out.description
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(desc.span()),
+                            desc.value(),
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse::<Directive>()?);
+                }
+                "cache_control" => {
+                    out.directives.push(Directive::parse_cache_control(input)?);
+                }
+                "ctx" | "context" | "Context" => {
+                    input.parse::<token::Eq>()?;
+                    let ctx = input.parse::<syn::Type>()?;
+                    out.context
+                        .replace(SpanContainer::new(ident.span(), Some(ctx.span()), ctx))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "scalar" | "Scalar" | "ScalarValue" => {
+                    input.parse::<token::Eq>()?;
+                    let scl = input.parse::<scalar::AttrValue>()?;
+                    out.scalar
+                        .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "internal" => {
+                    out.is_internal = true;
+                }
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl Attr {
+    /// Tries to merge two [`Attr`]s into a single one, reporting about
+    /// duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            name: try_merge_opt!(name: self, another),
+            description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            context: try_merge_opt!(context: self, another),
+            scalar: try_merge_opt!(scalar: self, another),
+            is_internal: self.is_internal || another.is_internal,
+        })
+    }
+
+    /// Parses [`Attr`] from the given multiple `name`d [`syn::Attribute`]s
+    /// placed on a type definition.
+    fn from_attrs(name: &str, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut attr = filter_attrs(name, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
+
+        if attr.description.is_none() {
+            attr.description = get_doc_comment(attrs);
+        }
+
+        Ok(attr)
+    }
+}
+
+/// Definition of a [GraphQL object][1] merged from multiple other ones for
+/// code generation.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Objects
+struct Definition {
+    /// Name of this [GraphQL object][1] in GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    name: String,
+
+    /// Rust type that this [GraphQL object][1] is represented with.
+    ///
+    /// It should contain all its generics, if any.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    ty: syn::Type,
+
+    /// Generics of the Rust type that this [GraphQL object][1] is implemented
+    /// for.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    generics: syn::Generics,
+
+    /// Description of this [GraphQL object][1] to put into GraphQL schema.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    description: Option<String>,
+
+    /// [Type system directives][0] applied to this [GraphQL object][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    directives: Vec<Directive>,
+
+    /// Explicitly specified Rust type of [`Context`] to generate
+    /// [`GraphQLType`] implementation with for this [GraphQL object][1].
+    ///
+    /// If [`None`], then the [`Context`] of the first merged object is used.
+    ///
+    /// [`Context`]: juniper::Context
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    context: Option<syn::Type>,
+
+    /// [`ScalarValue`] parametrization to generate [`GraphQLType`]
+    /// implementation with for this [GraphQL object][1].
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    scalar: scalar::Type,
+
+    /// Struct fields holding the [GraphQL objects][1] being merged, along with
+    /// their Rust types.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    parts: Vec<(syn::Member, syn::Type)>,
+}
+
+impl ToTokens for Definition {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        self.impl_graphql_object_tokens().to_tokens(into);
+        self.impl_output_type_tokens().to_tokens(into);
+        self.impl_graphql_type_tokens().to_tokens(into);
+        self.impl_graphql_value_tokens().to_tokens(into);
+        self.impl_graphql_value_async_tokens().to_tokens(into);
+        self.impl_reflection_traits_tokens().to_tokens(into);
+    }
+}
+
+impl Definition {
+    /// Returns prepared [`syn::Generics::split_for_impl`] for [`GraphQLType`]
+    /// trait (and similar) implementation of this [GraphQL object][1].
+    ///
+    /// If `for_async` is `true`, then additional predicates are added to suit
+    /// the [`GraphQLAsyncValue`] trait (and similar) requirements.
+    ///
+    /// [`GraphQLAsyncValue`]: juniper::GraphQLAsyncValue
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    #[must_use]
+    fn impl_generics(&self, for_async: bool) -> (TokenStream, Option<syn::WhereClause>) {
+        let mut generics = self.generics.clone();
+
+        let scalar = &self.scalar;
+        if scalar.is_implicit_generic() {
+            generics.params.push(parse_quote! { #scalar });
+        }
+        if scalar.is_generic() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #scalar: ::juniper::ScalarValue });
+        }
+        if let Some(bound) = scalar.bounds() {
+            generics.make_where_clause().predicates.push(bound);
+        }
+
+        if for_async {
+            let self_ty = if self.generics.lifetimes().next().is_some() {
+                let mut lifetimes = vec![];
+
+                // Modify lifetime names to omit "lifetime name `'a` shadows a
+                // lifetime name that is already in scope" error.
+                let mut ty = self.ty.clone();
+                ty.lifetimes_iter_mut(&mut |lt| {
+                    let ident = lt.ident.unraw();
+                    lt.ident = format_ident!("__fa__{}", ident);
+                    lifetimes.push(lt.clone());
+                });
+
+                quote! { for<#( #lifetimes ),*> #ty }
+            } else {
+                quote! { Self }
+            };
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #self_ty: Sync });
+
+            if scalar.is_generic() {
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! { #scalar: Send + Sync });
+            }
+        }
+
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        (quote! { #impl_generics }, where_clause.cloned())
+    }
+
+    /// Returns Rust type of [`Context`] to resolve this [GraphQL object][1]
+    /// with.
+    ///
+    /// [`Context`]: juniper::Context
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    #[must_use]
+    fn context_ty(&self) -> syn::Type {
+        let scalar = &self.scalar;
+        self.context.clone().unwrap_or_else(|| {
+            let first = &self.parts[0].1;
+            parse_quote! { <#first as ::juniper::GraphQLValue<#scalar>>::Context }
+        })
+    }
+
+    /// Returns generated code implementing [`GraphQLObject`] trait for this
+    /// [GraphQL object][1], asserting that the merged objects don't define
+    /// fields with the same name.
+    ///
+    /// [`GraphQLObject`]: juniper::GraphQLObject
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    #[must_use]
+    fn impl_graphql_object_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+        let const_scalar = scalar.default_ty();
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let part_tys = self.parts.iter().map(|(_, ty)| ty);
+
+        let generics = {
+            let mut generics = self.generics.clone();
+            if scalar.is_implicit_generic() {
+                generics.params.push(parse_quote! { #scalar })
+            }
+            generics
+        };
+        let mut const_ty = ty.clone();
+        generics.replace_type_with_defaults(&mut const_ty);
+        let const_part_tys = part_tys.clone().cloned().map(|mut ty| {
+            generics.replace_type_with_defaults(&mut ty);
+            ty
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::marker::GraphQLObject<#scalar> for #ty #where_clause
+            {
+                fn mark() {
+                    #( <#part_tys as ::juniper::marker::GraphQLObject<#scalar>>::mark(); )*
+                    ::juniper::assert_merged_fields!(
+                        #const_scalar, #const_ty, #( #const_part_tys ),*
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`marker::IsOutputType`] trait for
+    /// this [GraphQL object][1].
+    ///
+    /// [`marker::IsOutputType`]: juniper::marker::IsOutputType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    #[must_use]
+    fn impl_output_type_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let part_tys = self.parts.iter().map(|(_, ty)| ty);
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::marker::IsOutputType<#scalar> for #ty #where_clause
+            {
+                fn mark() {
+                    #( <#part_tys as ::juniper::marker::IsOutputType<#scalar>>::mark(); )*
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLType`] trait for this
+    /// [GraphQL object][1], exposing the fields of all the merged objects.
+    ///
+    /// [`GraphQLType`]: juniper::GraphQLType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    #[must_use]
+    fn impl_graphql_type_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        let name = &self.name;
+        let description = self
+            .description
+            .as_ref()
+            .map(|desc| quote! { .description(#desc) });
+        let directives = directive::meta_tokens(&self.directives);
+
+        let part_tys = self.parts.iter().map(|(_, ty)| ty);
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::GraphQLType<#scalar> for #ty #where_clause
+            {
+                fn name(_ : &Self::TypeInfo) -> Option<&'static str> {
+                    Some(#name)
+                }
+
+                fn meta<'r>(
+                    info: &Self::TypeInfo,
+                    registry: &mut ::juniper::Registry<'r, #scalar>
+                ) -> ::juniper::meta::MetaType<'r, #scalar>
+                where #scalar: 'r,
+                {
+                    let mut fields = Vec::new();
+                    #( fields.extend(::juniper::macros::helper::merged_object_fields(
+                        <#part_tys as ::juniper::GraphQLType<#scalar>>::meta(info, registry),
+                        #name,
+                    )); )*
+                    registry.build_object_type::<#ty>(info, &fields)
+                        #description
+                        #directives
+                        .into_meta()
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLValue`] trait for this
+    /// [GraphQL object][1], delegating fields resolution to the merged objects.
+    ///
+    /// [`GraphQLValue`]: juniper::GraphQLValue
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    #[must_use]
+    fn impl_graphql_value_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+        let context = self.context_ty();
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+        let ty_name = ty.to_token_stream().to_string();
+
+        let name = &self.name;
+
+        let fields_resolvers = self.parts.iter().map(|(member, part_ty)| {
+            quote! {
+                if ::juniper::macros::reflect::str_exists_in_arr(
+                    field,
+                    <#part_ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES,
+                ) {
+                    return <#part_ty as ::juniper::GraphQLValue<#scalar>>::resolve_field(
+                        &self.#member, info, field, args, executor,
+                    );
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::GraphQLValue<#scalar> for #ty #where_clause
+            {
+                type Context = #context;
+                type TypeInfo = ();
+
+                fn type_name<'__i>(&self, info: &'__i Self::TypeInfo) -> Option<&'__i str> {
+                    <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+                }
+
+                fn resolve_field(
+                    &self,
+                    info: &Self::TypeInfo,
+                    field: &str,
+                    args: &::juniper::Arguments<#scalar>,
+                    executor: &::juniper::Executor<Self::Context, #scalar>,
+                ) -> ::juniper::ExecutionResult<#scalar> {
+                    #( #fields_resolvers )*
+                    Err(::juniper::FieldError::from(format!(
+                        "Field `{}` not found on type `{}`",
+                        field, #ty_name,
+                    )))
+                }
+
+                fn concrete_type_name(
+                    &self,
+                    _: &Self::Context,
+                    _: &Self::TypeInfo,
+                ) -> String {
+                    #name.to_string()
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`GraphQLValueAsync`] trait for this
+    /// [GraphQL object][1], delegating fields resolution to the merged objects.
+    ///
+    /// [`GraphQLValueAsync`]: juniper::GraphQLValueAsync
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    #[must_use]
+    fn impl_graphql_value_async_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(true);
+        let ty = &self.ty;
+        let ty_name = ty.to_token_stream().to_string();
+
+        let fields_resolvers = self.parts.iter().map(|(member, part_ty)| {
+            quote! {
+                if ::juniper::macros::reflect::str_exists_in_arr(
+                    field,
+                    <#part_ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES,
+                ) {
+                    return <#part_ty as ::juniper::GraphQLValueAsync<#scalar>>::resolve_field_async(
+                        &self.#member, info, field, args, executor,
+                    );
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::GraphQLValueAsync<#scalar> for #ty #where_clause
+            {
+                fn resolve_field_async<'b>(
+                    &'b self,
+                    info: &'b Self::TypeInfo,
+                    field: &'b str,
+                    args: &'b ::juniper::Arguments<#scalar>,
+                    executor: &'b ::juniper::Executor<Self::Context, #scalar>,
+                ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
+                    #( #fields_resolvers )*
+                    ::juniper::macros::helper::err_fut(format!(
+                        "Field `{}` not found on type `{}`",
+                        field, #ty_name,
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`BaseType`], [`BaseSubTypes`] and
+    /// [`WrappedType`] traits for this [GraphQL object][1].
+    ///
+    /// [`BaseSubTypes`]: juniper::macros::reflect::BaseSubTypes
+    /// [`BaseType`]: juniper::macros::reflect::BaseType
+    /// [`WrappedType`]: juniper::macros::reflect::WrappedType
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    #[must_use]
+    fn impl_reflection_traits_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+        let name = &self.name;
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+
+        quote! {
+            #[automatically_derived]
+            impl#impl_generics ::juniper::macros::reflect::BaseType<#scalar>
+                for #ty
+                #where_clause
+            {
+                const NAME: ::juniper::macros::reflect::Type = #name;
+            }
+
+            #[automatically_derived]
+            impl#impl_generics ::juniper::macros::reflect::BaseSubTypes<#scalar>
+                for #ty
+                #where_clause
+            {
+                const NAMES: ::juniper::macros::reflect::Types =
+                    &[<Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME];
+            }
+
+            #[automatically_derived]
+            impl#impl_generics ::juniper::macros::reflect::WrappedType<#scalar>
+                for #ty
+                #where_clause
+            {
+                const VALUE: ::juniper::macros::reflect::WrappedValue = 1;
+            }
+        }
+    }
+}
@@ -16,7 +16,7 @@ use crate::{
     util::{path_eq_single, span_container::SpanContainer, RenameRule},
 };
 
-use super::{Attr, Definition, Query};
+use super::{Attr, Computed, Definition, Query};
 
 /// [`GraphQLScope`] of errors for `#[graphql_object]` macro.
 const ERR: GraphQLScope = GraphQLScope::ObjectAttr;
@@ -66,6 +66,37 @@ where
     }
 
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
+    let instantiations = attr.instantiations(&ast.generics, &scalar)?;
+    if let Some(remote) = &attr.remote {
+        return Err(ERR.custom_error(
+            remote.span_ident(),
+            "`remote` argument is supported only by `#[derive(GraphQLObject)]`",
+        ));
+    }
+    if let Some(computed) = &attr.computed {
+        if TypeId::of::<Operation>() != TypeId::of::<Query>() {
+            return Err(ERR.custom_error(
+                computed.span_ident(),
+                "computed fields are supported only for queries",
+            ));
+        }
+        let conflicting = [
+            ("name", attr.name.is_some()),
+            ("directive", !attr.directives.is_empty()),
+            ("impl", !attr.interfaces.is_empty()),
+            ("concrete", !attr.concrete.is_empty()),
+        ];
+        if let Some((arg, _)) = conflicting.iter().find(|(_, is_set)| *is_set) {
+            return Err(ERR.custom_error(
+                computed.span_ident(),
+                format!(
+                    "`{}` argument should be specified on the struct, \
+                     not on its computed fields",
+                    arg,
+                ),
+            ));
+        }
+    }
 
     proc_macro_error::abort_if_dirty();
 
@@ -76,7 +107,7 @@ where
         .unwrap_or(RenameRule::CamelCase);
 
     let async_only = TypeId::of::<Operation>() != TypeId::of::<Query>();
-    let fields: Vec<_> = ast
+    let mut fields: Vec<_> = ast
         .items
         .iter_mut()
         .filter_map(|item| {
@@ -87,6 +118,7 @@ where
             }
         })
         .collect();
+    field::prepend_guard(&mut fields, attr.guard.as_deref());
 
     proc_macro_error::abort_if_dirty();
 
@@ -112,27 +144,37 @@ where
                 })
             })
         })
-        .unwrap_or_else(|| parse_quote! { () });
+        .unwrap_or_else(|| {
+            if attr.computed.is_some() {
+                parse_quote! { <Self as ::juniper::GraphQLValue<#scalar>>::Context }
+            } else {
+                parse_quote! { () }
+            }
+        });
 
-    let generated_code = Definition::<Operation> {
+    let definitions = Definition::<Operation> {
         name,
         ty: ast.self_ty.unparenthesized().clone(),
         generics: ast.generics.clone(),
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
         context,
         scalar,
         fields,
+        flattened: vec![],
         interfaces: attr
             .interfaces
             .iter()
             .map(|ty| ty.as_ref().clone())
             .collect(),
+        computed: attr.computed.map(|_| Computed::Providing),
         _operation: PhantomData,
-    };
+    }
+    .instantiate(instantiations);
 
     Ok(quote! {
         #ast
-        #generated_code
+        #( #definitions )*
     })
 }
 
@@ -160,6 +202,20 @@ fn parse_field(
     if attr.ignore.is_some() {
         return None;
     }
+    if let Some(flatten) = &attr.flatten {
+        ERR.emit_custom(
+            flatten.span_ident(),
+            "`flatten` argument is supported only by `#[derive(GraphQLObject)]`",
+        );
+        return None;
+    }
+    if let Some(with) = &attr.with {
+        ERR.emit_custom(
+            with.span_ident(),
+            "`with` argument is supported only by `#[derive(GraphQLObject)]`",
+        );
+        return None;
+    }
 
     if async_only && method.sig.asyncness.is_none() {
         return err_no_sync_resolvers(&method.sig);
@@ -227,10 +283,17 @@ fn parse_field(
         ty,
         description,
         deprecated,
+        directives: attr.directives,
+        guards: attr
+            .guard
+            .map(SpanContainer::into_inner)
+            .into_iter()
+            .collect(),
         ident: method_ident.clone(),
         arguments: Some(arguments),
         has_receiver: method.sig.receiver().is_some(),
         is_async: method.sig.asyncness.is_some(),
+        with: None,
     })
 }
 
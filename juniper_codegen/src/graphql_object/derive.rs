@@ -4,16 +4,16 @@ use std::marker::PhantomData;
 
 use proc_macro2::TokenStream;
 use proc_macro_error::ResultExt as _;
-use quote::ToTokens;
+use quote::quote;
 use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _};
 
 use crate::{
-    common::{field, parse::TypeExt as _, scalar},
+    common::{field, parse::TypeExt as _, remote, scalar},
     result::GraphQLScope,
     util::{span_container::SpanContainer, RenameRule},
 };
 
-use super::{Attr, Definition, Query};
+use super::{Attr, Computed, Definition, FlattenedField, Query};
 
 /// [`GraphQLScope`] of errors for `#[derive(GraphQLObject)]` macro.
 const ERR: GraphQLScope = GraphQLScope::ObjectDerive;
@@ -26,13 +26,16 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         syn::Data::Struct(_) => expand_struct(ast),
         _ => Err(ERR.custom_error(ast.span(), "can only be derived for structs")),
     }
-    .map(ToTokens::into_token_stream)
 }
 
 /// Expands into generated code a `#[derive(GraphQLObject)]` macro placed on a
 /// Rust struct.
-fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
+fn expand_struct(ast: syn::DeriveInput) -> syn::Result<TokenStream> {
     let attr = Attr::from_attrs("graphql", &ast.attrs)?;
+    let remote_conversions = attr
+        .remote
+        .as_ref()
+        .map(|remote| remote::impl_from_tokens(&ast.ident, &ast.generics, remote, &ast.data));
 
     let struct_span = ast.span();
     let struct_ident = ast.ident;
@@ -55,6 +58,20 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
     }
 
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
+    let instantiations = attr.instantiations(&ast.generics, &scalar)?;
+    if let Some(computed) = &attr.computed {
+        let is_generic = ast
+            .generics
+            .type_params()
+            .any(|p| !matches!(&scalar, scalar::Type::ExplicitGeneric(s) if *s == p.ident));
+        if is_generic && instantiations.is_empty() {
+            ERR.emit_custom(
+                computed.span_ident(),
+                "cannot merge computed fields into a generic struct, \
+                 consider specifying its `concrete(...)` instantiations",
+            );
+        }
+    }
 
     proc_macro_error::abort_if_dirty();
 
@@ -64,22 +81,23 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
         .copied()
         .unwrap_or(RenameRule::CamelCase);
 
-    let mut fields = vec![];
+    let (mut fields, mut flattened) = (vec![], vec![]);
     if let syn::Data::Struct(data) = &ast.data {
         if let syn::Fields::Named(fs) = &data.fields {
             fields = fs
                 .named
                 .iter()
-                .filter_map(|f| parse_field(f, &renaming))
+                .filter_map(|f| parse_field(f, &renaming, &mut flattened))
                 .collect();
         } else {
             ERR.emit_custom(struct_span, "only named fields are allowed");
         }
     }
+    field::prepend_guard(&mut fields, attr.guard.as_deref());
 
     proc_macro_error::abort_if_dirty();
 
-    if fields.is_empty() {
+    if fields.is_empty() && flattened.is_empty() {
         ERR.emit_custom(struct_span, "must have at least one field");
     }
     if !field::all_different(&fields) {
@@ -88,31 +106,46 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
 
     proc_macro_error::abort_if_dirty();
 
-    Ok(Definition {
+    let definitions = Definition::<Query> {
         name,
         ty,
         generics: ast.generics,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
         context: attr
             .context
             .map(SpanContainer::into_inner)
             .unwrap_or_else(|| parse_quote! { () }),
         scalar,
         fields,
+        flattened,
         interfaces: attr
             .interfaces
             .iter()
             .map(|ty| ty.as_ref().clone())
             .collect(),
+        computed: attr.computed.map(|_| Computed::Merging),
         _operation: PhantomData,
+    }
+    .instantiate(instantiations);
+
+    Ok(quote! {
+        #( #definitions )*
+        #remote_conversions
     })
 }
 
 /// Parses a [`field::Definition`] from the given Rust struct [`syn::Field`].
 ///
-/// Returns [`None`] if parsing fails, or the struct field is ignored.
+/// Returns [`None`] if parsing fails, or the struct field is ignored or
+/// flattened. Flattened struct fields are collected into the given `flattened`
+/// ones instead.
 #[must_use]
-fn parse_field(field: &syn::Field, renaming: &RenameRule) -> Option<field::Definition> {
+fn parse_field(
+    field: &syn::Field,
+    renaming: &RenameRule,
+    flattened: &mut Vec<FlattenedField>,
+) -> Option<field::Definition> {
     let attr = field::Attr::from_attrs("graphql", &field.attrs)
         .map_err(|e| proc_macro_error::emit_error!(e))
         .ok()?;
@@ -123,6 +156,14 @@ fn parse_field(field: &syn::Field, renaming: &RenameRule) -> Option<field::Defin
 
     let field_ident = field.ident.as_ref().unwrap();
 
+    if attr.flatten.is_some() {
+        flattened.push(FlattenedField {
+            ident: field_ident.clone(),
+            ty: field.ty.unparenthesized().clone(),
+        });
+        return None;
+    }
+
     let name = attr
         .name
         .as_ref()
@@ -152,9 +193,16 @@ fn parse_field(field: &syn::Field, renaming: &RenameRule) -> Option<field::Defin
         ty,
         description,
         deprecated,
+        directives: attr.directives,
+        guards: attr
+            .guard
+            .map(SpanContainer::into_inner)
+            .into_iter()
+            .collect(),
         ident: field_ident.clone(),
         arguments: None,
         has_receiver: false,
         is_async: false,
+        with: attr.with.map(SpanContainer::into_inner),
     })
 }
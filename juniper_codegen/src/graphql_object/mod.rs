@@ -5,25 +5,27 @@
 pub mod attr;
 pub mod derive;
 
-use std::{any::TypeId, collections::HashSet, convert::TryInto as _, marker::PhantomData};
+use std::{any::TypeId, collections::HashSet, convert::TryInto as _, iter, marker::PhantomData};
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     parse_quote,
     spanned::Spanned as _,
     token,
+    visit_mut::VisitMut as _,
 };
 
 use crate::{
     common::{
+        directive::{self, Directive},
         field, gen,
         parse::{
             attr::{err, OptionExt as _},
-            GenericsExt as _, ParseBufferExt as _, TypeExt,
+            GenericsExt as _, ParseBufferExt as _, ReplaceTypeParams, TypeExt,
         },
-        scalar,
+        remote, scalar,
     },
     util::{filter_attrs, get_doc_comment, span_container::SpanContainer, RenameRule},
 };
@@ -50,6 +52,20 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/June2018/#sec-Descriptions
     pub(crate) description: Option<SpanContainer<String>>,
 
+    /// Explicitly specified [type system directives][0] applied to this
+    /// [GraphQL object][1] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) directives: Vec<Directive>,
+
+    /// Explicitly specified [`Guard`] checked before resolving any field of
+    /// this [GraphQL object][1] type.
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) guard: Option<SpanContainer<syn::Expr>>,
+
     /// Explicitly specified type of [`Context`] to use for resolving this
     /// [GraphQL object][1] type with.
     ///
@@ -88,6 +104,27 @@ pub(crate) struct Attr {
     /// [1]: https://spec.graphql.org/June2018/#sec-Objects
     pub(crate) rename_fields: Option<SpanContainer<RenameRule>>,
 
+    /// Explicitly specified [`Concrete`] instantiations of this generic
+    /// [GraphQL object][1] type.
+    ///
+    /// If empty, then the code is generated for the generic type itself.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) concrete: Vec<Concrete>,
+
+    /// Explicitly specified marker indicating that this [GraphQL object][1]
+    /// type is split between a struct and a separate `impl` block declaring
+    /// its computed fields.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) computed: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified remote (foreign) Rust type, mirrored by the struct
+    /// this [GraphQL object][1] type is derived for.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) remote: Option<SpanContainer<syn::Type>>,
+
     /// Indicator whether the generated code is intended to be used only inside
     /// the [`juniper`] library.
     pub(crate) is_internal: bool,
@@ -121,6 +158,20 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse::<Directive>()?);
+                }
+                "cache_control" => {
+                    out.directives.push(Directive::parse_cache_control(input)?);
+                }
+                "guard" => {
+                    input.parse::<token::Eq>()?;
+                    let guard = input.parse::<syn::Expr>()?;
+                    out.guard
+                        .replace(SpanContainer::new(ident.span(), Some(guard.span()), guard))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ctx" | "context" | "Context" => {
                     input.parse::<token::Eq>()?;
                     let ctx = input.parse::<syn::Type>()?;
@@ -158,6 +209,24 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?;
                 }
+                "concrete" => {
+                    out.concrete.push(input.parse::<Concrete>()?);
+                }
+                "computed" => out
+                    .computed
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "remote" => {
+                    input.parse::<token::Eq>()?;
+                    let remote = remote::parse_type(input)?;
+                    out.remote
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(remote.span()),
+                            remote,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "internal" => {
                     out.is_internal = true;
                 }
@@ -178,10 +247,19 @@ impl Attr {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
+            guard: try_merge_opt!(guard: self, another),
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             interfaces: try_merge_hashset!(interfaces: self, another => span_joined),
             rename_fields: try_merge_opt!(rename_fields: self, another),
+            concrete: self.concrete.into_iter().chain(another.concrete).collect(),
+            computed: try_merge_opt!(computed: self, another),
+            remote: try_merge_opt!(remote: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
     }
@@ -199,6 +277,210 @@ impl Attr {
 
         Ok(attr)
     }
+
+    /// Resolves the [`Concrete`] instantiations of this generic
+    /// [GraphQL object][1] type with the provided `generics`, rendering their
+    /// names from the `{T}` placeholders of the `name` template, if required.
+    ///
+    /// # Errors
+    ///
+    /// If the instantiations don't match the type parameters, or if the `name`
+    /// template contains placeholders while there are no instantiations.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) fn instantiations(
+        &self,
+        generics: &syn::Generics,
+        scalar: &scalar::Type,
+    ) -> syn::Result<Vec<Instantiation>> {
+        let type_params: Vec<_> = generics
+            .type_params()
+            .map(|p| &p.ident)
+            .filter(|ident| !matches!(scalar, scalar::Type::ExplicitGeneric(s) if s == *ident))
+            .collect();
+
+        let template = self.name.as_ref();
+        let has_placeholders = matches!(template, Some(t) if t.contains('{'));
+        if let Some(t) = template.filter(|_| has_placeholders) {
+            if self.concrete.is_empty() {
+                return Err(syn::Error::new(
+                    t.span_ident(),
+                    "name template requires `concrete(...)` instantiations",
+                ));
+            }
+            for placeholder in t.split('{').skip(1) {
+                let param = placeholder.split('}').next().unwrap_or_default();
+                if !type_params.iter().any(|p| *p == param) {
+                    return Err(syn::Error::new(
+                        t.span_ident(),
+                        format!("unknown type parameter `{}` in name template", param),
+                    ));
+                }
+            }
+        }
+
+        self.concrete
+            .iter()
+            .map(|c| {
+                if c.params.len() != type_params.len() {
+                    return Err(syn::Error::new(
+                        c.span,
+                        format!(
+                            "expected {} type parameter(s) in `params(...)`, found {}",
+                            type_params.len(),
+                            c.params.len(),
+                        ),
+                    ));
+                }
+                let params: Vec<_> = type_params
+                    .iter()
+                    .map(|p| (*p).clone())
+                    .zip(c.params.iter().cloned())
+                    .collect();
+
+                let name = match (&c.name, template) {
+                    (Some(name), _) => name.as_ref().clone(),
+                    (None, Some(t)) if has_placeholders => {
+                        let mut name = t.as_ref().clone();
+                        for (param, ty) in &params {
+                            let ty_name = ty.topmost_ident().ok_or_else(|| {
+                                syn::Error::new(
+                                    ty.span(),
+                                    "cannot render name template with this type, \
+                                     specify `name = \"...\"` explicitly",
+                                )
+                            })?;
+                            name = name
+                                .replace(&format!("{{{}}}", param), &ty_name.unraw().to_string());
+                        }
+                        name
+                    }
+                    _ => {
+                        return Err(syn::Error::new(
+                            c.span,
+                            "expected either `name = \"...\"` in `concrete(...)`, \
+                             or `{T}` placeholders in the type's `name` template",
+                        ))
+                    }
+                };
+
+                Ok(Instantiation { name, params })
+            })
+            .collect()
+    }
+}
+
+/// Concrete instantiation of a generic [GraphQL object][1] type, specified
+/// with a `concrete(name = "...", params(...))` attribute argument.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Objects
+#[derive(Debug)]
+pub(crate) struct Concrete {
+    /// Explicitly specified name of this instantiation in GraphQL schema.
+    ///
+    /// If [`None`], then it's rendered from the `name` template of the generic
+    /// [GraphQL object][1] type.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    name: Option<SpanContainer<String>>,
+
+    /// Rust types substituting the type parameters of the generic
+    /// [GraphQL object][1] type, in their declaration order.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    params: Vec<syn::Type>,
+
+    /// [`Span`] of this [`Concrete`] instantiation arguments.
+    span: Span,
+}
+
+impl Parse for Concrete {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let span = input.span();
+        let content;
+        let _ = syn::parenthesized!(content in input);
+
+        let (mut name, mut params) = (None, None);
+        while !content.is_empty() {
+            let ident = content.parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "name" => {
+                    content.parse::<token::Eq>()?;
+                    let lit = content.parse::<syn::LitStr>()?;
+                    name.replace(SpanContainer::new(
+                        ident.span(),
+                        Some(lit.span()),
+                        lit.value(),
+                    ))
+                    .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "params" => {
+                    let tys = content.parse_maybe_wrapped_and_punctuated::<
+                        syn::Type, token::Paren, token::Comma,
+                    >()?;
+                    params
+                        .replace(tys.into_iter().collect())
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            content.try_parse::<token::Comma>()?;
+        }
+
+        Ok(Self {
+            name,
+            params: params.ok_or_else(|| syn::Error::new(span, "expected `params(...)`"))?,
+            span,
+        })
+    }
+}
+
+/// [`Concrete`] instantiation of a generic [GraphQL object][1] type, resolved
+/// against its type parameters.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Objects
+pub(crate) struct Instantiation {
+    /// Name of this instantiation in GraphQL schema.
+    name: String,
+
+    /// Type parameters of the generic [GraphQL object][1] type along with the
+    /// Rust types substituting them.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    params: Vec<(syn::Ident, syn::Type)>,
+}
+
+/// Role of a [`Definition`] in a [GraphQL object][1] split between a struct
+/// and a separate `impl` block declaring its computed fields.
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Objects
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Computed {
+    /// Struct with `#[derive(GraphQLObject)]`, merging the computed fields into
+    /// its own ones.
+    Merging,
+
+    /// `impl` block with `#[graphql_object]`, providing the computed fields.
+    Providing,
+}
+
+/// Rust struct field with `#[graphql(flatten)]` attribute, inlining the
+/// [GraphQL fields][2] of its type into the parent [GraphQL object][1].
+///
+/// [1]: https://spec.graphql.org/June2018/#sec-Objects
+/// [2]: https://spec.graphql.org/June2018/#sec-Language.Fields
+#[derive(Clone, Debug)]
+pub(crate) struct FlattenedField {
+    /// [`syn::Ident`] of the flattened Rust struct field.
+    pub(crate) ident: syn::Ident,
+
+    /// Rust type of the flattened Rust struct field, representing a
+    /// [GraphQL object][1].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) ty: syn::Type,
 }
 
 /// Definition of [GraphQL object][1] for code generation.
@@ -229,6 +511,12 @@ pub(crate) struct Definition<Operation: ?Sized> {
     /// [1]: https://spec.graphql.org/June2018/#sec-Objects
     pub(crate) description: Option<String>,
 
+    /// [Type system directives][0] applied to this [GraphQL object][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) directives: Vec<Directive>,
+
     /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
     /// for this [GraphQL object][1].
     ///
@@ -251,12 +539,25 @@ pub(crate) struct Definition<Operation: ?Sized> {
     /// [2]: https://spec.graphql.org/June2018/#sec-Language.Fields
     pub(crate) fields: Vec<field::Definition>,
 
+    /// [`FlattenedField`]s of this [GraphQL object][1], whose
+    /// [GraphQL fields][2] are inlined after the own [`Definition::fields`].
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    /// [2]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    pub(crate) flattened: Vec<FlattenedField>,
+
     /// [GraphQL interfaces][2] implemented by this [GraphQL object][1].
     ///
     /// [1]: https://spec.graphql.org/June2018/#sec-Objects
     /// [2]: https://spec.graphql.org/June2018/#sec-Interfaces
     pub(crate) interfaces: HashSet<syn::Type>,
 
+    /// [`Computed`] role of this [`Definition`], if its [GraphQL object][1] is
+    /// split between a struct and a separate `impl` block.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    pub(crate) computed: Option<Computed>,
+
     /// [GraphQL operation][1] this [`Definition`] should generate code for.
     ///
     /// Either [GraphQL query][2] or [GraphQL subscription][3].
@@ -268,6 +569,103 @@ pub(crate) struct Definition<Operation: ?Sized> {
 }
 
 impl<Operation: ?Sized + 'static> Definition<Operation> {
+    /// Returns [`Definition`]s of the provided concrete [`Instantiation`]s of
+    /// this generic [GraphQL object][1], or this [`Definition`] as is, if there
+    /// are no [`Instantiation`]s.
+    ///
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    #[must_use]
+    pub(crate) fn instantiate(self, instantiations: Vec<Instantiation>) -> Vec<Self> {
+        if instantiations.is_empty() {
+            return vec![self];
+        }
+
+        instantiations
+            .into_iter()
+            .map(|inst| {
+                let mut replace = ReplaceTypeParams(&inst.params);
+
+                let mut generics = self.generics.clone();
+                generics.params = generics
+                    .params
+                    .into_iter()
+                    .filter(|p| match p {
+                        syn::GenericParam::Type(p) => {
+                            inst.params.iter().all(|(param, _)| *param != p.ident)
+                        }
+                        _ => true,
+                    })
+                    .collect();
+                replace.visit_generics_mut(&mut generics);
+
+                let mut ty = self.ty.clone();
+                replace.visit_type_mut(&mut ty);
+                let mut context = self.context.clone();
+                replace.visit_type_mut(&mut context);
+
+                let fields = self
+                    .fields
+                    .iter()
+                    .cloned()
+                    .map(|mut f| {
+                        replace.visit_type_mut(&mut f.ty);
+                        f.guards.iter_mut().for_each(|g| replace.visit_expr_mut(g));
+                        for arg in f.arguments.iter_mut().flatten() {
+                            match arg {
+                                field::MethodArgument::Regular(arg) => {
+                                    replace.visit_type_mut(&mut arg.ty);
+                                    if let Some(Some(default)) = &mut arg.default {
+                                        replace.visit_expr_mut(default);
+                                    }
+                                }
+                                field::MethodArgument::Context(ty) => {
+                                    replace.visit_type_mut(ty);
+                                }
+                                field::MethodArgument::Executor => {}
+                            }
+                        }
+                        f
+                    })
+                    .collect();
+
+                let flattened = self
+                    .flattened
+                    .iter()
+                    .cloned()
+                    .map(|mut f| {
+                        replace.visit_type_mut(&mut f.ty);
+                        f
+                    })
+                    .collect();
+
+                let interfaces = self
+                    .interfaces
+                    .iter()
+                    .cloned()
+                    .map(|mut iface| {
+                        replace.visit_type_mut(&mut iface);
+                        iface
+                    })
+                    .collect();
+
+                Self {
+                    name: inst.name,
+                    ty,
+                    generics,
+                    description: self.description.clone(),
+                    directives: self.directives.clone(),
+                    context,
+                    scalar: self.scalar.clone(),
+                    fields,
+                    flattened,
+                    interfaces,
+                    computed: self.computed,
+                    _operation: PhantomData,
+                }
+            })
+            .collect()
+    }
+
     /// Returns prepared [`syn::Generics::split_for_impl`] for [`GraphQLType`]
     /// trait (and similar) implementation of this [GraphQL object][1].
     ///
@@ -329,6 +727,60 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
         (quote! { #impl_generics }, where_clause.cloned())
     }
 
+    /// Returns generated `const` expression of [`Names`] of the own
+    /// [GraphQL fields][2] of this [GraphQL object][1], including the computed
+    /// ones (if any), but excluding the ones of its [`FlattenedField`]s.
+    ///
+    /// [`Names`]: juniper::macros::reflect::Names
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    /// [2]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    #[must_use]
+    fn own_fields_names_tokens(&self) -> TokenStream {
+        let fields = self.fields.iter().map(|f| &f.name);
+        let mut names = vec![quote! { &[#(#fields),*] }];
+        if self.computed == Some(Computed::Merging) {
+            let const_scalar = self.scalar.default_ty();
+            let mut const_ty = self.ty.clone();
+            self.generics.replace_type_with_defaults(&mut const_ty);
+            names.push(quote! {
+                <#const_ty as ::juniper::macros::helper::ComputedFields<
+                    #const_scalar,
+                >>::NAMES
+            });
+        }
+        gen::concat_names(names)
+    }
+
+    /// Returns generated `const` [`FieldName`] of the own [GraphQL field][2] of
+    /// this [GraphQL object][1] with the given `name`, to implement the
+    /// reflection traits with.
+    ///
+    /// For a struct merging computed fields, a `name` of a computed field too
+    /// is hashed differently, so the implementations don't conflict, and the
+    /// collision is reported by the `assert_computed_fields!` check only.
+    ///
+    /// [`FieldName`]: juniper::macros::reflect::FieldName
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    /// [2]: https://spec.graphql.org/June2018/#sec-Language.Fields
+    #[must_use]
+    fn field_name_tokens(&self, name: &str) -> TokenStream {
+        if self.computed == Some(Computed::Merging) {
+            let const_scalar = self.scalar.default_ty();
+            let mut const_ty = self.ty.clone();
+            self.generics.replace_type_with_defaults(&mut const_ty);
+            quote! {{
+                ::juniper::macros::reflect::struct_field_name(
+                    #name,
+                    <#const_ty as ::juniper::macros::helper::ComputedFields<
+                        #const_scalar,
+                    >>::NAMES,
+                )
+            }}
+        } else {
+            quote! { { ::juniper::macros::reflect::fnv1a128(#name) } }
+        }
+    }
+
     /// Returns generated code implementing [`marker::IsOutputType`] trait for
     /// this [GraphQL object][1].
     ///
@@ -375,7 +827,21 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
         let name = &self.name;
         let (impl_generics, where_clause) = self.impl_generics(false);
         let ty = &self.ty;
-        let fields = self.fields.iter().map(|f| &f.name);
+        let fields_names = {
+            let const_scalar = scalar.default_ty();
+            let flattened = self.flattened.iter().map(|f| {
+                let mut const_ty = f.ty.clone();
+                self.generics.replace_type_with_defaults(&mut const_ty);
+                quote! {
+                    <#const_ty as ::juniper::macros::reflect::Fields<#const_scalar>>::NAMES
+                }
+            });
+            gen::concat_names(
+                iter::once(self.own_fields_names_tokens())
+                    .chain(flattened)
+                    .collect(),
+            )
+        };
         let interfaces = self.interfaces.iter();
 
         quote! {
@@ -418,7 +884,7 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
                 for #ty
                 #where_clause
             {
-                const NAMES: ::juniper::macros::reflect::Names = &[#(#fields),*];
+                const NAMES: ::juniper::macros::reflect::Names = #fields_names;
             }
         }
     }
@@ -441,6 +907,8 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
             .as_ref()
             .map(|desc| quote! { .description(#desc) });
 
+        let directives = directive::meta_tokens(&self.directives);
+
         let extract_stream_type = TypeId::of::<Operation>() != TypeId::of::<Query>();
         let fields_meta = self
             .fields
@@ -453,6 +921,32 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
             let (a, b) = (quote!(#a).to_string(), quote!(#b).to_string());
             a.cmp(&b)
         });
+        let is_computed = self.computed == Some(Computed::Merging);
+        let extra_fields = (is_computed || !self.flattened.is_empty()).then(|| {
+            let flattened = self.flattened.iter().map(|f| {
+                let ty = &f.ty;
+                quote! {
+                    fields.extend(::juniper::macros::helper::flattened_object_fields(
+                        <#ty as ::juniper::GraphQLType<#scalar>>::meta(info, registry),
+                        #name,
+                    ));
+                }
+            });
+            let computed = is_computed.then(|| {
+                quote! {
+                    fields.extend(
+                        <Self as ::juniper::macros::helper::ComputedFields<#scalar>>
+                            ::computed_fields(info, registry),
+                    );
+                }
+            });
+            quote! {
+                let mut fields = ::std::vec::Vec::from(fields);
+                #( #flattened )*
+                #computed
+            }
+        });
+
         let interfaces = (!interface_tys.is_empty()).then(|| {
             quote! {
                 .interfaces(&[
@@ -478,9 +972,11 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
                     let fields = [
                         #( #fields_meta, )*
                     ];
+                    #extra_fields
                     registry.build_object_type::<#ty>(info, &fields)
                         #description
                         #interfaces
+                        #directives
                         .into_meta()
                 }
             }
@@ -495,6 +991,15 @@ struct Query;
 
 impl ToTokens for Definition<Query> {
     fn to_tokens(&self, into: &mut TokenStream) {
+        if self.computed == Some(Computed::Providing) {
+            self.impl_computed_fields_tokens().to_tokens(into);
+            self.impl_computed_fields_async_tokens().to_tokens(into);
+            self.impl_field_meta_tokens().to_tokens(into);
+            self.impl_field_tokens().to_tokens(into);
+            self.impl_async_field_tokens().to_tokens(into);
+            return;
+        }
+
         self.impl_graphql_object_tokens().to_tokens(into);
         self.impl_output_type_tokens().to_tokens(into);
         self.impl_graphql_type_tokens().to_tokens(into);
@@ -535,6 +1040,32 @@ impl Definition<Query> {
             ty
         });
 
+        let assert_computed = (self.computed == Some(Computed::Merging)).then(|| {
+            let mut const_ty = ty.clone();
+            generics.replace_type_with_defaults(&mut const_ty);
+            let fields = self.fields.iter().map(|f| &f.name);
+            quote! {
+                ::juniper::assert_computed_fields!(#const_scalar, #const_ty, #(#fields),*);
+            }
+        });
+
+        let flattened_tys = self.flattened.iter().map(|f| &f.ty);
+        let assert_flattened = (!self.flattened.is_empty()).then(|| {
+            let mut const_ty = ty.clone();
+            generics.replace_type_with_defaults(&mut const_ty);
+            let own = self.own_fields_names_tokens();
+            let const_flattened_tys = self.flattened.iter().map(|f| {
+                let mut ty = f.ty.clone();
+                generics.replace_type_with_defaults(&mut ty);
+                ty
+            });
+            quote! {
+                ::juniper::assert_flattened_fields!(
+                    #const_scalar, #const_ty, #own, #(#const_flattened_tys),*
+                );
+            }
+        });
+
         // TODO: Make it work by repeating `sa::assert_type_ne_all!` expansion,
         //       but considering generics.
         //let interface_tys: Vec<_> = self.interfaces.iter().collect();
@@ -548,9 +1079,12 @@ impl Definition<Query> {
             {
                 fn mark() {
                     #( <#interface_tys as ::juniper::marker::GraphQLInterface<#scalar>>::mark(); )*
+                    #( <#flattened_tys as ::juniper::marker::GraphQLObject<#scalar>>::mark(); )*
                     ::juniper::assert_implemented_for!(
                         #const_scalar, #ty, #(#const_interface_tys),*
                     );
+                    #assert_computed
+                    #assert_flattened
                 }
             }
         }
@@ -572,6 +1106,7 @@ impl Definition<Query> {
             .iter()
             .map(|field| {
                 let (name, ty) = (&field.name, field.ty.clone());
+                let field_name = self.field_name_tokens(name);
 
                 let arguments = field
                     .arguments
@@ -591,21 +1126,44 @@ impl Definition<Query> {
                     })
                     .collect::<Vec<_>>();
 
+                let (ty_name, sub_types, wrapped_value) = if let Some(with) = &field.with {
+                    let generics = quote! { ::<_, #ty, #context, #scalar, _> };
+                    (
+                        quote! {
+                            ::juniper::macros::helper::resolver::output_type #generics(&(#with))
+                        },
+                        quote! {
+                            ::juniper::macros::helper::resolver::output_sub_types #generics(
+                                &(#with),
+                            )
+                        },
+                        quote! {
+                            ::juniper::macros::helper::resolver::output_wrapped_value #generics(
+                                &(#with),
+                            )
+                        },
+                    )
+                } else {
+                    (
+                        quote! { <#ty as ::juniper::macros::reflect::BaseType<#scalar>>::NAME },
+                        quote! { <#ty as ::juniper::macros::reflect::BaseSubTypes<#scalar>>::NAMES },
+                        quote! { <#ty as ::juniper::macros::reflect::WrappedType<#scalar>>::VALUE },
+                    )
+                };
+
                 quote! {
                     #[allow(deprecated, non_snake_case)]
                     #[automatically_derived]
                     impl #impl_generics ::juniper::macros::reflect::FieldMeta<
                         #scalar,
-                        { ::juniper::macros::reflect::fnv1a128(#name) }
+                        #field_name
                     > for #impl_ty #where_clause {
                         type Context = #context;
                         type TypeInfo = ();
-                        const TYPE: ::juniper::macros::reflect::Type =
-                            <#ty as ::juniper::macros::reflect::BaseType<#scalar>>::NAME;
-                        const SUB_TYPES: ::juniper::macros::reflect::Types =
-                            <#ty as ::juniper::macros::reflect::BaseSubTypes<#scalar>>::NAMES;
+                        const TYPE: ::juniper::macros::reflect::Type = #ty_name;
+                        const SUB_TYPES: ::juniper::macros::reflect::Types = #sub_types;
                         const WRAPPED_VALUE: juniper::macros::reflect::WrappedValue =
-                            <#ty as ::juniper::macros::reflect::WrappedType<#scalar>>::VALUE;
+                            #wrapped_value;
                         const ARGUMENTS: &'static [(
                             ::juniper::macros::reflect::Name,
                             ::juniper::macros::reflect::Type,
@@ -631,6 +1189,7 @@ impl Definition<Query> {
             .iter()
             .map(|field| {
                 let (name, mut res_ty, ident) = (&field.name, field.ty.clone(), &field.ident);
+                let field_name = self.field_name_tokens(name);
 
                 let resolve = if field.is_async {
                     quote! {
@@ -654,6 +1213,18 @@ impl Definition<Query> {
                         });
 
                         quote! { Self::#ident(#rcv #( #args ),*) }
+                    } else if let Some(with) = &field.with {
+                        res_ty = parse_quote! { _ };
+                        quote! {
+                            ::juniper::macros::helper::resolver::FieldResolver::call_sync(
+                                &(#with), &self.#ident, executor.context(), executor,
+                            )
+                            .unwrap_or_else(|| ::std::panic!(
+                                "Tried to resolve async field `{}` on type `{}` with a sync resolver",
+                                #name,
+                                <Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
+                            ))
+                        }
                     } else {
                         res_ty = parse_quote! { _ };
                         quote! { &self.#ident }
@@ -661,10 +1232,13 @@ impl Definition<Query> {
 
                     let resolving_code = gen::sync_resolving_code();
 
-                    quote! {
-                        let res: #res_ty = #res;
-                        #resolving_code
-                    }
+                    field.guarded_resolve_tokens(
+                        scalar,
+                        quote! {
+                            let res: #res_ty = #res;
+                            #resolving_code
+                        },
+                    )
                 };
 
                 quote! {
@@ -672,7 +1246,7 @@ impl Definition<Query> {
                     #[automatically_derived]
                     impl #impl_generics ::juniper::macros::reflect::Field<
                         #scalar,
-                        { ::juniper::macros::reflect::fnv1a128(#name) }
+                        #field_name
                     > for #impl_ty
                         #where_clause
                     {
@@ -704,6 +1278,7 @@ impl Definition<Query> {
             .iter()
             .map(|field| {
                 let (name, mut res_ty, ident) = (&field.name, field.ty.clone(), &field.ident);
+                let field_name = self.field_name_tokens(name);
 
                 let mut res = if field.is_method() {
                     let args = field
@@ -718,22 +1293,36 @@ impl Definition<Query> {
                     });
 
                     quote! { Self::#ident(#rcv #( #args ),*) }
+                } else if let Some(with) = &field.with {
+                    res_ty = parse_quote! { _ };
+                    quote! {
+                        ::juniper::macros::helper::resolver::FieldResolver::call_async(
+                            &(#with), &self.#ident, executor.context(), executor,
+                        )
+                    }
                 } else {
                     res_ty = parse_quote! { _ };
                     quote! { &self.#ident }
                 };
-                if !field.is_async {
+                if !field.is_async && field.with.is_none() {
                     res = quote! { ::juniper::futures::future::ready(#res) };
                 }
 
                 let resolving_code = gen::async_resolving_code(Some(&res_ty));
+                let resolve = field.guarded_resolve_async_tokens(
+                    scalar,
+                    quote! {
+                        let fut = #res;
+                        #resolving_code
+                    },
+                );
 
                 quote! {
                     #[allow(deprecated, non_snake_case)]
                     #[automatically_derived]
                     impl #impl_generics ::juniper::macros::reflect::AsyncField<
                         #scalar,
-                        { ::juniper::macros::reflect::fnv1a128(#name) }
+                        #field_name
                     > for #impl_ty
                         #where_clause
                     {
@@ -743,8 +1332,7 @@ impl Definition<Query> {
                             args: &'b ::juniper::Arguments<#scalar>,
                             executor: &'b ::juniper::Executor<Self::Context, #scalar>,
                         ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
-                            let fut = #res;
-                            #resolving_code
+                            #resolve
                         }
                     }
                 }
@@ -769,19 +1357,39 @@ impl Definition<Query> {
         let name = &self.name;
 
         let fields_resolvers = self.fields.iter().map(|f| {
-            let name = &f.name;
+            let (name, field_name) = (&f.name, self.field_name_tokens(&f.name));
             quote! {
                 #name => {
                     ::juniper::macros::reflect::Field::<
                         #scalar,
-                        { ::juniper::macros::reflect::fnv1a128(#name) }
+                        #field_name
                     >::call(self, info, args, executor)
                 }
             }
         });
 
-        let no_field_err =
-            field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+        let flattened_resolvers = self.flattened.iter().map(|f| {
+            let (ident, ty) = (&f.ident, &f.ty);
+            quote! {
+                _ if ::juniper::macros::reflect::str_exists_in_arr(
+                    field,
+                    <#ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES,
+                ) => {
+                    <#ty as ::juniper::GraphQLValue<#scalar>>::resolve_field(
+                        &self.#ident, info, field, args, executor,
+                    )
+                }
+            }
+        });
+
+        let no_field = if self.computed == Some(Computed::Merging) {
+            quote! {
+                <Self as ::juniper::macros::helper::ComputedFields<#scalar>>
+                    ::resolve_computed_field(self, info, field, args, executor)
+            }
+        } else {
+            field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name)
+        };
 
         quote! {
             #[allow(deprecated)]
@@ -804,7 +1412,8 @@ impl Definition<Query> {
                 ) -> ::juniper::ExecutionResult<#scalar> {
                     match field {
                         #( #fields_resolvers )*
-                        _ => #no_field_err,
+                        #( #flattened_resolvers )*
+                        _ => #no_field,
                     }
                 }
 
@@ -832,6 +1441,145 @@ impl Definition<Query> {
         let ty = &self.ty;
         let ty_name = ty.to_token_stream().to_string();
 
+        let fields_resolvers = self.fields.iter().map(|f| {
+            let (name, field_name) = (&f.name, self.field_name_tokens(&f.name));
+            quote! {
+                #name => {
+                    ::juniper::macros::reflect::AsyncField::<
+                        #scalar,
+                        #field_name
+                    >::call(self, info, args, executor)
+                }
+            }
+        });
+
+        let flattened_resolvers = self.flattened.iter().map(|f| {
+            let (ident, ty) = (&f.ident, &f.ty);
+            quote! {
+                _ if ::juniper::macros::reflect::str_exists_in_arr(
+                    field,
+                    <#ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES,
+                ) => {
+                    <#ty as ::juniper::GraphQLValueAsync<#scalar>>::resolve_field_async(
+                        &self.#ident, info, field, args, executor,
+                    )
+                }
+            }
+        });
+
+        let no_field = if self.computed == Some(Computed::Merging) {
+            quote! {
+                <Self as ::juniper::macros::helper::ComputedFieldsAsync<#scalar>>
+                    ::resolve_computed_field_async(self, info, field, args, executor)
+            }
+        } else {
+            let no_field_err =
+                field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+            quote! { Box::pin(async move { #no_field_err }) }
+        };
+
+        quote! {
+            #[allow(deprecated, non_snake_case)]
+            #[automatically_derived]
+            impl#impl_generics ::juniper::GraphQLValueAsync<#scalar> for #ty #where_clause
+            {
+                fn resolve_field_async<'b>(
+                    &'b self,
+                    info: &'b Self::TypeInfo,
+                    field: &'b str,
+                    args: &'b ::juniper::Arguments<#scalar>,
+                    executor: &'b ::juniper::Executor<Self::Context, #scalar>,
+                ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
+                    match field {
+                        #( #fields_resolvers )*
+                        #( #flattened_resolvers )*
+                        _ => #no_field,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`ComputedFields`] trait for this
+    /// [GraphQL object][1], providing the computed fields of its `impl` block.
+    ///
+    /// [`ComputedFields`]: juniper::macros::helper::ComputedFields
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    #[must_use]
+    fn impl_computed_fields_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(false);
+        let ty = &self.ty;
+        let ty_name = ty.to_token_stream().to_string();
+
+        let names = self.fields.iter().map(|f| &f.name);
+        let fields_meta = self.fields.iter().map(|f| f.method_meta_tokens(None));
+        let fields_resolvers = self.fields.iter().map(|f| {
+            let name = &f.name;
+            quote! {
+                #name => {
+                    ::juniper::macros::reflect::Field::<
+                        #scalar,
+                        { ::juniper::macros::reflect::fnv1a128(#name) }
+                    >::call(self, info, args, executor)
+                }
+            }
+        });
+
+        let no_field_err =
+            field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
+
+        quote! {
+            #[allow(deprecated)]
+            #[automatically_derived]
+            impl#impl_generics ::juniper::macros::helper::ComputedFields<#scalar>
+                for #ty
+                #where_clause
+            {
+                const NAMES: ::juniper::macros::reflect::Names = &[#(#names),*];
+
+                fn computed_fields<'r>(
+                    info: &Self::TypeInfo,
+                    registry: &mut ::juniper::Registry<'r, #scalar>
+                ) -> ::std::vec::Vec<::juniper::meta::Field<'r, #scalar>>
+                where #scalar: 'r,
+                {
+                    ::std::vec![
+                        #( #fields_meta, )*
+                    ]
+                }
+
+                fn resolve_computed_field(
+                    &self,
+                    info: &Self::TypeInfo,
+                    field: &str,
+                    args: &::juniper::Arguments<#scalar>,
+                    executor: &::juniper::Executor<Self::Context, #scalar>,
+                ) -> ::juniper::ExecutionResult<#scalar> {
+                    match field {
+                        #( #fields_resolvers )*
+                        _ => #no_field_err,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`ComputedFieldsAsync`] trait for
+    /// this [GraphQL object][1], providing the computed fields of its `impl`
+    /// block.
+    ///
+    /// [`ComputedFieldsAsync`]: juniper::macros::helper::ComputedFieldsAsync
+    /// [1]: https://spec.graphql.org/June2018/#sec-Objects
+    #[must_use]
+    fn impl_computed_fields_async_tokens(&self) -> TokenStream {
+        let scalar = &self.scalar;
+
+        let (impl_generics, where_clause) = self.impl_generics(true);
+        let ty = &self.ty;
+        let ty_name = ty.to_token_stream().to_string();
+
         let fields_resolvers = self.fields.iter().map(|f| {
             let name = &f.name;
             quote! {
@@ -850,9 +1598,11 @@ impl Definition<Query> {
         quote! {
             #[allow(deprecated, non_snake_case)]
             #[automatically_derived]
-            impl#impl_generics ::juniper::GraphQLValueAsync<#scalar> for #ty #where_clause
+            impl#impl_generics ::juniper::macros::helper::ComputedFieldsAsync<#scalar>
+                for #ty
+                #where_clause
             {
-                fn resolve_field_async<'b>(
+                fn resolve_computed_field_async<'b>(
                     &'b self,
                     info: &'b Self::TypeInfo,
                     field: &'b str,
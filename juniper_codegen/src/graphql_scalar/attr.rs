@@ -63,6 +63,8 @@ fn expand_on_type_alias(
             .unwrap_or_else(|| ast.ident.to_string()),
         description: attr.description.as_deref().cloned(),
         specified_by_url: attr.specified_by_url.as_deref().cloned(),
+        directives: attr.directives.clone(),
+        structured_input: attr.structured_input,
         scalar,
     };
 
@@ -95,6 +97,8 @@ fn expand_on_derive_input(
             .unwrap_or_else(|| ast.ident.to_string()),
         description: attr.description.as_deref().cloned(),
         specified_by_url: attr.specified_by_url.as_deref().cloned(),
+        directives: attr.directives.clone(),
+        structured_input: attr.structured_input,
         scalar,
     };
 
@@ -32,6 +32,8 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
             .unwrap_or_else(|| ast.ident.to_string()),
         description: attr.description.as_deref().cloned(),
         specified_by_url: attr.specified_by_url.as_deref().cloned(),
+        directives: attr.directives.clone(),
+        structured_input: attr.structured_input,
         scalar,
     }
     .to_token_stream())
@@ -16,6 +16,7 @@ use url::Url;
 
 use crate::{
     common::{
+        directive::{self, Directive},
         parse::{
             attr::{err, OptionExt as _},
             ParseBufferExt as _,
@@ -49,6 +50,13 @@ struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     specified_by_url: Option<SpanContainer<Url>>,
 
+    /// Explicitly specified [type system directives][0] applied to this
+    /// [GraphQL scalar][1] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    directives: Vec<Directive>,
+
     /// Explicitly specified type (or type parameter with its bounds) of
     /// [`ScalarValue`] to use for resolving this [GraphQL scalar][1] type with.
     ///
@@ -91,6 +99,12 @@ struct Attr {
     /// Indicator for single-field structs allowing to delegate implmemntations
     /// of non-provided resolvers to that field.
     transparent: bool,
+
+    /// Indicator whether this [GraphQL scalar][1] accepts lists and objects as
+    /// its input values (like a `JSON` scalar does).
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    structured_input: bool,
 }
 
 impl Parse for Attr {
@@ -131,6 +145,10 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(lit.span()), url))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse::<Directive>()?);
+                }
                 "scalar" | "Scalar" | "ScalarValue" => {
                     input.parse::<token::Eq>()?;
                     let scl = input.parse::<scalar::AttrValue>()?;
@@ -219,6 +237,9 @@ impl Parse for Attr {
                 "transparent" => {
                     out.transparent = true;
                 }
+                "structured_input" => {
+                    out.structured_input = true;
+                }
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -237,6 +258,11 @@ impl Attr {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
             specified_by_url: try_merge_opt!(specified_by_url: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
             scalar: try_merge_opt!(scalar: self, another),
             to_output: try_merge_opt!(to_output: self, another),
             from_input: try_merge_opt!(from_input: self, another),
@@ -244,6 +270,7 @@ impl Attr {
             with: try_merge_opt!(with: self, another),
             where_clause: try_merge_opt!(where_clause: self, another),
             transparent: self.transparent || another.transparent,
+            structured_input: self.structured_input || another.structured_input,
         })
     }
 
@@ -311,6 +338,18 @@ struct Definition {
     /// [1]: https://spec.graphql.org/October2021#sec-Scalars
     specified_by_url: Option<Url>,
 
+    /// [Type system directives][0] applied to this [GraphQL scalar][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    directives: Vec<Directive>,
+
+    /// Indicator whether this [GraphQL scalar][1] accepts lists and objects as
+    /// its input values.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Scalars
+    structured_input: bool,
+
     /// [`ScalarValue`] parametrization to generate [`GraphQLType`]
     /// implementation with for this [GraphQL scalar][1].
     ///
@@ -375,6 +414,10 @@ impl Definition {
             let url_lit = url.as_str();
             quote! { .specified_by_url(#url_lit) }
         });
+        let directives = directive::meta_tokens(&self.directives);
+        let structured_input = self
+            .structured_input
+            .then(|| quote! { .structured_input() });
 
         let (ty, generics) = self.impl_self_and_generics(false);
         let (impl_gens, _, where_clause) = generics.split_for_impl();
@@ -398,6 +441,8 @@ impl Definition {
                     registry.build_scalar_type::<Self>(info)
                         #description
                         #specified_by_url
+                        #directives
+                        #structured_input
                         .into_meta()
                 }
             }
@@ -95,6 +95,7 @@ fn expand_on_trait(
         ty: parse_quote! { #trait_ident },
         is_trait_object: true,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
         context,
         scalar: scalar::Type::parse(attr.scalar.as_deref(), &ast.generics),
         generics: ast.generics.clone(),
@@ -83,6 +83,7 @@ fn expand_enum(ast: syn::DeriveInput) -> syn::Result<Definition> {
         ty: parse_quote! { #enum_ident },
         is_trait_object: false,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
         context: attr
             .context
             .map(SpanContainer::into_inner)
@@ -213,6 +214,7 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition> {
         ty: parse_quote! { #struct_ident },
         is_trait_object: false,
         description: attr.description.map(SpanContainer::into_inner),
+        directives: attr.directives,
         context: attr
             .context
             .map(SpanContainer::into_inner)
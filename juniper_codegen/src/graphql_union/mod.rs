@@ -19,6 +19,7 @@ use syn::{
 
 use crate::{
     common::{
+        directive::{self, Directive},
         gen,
         parse::{
             attr::{err, OptionExt as _},
@@ -53,6 +54,13 @@ struct Attr {
     /// [2]: https://spec.graphql.org/June2018/#sec-Descriptions
     description: Option<SpanContainer<String>>,
 
+    /// Explicitly specified [type system directives][0] applied to this
+    /// [GraphQL union][1] type.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Unions
+    directives: Vec<Directive>,
+
     /// Explicitly specified type of [`Context`] to use for resolving this
     /// [GraphQL union][1] type with.
     ///
@@ -120,6 +128,13 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "directive" => {
+                    input.parse::<token::Eq>()?;
+                    out.directives.push(input.parse::<Directive>()?);
+                }
+                "cache_control" => {
+                    out.directives.push(Directive::parse_cache_control(input)?);
+                }
                 "ctx" | "context" | "Context" => {
                     input.parse::<token::Eq>()?;
                     let ctx = input.parse::<syn::Type>()?;
@@ -164,6 +179,11 @@ impl Attr {
         Ok(Self {
             name: try_merge_opt!(name: self, another),
             description: try_merge_opt!(description: self, another),
+            directives: self
+                .directives
+                .into_iter()
+                .chain(another.directives)
+                .collect(),
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             external_resolvers: try_merge_hashmap!(
@@ -285,6 +305,12 @@ struct Definition {
     /// [1]: https://spec.graphql.org/June2018/#sec-Unions
     description: Option<String>,
 
+    /// [Type system directives][0] applied to this [GraphQL union][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Type-System.Directives
+    /// [1]: https://spec.graphql.org/June2018/#sec-Unions
+    directives: Vec<Directive>,
+
     /// Rust type of [`Context`] to generate [`GraphQLType`] implementation with
     /// for this [GraphQL union][1].
     ///
@@ -474,6 +500,7 @@ impl Definition {
             .description
             .as_ref()
             .map(|desc| quote! { .description(#desc) });
+        let directives = directive::meta_tokens(&self.directives);
 
         let variant_tys = self.variants.iter().map(|var| &var.ty);
 
@@ -496,6 +523,7 @@ impl Definition {
                     ];
                     registry.build_union_type::<#ty_full>(info, &types)
                         #description
+                        #directives
                         .into_meta()
                 }
             }
@@ -100,11 +100,11 @@ macro_rules! try_merge_hashset {
     };
 }
 
-mod derive_enum;
-mod derive_input_object;
-
 mod common;
+mod graphql_enum;
+mod graphql_input_object;
 mod graphql_interface;
+mod graphql_merged_object;
 mod graphql_object;
 mod graphql_scalar;
 mod graphql_subscription;
@@ -115,26 +115,291 @@ use proc_macro::TokenStream;
 use proc_macro_error::{proc_macro_error, ResultExt as _};
 use result::GraphQLScope;
 
+/// `#[derive(GraphQLEnum)]` macro for deriving a [GraphQL enum][1]
+/// implementation for Rust enums.
+///
+/// Only unit variants are allowed to be [values][2] of the
+/// [GraphQL enum][1]. By default, variant names are `SCREAMING_SNAKE_CASE`d.
+///
+/// ```
+/// use juniper::GraphQLEnum;
+///
+/// // Exposed in the GraphQL schema as `enum Episode { NEW_HOPE EMPIRE JEDI }`.
+/// #[derive(GraphQLEnum)]
+/// enum Episode {
+///     NewHope,
+///     Empire,
+///     Jedi,
+/// }
+/// ```
+///
+/// # Custom name, description and deprecation
+///
+/// The name of a [GraphQL enum][1] or its [values][2] may be overridden with
+/// the `name` attribute's argument. By default, the type name is used without
+/// any changes.
+///
+/// The description of a [GraphQL enum][1] or its [values][2] may be specified
+/// either with the `description`/`desc` attribute's argument, or with a
+/// regular Rust doc comment.
+///
+/// A [value][2] of a [GraphQL enum][1] may be deprecated by specifying the
+/// `deprecated` attribute's argument, or with a regular Rust `#[deprecated]`
+/// attribute.
+///
+/// ```
+/// # use juniper::GraphQLEnum;
+/// #
+/// /// Episode of the original trilogy.
+/// #[derive(GraphQLEnum)]
+/// #[graphql(name = "StarWarsEpisode")]
+/// enum Episode {
+///     #[graphql(name = "NEWHOPE", desc = "The first one.")]
+///     NewHope,
+///     /// The second one.
+///     Empire,
+///     #[graphql(deprecated = "Nobody likes it.")]
+///     Jedi,
+///     #[deprecated]
+///     Phantom,
+/// }
+/// ```
+///
+/// # Renaming policy
+///
+/// By default, all [GraphQL enum values][2] are renamed with a
+/// `SCREAMING_SNAKE_CASE` policy (so `NewHope` becomes `NEW_HOPE`). This
+/// complies with the default GraphQL naming conventions, as demonstrated in
+/// the [spec][0].
+///
+/// However, if you need for some reason another naming convention, it's
+/// possible to do so by using the `rename_all` attribute's argument. At the
+/// moment, it supports the following policies only: `SCREAMING_SNAKE_CASE`,
+/// `camelCase`, `none` (disables any renaming).
+///
+/// ```
+/// # use juniper::GraphQLEnum;
+/// #
+/// #[derive(GraphQLEnum)]
+/// #[graphql(rename_all = "none")] // disables renaming
+/// enum Episode {
+///     NewHope,
+///     Empire,
+///     Jedi,
+/// }
+/// ```
+///
+/// # Ignoring enum variants
+///
+/// To omit exposing a Rust enum variant in the GraphQL schema, use the
+/// `ignore` (or `skip`) attribute's argument directly on that variant. Ignored
+/// variants may hold data, so they may be used to consume otherwise unused
+/// generic parameters. Resolving an ignored variant panics.
+///
+/// ```
+/// # use std::marker::PhantomData;
+/// # use juniper::GraphQLEnum;
+/// #
+/// #[derive(GraphQLEnum)]
+/// enum Episode<T> {
+///     NewHope,
+///     Empire,
+///     Jedi,
+///     #[graphql(ignore)]
+///     _Phantom(PhantomData<T>),
+/// }
+/// ```
+///
+/// # Custom context and `ScalarValue`
+///
+/// By default, the generated implementation uses unit type `()` as
+/// [`Context`] and is generic over any [`ScalarValue`] type. Both may be
+/// specified explicitly with the `context` and `scalar` attribute's arguments.
+/// The `scalar` argument accepts either a concrete type, or a type parameter
+/// with its bounds (like `scalar = S: ScalarValue + Clone`).
+///
+/// ```
+/// # use juniper::{DefaultScalarValue, GraphQLEnum};
+/// #
+/// struct Database;
+///
+/// impl juniper::Context for Database {}
+///
+/// #[derive(GraphQLEnum)]
+/// #[graphql(context = Database, scalar = DefaultScalarValue)]
+/// enum Episode {
+///     NewHope,
+///     Empire,
+///     Jedi,
+/// }
+/// ```
+///
+/// [`Context`]: juniper::Context
+/// [`ScalarValue`]: juniper::ScalarValue
+/// [0]: https://spec.graphql.org/June2018
+/// [1]: https://spec.graphql.org/June2018/#sec-Enums
+/// [2]: https://spec.graphql.org/June2018/#EnumValuesDefinition
 #[proc_macro_error]
 #[proc_macro_derive(GraphQLEnum, attributes(graphql))]
-pub fn derive_enum(input: TokenStream) -> TokenStream {
-    let ast = syn::parse::<syn::DeriveInput>(input).unwrap();
-    let gen = derive_enum::impl_enum(ast, GraphQLScope::DeriveEnum);
-    match gen {
-        Ok(gen) => gen.into(),
-        Err(err) => proc_macro_error::abort!(err),
-    }
+pub fn derive_enum(body: TokenStream) -> TokenStream {
+    self::graphql_enum::derive::expand(body.into())
+        .unwrap_or_abort()
+        .into()
 }
 
+/// `#[derive(GraphQLInputObject)]` macro for deriving a
+/// [GraphQL input object][1] implementation for Rust structs with named
+/// fields.
+///
+/// Every field of the struct should be a GraphQL input type. By default,
+/// field names are `camelCased`.
+///
+/// ```
+/// use juniper::GraphQLInputObject;
+///
+/// // Exposed in the GraphQL schema as
+/// // `input Point2D { x: Float! yCoord: Float! }`.
+/// #[derive(GraphQLInputObject)]
+/// struct Point2D {
+///     x: f64,
+///     y_coord: f64,
+/// }
+/// ```
+///
+/// # Custom name, description and default value
+///
+/// The name of a [GraphQL input object][1] or its fields may be overridden
+/// with the `name` attribute's argument. By default, the type name is used
+/// without any changes.
+///
+/// The description of a [GraphQL input object][1] or its fields may be
+/// specified either with the `description`/`desc` attribute's argument, or
+/// with a regular Rust doc comment.
+///
+/// The [default value][2] of a field may be specified with the `default`
+/// attribute's argument, accepting any Rust expression convertible into the
+/// field type via [`Into`]. If no expression is specified, then the
+/// [`Default`] value of the field type is used.
+///
+/// ```
+/// # use juniper::GraphQLInputObject;
+/// #
+/// /// Point on a 2D plane.
+/// #[derive(GraphQLInputObject)]
+/// #[graphql(name = "Point")]
+/// struct Point2D {
+///     #[graphql(name = "abscissa", desc = "Horizontal coordinate.")]
+///     x: f64,
+///     /// Vertical coordinate.
+///     #[graphql(default = 1.5)]
+///     y: f64,
+///     #[graphql(default)]
+///     label: String,
+/// }
+/// ```
+///
+/// # Renaming policy
+///
+/// By default, all [GraphQL input object][1] fields are renamed with a
+/// `camelCase` policy (so `y_coord: f64` becomes `yCoord: Float!`). This
+/// complies with the default GraphQL naming conventions, as demonstrated in
+/// the [spec][0].
+///
+/// However, if you need for some reason another naming convention, it's
+/// possible to do so by using the `rename_all` attribute's argument. At the
+/// moment, it supports the following policies only: `SCREAMING_SNAKE_CASE`,
+/// `camelCase`, `none` (disables any renaming).
+///
+/// ```
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// #[graphql(rename_all = "none")] // disables renaming
+/// struct Point2D {
+///     x: f64,
+///     y_coord: f64,
+/// }
+/// ```
+///
+/// # Ignoring struct fields
+///
+/// To omit exposing a Rust struct field in the GraphQL schema, use the `ignore`
+/// (or `skip`) attribute's argument directly on that field. Ignored fields are
+/// initialized with the `default` attribute's argument expression, if any, or
+/// with their [`Default`] value otherwise.
+///
+/// ```
+/// # use std::marker::PhantomData;
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// struct Point2D<T> {
+///     x: f64,
+///     y: f64,
+///     #[graphql(ignore, default = 2)]
+///     dimensions: u8,
+///     #[graphql(skip)]
+///     _unit: PhantomData<T>,
+/// }
+/// ```
+///
+/// # Flattening struct fields
+///
+/// Fields shared by several GraphQL input objects may be declared once in a
+/// separate GraphQL input object, and inlined into the other ones with a
+/// `flatten` attribute's argument placed on a struct field of its type.
+/// Inlined fields go after the own ones of the GraphQL input object, and are
+/// parsed from (and serialized into) the same input value. A field name
+/// defined more than once fails compilation.
+///
+/// ```
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// struct Pagination {
+///     #[graphql(default = 10)]
+///     first: i32,
+///     after: Option<String>,
+/// }
+///
+/// #[derive(GraphQLInputObject)]
+/// struct UsersFilter {
+///     name: Option<String>,
+///     #[graphql(flatten)] // exposes `first` and `after` fields on `UsersFilter`
+///     pagination: Pagination,
+/// }
+/// ```
+///
+/// # Custom context and `ScalarValue`
+///
+/// By default, the generated implementation uses unit type `()` as
+/// [`Context`] and is generic over any [`ScalarValue`] type. Both may be
+/// specified explicitly with the `context` and `scalar` attribute's arguments.
+/// The `scalar` argument accepts either a concrete type, or a type parameter
+/// with its bounds (like `scalar = S: ScalarValue + Clone`).
+///
+/// ```
+/// # use juniper::{DefaultScalarValue, GraphQLInputObject};
+/// #
+/// #[derive(GraphQLInputObject)]
+/// #[graphql(scalar = DefaultScalarValue)]
+/// struct Point2D {
+///     x: f64,
+///     y: f64,
+/// }
+/// ```
+///
+/// [`Context`]: juniper::Context
+/// [`ScalarValue`]: juniper::ScalarValue
+/// [0]: https://spec.graphql.org/June2018
+/// [1]: https://spec.graphql.org/June2018/#sec-Input-Objects
+/// [2]: https://spec.graphql.org/June2018/#DefaultValue
 #[proc_macro_error]
 #[proc_macro_derive(GraphQLInputObject, attributes(graphql))]
-pub fn derive_input_object(input: TokenStream) -> TokenStream {
-    let ast = syn::parse::<syn::DeriveInput>(input).unwrap();
-    let gen = derive_input_object::impl_input_object(ast, GraphQLScope::DeriveInputObject);
-    match gen {
-        Ok(gen) => gen.into(),
-        Err(err) => proc_macro_error::abort!(err),
-    }
+pub fn derive_input_object(body: TokenStream) -> TokenStream {
+    self::graphql_input_object::derive::expand(body.into())
+        .unwrap_or_abort()
+        .into()
 }
 
 /// `#[derive(GraphQLScalar)]` macro for deriving a [GraphQL scalar][0]
@@ -433,6 +698,13 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
 /// It also may be used to provide additional bounds to the [`ScalarValue`]
 /// generic, like the following: `#[graphql(scalar = S: Trait)]`.
 ///
+/// # Structured input
+///
+/// By default, lists and objects are never accepted as input values of a
+/// [GraphQL scalar][0], even if its `from_input_with` function is able to
+/// handle them. Scalars representing arbitrary structured data (like `JSON`)
+/// may opt into accepting them via `#[graphql(structured_input)]` attribute.
+///
 /// # Additional arbitrary trait bounds
 ///
 /// [GraphQL scalar][0] type implementation may be bound with any additional
@@ -552,6 +824,11 @@ pub fn graphql_scalar(attr: TokenStream, body: TokenStream) -> TokenStream {
 /// `as_bool` attribute argumentes (names correspond to [`ScalarValue`] required
 /// methods).
 ///
+/// Variants holding integers wider than 32 bits may be additionally marked with
+/// an optional `as_big_int` attribute argument, so 64-bit (and wider) integer
+/// GraphQL scalars are stored in them losslessly rather than being converted
+/// into strings.
+///
 /// ```rust
 /// # use std::{fmt, convert::TryInto as _};
 /// #
@@ -563,6 +840,7 @@ pub fn graphql_scalar(attr: TokenStream, body: TokenStream) -> TokenStream {
 /// enum MyScalarValue {
 ///     #[value(as_float, as_int)]
 ///     Int(i32),
+///     #[value(as_big_int)]
 ///     Long(i64),
 ///     #[value(as_float)]
 ///     Float(f64),
@@ -1104,6 +1382,185 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Computed fields
+///
+/// To add fields resolved by methods to the ones derived from the struct
+/// fields, a `computed` attribute's argument should be specified both on the
+/// struct and on a separate `impl` block with `#[graphql_object]` attribute,
+/// declaring the additional fields. They're merged into a single GraphQL
+/// object, and a field declared by both fails compilation.
+///
+/// ```
+/// # use juniper::{graphql_object, GraphQLObject};
+/// #
+/// #[derive(GraphQLObject)]
+/// #[graphql(computed)]
+/// struct Human {
+///     first_name: String,
+///     last_name: String,
+/// }
+///
+/// #[graphql_object(computed)]
+/// impl Human {
+///     fn full_name(&self) -> String {
+///         format!("{} {}", self.first_name, self.last_name)
+///     }
+/// }
+/// ```
+///
+/// The `name`, `description`, `impl` and other arguments describing the whole
+/// GraphQL object should be specified on the struct only.
+///
+/// # Flattening struct fields
+///
+/// Fields shared by several GraphQL objects may be declared once in a separate
+/// GraphQL object, and inlined into the other ones with a `flatten` attribute's
+/// argument placed on a struct field of its type. Inlined fields go after the
+/// own ones of the GraphQL object, and are resolved by the flattened struct
+/// field, so it should use the same [`Context`]. A field name defined more than
+/// once fails compilation.
+///
+/// ```
+/// # use juniper::GraphQLObject;
+/// #
+/// #[derive(GraphQLObject)]
+/// struct Timestamps {
+///     created_at: i32,
+///     updated_at: Option<i32>,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// struct Post {
+///     title: String,
+///     #[graphql(flatten)] // exposes `createdAt` and `updatedAt` fields on `Post`
+///     timestamps: Timestamps,
+/// }
+/// ```
+///
+/// # Custom field resolvers
+///
+/// A struct field may be exposed in a different shape by specifying a function
+/// in a `with` (or `resolve`) attribute's argument. The function is called with
+/// a reference to the struct field's value, the [`Context`] and the
+/// [`Executor`], and may be either sync or `async`. The GraphQL type of the
+/// field is inferred from the function's return type.
+///
+/// Unless a `scalar` attribute's argument is specified, the function should
+/// be generic over the [`ScalarValue`] of the [`Executor`].
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use juniper::{Executor, GraphQLObject};
+/// #
+/// struct Database {
+///     users: HashMap<i32, User>,
+/// }
+///
+/// impl juniper::Context for Database {}
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(context = Database)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// fn format_timestamp<S>(ts: &i64, _: &Database, _: &Executor<'_, '_, Database, S>) -> String {
+///     format!("{}s", ts)
+/// }
+///
+/// async fn user_by_id<'a, S>(
+///     id: &i32,
+///     db: &'a Database,
+///     _: &Executor<'_, '_, Database, S>,
+/// ) -> Option<&'a User> {
+///     db.users.get(id)
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(context = Database)]
+/// struct Post {
+///     #[graphql(with = format_timestamp)] // exposed as `String!`
+///     created_at: i64,
+///     #[graphql(name = "author", with = user_by_id)] // exposed as `User`
+///     author_id: i32,
+/// }
+/// ```
+///
+/// # Generic types
+///
+/// A generic struct can be exposed in the GraphQL schema once per each of its
+/// concrete instantiations, specified with `concrete` attribute's arguments.
+/// Their names are either specified explicitly, or rendered from a `name`
+/// template, where `{T}` placeholders are substituted with names of the types
+/// used for the corresponding type parameters.
+///
+/// ```
+/// # use juniper::GraphQLObject;
+/// #
+/// #[derive(GraphQLObject)]
+/// struct User {
+///     id: String,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// struct Post {
+///     title: String,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(
+///     name = "{T}Page",
+///     concrete(params(User)), // exposed as `UserPage`
+///     concrete(name = "PostsPage", params(Post)),
+/// )]
+/// struct Page<T> {
+///     items: Vec<T>,
+///     total: i32,
+/// }
+/// ```
+///
+/// Exposing different Rust types under the same GraphQL name is reported with
+/// a panic once the schema is constructed.
+///
+/// # Remote types
+///
+/// Orphan rules don't allow implementing GraphQL traits for a struct defined
+/// in another crate. Instead, a local mirror of such a struct can be declared
+/// with a `remote` attribute's argument, which additionally generates [`From`]
+/// conversions between the local and the remote structs. Fields are converted
+/// with [`Into`], so they may be mirrored by local types too.
+///
+/// ```
+/// # use juniper::{graphql_object, GraphQLObject};
+/// #
+/// mod domain {
+///     pub struct Human {
+///         pub id: String,
+///         pub home_planet: String,
+///     }
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// #[graphql(name = "Human", remote = "domain::Human")]
+/// struct HumanDef {
+///     id: String,
+///     home_planet: String,
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn human(id: String) -> HumanDef {
+///         domain::Human {
+///             id,
+///             home_planet: "Tatooine".into(),
+///         }
+///         .into()
+///     }
+/// }
+/// ```
+///
 /// # Custom `ScalarValue`
 ///
 /// By default, `#[derive(GraphQLObject)]` macro generates code, which is
@@ -1130,6 +1587,8 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// [`Context`]: juniper::Context
+/// [`Executor`]: juniper::Executor
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [1]: https://spec.graphql.org/June2018/#sec-Objects
 #[proc_macro_error]
@@ -1140,6 +1599,126 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
         .into()
 }
 
+/// `#[derive(GraphQLMergedObject)]` macro for deriving a [GraphQL object][1]
+/// implementation for structs, which exposes the fields of all the GraphQL
+/// objects it's composed of.
+///
+/// This allows splitting a big root type (like `Query` or `Mutation`) across
+/// multiple `#[graphql_object]` impls (possibly living in different crates),
+/// and merging them back into a single GraphQL type.
+///
+/// ```
+/// use juniper::{graphql_object, GraphQLMergedObject};
+///
+/// struct UserQuery;
+///
+/// #[graphql_object]
+/// impl UserQuery {
+///     fn user_name() -> &'static str {
+///         "John"
+///     }
+/// }
+///
+/// struct PostQuery;
+///
+/// #[graphql_object]
+/// impl PostQuery {
+///     fn post_title() -> &'static str {
+///         "Hello"
+///     }
+/// }
+///
+/// // Exposed in the GraphQL schema as `type Query { userName: String!
+/// // postTitle: String! }`.
+/// #[derive(GraphQLMergedObject)]
+/// struct Query(UserQuery, PostQuery);
+/// ```
+///
+/// Both tuple structs and structs with named fields are supported. Every field
+/// of the struct should be a [GraphQL object][1] (including another
+/// `#[derive(GraphQLObject)]` one), all of them resolving with the same
+/// [`Context`] and [`ScalarValue`].
+///
+/// # Fields collision
+///
+/// Merged [GraphQL objects][1] cannot define fields with the same name. This
+/// is checked at compile time:
+///
+/// ```compile_fail
+/// # use juniper::{graphql_object, GraphQLMergedObject};
+/// #
+/// struct UserQuery;
+///
+/// #[graphql_object]
+/// impl UserQuery {
+///     fn id() -> i32 { 1 }
+/// }
+///
+/// struct PostQuery;
+///
+/// #[graphql_object]
+/// impl PostQuery {
+///     fn id() -> i32 { 2 }
+/// }
+///
+/// // Failed to merge `UserQuery` and `PostQuery` into `Query`:
+/// // field `id` is defined by both.
+/// #[derive(GraphQLMergedObject)]
+/// struct Query(UserQuery, PostQuery);
+/// ```
+///
+/// # Custom name, description, context and scalar
+///
+/// The merged [GraphQL object][1] accepts the same `name`, `description`/`desc`,
+/// `directive`, `cache_control`, `context` and `scalar` arguments of the
+/// `#[graphql]` helper attribute as `#[derive(GraphQLObject)]` does. If no
+/// `context` is specified, then the one of the first merged object is used.
+///
+/// ```
+/// # use juniper::{graphql_object, GraphQLMergedObject};
+/// #
+/// struct Database;
+///
+/// impl juniper::Context for Database {}
+///
+/// struct UserMutation;
+///
+/// #[graphql_object(context = Database)]
+/// impl UserMutation {
+///     fn create_user(name: String) -> String {
+///         name
+///     }
+/// }
+///
+/// struct PostMutation;
+///
+/// #[graphql_object(context = Database)]
+/// impl PostMutation {
+///     fn create_post(title: String) -> String {
+///         title
+///     }
+/// }
+///
+/// /// Root mutation type.
+/// #[derive(GraphQLMergedObject)]
+/// #[graphql(name = "Mutation", context = Database)]
+/// struct Mutation {
+///     users: UserMutation,
+///     posts: PostMutation,
+/// }
+/// ```
+///
+/// [`Context`]: juniper::Context
+/// [`ScalarValue`]: juniper::ScalarValue
+/// [1]: https://spec.graphql.org/June2018/#sec-Objects
+#[proc_macro_error]
+#[proc_macro_derive(GraphQLMergedObject, attributes(graphql))]
+pub fn derive_merged_object(body: TokenStream) -> TokenStream {
+    self::graphql_merged_object::derive::expand(body.into())
+        .unwrap_or_abort()
+        .into()
+}
+
 /// `#[graphql_object]` macro for generating a [GraphQL object][1]
 /// implementation for structs with computable field resolvers (declared via
 /// a regular Rust `impl` block).
@@ -1416,6 +1995,51 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Computed fields
+///
+/// An `impl` block with a `computed` attribute's argument declares fields,
+/// which are merged into the ones of a struct with `#[derive(GraphQLObject)]`
+/// and a `computed` attribute's argument. See
+/// `#[derive(GraphQLObject)]` macro for details.
+///
+/// # Generic types
+///
+/// A generic type can be exposed in the GraphQL schema once per each of its
+/// concrete instantiations, specified with `concrete` attribute's arguments.
+/// Their names are either specified explicitly, or rendered from a `name`
+/// template, where `{T}` placeholders are substituted with names of the types
+/// used for the corresponding type parameters.
+///
+/// ```
+/// # use juniper::{graphql_object, GraphQLObject};
+/// #
+/// #[derive(Clone, GraphQLObject)]
+/// struct User {
+///     id: String,
+/// }
+///
+/// #[derive(Clone, GraphQLObject)]
+/// struct Post {
+///     title: String,
+/// }
+///
+/// struct Connection<N>(Vec<N>);
+///
+/// #[graphql_object(
+///     name = "{N}Connection",
+///     concrete(params(User)), // exposed as `UserConnection`
+///     concrete(name = "PostsConnection", params(Post)),
+/// )]
+/// impl<N: Clone> Connection<N> {
+///     fn nodes(&self) -> Vec<N> {
+///         self.0.clone()
+///     }
+/// }
+/// ```
+///
+/// Exposing different Rust types under the same GraphQL name is reported with
+/// a panic once the schema is constructed.
+///
 /// # Custom `ScalarValue`
 ///
 /// By default, `#[graphql_object]` macro generates code, which is generic over
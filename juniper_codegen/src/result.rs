@@ -1,6 +1,5 @@
 //!
 
-use crate::util::duplicate::Duplicate;
 use proc_macro2::Span;
 use proc_macro_error::{Diagnostic, Level};
 use std::fmt;
@@ -13,25 +12,26 @@ pub enum GraphQLScope {
     InterfaceDerive,
     ObjectAttr,
     ObjectDerive,
+    MergedObjectDerive,
     ScalarAttr,
     ScalarDerive,
     ScalarValueDerive,
     UnionAttr,
     UnionDerive,
-    DeriveInputObject,
-    DeriveEnum,
+    EnumDerive,
+    InputObjectDerive,
 }
 
 impl GraphQLScope {
     pub fn spec_section(&self) -> &str {
         match self {
             Self::InterfaceAttr | Self::InterfaceDerive => "#sec-Interfaces",
-            Self::ObjectAttr | Self::ObjectDerive => "#sec-Objects",
+            Self::ObjectAttr | Self::ObjectDerive | Self::MergedObjectDerive => "#sec-Objects",
             Self::ScalarAttr | Self::ScalarDerive => "#sec-Scalars",
             Self::ScalarValueDerive => "#sec-Scalars.Built-in-Scalars",
             Self::UnionAttr | Self::UnionDerive => "#sec-Unions",
-            Self::DeriveInputObject => "#sec-Input-Objects",
-            Self::DeriveEnum => "#sec-Enums",
+            Self::EnumDerive => "#sec-Enums",
+            Self::InputObjectDerive => "#sec-Input-Objects",
         }
     }
 }
@@ -40,27 +40,17 @@ impl fmt::Display for GraphQLScope {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let name = match self {
             Self::InterfaceAttr | Self::InterfaceDerive => "interface",
-            Self::ObjectAttr | Self::ObjectDerive => "object",
+            Self::ObjectAttr | Self::ObjectDerive | Self::MergedObjectDerive => "object",
             Self::ScalarAttr | Self::ScalarDerive => "scalar",
             Self::ScalarValueDerive => "built-in scalars",
             Self::UnionAttr | Self::UnionDerive => "union",
-            Self::DeriveInputObject => "input object",
-            Self::DeriveEnum => "enum",
+            Self::EnumDerive => "enum",
+            Self::InputObjectDerive => "input object",
         };
         write!(f, "GraphQL {}", name)
     }
 }
 
-#[allow(unused_variables)]
-#[derive(Debug)]
-pub enum UnsupportedAttribute {
-    Skip,
-    Interface,
-    Scalar,
-    Deprecation,
-    Default,
-}
-
 impl GraphQLScope {
     fn spec_link(&self) -> String {
         format!("{}{}", SPEC_URL, self.spec_section())
@@ -84,61 +74,6 @@ impl GraphQLScope {
         syn::Error::new(span, format!("{} {}", self, msg.as_ref()))
     }
 
-    pub fn unsupported_attribute(&self, attribute: Span, kind: UnsupportedAttribute) {
-        Diagnostic::spanned(
-            attribute,
-            Level::Error,
-            format!("attribute `{:?}` can not be used at the top level of {}", kind, self),
-        )
-        .note("The macro is known to Juniper. However, not all valid #[graphql] attributes are available for each macro".to_string())
-        .emit();
-    }
-
-    pub fn unsupported_attribute_within(&self, attribute: Span, kind: UnsupportedAttribute) {
-        Diagnostic::spanned(
-            attribute,
-            Level::Error,
-            format!("attribute `{:?}` can not be used inside of {}", kind, self),
-        )
-        .note("The macro is known to Juniper. However, not all valid #[graphql] attributes are available for each macro".to_string())
-        .emit();
-    }
-
-    pub fn not_empty(&self, container: Span) {
-        Diagnostic::spanned(
-            container,
-            Level::Error,
-            format!("{} expects at least one field", self),
-        )
-        .note(self.spec_link())
-        .emit();
-    }
-
-    pub fn duplicate<'a, T: syn::spanned::Spanned + 'a>(
-        &self,
-        duplicates: impl IntoIterator<Item = &'a Duplicate<T>>,
-    ) {
-        duplicates
-            .into_iter()
-            .for_each(|dup| {
-                dup.spanned[1..]
-                    .iter()
-                    .for_each(|spanned| {
-                        Diagnostic::spanned(
-                            spanned.span(),
-                            Level::Error,
-                            format!(
-                                "{} does not allow fields with the same name",
-                                self
-                            ),
-                        )
-                            .help(format!("There is at least one other field with the same name `{}`, possibly renamed via the #[graphql] attribute", dup.name))
-                            .note(self.spec_link())
-                            .emit();
-                    });
-            })
-    }
-
     pub fn no_double_underscore(&self, field: Span) {
         Diagnostic::spanned(
             field,
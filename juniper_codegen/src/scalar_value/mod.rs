@@ -148,6 +148,9 @@ enum Method {
 
     /// `#[value(as_bool)]`.
     AsBool,
+
+    /// `#[value(as_big_int)]`.
+    AsBigInt,
 }
 
 /// Available arguments behind `#[value]` attribute when generating code for an
@@ -167,6 +170,7 @@ impl Parse for VariantAttr {
                 "as_string" => Method::AsString,
                 "into_string" => Method::IntoString,
                 "as_bool" => Method::AsBool,
+                "as_big_int" => Method::AsBigInt,
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -296,12 +300,50 @@ impl Definition {
             }
         });
 
+        let big_int_methods = self.methods.get(&Method::AsBigInt).map(|vars| {
+            let arms = vars.iter().map(|v| {
+                let arm = v.match_arm();
+                let call = v
+                    .expr
+                    .as_ref()
+                    .map_or_else(|| quote! { i128::from(*v) }, |f| quote! { #f(v) });
+                quote! { #arm => Some(#call), }
+            });
+            let tries = vars.iter().map(|v| {
+                let (var_ident, var_ty) = (&v.ident, v.field.ty());
+                let var_field = v.field.match_arg();
+                quote! {
+                    if let Ok(v) = <#var_ty as ::std::convert::TryFrom<i128>>::try_from(n) {
+                        return Some(Self::#var_ident#var_field);
+                    }
+                }
+            });
+
+            quote! {
+                fn as_big_int(&self) -> Option<i128> {
+                    match self {
+                        #(#arms)*
+                        _ => ::juniper::ScalarValue::as_int(self).map(i128::from),
+                    }
+                }
+
+                fn from_big_int(n: i128) -> Option<Self> {
+                    if let Ok(v) = <i32 as ::std::convert::TryFrom<i128>>::try_from(n) {
+                        return Some(<Self as ::std::convert::From<i32>>::from(v));
+                    }
+                    #(#tries)*
+                    None
+                }
+            }
+        });
+
         quote! {
             #[automatically_derived]
             impl#impl_gens ::juniper::ScalarValue for #ident#ty_gens
                 #where_clause
             {
                 #(#methods)*
+                #big_int_methods
             }
         }
     }
@@ -481,6 +523,13 @@ impl TryFrom<syn::Fields> for Field {
 }
 
 impl Field {
+    /// Returns the [`syn::Type`] of this [`Field`].
+    fn ty(&self) -> &syn::Type {
+        match self {
+            Self::Named(f) | Self::Unnamed(f) => &f.ty,
+        }
+    }
+
     /// Returns a [`Field`] for constructing or matching over a [`Variant`].
     fn match_arg(&self) -> TokenStream {
         match self {
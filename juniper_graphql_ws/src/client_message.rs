@@ -10,6 +10,7 @@ use crate::utils::default_for_null;
 #[serde(rename_all = "camelCase")]
 pub struct StartPayload<S> {
     /// The document body.
+    #[serde(default)]
     pub query: String,
 
     /// The optional variables.
@@ -18,6 +19,9 @@ pub struct StartPayload<S> {
 
     /// The optional operation name (required if the document contains multiple operations).
     pub operation_name: Option<String>,
+
+    /// The optional ID of the trusted document to execute instead of the `query`.
+    pub document_id: Option<String>,
 }
 
 /// ClientMessage defines the message types that clients can send.
@@ -83,6 +87,7 @@ mod test {
                     query: "query MyQuery { __typename }".to_string(),
                     variables: graphql_vars! {"foo": "bar"},
                     operation_name: Some("MyQuery".to_string()),
+                    document_id: None,
                 },
             },
             serde_json::from_str(
@@ -104,6 +109,7 @@ mod test {
                     query: "query MyQuery { __typename }".to_string(),
                     variables: graphql_vars! {},
                     operation_name: None,
+                    document_id: None,
                 },
             },
             serde_json::from_str(
@@ -136,6 +142,7 @@ mod test {
             query: "query".into(),
             variables: graphql_vars! {},
             operation_name: None,
+            document_id: None,
         };
 
         assert_eq!(expected, payload);
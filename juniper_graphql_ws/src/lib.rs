@@ -291,11 +291,35 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
         }
     }
 
-    async fn start(id: String, params: ExecutionParams<S>) -> BoxStream<'static, Reaction<S>> {
+    async fn start(id: String, mut params: ExecutionParams<S>) -> BoxStream<'static, Reaction<S>> {
         // TODO: This could be made more efficient if juniper exposed functionality to allow us to
         // parse and validate the query, determine whether it's a subscription, and then execute
         // it. For now, the query gets parsed and validated twice.
 
+        // Replace the referenced trusted document with its source, and reject the untrusted ones,
+        // as `juniper` executes any document directly.
+        #[cfg(feature = "trusted-documents")]
+        if let Some(documents) = params.schema.root_node().trusted_documents() {
+            let payload = &mut params.start_payload;
+            match payload.document_id.take() {
+                Some(document_id) => match documents.lookup(&document_id) {
+                    Some((source, name)) => {
+                        payload.query = source.to_owned();
+                        payload.operation_name = payload
+                            .operation_name
+                            .take()
+                            .or_else(|| name.map(str::to_owned));
+                    }
+                    None => return Self::unknown_document(id),
+                },
+                None if !documents.contains(&payload.query) => return Self::unknown_document(id),
+                None => {}
+            }
+        }
+        if params.start_payload.document_id.is_some() && params.start_payload.query.is_empty() {
+            return Self::unknown_document(id);
+        }
+
         let params = Arc::new(params);
 
         // Try to execute this as a query or mutation.
@@ -329,6 +353,15 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
         // Try to execute as a subscription.
         SubscriptionStart::new(id, params.clone()).boxed()
     }
+
+    /// Responds with [`GraphQLError::UnknownDocument`] to the operation with the provided `id`.
+    fn unknown_document(id: String) -> BoxStream<'static, Reaction<S>> {
+        Reaction::ServerMessage(ServerMessage::Error {
+            id,
+            payload: GraphQLError::UnknownDocument.into(),
+        })
+        .into_stream()
+    }
 }
 
 struct InterruptableStream<S> {
@@ -715,6 +748,7 @@ mod test {
                 query: "{context}".to_string(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                document_id: None,
             },
         })
         .await
@@ -760,6 +794,7 @@ mod test {
                 query: "subscription Foo {context}".to_string(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                document_id: None,
             },
         })
         .await
@@ -782,6 +817,7 @@ mod test {
                 query: "subscription Bar {context}".to_string(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                document_id: None,
             },
         })
         .await
@@ -874,6 +910,7 @@ mod test {
                 query: "subscription Foo {never}".to_string(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                document_id: None,
             },
         })
         .await
@@ -885,6 +922,7 @@ mod test {
                 query: "subscription Bar {never}".to_string(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                document_id: None,
             },
         })
         .await
@@ -919,6 +957,7 @@ mod test {
                 query: "asd".to_string(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                document_id: None,
             },
         })
         .await
@@ -982,6 +1021,7 @@ mod test {
                 query: "{context}".to_string(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                document_id: None,
             },
         })
         .await
@@ -1022,6 +1062,7 @@ mod test {
                 query: "subscription Foo {error}".to_string(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                document_id: None,
             },
         })
         .await
@@ -13,6 +13,11 @@ use juniper::{
 use serde_json::error::Error as SerdeError;
 use url::form_urlencoded;
 
+#[cfg(feature = "sse")]
+use futures::StreamExt as _;
+#[cfg(feature = "sse")]
+use juniper::http::sse;
+
 pub async fn graphql_sync<CtxT, QueryT, MutationT, SubscriptionT, S>(
     root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
     context: Arc<CtxT>,
@@ -55,6 +60,98 @@ where
     }
 }
 
+/// Handles GraphQL operations over [Server-Sent Events][1], following the
+/// [GraphQL over SSE protocol][2] in both "distinct connections" and "single
+/// connection" modes.
+///
+/// Requests carrying an event stream token are served in "single connection"
+/// mode via the provided `connections` registry, as well as `PUT` requests
+/// reserving such tokens. Any other `GET` or `POST` request is served in
+/// "distinct connections" mode.
+///
+/// See [`juniper::http::sse`] module for details.
+///
+/// [1]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+/// [2]: https://github.com/enisdenjo/graphql-sse/blob/master/PROTOCOL.md
+#[cfg(feature = "sse")]
+pub async fn graphql_sse<CtxT, QueryT, MutationT, SubscriptionT, S>(
+    root_node: Arc<RootNode<'static, QueryT, MutationT, SubscriptionT, S>>,
+    context: Arc<CtxT>,
+    connections: sse::SingleConnections<S>,
+    req: Request<Body>,
+) -> Response<Body>
+where
+    QueryT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    QueryT::TypeInfo: Send + Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    MutationT::TypeInfo: Send + Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    SubscriptionT::TypeInfo: Send + Sync,
+    CtxT: Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+{
+    let token = req
+        .headers()
+        .get(sse::TOKEN_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_owned)
+        .or_else(|| query_param(&req, sse::TOKEN_PARAM));
+
+    match (req.method().clone(), token) {
+        (Method::PUT, _) => match connections.reserve() {
+            Ok(token) => {
+                let mut resp = new_response(StatusCode::CREATED);
+                resp.headers_mut().insert(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static("text/plain; charset=utf-8"),
+                );
+                *resp.body_mut() = Body::from(token);
+                resp
+            }
+            Err(e) => render_sse_error(e),
+        },
+        (Method::GET, Some(token)) => match connections.open(&token) {
+            Ok(events) => new_sse_response(events),
+            Err(e) => render_sse_error(e),
+        },
+        (Method::POST, Some(token)) => {
+            let body = match hyper::body::to_bytes(req.into_body())
+                .await
+                .map_err(GraphQLRequestError::BodyHyper)
+                .and_then(|b| String::from_utf8(b.to_vec()).map_err(GraphQLRequestError::BodyUtf8))
+            {
+                Ok(body) => body,
+                Err(e) => return render_error(e),
+            };
+            match connections.execute(&token, root_node, context, &body).await {
+                Ok(()) => new_response(StatusCode::ACCEPTED),
+                Err(e) => render_sse_error(e),
+            }
+        }
+        (Method::DELETE, Some(token)) => {
+            let id = query_param(&req, sse::OPERATION_ID_PARAM).unwrap_or_default();
+            match connections.stop(&token, &id) {
+                Ok(()) => new_response(StatusCode::OK),
+                Err(e) => render_sse_error(e),
+            }
+        }
+        _ => match parse_req(req).await {
+            Ok(GraphQLBatchRequest::Single(req)) => {
+                match sse::subscribe(root_node, context, req).await {
+                    Ok(events) => new_sse_response(events),
+                    Err(rejection) => {
+                        new_json_response(StatusCode::BAD_REQUEST, rejection.into_body())
+                    }
+                }
+            }
+            Ok(GraphQLBatchRequest::Batch(_)) => render_error(GraphQLRequestError::Invalid(
+                "batch requests are not supported over SSE".into(),
+            )),
+            Err(resp) => resp,
+        },
+    }
+}
+
 async fn parse_req<S: ScalarValue>(
     req: Request<Body>,
 ) -> Result<GraphQLBatchRequest<S>, Response<Body>> {
@@ -177,6 +274,12 @@ where
         header::CONTENT_TYPE,
         HeaderValue::from_static("application/json"),
     );
+    if let Some(policy) = res.cache_policy() {
+        resp.headers_mut().insert(
+            header::CACHE_CONTROL,
+            HeaderValue::from_str(&policy.header_value()).unwrap(),
+        );
+    }
     *resp.body_mut() = body;
     resp
 }
@@ -208,6 +311,12 @@ where
         header::CONTENT_TYPE,
         HeaderValue::from_static("application/json"),
     );
+    if let Some(policy) = res.cache_policy() {
+        resp.headers_mut().insert(
+            header::CACHE_CONTROL,
+            HeaderValue::from_str(&policy.header_value()).unwrap(),
+        );
+    }
     *resp.body_mut() = body;
     resp
 }
@@ -219,6 +328,7 @@ where
     let mut query = None;
     let operation_name = None;
     let mut variables = None;
+    let mut document_id = None;
     for (key, value) in form_urlencoded::parse(input.as_bytes()).into_owned() {
         match key.as_ref() {
             "query" => {
@@ -243,14 +353,27 @@ where
                     Err(e) => return Err(e),
                 }
             }
+            "documentId" => {
+                if document_id.is_some() {
+                    return Err(invalid_err("documentId"));
+                }
+                document_id = Some(value)
+            }
             _ => continue,
         }
     }
-    match query {
-        Some(query) => Ok(JuniperGraphQLRequest::new(query, operation_name, variables)),
-        None => Err(GraphQLRequestError::Invalid(
+    match (query, document_id) {
+        (None, None) => Err(GraphQLRequestError::Invalid(
             "'query' parameter is missing".to_string(),
         )),
+        (query, document_id) => {
+            let req =
+                JuniperGraphQLRequest::new(query.unwrap_or_default(), operation_name, variables);
+            Ok(match document_id {
+                Some(id) => req.with_document_id(id),
+                None => req,
+            })
+        }
     }
 }
 
@@ -267,6 +390,53 @@ fn new_response(code: StatusCode) -> Response<Body> {
     r
 }
 
+#[cfg(feature = "sse")]
+fn new_json_response(code: StatusCode, body: String) -> Response<Body> {
+    let mut resp = new_response(code);
+    resp.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    *resp.body_mut() = Body::from(body);
+    resp
+}
+
+#[cfg(feature = "sse")]
+fn new_sse_response<S: ScalarValue>(events: sse::EventStream<S>) -> Response<Body> {
+    let mut resp = new_response(StatusCode::OK);
+    resp.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(sse::CONTENT_TYPE),
+    );
+    resp.headers_mut()
+        .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    *resp.body_mut() =
+        Body::wrap_stream(events.map(|e| Ok::<_, std::convert::Infallible>(e.to_string())));
+    resp
+}
+
+#[cfg(feature = "sse")]
+fn render_sse_error(err: sse::SingleConnectionError) -> Response<Body> {
+    let code = StatusCode::from_u16(err.status_code()).unwrap_or(StatusCode::BAD_REQUEST);
+    match err {
+        sse::SingleConnectionError::Rejected(rejection) => {
+            new_json_response(code, rejection.into_body())
+        }
+        err => {
+            let mut resp = new_response(code);
+            *resp.body_mut() = Body::from(err.to_string());
+            resp
+        }
+    }
+}
+
+#[cfg(feature = "sse")]
+fn query_param(req: &Request<Body>, name: &str) -> Option<String> {
+    form_urlencoded::parse(req.uri().query()?.as_bytes())
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.into_owned())
+}
+
 fn new_html_response(code: StatusCode) -> Response<Body> {
     let mut resp = new_response(code);
     resp.headers_mut().insert(
@@ -314,11 +484,11 @@ mod tests {
     use hyper::{
         server::Server,
         service::{make_service_fn, service_fn},
-        Body, Method, Response, StatusCode,
+        Body, Method, Request, Response, StatusCode,
     };
     use juniper::{
         http::tests as http_tests,
-        tests::fixtures::starwars::schema::{Database, Query},
+        tests::fixtures::starwars::schema::{Database, Query, Subscription},
         EmptyMutation, EmptySubscription, RootNode,
     };
     use reqwest::{self, blocking::Response as ReqwestResponse};
@@ -376,16 +546,25 @@ mod tests {
         }
     }
 
-    async fn run_hyper_integration(is_sync: bool) {
-        let port = if is_sync { 3002 } else { 3001 };
+    async fn run_hyper_integration(is_sync: bool, is_trusted: bool) {
+        let port = match (is_sync, is_trusted) {
+            (_, true) => 3003,
+            (true, false) => 3002,
+            (false, false) => 3001,
+        };
         let addr: SocketAddr = ([127, 0, 0, 1], port).into();
 
         let db = Arc::new(Database::new());
-        let root_node = Arc::new(RootNode::new(
+        let root_node = RootNode::new(
             Query,
             EmptyMutation::<Database>::new(),
             EmptySubscription::<Database>::new(),
-        ));
+        );
+        let root_node = Arc::new(if is_trusted {
+            http_tests::with_trusted_documents(root_node)
+        } else {
+            root_node
+        });
 
         let new_service = make_service_fn(move |_| {
             let root_node = root_node.clone();
@@ -434,7 +613,11 @@ mod tests {
         tokio::task::spawn_blocking(move || {
             thread::sleep(Duration::from_millis(10)); // wait 10ms for server to bind
             let integration = TestHyperIntegration { port };
-            http_tests::run_http_test_suite(&integration);
+            if is_trusted {
+                http_tests::run_trusted_documents_test_suite(&integration);
+            } else {
+                http_tests::run_http_test_suite(&integration);
+            }
             shutdown.abort();
         });
 
@@ -443,13 +626,91 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "sse")]
+    struct TestHyperSseIntegration {
+        root_node: Arc<RootNode<'static, Query, EmptyMutation<Database>, Subscription>>,
+        context: Arc<Database>,
+        connections: juniper::http::sse::SingleConnections,
+    }
+
+    #[cfg(feature = "sse")]
+    impl http_tests::SseIntegration for TestHyperSseIntegration {
+        fn request<'a>(
+            &'a self,
+            method: &'a str,
+            url: &'a str,
+            headers: &'a [(&'a str, &'a str)],
+            body: Option<&'a str>,
+        ) -> juniper::LocalBoxFuture<'a, http_tests::SseTestResponse> {
+            use futures::StreamExt as _;
+
+            let mut req = Request::builder()
+                .method(method)
+                .uri(format!("/graphql{}", url));
+            for (name, value) in headers {
+                req = req.header(*name, *value);
+            }
+            let req = req
+                .body(body.map_or_else(Body::empty, |b| Body::from(b.to_owned())))
+                .unwrap();
+
+            Box::pin(async move {
+                let resp = super::graphql_sse(
+                    self.root_node.clone(),
+                    self.context.clone(),
+                    self.connections.clone(),
+                    req,
+                )
+                .await;
+
+                let status_code = resp.status().as_u16() as i32;
+                let content_type = resp
+                    .headers()
+                    .get(hyper::header::CONTENT_TYPE)
+                    .map(|ct| ct.to_str().unwrap().to_owned())
+                    .unwrap_or_default();
+                let body = resp
+                    .into_body()
+                    .map(|chunk| String::from_utf8(chunk.unwrap().to_vec()).unwrap())
+                    .boxed_local();
+
+                http_tests::SseTestResponse {
+                    status_code,
+                    content_type,
+                    body,
+                }
+            })
+        }
+    }
+
+    #[cfg(feature = "sse")]
+    #[tokio::test]
+    async fn test_hyper_sse_integration() {
+        let integration = TestHyperSseIntegration {
+            root_node: Arc::new(RootNode::new(
+                Query,
+                EmptyMutation::<Database>::new(),
+                Subscription,
+            )),
+            context: Arc::new(Database::new()),
+            connections: Default::default(),
+        };
+
+        http_tests::run_sse_test_suite(&integration).await;
+    }
+
     #[tokio::test]
     async fn test_hyper_integration() {
-        run_hyper_integration(false).await
+        run_hyper_integration(false, false).await
     }
 
     #[tokio::test]
     async fn test_sync_hyper_integration() {
-        run_hyper_integration(true).await
+        run_hyper_integration(true, false).await
+    }
+
+    #[tokio::test]
+    async fn test_trusted_hyper_integration() {
+        run_hyper_integration(false, true).await
     }
 }
@@ -10,6 +10,8 @@ use iron::{
     prelude::*,
     status,
 };
+#[cfg(feature = "trusted-documents")]
+use juniper::http::trusted_documents::TrustedDocuments;
 use juniper::{
     http, http::GraphQLBatchRequest, DefaultScalarValue, GraphQLType, InputValue, RootNode,
     ScalarValue,
@@ -115,21 +117,36 @@ where
         }
     }
 
+    /// Restricts the documents executed by this handler to the provided
+    /// [`TrustedDocuments`].
+    ///
+    /// See [`RootNode::with_trusted_documents()`] for details.
+    #[cfg(feature = "trusted-documents")]
+    #[must_use]
+    pub fn with_trusted_documents(mut self, documents: TrustedDocuments<S>) -> Self {
+        self.root_node = self.root_node.with_trusted_documents(documents);
+        self
+    }
+
     fn handle_get(&self, req: &mut Request) -> IronResult<GraphQLBatchRequest<S>> {
         let url_query = req
             .get_mut::<UrlEncodedQuery>()
             .map_err(GraphQLIronError::Url)?;
 
-        let query = parse_url_param(url_query.remove("query"))?
-            .ok_or(GraphQLIronError::InvalidData("No query provided"))?;
+        let document_id = parse_url_param(url_query.remove("documentId"))?;
+        let query = match parse_url_param(url_query.remove("query"))? {
+            Some(query) => query,
+            None if document_id.is_some() => String::new(),
+            None => return Err(GraphQLIronError::InvalidData("No query provided").into()),
+        };
         let operation_name = parse_url_param(url_query.remove("operationName"))?;
         let variables = parse_variable_param(url_query.remove("variables"))?;
 
-        Ok(GraphQLBatchRequest::Single(http::GraphQLRequest::new(
-            query,
-            operation_name,
-            variables,
-        )))
+        let req = http::GraphQLRequest::new(query, operation_name, variables);
+        Ok(GraphQLBatchRequest::Single(match document_id {
+            Some(id) => req.with_document_id(id),
+            None => req,
+        }))
     }
 
     fn handle_post_json(&self, req: &mut Request) -> IronResult<GraphQLBatchRequest<S>> {
@@ -328,21 +345,32 @@ mod tests {
         )
     }
 
-    struct TestIronIntegration;
+    struct TestIronIntegration {
+        is_trusted: bool,
+    }
 
     impl http_tests::HttpIntegration for TestIronIntegration {
         fn get(&self, url: &str) -> http_tests::TestResponse {
-            request::get(&fixup_url(url), Headers::new(), &make_handler())
-                .map(make_test_response)
-                .unwrap_or_else(make_test_error_response)
+            request::get(
+                &fixup_url(url),
+                Headers::new(),
+                &make_handler(self.is_trusted),
+            )
+            .map(make_test_response)
+            .unwrap_or_else(make_test_error_response)
         }
 
         fn post_json(&self, url: &str, body: &str) -> http_tests::TestResponse {
             let mut headers = Headers::new();
             headers.set(ContentType::json());
-            request::post(&fixup_url(url), headers, body, &make_handler())
-                .map(make_test_response)
-                .unwrap_or_else(make_test_error_response)
+            request::post(
+                &fixup_url(url),
+                headers,
+                body,
+                &make_handler(self.is_trusted),
+            )
+            .map(make_test_response)
+            .unwrap_or_else(make_test_error_response)
         }
 
         fn post_graphql(&self, url: &str, body: &str) -> http_tests::TestResponse {
@@ -352,19 +380,31 @@ mod tests {
                 SubLevel::Ext("graphql".into()),
                 vec![],
             )));
-            request::post(&fixup_url(url), headers, body, &make_handler())
-                .map(make_test_response)
-                .unwrap_or_else(make_test_error_response)
+            request::post(
+                &fixup_url(url),
+                headers,
+                body,
+                &make_handler(self.is_trusted),
+            )
+            .map(make_test_response)
+            .unwrap_or_else(make_test_error_response)
         }
     }
 
     #[test]
     fn test_iron_integration() {
-        let integration = TestIronIntegration;
+        let integration = TestIronIntegration { is_trusted: false };
 
         http_tests::run_http_test_suite(&integration);
     }
 
+    #[test]
+    fn test_iron_integration_with_trusted_documents() {
+        let integration = TestIronIntegration { is_trusted: true };
+
+        http_tests::run_trusted_documents_test_suite(&integration);
+    }
+
     fn context_factory(_: &mut Request) -> IronResult<Database> {
         Ok(Database::new())
     }
@@ -401,12 +441,16 @@ mod tests {
         }
     }
 
-    fn make_handler() -> Box<dyn Handler> {
-        Box::new(<GraphQLHandler<_, _, _, _, _, DefaultScalarValue>>::new(
+    fn make_handler(is_trusted: bool) -> Box<dyn Handler> {
+        let mut handler = <GraphQLHandler<_, _, _, _, _, DefaultScalarValue>>::new(
             context_factory,
             Query,
             EmptyMutation::<Database>::new(),
             EmptySubscription::<Database>::new(),
-        ))
+        );
+        if is_trusted {
+            handler.root_node = http_tests::with_trusted_documents(handler.root_node);
+        }
+        Box::new(handler)
     }
 }
@@ -178,6 +178,7 @@ pub struct GraphQLContext<'f, S: ScalarValue> {
     query: Option<String>,
     operation_name: Option<String>,
     variables: Option<InputValue<S>>,
+    document_id: Option<String>,
     errors: Errors<'f>,
 }
 
@@ -202,6 +203,16 @@ impl<'f, S: ScalarValue> GraphQLContext<'f, S> {
         }
     }
 
+    fn document_id(&mut self, value: String) {
+        if self.document_id.is_some() {
+            let error = Error::from(ErrorKind::Duplicate).with_name("documentId");
+
+            self.errors.push(error)
+        } else {
+            self.document_id = Some(value);
+        }
+    }
+
     fn variables(&mut self, value: String) {
         if self.variables.is_some() {
             let error = Error::from(ErrorKind::Duplicate).with_name("variables");
@@ -236,6 +247,7 @@ where
             query: None,
             operation_name: None,
             variables: None,
+            document_id: None,
             errors: Errors::new(),
         }
     }
@@ -245,6 +257,7 @@ where
             Some("query") => ctx.query(field.value.to_owned()),
             Some("operation_name") => ctx.operation_name(field.value.to_owned()),
             Some("variables") => ctx.variables(field.value.to_owned()),
+            Some("documentId") => ctx.document_id(field.value.to_owned()),
             Some(key) => {
                 if ctx.opts.strict {
                     let error = Error::from(ErrorKind::Unknown).with_name(key);
@@ -271,18 +284,27 @@ where
     }
 
     fn finalize(mut ctx: Self::Context) -> rocket::form::Result<'f, Self> {
-        if ctx.query.is_none() {
+        if ctx.query.is_none() && ctx.document_id.is_none() {
             let error = Error::from(ErrorKind::Missing).with_name("query");
 
             ctx.errors.push(error)
         }
 
-        match ctx.errors.is_empty() {
-            true => Ok(GraphQLRequest(GraphQLBatchRequest::Single(
-                http::GraphQLRequest::new(ctx.query.unwrap(), ctx.operation_name, ctx.variables),
-            ))),
-            false => Err(ctx.errors),
+        if !ctx.errors.is_empty() {
+            return Err(ctx.errors);
         }
+
+        let req = http::GraphQLRequest::new(
+            ctx.query.unwrap_or_default(),
+            ctx.operation_name,
+            ctx.variables,
+        );
+        Ok(GraphQLRequest(GraphQLBatchRequest::Single(
+            match ctx.document_id {
+                Some(id) => req.with_document_id(id),
+                None => req,
+            },
+        )))
     }
 }
 
@@ -561,6 +583,22 @@ mod tests {
         http_tests::run_http_test_suite(&integration);
     }
 
+    #[rocket::async_test]
+    async fn test_rocket_integration_with_trusted_documents() {
+        let rocket = Rocket::build()
+            .manage(Database::new())
+            .manage(http_tests::with_trusted_documents(Schema::new(
+                Query,
+                EmptyMutation::<Database>::new(),
+                EmptySubscription::<Database>::new(),
+            )))
+            .mount("/", routes![post_graphql_handler, get_graphql_handler]);
+        let client = Client::untracked(rocket).await.expect("valid rocket");
+        let integration = TestRocketIntegration { client };
+
+        http_tests::run_trusted_documents_test_suite(&integration);
+    }
+
     #[rocket::async_test]
     async fn test_operation_names() {
         #[post("/", data = "<request>")]
@@ -2,22 +2,41 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 
+mod pubsub;
+mod shared;
+
 use std::{
+    collections::HashMap,
+    error::Error as StdError,
+    fmt,
     iter::FromIterator,
     pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, Weak},
     task::{self, Poll},
 };
 
-use futures::{future, stream, FutureExt as _, Stream, StreamExt as _, TryFutureExt as _};
+use futures::{
+    channel::oneshot,
+    future::{self, Shared},
+    stream, FutureExt as _, Stream, StreamExt as _,
+};
 use juniper::{
     http::GraphQLRequest, BoxFuture, ExecutionError, ExecutionOutput, GraphQLError,
     GraphQLSubscriptionType, GraphQLTypeAsync, Object, ScalarValue, SubscriptionConnection,
     SubscriptionCoordinator, Value, ValuesStream,
 };
 
-/// Simple [`SubscriptionCoordinator`] implementation:
+use self::shared::{Hub, SharedStream};
+
+pub use self::pubsub::{Backpressure, Lagged, PubSub, Subscription, Topic};
+
+/// [`SubscriptionCoordinator`] implementation:
 /// - contains the schema
 /// - handles subscription start
+/// - limits the number of active subscriptions, both globally and per context
+/// - de-duplicates identical subscriptions onto a single upstream stream
+///
+/// By default, no limits are applied and no de-duplication is performed.
 pub struct Coordinator<'a, QueryT, MutationT, SubscriptionT, CtxT, S>
 where
     QueryT: GraphQLTypeAsync<S, Context = CtxT> + Send,
@@ -30,8 +49,18 @@ where
     S: ScalarValue + Send + Sync,
 {
     root_node: juniper::RootNode<'a, QueryT, MutationT, SubscriptionT, S>,
+    max_subscriptions: Option<usize>,
+    max_subscriptions_per_context: Option<usize>,
+    context_key: Option<ContextKeyFn<CtxT>>,
+    deduplicate: bool,
+    deduplication_capacity: usize,
+    active: Arc<Mutex<ActiveSubscriptions>>,
+    upstreams: Mutex<HashMap<DeduplicationKey, Upstream<'a, S>>>,
 }
 
+/// Function extracting a key from a context, identifying its subscriber.
+type ContextKeyFn<CtxT> = Box<dyn Fn(&CtxT) -> String + Send + Sync>;
+
 impl<'a, QueryT, MutationT, SubscriptionT, CtxT, S>
     Coordinator<'a, QueryT, MutationT, SubscriptionT, CtxT, S>
 where
@@ -44,9 +73,183 @@ where
     CtxT: Sync,
     S: ScalarValue + Send + Sync,
 {
+    /// Default number of responses buffered for each de-duplicated
+    /// subscription (see [`Coordinator::with_deduplication_capacity()`]).
+    pub const DEFAULT_DEDUPLICATION_CAPACITY: usize = 16;
+
     /// Builds new [`Coordinator`] with specified `root_node`
     pub fn new(root_node: juniper::RootNode<'a, QueryT, MutationT, SubscriptionT, S>) -> Self {
-        Self { root_node }
+        Self {
+            root_node,
+            max_subscriptions: None,
+            max_subscriptions_per_context: None,
+            context_key: None,
+            deduplicate: false,
+            deduplication_capacity: Self::DEFAULT_DEDUPLICATION_CAPACITY,
+            active: Arc::new(Mutex::new(ActiveSubscriptions::default())),
+            upstreams: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Specifies the maximum number of active subscriptions. If this number
+    /// is reached, attempting to start more will result in a
+    /// [`SubscribeError::TooManySubscriptions`]. By default, there is no limit.
+    #[must_use]
+    pub fn with_max_subscriptions(mut self, max: usize) -> Self {
+        self.max_subscriptions = Some(max);
+        self
+    }
+
+    /// Specifies the maximum number of active subscriptions per context key
+    /// (see [`Coordinator::with_context_key()`]). If this number is reached,
+    /// attempting to start more will result in a
+    /// [`SubscribeError::TooManyContextSubscriptions`]. By default, there is no
+    /// limit.
+    ///
+    /// Without a context key specified, all the contexts are considered the
+    /// same one.
+    #[must_use]
+    pub fn with_max_subscriptions_per_context(mut self, max: usize) -> Self {
+        self.max_subscriptions_per_context = Some(max);
+        self
+    }
+
+    /// Specifies the function extracting a key from a context, identifying
+    /// its subscriber (a user or a session, for example).
+    ///
+    /// The key is used for limiting subscriptions per context and for
+    /// de-duplicating subscriptions.
+    #[must_use]
+    pub fn with_context_key<F>(mut self, key: F) -> Self
+    where
+        F: Fn(&CtxT) -> String + Send + Sync + 'static,
+    {
+        self.context_key = Some(Box::new(key));
+        self
+    }
+
+    /// Enables de-duplication of subscriptions: the ones having identical
+    /// query, operation name, variables and context key (see
+    /// [`Coordinator::with_context_key()`]) share a single upstream stream,
+    /// resolved with the context of the first one.
+    ///
+    /// Without a context key specified, all the contexts are considered the
+    /// same one, so subscriptions of different contexts will be de-duplicated
+    /// too.
+    #[must_use]
+    pub fn with_deduplication(mut self) -> Self {
+        self.deduplicate = true;
+        self
+    }
+
+    /// Specifies the maximum number of responses buffered for each
+    /// de-duplicated subscription (see [`Coordinator::with_deduplication()`])
+    /// not keeping up with its shared upstream stream. Once reached, the oldest
+    /// buffered response is dropped, so a slow subscriber misses responses
+    /// rather than making the upstream buffer all of them. By default,
+    /// [`Coordinator::DEFAULT_DEDUPLICATION_CAPACITY`] is used.
+    ///
+    /// # Panics
+    ///
+    /// If `capacity` is zero.
+    #[must_use]
+    pub fn with_deduplication_capacity(mut self, capacity: usize) -> Self {
+        assert!(capacity > 0, "de-duplication capacity must be non-zero");
+        self.deduplication_capacity = capacity;
+        self
+    }
+
+    /// Returns the number of active subscriptions, including the de-duplicated
+    /// ones.
+    pub fn active_subscriptions(&self) -> usize {
+        lock(&self.active).total
+    }
+
+    /// Returns the number of active subscriptions having the same context key
+    /// as the provided `context` (see [`Coordinator::with_context_key()`]).
+    pub fn active_subscriptions_for(&self, context: &CtxT) -> usize {
+        let key = self.context_key(context);
+        lock(&self.active)
+            .per_context
+            .get(&key)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of upstream streams shared by de-duplicated
+    /// subscriptions (see [`Coordinator::with_deduplication()`]).
+    pub fn active_upstreams(&self) -> usize {
+        lock(&self.upstreams)
+            .values()
+            .filter_map(Upstream::hub)
+            .count()
+    }
+
+    fn context_key(&self, context: &CtxT) -> String {
+        self.context_key
+            .as_ref()
+            .map(|key| key(context))
+            .unwrap_or_default()
+    }
+
+    /// Reserves a slot for a new subscription of the provided context key,
+    /// released once the returned [`ActiveGuard`] is dropped.
+    fn reserve(&self, context_key: String) -> Result<ActiveGuard, SubscribeError<'a>> {
+        let mut active = lock(&self.active);
+        if let Some(max) = self.max_subscriptions {
+            if active.total >= max {
+                return Err(SubscribeError::TooManySubscriptions { max });
+            }
+        }
+        let per_context = active.per_context.entry(context_key.clone()).or_default();
+        if let Some(max) = self.max_subscriptions_per_context {
+            if *per_context >= max {
+                return Err(SubscribeError::TooManyContextSubscriptions { max });
+            }
+        }
+        *per_context += 1;
+        active.total += 1;
+
+        Ok(ActiveGuard {
+            active: Arc::clone(&self.active),
+            context_key,
+        })
+    }
+
+    /// Joins the live [`Hub`] for the provided [`DeduplicationKey`], waiting
+    /// for it if it's being started by another subscription.
+    ///
+    /// If there is no such [`Hub`], then reserves the [`DeduplicationKey`] for
+    /// the caller to start it, so concurrent identical subscriptions wait for
+    /// it rather than starting their own upstreams.
+    async fn join_or_reserve(
+        &'a self,
+        key: DeduplicationKey,
+    ) -> Result<SharedStream<'a, S>, PendingUpstream<'a, S>> {
+        loop {
+            let started = {
+                let mut upstreams = lock(&self.upstreams);
+                if let Some(Upstream::Pending(started)) = upstreams.get(&key) {
+                    started.clone()
+                } else if let Some(hub) = upstreams.get(&key).and_then(Upstream::hub) {
+                    return Ok(SharedStream::join(hub));
+                } else {
+                    upstreams.retain(|_, upstream| {
+                        !matches!(upstream, Upstream::Started(hub) if hub.strong_count() == 0)
+                    });
+                    let (tx, rx) = oneshot::channel();
+                    upstreams.insert(key.clone(), Upstream::Pending(rx.shared()));
+                    return Err(PendingUpstream {
+                        upstreams: &self.upstreams,
+                        key,
+                        _started: tx,
+                    });
+                }
+            };
+            // Resolves once the other subscription either started the `Hub`
+            // or failed to, so the lookup should be retried.
+            let _ = started.await;
+        }
     }
 }
 
@@ -64,16 +267,189 @@ where
 {
     type Connection = Connection<'a, S>;
 
-    type Error = GraphQLError<'a>;
+    type Error = SubscribeError<'a>;
 
     fn subscribe(
         &'a self,
         req: &'a GraphQLRequest<S>,
         context: &'a CtxT,
     ) -> BoxFuture<'a, Result<Self::Connection, Self::Error>> {
-        juniper::http::resolve_into_stream(req, &self.root_node, context)
-            .map_ok(|(stream, errors)| Connection::from_stream(stream, errors))
-            .boxed()
+        let context_key = self.context_key(context);
+        let guard = match self.reserve(context_key.clone()) {
+            Ok(guard) => guard,
+            Err(e) => return future::ready(Err(e)).boxed(),
+        };
+
+        let dedup_key = self.deduplicate.then(|| DeduplicationKey {
+            query: req.query.clone(),
+            operation_name: req.operation_name.clone(),
+            variables: req.variables.as_ref().map(ToString::to_string),
+            context_key,
+        });
+
+        async move {
+            let pending = match dedup_key {
+                Some(key) => match self.join_or_reserve(key).await {
+                    Ok(stream) => return Ok(Connection::tracked(stream, guard)),
+                    Err(pending) => Some(pending),
+                },
+                None => None,
+            };
+
+            let (stream, errors) =
+                juniper::http::resolve_into_stream(req, &self.root_node, context).await?;
+            let has_errors = !errors.is_empty();
+            let stream = whole_responses_stream(stream, errors);
+
+            Ok(match pending {
+                Some(pending) if !has_errors => {
+                    let hub = Hub::new(stream, self.deduplication_capacity);
+                    pending.start(&hub);
+                    Connection::tracked(SharedStream::join(hub), guard)
+                }
+                _ => Connection::tracked(stream, guard),
+            })
+        }
+        .boxed()
+    }
+}
+
+/// Error of starting a subscription via [`Coordinator`].
+#[derive(Debug)]
+pub enum SubscribeError<'a> {
+    /// Subscription operation failed to be parsed, validated or resolved.
+    GraphQL(GraphQLError<'a>),
+
+    /// Maximum number of active subscriptions has been reached.
+    TooManySubscriptions {
+        /// Configured maximum number of active subscriptions.
+        max: usize,
+    },
+
+    /// Maximum number of active subscriptions per context has been reached.
+    TooManyContextSubscriptions {
+        /// Configured maximum number of active subscriptions per context.
+        max: usize,
+    },
+}
+
+impl<'a> From<GraphQLError<'a>> for SubscribeError<'a> {
+    fn from(e: GraphQLError<'a>) -> Self {
+        Self::GraphQL(e)
+    }
+}
+
+impl<'a> fmt::Display for SubscribeError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GraphQL(e) => write!(f, "{}", e),
+            Self::TooManySubscriptions { max } => {
+                write!(f, "Too many active subscriptions, maximum is {}", max)
+            }
+            Self::TooManyContextSubscriptions { max } => write!(
+                f,
+                "Too many active subscriptions of this context, maximum is {}",
+                max,
+            ),
+        }
+    }
+}
+
+impl<'a> StdError for SubscribeError<'a> {}
+
+/// Key identifying identical subscriptions to be de-duplicated.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct DeduplicationKey {
+    query: String,
+    operation_name: Option<String>,
+    variables: Option<String>,
+    context_key: String,
+}
+
+/// Upstream stream of de-duplicated subscriptions.
+enum Upstream<'a, S> {
+    /// Upstream being started by a subscription, resolving once it's either
+    /// started or failed to.
+    Pending(Shared<oneshot::Receiver<()>>),
+
+    /// Started upstream, shared via its [`Hub`].
+    Started(Weak<Mutex<Hub<'a, S>>>),
+}
+
+impl<'a, S> Upstream<'a, S> {
+    /// Returns the live [`Hub`] of this [`Upstream`], if it's started and not
+    /// finished yet.
+    fn hub(&self) -> Option<Arc<Mutex<Hub<'a, S>>>> {
+        match self {
+            Self::Pending(_) => None,
+            Self::Started(hub) => hub.upgrade().filter(|hub| !lock(hub).is_finished()),
+        }
+    }
+}
+
+/// Reservation of a [`DeduplicationKey`] for starting its [`Upstream`].
+///
+/// Releases the reservation once dropped, unless the [`Upstream`] has been
+/// started, waking the subscriptions waiting for it.
+struct PendingUpstream<'a, S> {
+    upstreams: &'a Mutex<HashMap<DeduplicationKey, Upstream<'a, S>>>,
+    key: DeduplicationKey,
+    _started: oneshot::Sender<()>,
+}
+
+impl<'a, S> PendingUpstream<'a, S> {
+    /// Shares the provided [`Hub`] as the started [`Upstream`].
+    fn start(self, hub: &Arc<Mutex<Hub<'a, S>>>) {
+        lock(self.upstreams).insert(self.key.clone(), Upstream::Started(Arc::downgrade(hub)));
+    }
+}
+
+impl<'a, S> Drop for PendingUpstream<'a, S> {
+    fn drop(&mut self) {
+        let mut upstreams = lock(self.upstreams);
+        if matches!(upstreams.get(&self.key), Some(Upstream::Pending(_))) {
+            upstreams.remove(&self.key);
+        }
+    }
+}
+
+/// Numbers of active subscriptions of a [`Coordinator`].
+#[derive(Debug, Default)]
+struct ActiveSubscriptions {
+    total: usize,
+    per_context: HashMap<String, usize>,
+}
+
+/// Slot of an active subscription, released once dropped.
+struct ActiveGuard {
+    active: Arc<Mutex<ActiveSubscriptions>>,
+    context_key: String,
+}
+
+impl Drop for ActiveGuard {
+    fn drop(&mut self) {
+        let mut active = lock(&self.active);
+        active.total -= 1;
+        if let Some(count) = active.per_context.get_mut(&self.context_key) {
+            *count -= 1;
+            if *count == 0 {
+                active.per_context.remove(&self.context_key);
+            }
+        }
+    }
+}
+
+/// [`Stream`] holding an [`ActiveGuard`] for as long as it's alive.
+struct Tracked<St> {
+    stream: St,
+    _guard: ActiveGuard,
+}
+
+impl<St: Stream + Unpin> Stream for Tracked<St> {
+    type Item = St::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.stream).poll_next(cx)
     }
 }
 
@@ -102,6 +478,20 @@ where
             stream: whole_responses_stream(stream, errors),
         }
     }
+
+    /// Creates new [`Connection`] from the given stream, keeping the provided
+    /// [`ActiveGuard`] while it's alive.
+    fn tracked<St>(stream: St, guard: ActiveGuard) -> Self
+    where
+        St: Stream<Item = ExecutionOutput<S>> + Send + Unpin + 'a,
+    {
+        Self {
+            stream: Box::pin(Tracked {
+                stream,
+                _guard: guard,
+            }),
+        }
+    }
 }
 
 impl<'a, S> SubscriptionConnection<S> for Connection<'a, S> where S: ScalarValue + Send + Sync + 'a {}
@@ -242,6 +632,198 @@ where
     }
 }
 
+/// Locks the provided [`Mutex`], ignoring its poisoning, as the guarded data
+/// always remains consistent.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod coordinator {
+    use futures::{future, StreamExt as _};
+    use juniper::{
+        graphql_object, graphql_subscription, graphql_value, http::GraphQLRequest,
+        DefaultScalarValue, EmptyMutation, RootNode, SubscriptionCoordinator as _,
+    };
+
+    use super::{Coordinator, ExecutionOutput, PubSub, SubscribeError, Subscription, Topic};
+
+    const NUMBERS: Topic<i32> = Topic::new("numbers");
+
+    struct Context {
+        user: String,
+        pubsub: PubSub,
+    }
+
+    impl juniper::Context for Context {}
+
+    struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        fn ping() -> bool {
+            true
+        }
+    }
+
+    struct Root;
+
+    #[graphql_subscription(context = Context)]
+    impl Root {
+        async fn numbers(ctx: &Context) -> Subscription<i32> {
+            // Lets the concurrent subscriptions interleave with this one.
+            tokio::task::yield_now().await;
+            ctx.pubsub.subscribe(&NUMBERS)
+        }
+    }
+
+    type TestCoordinator<'a> =
+        Coordinator<'a, Query, EmptyMutation<Context>, Root, Context, DefaultScalarValue>;
+
+    fn coordinator<'a>() -> TestCoordinator<'a> {
+        Coordinator::new(RootNode::new(Query, EmptyMutation::new(), Root))
+    }
+
+    fn request() -> GraphQLRequest {
+        GraphQLRequest::new("subscription { numbers }".into(), None, None)
+    }
+
+    fn context(user: &str, pubsub: &PubSub) -> Context {
+        Context {
+            user: user.into(),
+            pubsub: pubsub.clone(),
+        }
+    }
+
+    fn number(n: i32) -> String {
+        let output = ExecutionOutput::<DefaultScalarValue>::from_data(graphql_value!({
+            "numbers": n,
+        }));
+        serde_json::to_string(&output).unwrap()
+    }
+
+    #[tokio::test]
+    async fn limits_subscriptions() {
+        let coordinator = coordinator().with_max_subscriptions(1);
+        let (req, ctx) = (request(), context("alice", &PubSub::new()));
+
+        let first = coordinator.subscribe(&req, &ctx).await.unwrap();
+        assert_eq!(coordinator.active_subscriptions(), 1);
+        assert!(matches!(
+            coordinator.subscribe(&req, &ctx).await,
+            Err(SubscribeError::TooManySubscriptions { max: 1 }),
+        ));
+
+        drop(first);
+        assert_eq!(coordinator.active_subscriptions(), 0);
+        assert!(coordinator.subscribe(&req, &ctx).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn limits_subscriptions_per_context() {
+        let coordinator = coordinator()
+            .with_context_key(|ctx: &Context| ctx.user.clone())
+            .with_max_subscriptions_per_context(1);
+        let pubsub = PubSub::new();
+        let (alice, bob) = (context("alice", &pubsub), context("bob", &pubsub));
+        let req = request();
+
+        let _first = coordinator.subscribe(&req, &alice).await.unwrap();
+        assert!(matches!(
+            coordinator.subscribe(&req, &alice).await,
+            Err(SubscribeError::TooManyContextSubscriptions { max: 1 }),
+        ));
+        let _second = coordinator.subscribe(&req, &bob).await.unwrap();
+
+        assert_eq!(coordinator.active_subscriptions(), 2);
+        assert_eq!(coordinator.active_subscriptions_for(&alice), 1);
+        assert_eq!(coordinator.active_subscriptions_for(&bob), 1);
+    }
+
+    #[tokio::test]
+    async fn deduplicates_identical_subscriptions() {
+        let coordinator = coordinator()
+            .with_context_key(|ctx: &Context| ctx.user.clone())
+            .with_deduplication();
+        let pubsub = PubSub::new();
+        let (alice, bob) = (context("alice", &pubsub), context("bob", &pubsub));
+        let req = request();
+
+        let mut first = coordinator.subscribe(&req, &alice).await.unwrap();
+        let mut second = coordinator.subscribe(&req, &alice).await.unwrap();
+        assert_eq!(coordinator.active_subscriptions(), 2);
+        assert_eq!(coordinator.active_upstreams(), 1);
+        assert_eq!(pubsub.subscribers_count(&NUMBERS), 1);
+
+        let third = coordinator.subscribe(&req, &bob).await.unwrap();
+        assert_eq!(coordinator.active_upstreams(), 2);
+        assert_eq!(pubsub.subscribers_count(&NUMBERS), 2);
+
+        pubsub.publish(&NUMBERS, 42);
+        let expected = ExecutionOutput::<DefaultScalarValue>::from_data(graphql_value!({
+            "numbers": 42,
+        }));
+        let expected = serde_json::to_string(&expected).unwrap();
+        for conn in [&mut first, &mut second] {
+            let output = conn.next().await.unwrap();
+            assert_eq!(serde_json::to_string(&output).unwrap(), expected);
+        }
+
+        drop(third);
+        assert_eq!(pubsub.subscribers_count(&NUMBERS), 1);
+        drop(first);
+        assert_eq!(pubsub.subscribers_count(&NUMBERS), 1);
+        drop(second);
+        assert_eq!(pubsub.subscribers_count(&NUMBERS), 0);
+        assert_eq!(coordinator.active_upstreams(), 0);
+    }
+
+    #[tokio::test]
+    async fn deduplicates_concurrent_subscriptions() {
+        let coordinator = coordinator().with_deduplication();
+        let pubsub = PubSub::new();
+        let (req, ctx) = (request(), context("alice", &pubsub));
+
+        let (first, second) = future::join(
+            coordinator.subscribe(&req, &ctx),
+            coordinator.subscribe(&req, &ctx),
+        )
+        .await;
+        let (mut first, mut second) = (first.unwrap(), second.unwrap());
+        assert_eq!(coordinator.active_upstreams(), 1);
+        assert_eq!(pubsub.subscribers_count(&NUMBERS), 1);
+
+        pubsub.publish(&NUMBERS, 1);
+        for conn in [&mut first, &mut second] {
+            let output = conn.next().await.unwrap();
+            assert_eq!(serde_json::to_string(&output).unwrap(), number(1));
+        }
+    }
+
+    #[tokio::test]
+    async fn lags_slow_deduplicated_subscriptions() {
+        let coordinator = coordinator()
+            .with_deduplication()
+            .with_deduplication_capacity(2);
+        let pubsub = PubSub::new();
+        let (req, ctx) = (request(), context("alice", &pubsub));
+
+        let mut fast = coordinator.subscribe(&req, &ctx).await.unwrap();
+        let mut slow = coordinator.subscribe(&req, &ctx).await.unwrap();
+
+        for n in 1..=5 {
+            pubsub.publish(&NUMBERS, n);
+            let output = fast.next().await.unwrap();
+            assert_eq!(serde_json::to_string(&output).unwrap(), number(n));
+        }
+
+        for n in [4, 5] {
+            let output = slow.next().await.unwrap();
+            assert_eq!(serde_json::to_string(&output).unwrap(), number(n));
+        }
+    }
+}
+
 #[cfg(test)]
 mod whole_responses_stream {
     use futures::{stream, StreamExt as _};
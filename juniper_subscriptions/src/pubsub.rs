@@ -0,0 +1,601 @@
+//! In-memory publish/subscribe broker for fanning events out to subscribers.
+
+use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
+    collections::{HashMap, VecDeque},
+    convert::TryFrom as _,
+    error::Error as StdError,
+    fmt,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{self, Poll, Waker},
+};
+
+use futures::Stream;
+use juniper::{graphql_value, FieldError, IntoFieldError, ScalarValue};
+
+use crate::lock;
+
+/// Policy applied when a [`Subscription`] doesn't keep up with the published
+/// payloads and its buffer is full.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Backpressure {
+    /// Drops the oldest buffered payload to make room for the new one.
+    #[default]
+    DropOldest,
+
+    /// Drops the new payload and reports the number of missed payloads to the
+    /// subscriber as a [`Lagged`] error, before yielding the buffered ones.
+    Error,
+}
+
+/// Error yielded by a [`Subscription`] which has missed some payloads due to
+/// [`Backpressure::Error`] policy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Lagged {
+    /// Number of payloads missed by the [`Subscription`].
+    pub missed: u64,
+}
+
+impl fmt::Display for Lagged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "subscription lagged behind and missed {} events",
+            self.missed
+        )
+    }
+}
+
+impl StdError for Lagged {}
+
+impl<S: ScalarValue> IntoFieldError<S> for Lagged {
+    fn into_field_error(self) -> FieldError<S> {
+        let missed = i32::try_from(self.missed).unwrap_or(i32::MAX);
+        FieldError::new(self, graphql_value!({ "missed": missed }))
+    }
+}
+
+/// Typed topic of a [`PubSub`].
+///
+/// Topics are identified by both their name and payload type, so topics with
+/// the same name but different payload types never interfere.
+pub struct Topic<T> {
+    name: Cow<'static, str>,
+    _payload: PhantomData<fn() -> T>,
+}
+
+impl<T> Topic<T> {
+    /// Creates a new [`Topic`] with the provided static `name`.
+    ///
+    /// Being `const`, it allows declaring topics as constants.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            _payload: PhantomData,
+        }
+    }
+
+    /// Creates a new [`Topic`] with the provided dynamic `name`.
+    ///
+    /// Useful for topics scoped to some entity, like `chat:<room_id>`.
+    pub fn named(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            _payload: PhantomData,
+        }
+    }
+
+    /// Returns the name of this [`Topic`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<T: 'static> Topic<T> {
+    fn key(&self) -> TopicKey {
+        (self.name.clone(), TypeId::of::<T>())
+    }
+}
+
+impl<T> Clone for Topic<T> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            _payload: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Topic<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Topic").field(&self.name).finish()
+    }
+}
+
+/// Key identifying a [`Channel`] of a [`Topic`].
+type TopicKey = (Cow<'static, str>, TypeId);
+
+/// Predicate deciding whether a payload should be delivered to a subscriber.
+type Filter<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
+
+/// In-memory publish/subscribe broker.
+///
+/// Fans payloads published into a [`Topic`] out to all the [`Subscription`]s
+/// of this [`Topic`]. Each [`Subscription`] buffers up to
+/// [`PubSub::with_capacity()`] payloads, applying the configured
+/// [`Backpressure`] policy once its buffer is full.
+///
+/// [`PubSub`] is cheaply [`Clone`]able, with all the clones sharing the same
+/// topics, so it's intended to be stored in a context. Once all of them are
+/// dropped, the [`Subscription`]s are terminated.
+///
+/// ```rust
+/// # use futures::StreamExt as _;
+/// # use juniper::graphql_subscription;
+/// # use juniper_subscriptions::{PubSub, Subscription, Topic};
+/// #
+/// const MESSAGES: Topic<String> = Topic::new("messages");
+///
+/// struct Context {
+///     pubsub: PubSub,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// struct Root;
+///
+/// #[graphql_subscription(context = Context)]
+/// impl Root {
+///     async fn messages(ctx: &Context, prefix: String) -> Subscription<String> {
+///         ctx.pubsub
+///             .subscribe_filtered(&MESSAGES, move |msg: &String| msg.starts_with(&prefix))
+///     }
+/// }
+/// #
+/// # futures::executor::block_on(async {
+/// let pubsub = PubSub::new();
+/// let mut sub = pubsub.subscribe(&MESSAGES);
+///
+/// pubsub.publish(&MESSAGES, "hello".to_owned());
+/// assert_eq!(sub.next().await, Some(Ok("hello".to_owned())));
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct PubSub {
+    topics: Arc<Mutex<Topics>>,
+    capacity: usize,
+    backpressure: Backpressure,
+}
+
+impl PubSub {
+    /// Default number of payloads buffered by each [`Subscription`].
+    pub const DEFAULT_CAPACITY: usize = 16;
+
+    /// Creates a new [`PubSub`] with [`PubSub::DEFAULT_CAPACITY`] and
+    /// [`Backpressure::DropOldest`] policy.
+    pub fn new() -> Self {
+        Self {
+            topics: Arc::new(Mutex::new(Topics::default())),
+            capacity: Self::DEFAULT_CAPACITY,
+            backpressure: Backpressure::default(),
+        }
+    }
+
+    /// Specifies the maximum number of payloads buffered by each
+    /// [`Subscription`] created afterwards.
+    ///
+    /// # Panics
+    ///
+    /// If `capacity` is zero.
+    #[must_use]
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        assert!(capacity > 0, "`PubSub` capacity must be non-zero");
+        self.capacity = capacity;
+        self
+    }
+
+    /// Specifies the [`Backpressure`] policy of each [`Subscription`] created
+    /// afterwards.
+    #[must_use]
+    pub fn with_backpressure(mut self, backpressure: Backpressure) -> Self {
+        self.backpressure = backpressure;
+        self
+    }
+
+    /// Publishes the provided `payload` into the provided [`Topic`], returning
+    /// the number of [`Subscription`]s it has been delivered to.
+    pub fn publish<T>(&self, topic: &Topic<T>, payload: T) -> usize
+    where
+        T: Clone + Send + 'static,
+    {
+        let mut topics = lock(&self.topics);
+        let channel = match topics.channel_mut::<T>(&topic.key()) {
+            Some(ch) => ch,
+            None => return 0,
+        };
+
+        let mut delivered = 0;
+        for sub in channel.subscribers.values() {
+            if let Some(filter) = &sub.filter {
+                if !filter(&payload) {
+                    continue;
+                }
+            }
+            lock(&sub.buffer).push(payload.clone(), sub.backpressure, sub.capacity);
+            delivered += 1;
+        }
+        delivered
+    }
+
+    /// Subscribes to all the payloads published into the provided [`Topic`].
+    ///
+    /// The subscriber is removed once the returned [`Subscription`] is dropped.
+    pub fn subscribe<T>(&self, topic: &Topic<T>) -> Subscription<T>
+    where
+        T: Send + 'static,
+    {
+        self.subscribe_inner(topic, None)
+    }
+
+    /// Subscribes to the payloads published into the provided [`Topic`] which
+    /// satisfy the provided `filter` predicate.
+    ///
+    /// The predicate is usually a closure capturing subscription field
+    /// arguments. It's called on publishing, so should be cheap.
+    pub fn subscribe_filtered<T, F>(&self, topic: &Topic<T>, filter: F) -> Subscription<T>
+    where
+        T: Send + 'static,
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        self.subscribe_inner(topic, Some(Box::new(filter)))
+    }
+
+    /// Returns the number of active [`Subscription`]s of the provided
+    /// [`Topic`].
+    pub fn subscribers_count<T: 'static>(&self, topic: &Topic<T>) -> usize {
+        lock(&self.topics)
+            .channels
+            .get(&topic.key())
+            .map_or(0, |ch| ch.len())
+    }
+
+    fn subscribe_inner<T>(&self, topic: &Topic<T>, filter: Option<Filter<T>>) -> Subscription<T>
+    where
+        T: Send + 'static,
+    {
+        let key = topic.key();
+        let buffer = Arc::new(Mutex::new(Buffer::new()));
+
+        let mut topics = lock(&self.topics);
+        let id = topics.next_id;
+        topics.next_id += 1;
+        topics
+            .channels
+            .entry(key.clone())
+            .or_insert_with(|| Box::new(Channel::<T>::default()))
+            .as_any_mut()
+            .downcast_mut::<Channel<T>>()
+            .expect("`TopicKey` contains `TypeId` of the payload")
+            .subscribers
+            .insert(
+                id,
+                Subscriber {
+                    filter,
+                    capacity: self.capacity,
+                    backpressure: self.backpressure,
+                    buffer: Arc::clone(&buffer),
+                },
+            );
+
+        Subscription {
+            buffer,
+            topics: Arc::downgrade(&self.topics),
+            key,
+            id,
+        }
+    }
+}
+
+impl Default for PubSub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for PubSub {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PubSub")
+            .field("capacity", &self.capacity)
+            .field("backpressure", &self.backpressure)
+            .finish()
+    }
+}
+
+/// [`Stream`] of payloads published into a [`Topic`] of a [`PubSub`].
+///
+/// Yields [`Lagged`] errors if [`Backpressure::Error`] policy is used, so may
+/// be returned directly from a `#[graphql_subscription]` field resolver.
+///
+/// Unsubscribes once dropped, and terminates once all the [`PubSub`] clones
+/// are dropped.
+pub struct Subscription<T: 'static> {
+    buffer: Arc<Mutex<Buffer<T>>>,
+    topics: Weak<Mutex<Topics>>,
+    key: TopicKey,
+    id: u64,
+}
+
+impl<T: 'static> Stream for Subscription<T> {
+    type Item = Result<T, Lagged>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut buffer = lock(&self.buffer);
+        if buffer.missed > 0 {
+            let missed = std::mem::take(&mut buffer.missed);
+            return Poll::Ready(Some(Err(Lagged { missed })));
+        }
+        if let Some(payload) = buffer.payloads.pop_front() {
+            return Poll::Ready(Some(Ok(payload)));
+        }
+        if buffer.closed {
+            return Poll::Ready(None);
+        }
+        buffer.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<T: 'static> Drop for Subscription<T> {
+    fn drop(&mut self) {
+        if let Some(topics) = self.topics.upgrade() {
+            let mut topics = lock(&topics);
+            if let Some(ch) = topics.channels.get_mut(&self.key) {
+                ch.remove(self.id);
+                if ch.len() == 0 {
+                    topics.channels.remove(&self.key);
+                }
+            }
+        }
+    }
+}
+
+impl<T: 'static> fmt::Debug for Subscription<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscription")
+            .field("topic", &self.key.0)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+/// All the [`Channel`]s of a [`PubSub`], keyed by their [`Topic`]s.
+#[derive(Default)]
+struct Topics {
+    channels: HashMap<TopicKey, Box<dyn AnyChannel>>,
+    next_id: u64,
+}
+
+impl Topics {
+    fn channel_mut<T: 'static>(&mut self, key: &TopicKey) -> Option<&mut Channel<T>> {
+        self.channels
+            .get_mut(key)
+            .and_then(|ch| ch.as_any_mut().downcast_mut())
+    }
+}
+
+impl Drop for Topics {
+    fn drop(&mut self) {
+        for ch in self.channels.values_mut() {
+            ch.close();
+        }
+    }
+}
+
+/// Type-erased [`Channel`].
+trait AnyChannel: Send {
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    fn len(&self) -> usize;
+
+    fn remove(&mut self, id: u64);
+
+    fn close(&mut self);
+}
+
+/// Subscribers of a single [`Topic`].
+struct Channel<T> {
+    subscribers: HashMap<u64, Subscriber<T>>,
+}
+
+impl<T> Default for Channel<T> {
+    fn default() -> Self {
+        Self {
+            subscribers: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Send + 'static> AnyChannel for Channel<T> {
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn len(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    fn remove(&mut self, id: u64) {
+        self.subscribers.remove(&id);
+    }
+
+    fn close(&mut self) {
+        for sub in self.subscribers.values() {
+            let mut buffer = lock(&sub.buffer);
+            buffer.closed = true;
+            buffer.wake();
+        }
+    }
+}
+
+/// Subscriber of a [`Channel`].
+struct Subscriber<T> {
+    filter: Option<Filter<T>>,
+    capacity: usize,
+    backpressure: Backpressure,
+    buffer: Arc<Mutex<Buffer<T>>>,
+}
+
+/// Payloads buffered for a single [`Subscription`].
+struct Buffer<T> {
+    payloads: VecDeque<T>,
+    missed: u64,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl<T> Buffer<T> {
+    fn new() -> Self {
+        Self {
+            payloads: VecDeque::new(),
+            missed: 0,
+            closed: false,
+            waker: None,
+        }
+    }
+
+    fn push(&mut self, payload: T, backpressure: Backpressure, capacity: usize) {
+        if self.payloads.len() >= capacity {
+            match backpressure {
+                Backpressure::DropOldest => {
+                    self.payloads.pop_front();
+                }
+                Backpressure::Error => {
+                    self.missed += 1;
+                    self.wake();
+                    return;
+                }
+            }
+        }
+        self.payloads.push_back(payload);
+        self.wake();
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{FutureExt as _, StreamExt as _};
+
+    use super::{Backpressure, Lagged, PubSub, Topic};
+
+    const NUMBERS: Topic<i32> = Topic::new("numbers");
+
+    #[tokio::test]
+    async fn delivers_to_all_subscribers() {
+        let pubsub = PubSub::new();
+        let mut first = pubsub.subscribe(&NUMBERS);
+        let mut second = pubsub.subscribe(&NUMBERS);
+
+        assert_eq!(pubsub.publish(&NUMBERS, 1), 2);
+
+        assert_eq!(first.next().await, Some(Ok(1)));
+        assert_eq!(second.next().await, Some(Ok(1)));
+    }
+
+    #[tokio::test]
+    async fn separates_topics_by_payload_type() {
+        let pubsub = PubSub::new();
+        let strings = Topic::<String>::new("numbers");
+        let mut numbers = pubsub.subscribe(&NUMBERS);
+
+        assert_eq!(pubsub.publish(&strings, "1".into()), 0);
+        assert_eq!(pubsub.publish(&NUMBERS, 2), 1);
+
+        assert_eq!(numbers.next().await, Some(Ok(2)));
+    }
+
+    #[tokio::test]
+    async fn filters_payloads() {
+        let pubsub = PubSub::new();
+        let min = 10;
+        let sub = pubsub.subscribe_filtered(&NUMBERS, move |n| *n >= min);
+
+        for n in [1, 10, 5, 20] {
+            pubsub.publish(&NUMBERS, n);
+        }
+        drop(pubsub);
+
+        assert_eq!(sub.collect::<Vec<_>>().await, vec![Ok(10), Ok(20)]);
+    }
+
+    #[tokio::test]
+    async fn drops_oldest_when_full() {
+        let pubsub = PubSub::new().with_capacity(2);
+        let sub = pubsub.subscribe(&NUMBERS);
+
+        for n in 1..=4 {
+            pubsub.publish(&NUMBERS, n);
+        }
+        drop(pubsub);
+
+        assert_eq!(sub.collect::<Vec<_>>().await, vec![Ok(3), Ok(4)]);
+    }
+
+    #[tokio::test]
+    async fn errors_when_lagged() {
+        let pubsub = PubSub::new()
+            .with_capacity(2)
+            .with_backpressure(Backpressure::Error);
+        let mut sub = pubsub.subscribe(&NUMBERS);
+
+        for n in 1..=5 {
+            pubsub.publish(&NUMBERS, n);
+        }
+
+        assert_eq!(sub.next().await, Some(Err(Lagged { missed: 3 })));
+        assert_eq!(sub.next().await, Some(Ok(1)));
+        pubsub.publish(&NUMBERS, 6);
+        assert_eq!(sub.next().await, Some(Ok(2)));
+        assert_eq!(sub.next().await, Some(Ok(6)));
+        assert_eq!(sub.next().now_or_never(), None);
+    }
+
+    #[tokio::test]
+    async fn unsubscribes_on_drop() {
+        let pubsub = PubSub::new();
+        let first = pubsub.subscribe(&NUMBERS);
+        let second = pubsub.subscribe(&NUMBERS);
+        assert_eq!(pubsub.subscribers_count(&NUMBERS), 2);
+
+        drop(first);
+        assert_eq!(pubsub.subscribers_count(&NUMBERS), 1);
+        assert_eq!(pubsub.publish(&NUMBERS, 1), 1);
+
+        drop(second);
+        assert_eq!(pubsub.subscribers_count(&NUMBERS), 0);
+        assert_eq!(pubsub.publish(&NUMBERS, 2), 0);
+    }
+
+    #[tokio::test]
+    async fn wakes_pending_subscriber() {
+        let pubsub = PubSub::new();
+        let mut sub = pubsub.subscribe(&NUMBERS);
+
+        let publisher = pubsub.clone();
+        let handle = tokio::spawn(async move {
+            tokio::task::yield_now().await;
+            publisher.publish(&NUMBERS, 42);
+        });
+
+        assert_eq!(sub.next().await, Some(Ok(42)));
+        handle.await.unwrap();
+    }
+}
@@ -0,0 +1,152 @@
+//! Sharing of a single upstream subscription stream between multiple
+//! de-duplicated [`Connection`]s.
+//!
+//! [`Connection`]: crate::Connection
+
+use std::{
+    collections::{HashMap, VecDeque},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{self, Poll, Waker},
+};
+
+use futures::Stream;
+use juniper::ExecutionOutput;
+
+use crate::lock;
+
+/// Boxed upstream stream of whole subscription responses.
+pub(crate) type BoxedOutputStream<'a, S> =
+    Pin<Box<dyn Stream<Item = ExecutionOutput<S>> + Send + 'a>>;
+
+/// Upstream stream shared between multiple [`SharedStream`]s.
+///
+/// Whichever [`SharedStream`] is polled drives the upstream, and each yielded
+/// item is queued for all the other [`SharedStream`]s. Queues are bounded by
+/// the capacity of the [`Hub`], so a [`SharedStream`] not keeping up with the
+/// upstream lags behind, missing its oldest queued items, rather than making
+/// the [`Hub`] buffer all of them.
+pub(crate) struct Hub<'a, S> {
+    upstream: BoxedOutputStream<'a, S>,
+    capacity: usize,
+    subscribers: HashMap<u64, Subscriber<S>>,
+    next_id: u64,
+    finished: bool,
+}
+
+/// State of a single [`SharedStream`] inside a [`Hub`].
+struct Subscriber<S> {
+    queue: VecDeque<ExecutionOutput<S>>,
+    waker: Option<Waker>,
+}
+
+impl<'a, S> Hub<'a, S> {
+    /// Wraps the provided `upstream` into a new [`Hub`], queueing at most
+    /// `capacity` items for each [`SharedStream`].
+    pub(crate) fn new(upstream: BoxedOutputStream<'a, S>, capacity: usize) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(Self {
+            upstream,
+            capacity,
+            subscribers: HashMap::new(),
+            next_id: 0,
+            finished: false,
+        }))
+    }
+
+    /// Indicates whether the upstream of this [`Hub`] has been exhausted, so
+    /// no new [`SharedStream`]s should join it.
+    pub(crate) fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Wakes all the [`SharedStream`]s waiting for new items.
+    fn wake_all(&mut self) {
+        for sub in self.subscribers.values_mut() {
+            if let Some(waker) = sub.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// [`Stream`] of items produced by the upstream of a [`Hub`].
+pub(crate) struct SharedStream<'a, S> {
+    hub: Arc<Mutex<Hub<'a, S>>>,
+    id: u64,
+}
+
+impl<'a, S> SharedStream<'a, S> {
+    /// Joins the provided [`Hub`], receiving all its items produced afterwards.
+    pub(crate) fn join(hub: Arc<Mutex<Hub<'a, S>>>) -> Self {
+        let id = {
+            let mut h = lock(&hub);
+            let id = h.next_id;
+            h.next_id += 1;
+            h.subscribers.insert(
+                id,
+                Subscriber {
+                    queue: VecDeque::new(),
+                    waker: None,
+                },
+            );
+            id
+        };
+        Self { hub, id }
+    }
+}
+
+impl<'a, S: Clone> Stream for SharedStream<'a, S> {
+    type Item = ExecutionOutput<S>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut hub = lock(&self.hub);
+
+        let queued = hub
+            .subscribers
+            .get_mut(&self.id)
+            .and_then(|sub| sub.queue.pop_front());
+        if queued.is_some() {
+            return Poll::Ready(queued);
+        }
+        if hub.finished {
+            return Poll::Ready(None);
+        }
+
+        match hub.upstream.as_mut().poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                let capacity = hub.capacity;
+                for (id, sub) in &mut hub.subscribers {
+                    if *id != self.id {
+                        if sub.queue.len() >= capacity {
+                            sub.queue.pop_front();
+                        }
+                        sub.queue.push_back(item.clone());
+                    }
+                }
+                hub.wake_all();
+                Poll::Ready(Some(item))
+            }
+            Poll::Ready(None) => {
+                hub.finished = true;
+                hub.wake_all();
+                Poll::Ready(None)
+            }
+            Poll::Pending => {
+                if let Some(sub) = hub.subscribers.get_mut(&self.id) {
+                    sub.waker = Some(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<'a, S> Drop for SharedStream<'a, S> {
+    fn drop(&mut self) {
+        let mut hub = lock(&self.hub);
+        hub.subscribers.remove(&self.id);
+        // The upstream may have registered the waker of this stream only, so
+        // the remaining ones should re-poll it.
+        hub.wake_all();
+    }
+}
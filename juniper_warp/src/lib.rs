@@ -9,11 +9,16 @@ use anyhow::anyhow;
 use futures::{FutureExt as _, TryFutureExt};
 use juniper::{
     http::{GraphQLBatchRequest, GraphQLRequest},
-    ScalarValue,
+    CachePolicy, ScalarValue,
 };
 use tokio::task;
 use warp::{body, filters::BoxedFilter, http, hyper::body::Bytes, query, Filter};
 
+#[cfg(feature = "sse")]
+use juniper::http::sse;
+#[cfg(feature = "sse")]
+use warp::hyper;
+
 /// Make a filter for graphql queries/mutations.
 ///
 /// The `schema` argument is your juniper schema.
@@ -94,7 +99,7 @@ where
 
             Ok::<_, warp::Rejection>(build_response(
                 serde_json::to_vec(&resp)
-                    .map(|json| (json, resp.is_ok()))
+                    .map(|json| (json, resp.is_ok(), resp.cache_policy()))
                     .map_err(Into::into),
             ))
         }
@@ -113,7 +118,11 @@ where
 
             let resp = req.execute(&schema, &context).await;
 
-            Ok((serde_json::to_vec(&resp)?, resp.is_ok()))
+            Ok((
+                serde_json::to_vec(&resp)?,
+                resp.is_ok(),
+                resp.cache_policy(),
+            ))
         }
         .then(|res| async { Ok::<_, warp::Rejection>(build_response(res)) })
     };
@@ -122,21 +131,18 @@ where
         .and(body::bytes())
         .and_then(handle_post_graphql_request);
 
-    let handle_get_request = move |context: CtxT, mut qry: HashMap<String, String>| {
+    let handle_get_request = move |context: CtxT, qry: HashMap<String, String>| {
         let schema = schema.clone();
         async move {
-            let req = GraphQLRequest::new(
-                qry.remove("query")
-                    .ok_or_else(|| anyhow!("Missing GraphQL query string in query parameters"))?,
-                qry.remove("operation_name"),
-                qry.remove("variables")
-                    .map(|vs| serde_json::from_str(&vs))
-                    .transpose()?,
-            );
+            let req = parse_get_request(qry)?;
 
             let resp = req.execute(&schema, &context).await;
 
-            Ok((serde_json::to_vec(&resp)?, resp.is_ok()))
+            Ok((
+                serde_json::to_vec(&resp)?,
+                resp.is_ok(),
+                resp.cache_policy(),
+            ))
         }
         .then(|res| async move { Ok::<_, warp::Rejection>(build_response(res)) })
     };
@@ -174,7 +180,11 @@ where
         async move {
             let res = task::spawn_blocking(move || {
                 let resp = req.execute_sync(&schema, &context);
-                Ok((serde_json::to_vec(&resp)?, resp.is_ok()))
+                Ok((
+                    serde_json::to_vec(&resp)?,
+                    resp.is_ok(),
+                    resp.cache_policy(),
+                ))
             })
             .await?;
 
@@ -196,7 +206,11 @@ where
                 let req = GraphQLRequest::new(query.into(), None, None);
 
                 let resp = req.execute_sync(&schema, &context);
-                Ok((serde_json::to_vec(&resp)?, resp.is_ok()))
+                Ok((
+                    serde_json::to_vec(&resp)?,
+                    resp.is_ok(),
+                    resp.cache_policy(),
+                ))
             })
             .await?;
 
@@ -209,22 +223,18 @@ where
         .and(body::bytes())
         .and_then(handle_post_graphql_request);
 
-    let handle_get_request = move |context: CtxT, mut qry: HashMap<String, String>| {
+    let handle_get_request = move |context: CtxT, qry: HashMap<String, String>| {
         let schema = schema.clone();
         async move {
             let res = task::spawn_blocking(move || {
-                let req = GraphQLRequest::new(
-                    qry.remove("query").ok_or_else(|| {
-                        anyhow!("Missing GraphQL query string in query parameters")
-                    })?,
-                    qry.remove("operation_name"),
-                    qry.remove("variables")
-                        .map(|vs| serde_json::from_str(&vs))
-                        .transpose()?,
-                );
+                let req = parse_get_request(qry)?;
 
                 let resp = req.execute_sync(&schema, &context);
-                Ok((serde_json::to_vec(&resp)?, resp.is_ok()))
+                Ok((
+                    serde_json::to_vec(&resp)?,
+                    resp.is_ok(),
+                    resp.cache_policy(),
+                ))
             })
             .await?;
 
@@ -253,13 +263,19 @@ pub struct JoinError(task::JoinError);
 
 impl warp::reject::Reject for JoinError {}
 
-fn build_response(response: Result<(Vec<u8>, bool), anyhow::Error>) -> http::Response<Vec<u8>> {
+fn build_response(
+    response: Result<(Vec<u8>, bool, Option<CachePolicy>), anyhow::Error>,
+) -> http::Response<Vec<u8>> {
     match response {
-        Ok((body, is_ok)) => http::Response::builder()
-            .status(if is_ok { 200 } else { 400 })
-            .header("content-type", "application/json")
-            .body(body)
-            .expect("response is valid"),
+        Ok((body, is_ok, cache_policy)) => {
+            let mut builder = http::Response::builder()
+                .status(if is_ok { 200 } else { 400 })
+                .header("content-type", "application/json");
+            if let Some(policy) = cache_policy {
+                builder = builder.header("cache-control", policy.header_value());
+            }
+            builder.body(body).expect("response is valid")
+        }
         Err(_) => http::Response::builder()
             .status(http::StatusCode::INTERNAL_SERVER_ERROR)
             .body(Vec::new())
@@ -267,6 +283,220 @@ fn build_response(response: Result<(Vec<u8>, bool), anyhow::Error>) -> http::Res
     }
 }
 
+/// Make a filter serving GraphQL operations over [Server-Sent Events][1], following the
+/// [GraphQL over SSE protocol][2] in both "distinct connections" and "single connection" modes.
+///
+/// The `schema` and `context_extractor` arguments are the same as for [`make_graphql_filter()`].
+///
+/// The `connections` argument is the registry of event streams served in "single connection"
+/// mode. Requests carrying an event stream token are served in this mode, as well as `PUT`
+/// requests reserving such tokens. Any other `GET` or `POST` request is served in "distinct
+/// connections" mode.
+///
+/// See [`juniper::http::sse`] module for details.
+///
+/// Example:
+///
+/// ```
+/// # use juniper::{http::sse::SingleConnections, tests::fixtures::starwars::schema::{Database, Query, Subscription}, EmptyMutation, RootNode};
+/// # use juniper_warp::make_graphql_sse_filter;
+/// # use warp::Filter;
+/// #
+/// let schema = RootNode::new(Query, EmptyMutation::<Database>::new(), Subscription);
+/// let context_extractor = warp::any().map(Database::new).boxed();
+///
+/// let sse_endpoint = warp::path("graphql")
+///     .and(warp::path("stream"))
+///     .and(make_graphql_sse_filter(schema, context_extractor, SingleConnections::new()));
+/// ```
+///
+/// [1]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+/// [2]: https://github.com/enisdenjo/graphql-sse/blob/master/PROTOCOL.md
+#[cfg(feature = "sse")]
+pub fn make_graphql_sse_filter<Query, Mutation, Subscription, CtxT, S>(
+    schema: juniper::RootNode<'static, Query, Mutation, Subscription, S>,
+    context_extractor: BoxedFilter<(CtxT,)>,
+    connections: sse::SingleConnections<S>,
+) -> BoxedFilter<(http::Response<hyper::Body>,)>
+where
+    Query: juniper::GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Query::TypeInfo: Send + Sync,
+    Mutation: juniper::GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+    Mutation::TypeInfo: Send + Sync,
+    Subscription: juniper::GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+    Subscription::TypeInfo: Send + Sync,
+    CtxT: Send + Sync + 'static,
+    S: ScalarValue + Send + Sync + 'static,
+{
+    let schema = Arc::new(schema);
+
+    let handle_request = move |context: CtxT,
+                               method: http::Method,
+                               token: Option<String>,
+                               content_type: Option<String>,
+                               qry: HashMap<String, String>,
+                               body: Bytes| {
+        let schema = schema.clone();
+        let connections = connections.clone();
+        async move {
+            let context = Arc::new(context);
+            let token = token.or_else(|| qry.get(sse::TOKEN_PARAM).cloned());
+
+            Ok::<_, warp::Rejection>(match (method, token) {
+                (http::Method::PUT, _) => match connections.reserve() {
+                    Ok(token) => http::Response::builder()
+                        .status(http::StatusCode::CREATED)
+                        .header("content-type", "text/plain; charset=utf-8")
+                        .body(token.into())
+                        .expect("response is valid"),
+                    Err(e) => build_sse_error_response(e),
+                },
+                (http::Method::GET, Some(token)) => match connections.open(&token) {
+                    Ok(events) => build_sse_response(events),
+                    Err(e) => build_sse_error_response(e),
+                },
+                (http::Method::POST, Some(token)) => match str::from_utf8(body.as_ref()) {
+                    Ok(body) => match connections.execute(&token, schema, context, body).await {
+                        Ok(()) => build_text_response(http::StatusCode::ACCEPTED, String::new()),
+                        Err(e) => build_sse_error_response(e),
+                    },
+                    Err(e) => build_text_response(
+                        http::StatusCode::BAD_REQUEST,
+                        format!("Request body is not a valid UTF-8 string: {}", e),
+                    ),
+                },
+                (http::Method::DELETE, Some(token)) => {
+                    let id = qry
+                        .get(sse::OPERATION_ID_PARAM)
+                        .map(String::as_str)
+                        .unwrap_or_default();
+                    match connections.stop(&token, id) {
+                        Ok(()) => build_text_response(http::StatusCode::OK, String::new()),
+                        Err(e) => build_sse_error_response(e),
+                    }
+                }
+                (method, None) if method == http::Method::GET || method == http::Method::POST => {
+                    match parse_sse_request(method, content_type, qry, body) {
+                        Ok(req) => match sse::subscribe(schema, context, req).await {
+                            Ok(events) => build_sse_response(events),
+                            Err(rejection) => build_json_response(
+                                http::StatusCode::BAD_REQUEST,
+                                rejection.into_body(),
+                            ),
+                        },
+                        Err(e) => build_text_response(http::StatusCode::BAD_REQUEST, e.to_string()),
+                    }
+                }
+                _ => build_text_response(http::StatusCode::METHOD_NOT_ALLOWED, String::new()),
+            })
+        }
+    };
+
+    context_extractor
+        .and(warp::method())
+        .and(warp::header::optional::<String>(sse::TOKEN_HEADER))
+        .and(warp::header::optional::<String>("content-type"))
+        .and(
+            query::query::<HashMap<String, String>>()
+                .or(warp::any().map(HashMap::new))
+                .unify(),
+        )
+        .and(body::bytes())
+        .and_then(handle_request)
+        .boxed()
+}
+
+/// Parses a GraphQL request from the provided query parameters of a GET
+/// request.
+fn parse_get_request<S: ScalarValue>(
+    mut qry: HashMap<String, String>,
+) -> Result<GraphQLRequest<S>, anyhow::Error> {
+    let document_id = qry.remove("documentId");
+    let query = match qry.remove("query") {
+        Some(query) => query,
+        None if document_id.is_some() => String::new(),
+        None => return Err(anyhow!("Missing GraphQL query string in query parameters")),
+    };
+    let req = GraphQLRequest::new(
+        query,
+        qry.remove("operation_name"),
+        qry.remove("variables")
+            .map(|vs| serde_json::from_str(&vs))
+            .transpose()?,
+    );
+    Ok(match document_id {
+        Some(id) => req.with_document_id(id),
+        None => req,
+    })
+}
+
+/// Parses a GraphQL request served over SSE in "distinct connections" mode.
+#[cfg(feature = "sse")]
+fn parse_sse_request<S: ScalarValue>(
+    method: http::Method,
+    content_type: Option<String>,
+    qry: HashMap<String, String>,
+    body: Bytes,
+) -> Result<GraphQLRequest<S>, anyhow::Error> {
+    if method == http::Method::GET {
+        return parse_get_request(qry);
+    }
+    match content_type.as_deref() {
+        Some("application/json") => match serde_json::from_slice(body.as_ref())? {
+            GraphQLBatchRequest::Single(req) => Ok(req),
+            GraphQLBatchRequest::Batch(_) => Err(anyhow!("Batch requests are not supported")),
+        },
+        Some("application/graphql") => {
+            let query = str::from_utf8(body.as_ref())
+                .map_err(|e| anyhow!("Request body query is not a valid UTF-8 string: {}", e))?;
+            Ok(GraphQLRequest::new(query.into(), None, None))
+        }
+        _ => Err(anyhow!("Unsupported request content type")),
+    }
+}
+
+#[cfg(feature = "sse")]
+fn build_sse_response<S: ScalarValue>(events: sse::EventStream<S>) -> http::Response<hyper::Body> {
+    use futures::StreamExt as _;
+
+    http::Response::builder()
+        .header("content-type", sse::CONTENT_TYPE)
+        .header("cache-control", "no-cache")
+        .body(hyper::Body::wrap_stream(
+            events.map(|e| Ok::<_, std::convert::Infallible>(e.to_string())),
+        ))
+        .expect("response is valid")
+}
+
+#[cfg(feature = "sse")]
+fn build_sse_error_response(err: sse::SingleConnectionError) -> http::Response<hyper::Body> {
+    let code = http::StatusCode::from_u16(err.status_code()).expect("status code is valid");
+    match err {
+        sse::SingleConnectionError::Rejected(rejection) => {
+            build_json_response(code, rejection.into_body())
+        }
+        err => build_text_response(code, err.to_string()),
+    }
+}
+
+#[cfg(feature = "sse")]
+fn build_json_response(code: http::StatusCode, body: String) -> http::Response<hyper::Body> {
+    http::Response::builder()
+        .status(code)
+        .header("content-type", "application/json")
+        .body(body.into())
+        .expect("response is valid")
+}
+
+#[cfg(feature = "sse")]
+fn build_text_response(code: http::StatusCode, body: String) -> http::Response<hyper::Body> {
+    http::Response::builder()
+        .status(code)
+        .header("content-type", "text/plain; charset=utf-8")
+        .body(body.into())
+        .expect("response is valid")
+}
+
 /// Create a filter that replies with an HTML page containing GraphiQL. This does not handle routing, so you can mount it on any endpoint.
 ///
 /// For example:
@@ -647,7 +877,10 @@ mod tests {
 #[cfg(test)]
 mod tests_http_harness {
     use juniper::{
-        http::tests::{run_http_test_suite, HttpIntegration, TestResponse},
+        http::tests::{
+            run_http_test_suite, run_trusted_documents_test_suite, with_trusted_documents,
+            HttpIntegration, TestResponse,
+        },
         tests::fixtures::starwars::schema::{Database, Query},
         EmptyMutation, EmptySubscription, RootNode,
     };
@@ -663,12 +896,17 @@ mod tests_http_harness {
     }
 
     impl TestWarpIntegration {
-        fn new(is_sync: bool) -> Self {
+        fn new(is_sync: bool, is_trusted: bool) -> Self {
             let schema = RootNode::new(
                 Query,
                 EmptyMutation::<Database>::new(),
                 EmptySubscription::<Database>::new(),
             );
+            let schema = if is_trusted {
+                with_trusted_documents(schema)
+            } else {
+                schema
+            };
             let state = warp::any().map(move || Database::new());
 
             let filter = path::end().and(if is_sync {
@@ -762,11 +1000,95 @@ mod tests_http_harness {
 
     #[test]
     fn test_warp_integration() {
-        run_http_test_suite(&TestWarpIntegration::new(false));
+        run_http_test_suite(&TestWarpIntegration::new(false, false));
     }
 
     #[test]
     fn test_sync_warp_integration() {
-        run_http_test_suite(&TestWarpIntegration::new(true));
+        run_http_test_suite(&TestWarpIntegration::new(true, false));
+    }
+
+    #[test]
+    fn test_trusted_warp_integration() {
+        run_trusted_documents_test_suite(&TestWarpIntegration::new(false, true));
+    }
+}
+
+#[cfg(all(test, feature = "sse"))]
+mod tests_sse_harness {
+    use futures::StreamExt as _;
+    use juniper::{
+        http::{
+            sse::SingleConnections,
+            tests::{run_sse_test_suite, SseIntegration, SseTestResponse},
+        },
+        tests::fixtures::starwars::schema::{Database, Query, Subscription},
+        EmptyMutation, LocalBoxFuture, RootNode,
+    };
+    use warp::{
+        filters::{path, BoxedFilter},
+        http, hyper, Filter,
+    };
+
+    use super::*;
+
+    struct TestWarpSseIntegration {
+        filter: BoxedFilter<(http::Response<hyper::Body>,)>,
+    }
+
+    impl TestWarpSseIntegration {
+        fn new() -> Self {
+            let schema = RootNode::new(Query, EmptyMutation::<Database>::new(), Subscription);
+            let state = warp::any().map(Database::new);
+
+            let filter = path::end().and(make_graphql_sse_filter(
+                schema,
+                state.boxed(),
+                SingleConnections::new(),
+            ));
+            Self {
+                filter: filter.boxed(),
+            }
+        }
+    }
+
+    impl SseIntegration for TestWarpSseIntegration {
+        fn request<'a>(
+            &'a self,
+            method: &'a str,
+            url: &'a str,
+            headers: &'a [(&'a str, &'a str)],
+            body: Option<&'a str>,
+        ) -> LocalBoxFuture<'a, SseTestResponse> {
+            let mut req = warp::test::request().method(method).path(url);
+            for (name, value) in headers {
+                req = req.header(*name, *value);
+            }
+            if let Some(body) = body {
+                req = req.body(body);
+            }
+
+            Box::pin(async move {
+                let resp = req.filter(&self.filter).await.expect("request rejected");
+
+                SseTestResponse {
+                    status_code: resp.status().as_u16() as i32,
+                    content_type: resp
+                        .headers()
+                        .get("content-type")
+                        .map(|ct| ct.to_str().unwrap().to_owned())
+                        .unwrap_or_default(),
+                    body: resp
+                        .into_body()
+                        .map(|chunk| String::from_utf8(chunk.unwrap().to_vec()).unwrap())
+                        .boxed_local(),
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_warp_sse_integration() {
+        run_sse_test_suite(&TestWarpSseIntegration::new()).await;
     }
 }
@@ -0,0 +1,8 @@
+#[derive(juniper::GraphQLEnum)]
+enum Test {
+    Test,
+    #[graphql(ignore, name = "TEST2")]
+    Test2,
+}
+
+fn main() {}
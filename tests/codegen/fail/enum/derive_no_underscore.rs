@@ -0,0 +1,7 @@
+#[derive(juniper::GraphQLEnum)]
+enum Test {
+    #[graphql(name = "__test")]
+    Test,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(juniper::GraphQLEnum)]
+enum Test {
+    Test,
+    #[graphql(name = "TEST")]
+    Test2,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(juniper::GraphQLEnum)]
+enum Test {
+    Test,
+    Test2(i32),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(juniper::GraphQLInputObject)]
+struct Object {
+    #[graphql(ignore)]
+    test: String,
+}
+
+fn main() {}
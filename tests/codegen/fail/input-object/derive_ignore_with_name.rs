@@ -0,0 +1,8 @@
+#[derive(juniper::GraphQLInputObject)]
+struct Object {
+    test: String,
+    #[graphql(ignore, name = "other")]
+    other: String,
+}
+
+fn main() {}
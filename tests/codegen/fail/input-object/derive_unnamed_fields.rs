@@ -0,0 +1,4 @@
+#[derive(juniper::GraphQLInputObject)]
+struct Object(String);
+
+fn main() {}
@@ -0,0 +1,17 @@
+use juniper::graphql_object;
+
+struct Obj;
+
+#[graphql_object]
+impl Obj {
+    fn id(&self) -> &str {
+        "funA"
+    }
+
+    #[graphql(flatten)]
+    fn other(&self) -> &str {
+        "funB"
+    }
+}
+
+fn main() {}
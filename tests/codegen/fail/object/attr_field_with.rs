@@ -0,0 +1,21 @@
+use juniper::{graphql_object, Executor};
+
+fn shout<S>(value: &str, _: &(), _: &Executor<'_, '_, (), S>) -> String {
+    value.to_uppercase()
+}
+
+struct Obj;
+
+#[graphql_object]
+impl Obj {
+    fn id(&self) -> &str {
+        "funA"
+    }
+
+    #[graphql(with = shout)]
+    fn other(&self) -> &str {
+        "funB"
+    }
+}
+
+fn main() {}
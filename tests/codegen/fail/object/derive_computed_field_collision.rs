@@ -0,0 +1,16 @@
+use juniper::{graphql_object, GraphQLObject};
+
+#[derive(GraphQLObject)]
+#[graphql(computed)]
+struct Human {
+    name: String,
+}
+
+#[graphql_object(computed)]
+impl Human {
+    fn name(&self) -> String {
+        self.name.to_uppercase()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use juniper::GraphQLObject;
+
+#[derive(GraphQLObject)]
+struct Author {
+    id: String,
+}
+
+#[derive(GraphQLObject)]
+struct Post {
+    id: String,
+}
+
+#[derive(GraphQLObject)]
+struct Article {
+    #[graphql(flatten)]
+    author: Author,
+    #[graphql(flatten)]
+    post: Post,
+}
+
+fn main() {}
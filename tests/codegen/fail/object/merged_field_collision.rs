@@ -0,0 +1,24 @@
+use juniper::{graphql_object, GraphQLMergedObject};
+
+struct UserQuery;
+
+#[graphql_object]
+impl UserQuery {
+    fn name() -> &'static str {
+        "user"
+    }
+}
+
+struct PostQuery;
+
+#[graphql_object]
+impl PostQuery {
+    fn name() -> &'static str {
+        "post"
+    }
+}
+
+#[derive(GraphQLMergedObject)]
+struct Query(UserQuery, PostQuery);
+
+fn main() {}
@@ -1,7 +1,11 @@
+use std::marker::PhantomData;
+
 use fnv::FnvHashMap;
 use juniper::{
-    graphql_input_value, DefaultScalarValue, FromInputValue, GraphQLEnum, GraphQLType, Registry,
-    ToInputValue,
+    graphql_input_value,
+    macros::reflect::{BaseSubTypes, BaseType, WrappedType},
+    meta::{DeprecationStatus, MetaType},
+    DefaultScalarValue, FromInputValue, GraphQLEnum, GraphQLType, Registry, ToInputValue,
 };
 
 pub struct CustomContext {}
@@ -17,7 +21,7 @@ enum SomeEnum {
 }
 
 #[derive(juniper::GraphQLEnum, Debug, PartialEq)]
-#[graphql(rename = "none")]
+#[graphql(rename_all = "none")]
 enum NoRenameEnum {
     OneVariant,
     AnotherVariant,
@@ -51,11 +55,35 @@ enum OverrideDocEnum {
 }
 
 #[derive(GraphQLEnum)]
-#[graphql(context = CustomContext, noasync)]
+#[graphql(context = CustomContext)]
 enum ContextEnum {
     A,
 }
 
+#[derive(GraphQLEnum, Debug, PartialEq)]
+enum DeprecatedEnum {
+    #[deprecated(note = "Use `New`.")]
+    Old,
+    #[deprecated]
+    Older,
+    New,
+}
+
+#[derive(GraphQLEnum, Debug, PartialEq)]
+#[graphql(scalar = DefaultScalarValue)]
+enum ExplicitScalarEnum {
+    A,
+}
+
+#[derive(GraphQLEnum, Debug, PartialEq)]
+enum GenericEnum<T> {
+    Known,
+    #[graphql(ignore)]
+    _Phantom(PhantomData<T>),
+    #[graphql(skip)]
+    Unknown,
+}
+
 #[test]
 fn test_derived_enum() {
     // Ensure that rename works.
@@ -131,3 +159,83 @@ fn test_doc_custom_context() {
     test_context(ContextEnum::A);
     // test_context(OverrideDocEnum::Foo); does not work
 }
+
+#[test]
+fn test_deprecated_attribute() {
+    let mut registry: Registry = Registry::new(FnvHashMap::default());
+    let meta = DeprecatedEnum::meta(&(), &mut registry);
+    let values = match meta {
+        MetaType::Enum(meta) => meta.values,
+        _ => panic!("expected enum meta"),
+    };
+
+    let statuses: Vec<_> = values
+        .iter()
+        .map(|v| (v.name.as_str(), v.deprecation_status.clone()))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            (
+                "OLD",
+                DeprecationStatus::Deprecated(Some("Use `New`.".into())),
+            ),
+            ("OLDER", DeprecationStatus::Deprecated(None)),
+            ("NEW", DeprecationStatus::Current),
+        ],
+    );
+}
+
+#[test]
+fn test_explicit_scalar() {
+    fn implements_for_default_scalar<T: GraphQLType<DefaultScalarValue>>(_: T) {}
+
+    implements_for_default_scalar(ExplicitScalarEnum::A);
+}
+
+#[test]
+fn test_generic_with_ignored_variants() {
+    let mut registry: Registry = Registry::new(FnvHashMap::default());
+    let meta = GenericEnum::<i32>::meta(&(), &mut registry);
+    let values = match meta {
+        MetaType::Enum(meta) => meta.values,
+        _ => panic!("expected enum meta"),
+    };
+    assert_eq!(
+        values.iter().map(|v| v.name.as_str()).collect::<Vec<_>>(),
+        vec!["KNOWN"],
+    );
+
+    assert_eq!(
+        <_ as ToInputValue>::to_input_value(&GenericEnum::<i32>::Known),
+        graphql_input_value!("KNOWN"),
+    );
+    assert_eq!(
+        FromInputValue::<DefaultScalarValue>::from_input_value(&graphql_input_value!(KNOWN)),
+        Ok(GenericEnum::<i32>::Known),
+    );
+    assert!(
+        <GenericEnum<i32> as FromInputValue>::from_input_value(&graphql_input_value!(UNKNOWN))
+            .is_err()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_ignored_variant_cannot_be_converted() {
+    let _ = <_ as ToInputValue>::to_input_value(&GenericEnum::<i32>::Unknown);
+}
+
+#[test]
+fn test_reflection() {
+    assert_eq!(<SomeEnum as BaseType<DefaultScalarValue>>::NAME, "Some");
+    assert_eq!(
+        <SomeEnum as BaseSubTypes<DefaultScalarValue>>::NAMES,
+        ["Some"],
+    );
+    assert_eq!(<SomeEnum as WrappedType<DefaultScalarValue>>::VALUE, 1);
+    assert_eq!(
+        <GenericEnum<String> as BaseType<DefaultScalarValue>>::NAME,
+        "GenericEnum",
+    );
+}
@@ -1,7 +1,13 @@
+use std::marker::PhantomData;
+
 use fnv::FnvHashMap;
 use juniper::{
-    graphql_input_value, marker, DefaultScalarValue, FieldError, FromInputValue,
-    GraphQLInputObject, GraphQLType, GraphQLValue, InputValue, Registry, ToInputValue,
+    graphql_input_value,
+    macros::reflect::{BaseSubTypes, BaseType, WrappedType},
+    marker,
+    meta::MetaType,
+    DefaultScalarValue, FieldError, FromInputValue, GraphQLInputObject, GraphQLType, GraphQLValue,
+    InputValue, Registry, ToInputValue,
 };
 
 #[derive(GraphQLInputObject, Debug, PartialEq)]
@@ -12,7 +18,7 @@ use juniper::{
 )]
 struct Input {
     regular_field: String,
-    #[graphql(name = "haha", default = "33", description = "haha descr")]
+    #[graphql(name = "haha", default = 33, description = "haha descr")]
     c: i32,
 
     #[graphql(default)]
@@ -20,7 +26,7 @@ struct Input {
 }
 
 #[derive(GraphQLInputObject, Debug, PartialEq)]
-#[graphql(rename = "none")]
+#[graphql(rename_all = "none")]
 struct NoRenameInput {
     regular_field: String,
 }
@@ -85,6 +91,7 @@ impl<'a> GraphQLType<DefaultScalarValue> for &'a Fake {
                 name: "fake".to_string(),
                 description: None,
                 deprecation_status: juniper::meta::DeprecationStatus::Current,
+                directives: vec![],
             }],
         );
         meta.into_meta()
@@ -106,6 +113,25 @@ struct WithLifetime<'a> {
     regular_field: &'a Fake,
 }
 
+#[derive(GraphQLInputObject, Debug, PartialEq)]
+#[graphql(rename_all = "SCREAMING_SNAKE_CASE")]
+struct ScreamingInput {
+    regular_field: String,
+    #[graphql(default = "none")]
+    other_field: String,
+}
+
+#[derive(GraphQLInputObject, Debug, PartialEq)]
+struct GenericInput<T> {
+    regular_field: i32,
+    #[graphql(ignore)]
+    ignored: Vec<String>,
+    #[graphql(skip, default = 7)]
+    ignored_with_default: u8,
+    #[graphql(ignore)]
+    _phantom: PhantomData<T>,
+}
+
 #[test]
 fn test_derived_input_object() {
     assert_eq!(
@@ -189,3 +215,66 @@ fn test_doc_comment_override() {
     let meta = OverrideDocComment::meta(&(), &mut registry);
     assert_eq!(meta.description(), Some("obj override"));
 }
+
+#[test]
+fn test_rename_all_and_default_expression() {
+    let input: InputValue = graphql_input_value!({"REGULAR_FIELD": "a"});
+    let output: ScreamingInput = FromInputValue::from_input_value(&input).unwrap();
+    assert_eq!(
+        output,
+        ScreamingInput {
+            regular_field: "a".into(),
+            other_field: "none".into(),
+        },
+    );
+
+    assert_eq!(
+        <_ as ToInputValue>::to_input_value(&output),
+        graphql_input_value!({"REGULAR_FIELD": "a", "OTHER_FIELD": "none"}),
+    );
+}
+
+#[test]
+fn test_generic_with_ignored_fields() {
+    let mut registry: Registry = Registry::new(FnvHashMap::default());
+    let meta = GenericInput::<bool>::meta(&(), &mut registry);
+    let fields = match meta {
+        MetaType::InputObject(meta) => meta.input_fields,
+        _ => panic!("expected input object meta"),
+    };
+    assert_eq!(
+        fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+        vec!["regularField"],
+    );
+
+    let input: InputValue = graphql_input_value!({"regularField": 1, "ignored": ["x"]});
+    let output: GenericInput<bool> = FromInputValue::from_input_value(&input).unwrap();
+    assert_eq!(
+        output,
+        GenericInput {
+            regular_field: 1,
+            ignored: vec![],
+            ignored_with_default: 7,
+            _phantom: PhantomData,
+        },
+    );
+
+    assert_eq!(
+        <_ as ToInputValue>::to_input_value(&output),
+        graphql_input_value!({"regularField": 1}),
+    );
+}
+
+#[test]
+fn test_reflection() {
+    assert_eq!(<Input as BaseType<DefaultScalarValue>>::NAME, "MyInput");
+    assert_eq!(
+        <Input as BaseSubTypes<DefaultScalarValue>>::NAMES,
+        ["MyInput"],
+    );
+    assert_eq!(<Input as WrappedType<DefaultScalarValue>>::VALUE, 1);
+    assert_eq!(
+        <GenericInput<()> as BaseType<DefaultScalarValue>>::NAME,
+        "GenericInput",
+    );
+}
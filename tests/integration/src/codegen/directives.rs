@@ -0,0 +1,247 @@
+//! Tests for `directive = ...` attribute argument of code generation macros.
+
+use fnv::FnvHashMap;
+use juniper::{
+    graphql_input_value, graphql_interface, graphql_object,
+    meta::{AppliedDirective, MetaType},
+    DefaultScalarValue, GraphQLEnum, GraphQLInputObject, GraphQLObject, GraphQLScalar, GraphQLType,
+    GraphQLUnion, InputValue, Registry,
+};
+
+fn tag(name: &str) -> AppliedDirective<DefaultScalarValue> {
+    AppliedDirective::new("tag").argument("name", InputValue::scalar(name))
+}
+
+fn meta<T: GraphQLType<TypeInfo = ()>>(f: impl FnOnce(&MetaType)) {
+    let mut registry: Registry = Registry::new(FnvHashMap::default());
+    f(&T::meta(&(), &mut registry));
+}
+
+mod object {
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object(directive = @tag(name: "type"), directive = key(fields: "id"))]
+    impl Human {
+        #[graphql(directive = @tag(name: "field"))]
+        fn id(#[graphql(directive = tag(name: "arg"))] prefix: String) -> String {
+            prefix
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(directive = @tag(name: "type"))]
+    struct Droid {
+        #[graphql(directive = @tag(name: "field"), directive = @shareable)]
+        id: String,
+    }
+
+    #[test]
+    fn applies_directives_to_type_fields_and_arguments() {
+        meta::<Human>(|meta| {
+            assert_eq!(
+                meta.directives(),
+                [
+                    tag("type"),
+                    AppliedDirective::new("key").argument("fields", graphql_input_value!("id")),
+                ],
+            );
+
+            let field = meta.field_by_name("id").unwrap();
+            assert_eq!(field.directives, [tag("field")]);
+            let arg = &field.arguments.as_ref().unwrap()[0];
+            assert_eq!(arg.directives, [tag("arg")]);
+        });
+    }
+
+    #[test]
+    fn applies_directives_on_derive() {
+        meta::<Droid>(|meta| {
+            assert_eq!(meta.directives(), [tag("type")]);
+
+            let field = meta.field_by_name("id").unwrap();
+            assert_eq!(
+                field.directives,
+                [tag("field"), AppliedDirective::new("shareable")]
+            );
+        });
+    }
+}
+
+mod interface {
+    use super::*;
+
+    #[graphql_interface(for = Human, directive = @tag(name: "type"))]
+    trait Character {
+        #[graphql(directive = @tag(name: "field"))]
+        fn id(&self) -> &str;
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(impl = CharacterValue)]
+    struct Human {
+        id: String,
+    }
+
+    #[test]
+    fn applies_directives() {
+        meta::<CharacterValue>(|meta| {
+            assert_eq!(meta.directives(), [tag("type")]);
+
+            let field = meta.field_by_name("id").unwrap();
+            assert_eq!(field.directives, [tag("field")]);
+        });
+    }
+}
+
+mod union {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    struct Human {
+        id: String,
+    }
+
+    #[derive(GraphQLUnion)]
+    #[graphql(directive = @tag(name: "type"))]
+    enum Character {
+        Human(Human),
+    }
+
+    #[test]
+    fn applies_directives() {
+        meta::<Character>(|meta| {
+            assert_eq!(meta.directives(), [tag("type")]);
+        });
+    }
+}
+
+mod scalar {
+    use super::*;
+
+    #[derive(GraphQLScalar)]
+    #[graphql(transparent, directive = @tag(name: "type"))]
+    struct Counter(i32);
+
+    #[test]
+    fn applies_directives() {
+        meta::<Counter>(|meta| {
+            assert_eq!(meta.directives(), [tag("type")]);
+        });
+    }
+}
+
+mod enum_ {
+    use super::*;
+
+    #[derive(GraphQLEnum)]
+    #[graphql(directive = @tag(name: "type"))]
+    enum Episode {
+        #[graphql(directive = @tag(name: "value"))]
+        NewHope,
+        Empire,
+    }
+
+    #[test]
+    fn applies_directives() {
+        meta::<Episode>(|meta| {
+            assert_eq!(meta.directives(), [tag("type")]);
+
+            if let MetaType::Enum(meta) = meta {
+                assert_eq!(meta.values[0].directives, [tag("value")]);
+                assert!(meta.values[1].directives.is_empty());
+            } else {
+                panic!("expected enum meta, got: {:?}", meta);
+            }
+        });
+    }
+}
+
+mod input_object {
+    use super::*;
+
+    #[derive(GraphQLInputObject)]
+    #[graphql(directive = @tag(name: "type"))]
+    struct Point {
+        #[graphql(directive = @tag(name: "field"))]
+        x: f64,
+    }
+
+    #[test]
+    fn applies_directives() {
+        meta::<Point>(|meta| {
+            assert_eq!(meta.directives(), [tag("type")]);
+
+            if let MetaType::InputObject(meta) = meta {
+                assert_eq!(meta.input_fields[0].directives, [tag("field")]);
+            } else {
+                panic!("expected input object meta, got: {:?}", meta);
+            }
+        });
+    }
+}
+
+mod cache_control {
+    use super::*;
+
+    struct Query;
+
+    #[graphql_object(cache_control(max_age = 60))]
+    impl Query {
+        #[graphql(cache_control(max_age = 10, scope = "private"))]
+        fn me() -> &'static str {
+            "me"
+        }
+
+        #[graphql(cache_control(scope = "public"))]
+        fn version() -> &'static str {
+            "1"
+        }
+    }
+
+    #[derive(GraphQLUnion)]
+    #[graphql(cache_control(max_age = 30))]
+    enum Node {
+        Query(Query),
+    }
+
+    fn cache_control(
+        args: &[(&str, InputValue<DefaultScalarValue>)],
+    ) -> AppliedDirective<DefaultScalarValue> {
+        args.iter()
+            .fold(AppliedDirective::new("cacheControl"), |d, (n, v)| {
+                d.argument(n, v.clone())
+            })
+    }
+
+    #[test]
+    fn applies_cache_control_directives() {
+        meta::<Query>(|meta| {
+            assert_eq!(
+                meta.directives(),
+                [cache_control(&[("maxAge", graphql_input_value!(60))])],
+            );
+
+            let field = meta.field_by_name("me").unwrap();
+            assert_eq!(
+                field.directives,
+                [cache_control(&[
+                    ("maxAge", graphql_input_value!(10)),
+                    ("scope", graphql_input_value!(PRIVATE)),
+                ])],
+            );
+            let field = meta.field_by_name("version").unwrap();
+            assert_eq!(
+                field.directives,
+                [cache_control(&[("scope", graphql_input_value!(PUBLIC))])],
+            );
+        });
+        meta::<Node>(|meta| {
+            assert_eq!(
+                meta.directives(),
+                [cache_control(&[("maxAge", graphql_input_value!(30))])],
+            );
+        });
+    }
+}
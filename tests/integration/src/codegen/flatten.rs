@@ -0,0 +1,392 @@
+//! Tests for `#[graphql(flatten)]` attribute argument of
+//! `#[derive(GraphQLInputObject)]` and `#[derive(GraphQLObject)]` macros.
+
+use juniper::{
+    execute, execute_sync, graphql_input_value, graphql_object, graphql_value, graphql_vars,
+    macros::reflect::Fields, DefaultScalarValue, EmptyMutation, EmptySubscription, FromInputValue,
+    GraphQLInputObject, GraphQLObject, InputValue, RootNode, ToInputValue,
+};
+
+use crate::util::schema;
+
+mod input_object {
+    use super::*;
+
+    /// Pagination arguments.
+    #[derive(Debug, GraphQLInputObject, PartialEq)]
+    struct Pagination {
+        #[graphql(default = 10)]
+        first: i32,
+        after: Option<String>,
+    }
+
+    #[derive(Debug, GraphQLInputObject, PartialEq)]
+    struct Filter {
+        /// Substring to search for.
+        query: String,
+    }
+
+    #[derive(Debug, GraphQLInputObject, PartialEq)]
+    struct UsersQuery {
+        ascending: Option<bool>,
+        #[graphql(flatten)]
+        pagination: Pagination,
+        #[graphql(flatten)]
+        filter: Filter,
+    }
+
+    #[derive(Debug, GraphQLInputObject, PartialEq)]
+    struct PostsQuery {
+        #[graphql(flatten)]
+        users: UsersQuery,
+        #[graphql(name = "tag")]
+        tags: Vec<String>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn users(query: UsersQuery) -> String {
+            format!("{:?}", query)
+        }
+
+        fn posts(query: PostsQuery) -> i32 {
+            query.users.pagination.first + query.tags.len() as i32
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_flattened_fields() {
+        const DOC: &str = r#"{
+            users(query: {ascending: true, first: 2, after: "x", query: "john"})
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "users": "UsersQuery { \
+                        ascending: Some(true), \
+                        pagination: Pagination { first: 2, after: Some(\"x\") }, \
+                        filter: Filter { query: \"john\" } \
+                    }",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_defaults_of_flattened_fields() {
+        const DOC: &str = r#"{
+            posts(query: {query: "john", tag: ["rust", "graphql"]})
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"posts": 12}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_nested_flattened_fields() {
+        const DOC: &str = r#"{
+            users(query: {pagination: {first: 2}, query: "john"})
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert!(execute(DOC, None, &schema, &graphql_vars! {}, &())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn inlines_flattened_fields_into_meta() {
+        const DOC: &str = r#"{
+            __type(name: "PostsQuery") {
+                inputFields {
+                    name
+                    description
+                    defaultValue
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"inputFields": [
+                    {"name": "tag", "description": null, "defaultValue": null},
+                    {"name": "ascending", "description": null, "defaultValue": null},
+                    {"name": "first", "description": null, "defaultValue": "10"},
+                    {"name": "after", "description": null, "defaultValue": null},
+                    {
+                        "name": "query",
+                        "description": "Substring to search for.",
+                        "defaultValue": null,
+                    },
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn does_not_register_flattened_types() {
+        let schema = schema(QueryRoot);
+
+        assert!(schema.schema.concrete_type_by_name("Pagination").is_none());
+    }
+
+    #[test]
+    fn converts_from_and_to_input_value() {
+        let input: InputValue = graphql_input_value!({
+            "ascending": null,
+            "first": 5,
+            "after": "y",
+            "query": "doe",
+        });
+
+        let query = UsersQuery::from_input_value(&input).unwrap();
+        assert_eq!(
+            query,
+            UsersQuery {
+                ascending: None,
+                pagination: Pagination {
+                    first: 5,
+                    after: Some("y".into()),
+                },
+                filter: Filter {
+                    query: "doe".into(),
+                },
+            },
+        );
+        assert_eq!(<UsersQuery as ToInputValue>::to_input_value(&query), input,);
+    }
+
+    #[test]
+    fn reflects_flattened_fields() {
+        assert_eq!(
+            <UsersQuery as Fields<DefaultScalarValue>>::NAMES,
+            ["ascending", "first", "after", "query"],
+        );
+        assert_eq!(
+            <PostsQuery as Fields<DefaultScalarValue>>::NAMES,
+            ["tag", "ascending", "first", "after", "query"],
+        );
+    }
+}
+
+mod object {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    struct Timestamps {
+        /// Creation time.
+        created_at: i32,
+        updated_at: Option<i32>,
+    }
+
+    #[derive(GraphQLObject)]
+    struct Audit {
+        author: &'static str,
+        #[graphql(flatten)]
+        timestamps: Timestamps,
+    }
+
+    /// Article.
+    #[derive(GraphQLObject)]
+    struct Article {
+        #[graphql(flatten)]
+        audit: Audit,
+        title: &'static str,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn article() -> Article {
+            Article {
+                audit: Audit {
+                    author: "John",
+                    timestamps: Timestamps {
+                        created_at: 1,
+                        updated_at: Some(2),
+                    },
+                },
+                title: "Flattening",
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_flattened_fields() {
+        const DOC: &str = r#"{
+            article {
+                title
+                author
+                createdAt
+                updatedAt
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"article": {
+                    "title": "Flattening",
+                    "author": "John",
+                    "createdAt": 1,
+                    "updatedAt": 2,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_flattened_fields_synchronously() {
+        const DOC: &str = r#"{
+            article {
+                createdAt
+                title
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+            Ok((
+                graphql_value!({"article": {"createdAt": 1, "title": "Flattening"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn inlines_flattened_fields_into_meta() {
+        const DOC: &str = r#"{
+            __type(name: "Article") {
+                description
+                fields {
+                    name
+                    description
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {
+                    "description": "Article.",
+                    "fields": [
+                        {"name": "title", "description": null},
+                        {"name": "author", "description": null},
+                        {"name": "createdAt", "description": "Creation time."},
+                        {"name": "updatedAt", "description": null},
+                    ],
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn does_not_register_flattened_types() {
+        let schema = schema(QueryRoot);
+
+        assert!(schema.schema.concrete_type_by_name("Timestamps").is_none());
+    }
+
+    #[test]
+    fn reflects_flattened_fields() {
+        assert_eq!(
+            <Article as Fields<DefaultScalarValue>>::NAMES,
+            ["title", "author", "createdAt", "updatedAt"],
+        );
+    }
+}
+
+mod object_with_context {
+    use super::*;
+
+    struct Database {
+        views: i32,
+    }
+
+    impl juniper::Context for Database {}
+
+    struct Stats;
+
+    #[graphql_object(context = Database)]
+    impl Stats {
+        fn views(context: &Database) -> i32 {
+            context.views
+        }
+
+        async fn likes() -> i32 {
+            7
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(context = Database)]
+    struct Post {
+        title: &'static str,
+        #[graphql(flatten)]
+        stats: Stats,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Database)]
+    impl QueryRoot {
+        fn post() -> Post {
+            Post {
+                title: "Hello",
+                stats: Stats,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_flattened_fields_with_context() {
+        const DOC: &str = r#"{
+            post {
+                title
+                views
+                likes
+            }
+        }"#;
+
+        let schema = RootNode::new(
+            QueryRoot,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+        let db = Database { views: 42 };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &db).await,
+            Ok((
+                graphql_value!({"post": {"title": "Hello", "views": 42, "likes": 7}}),
+                vec![],
+            )),
+        );
+    }
+}
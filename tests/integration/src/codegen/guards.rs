@@ -0,0 +1,267 @@
+//! Tests for `guard = ...` attribute argument of code generation macros.
+
+use std::pin::Pin;
+
+use futures::{future, stream};
+use juniper::{
+    execute, execute_sync, executor::guard, graphql_interface, graphql_object,
+    graphql_subscription, graphql_value, graphql_vars, resolve_into_stream, Arguments,
+    EmptyMutation, ExecutionError, FieldError, FieldResult, Guard, RootNode, ScalarValue,
+};
+
+use crate::util::{extract_next, schema};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Role {
+    Admin,
+    Editor,
+}
+
+struct Context {
+    roles: Vec<Role>,
+}
+
+impl juniper::Context for Context {}
+
+impl<S: ScalarValue> Guard<Context, S> for Role {
+    fn check(&self, ctx: &Context, _: &Arguments<S>) -> FieldResult<(), S> {
+        if ctx.roles.contains(self) {
+            Ok(())
+        } else {
+            Err(FieldError::from(format!("{:?} role required", self)))
+        }
+    }
+}
+
+fn errors<S>(errs: &[ExecutionError<S>]) -> Vec<(Vec<&str>, &str)> {
+    errs.iter()
+        .map(|e| {
+            let path = e.path().iter().map(String::as_str).collect();
+            (path, e.error().message())
+        })
+        .collect()
+}
+
+mod object {
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object(context = Context, guard = guard::or(Role::Admin, Role::Editor))]
+    impl Human {
+        fn name() -> &'static str {
+            "Luke"
+        }
+
+        #[graphql(guard = Role::Admin)]
+        async fn home_planet() -> &'static str {
+            "Tatooine"
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Context)]
+    impl QueryRoot {
+        fn human() -> Option<Human> {
+            Some(Human)
+        }
+    }
+
+    #[test]
+    fn checks_type_guard_synchronously() {
+        const DOC: &str = "{ human { name } }";
+
+        let schema = schema(QueryRoot);
+
+        let ctx = Context { roles: vec![] };
+        let (res, errs) = execute_sync(DOC, None, &schema, &graphql_vars! {}, &ctx).unwrap();
+        assert_eq!(res, graphql_value!({"human": null}));
+        assert_eq!(
+            errors(&errs),
+            [(vec!["human", "name"], "Editor role required")],
+        );
+
+        let ctx = Context {
+            roles: vec![Role::Editor],
+        };
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &ctx),
+            Ok((graphql_value!({"human": {"name": "Luke"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn checks_type_guard_before_field_guard() {
+        const DOC: &str = "{ human { homePlanet } }";
+
+        let schema = schema(QueryRoot);
+
+        let ctx = Context { roles: vec![] };
+        let (_, errs) = execute(DOC, None, &schema, &graphql_vars! {}, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            errors(&errs),
+            [(vec!["human", "homePlanet"], "Editor role required")],
+        );
+
+        let ctx = Context {
+            roles: vec![Role::Editor],
+        };
+        let (_, errs) = execute(DOC, None, &schema, &graphql_vars! {}, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(
+            errors(&errs),
+            [(vec!["human", "homePlanet"], "Admin role required")],
+        );
+
+        let ctx = Context {
+            roles: vec![Role::Admin],
+        };
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((
+                graphql_value!({"human": {"homePlanet": "Tatooine"}}),
+                vec![]
+            )),
+        );
+    }
+}
+
+mod interface {
+    use super::*;
+
+    #[graphql_interface(for = Human, context = Context, guard = Role::Editor)]
+    trait Character {
+        fn id(&self) -> &str;
+
+        #[graphql(guard = Role::Admin)]
+        fn secret(&self) -> &str;
+    }
+
+    struct Human;
+
+    #[graphql_object(impl = CharacterValue, context = Context)]
+    impl Human {
+        fn id() -> &'static str {
+            "human-32"
+        }
+
+        fn secret() -> &'static str {
+            "jedi"
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Context)]
+    impl QueryRoot {
+        fn character() -> Option<CharacterValue> {
+            Some(Human.into())
+        }
+
+        fn human() -> Option<Human> {
+            Some(Human)
+        }
+    }
+
+    #[tokio::test]
+    async fn checks_guards_on_interface_fields() {
+        const DOC: &str = "{ character { id secret } }";
+
+        let schema = schema(QueryRoot);
+
+        let ctx = Context {
+            roles: vec![Role::Editor],
+        };
+        for (res, errs) in [
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &ctx).unwrap(),
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx)
+                .await
+                .unwrap(),
+        ] {
+            assert_eq!(res, graphql_value!({"character": null}));
+            assert_eq!(
+                errors(&errs),
+                [(vec!["character", "secret"], "Admin role required")],
+            );
+        }
+
+        let ctx = Context {
+            roles: vec![Role::Admin, Role::Editor],
+        };
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((
+                graphql_value!({"character": {"id": "human-32", "secret": "jedi"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn doesnt_check_interface_guards_on_implementers() {
+        const DOC: &str = "{ human { id secret } }";
+
+        let schema = schema(QueryRoot);
+
+        let ctx = Context { roles: vec![] };
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((
+                graphql_value!({"human": {"id": "human-32", "secret": "jedi"}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod subscription {
+    use super::*;
+
+    type Stream<'a, I> = Pin<Box<dyn futures::Stream<Item = I> + Send + 'a>>;
+
+    struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        fn empty() -> bool {
+            true
+        }
+    }
+
+    struct Subscription;
+
+    #[graphql_subscription(context = Context)]
+    impl Subscription {
+        #[graphql(guard = Role::Admin)]
+        async fn audit() -> Stream<'static, i32> {
+            Box::pin(stream::once(future::ready(1)))
+        }
+    }
+
+    #[tokio::test]
+    async fn checks_guard_before_resolving_stream() {
+        const DOC: &str = "subscription { audit }";
+
+        let schema = RootNode::new(Query, EmptyMutation::new(), Subscription);
+
+        let ctx = Context { roles: vec![] };
+        let (_, errs) =
+            extract_next(resolve_into_stream(DOC, None, &schema, &graphql_vars! {}, &ctx).await)
+                .await
+                .unwrap();
+        assert_eq!(errors(&errs), [(vec!["audit"], "Admin role required")]);
+
+        let ctx = Context {
+            roles: vec![Role::Admin],
+        };
+        assert_eq!(
+            extract_next(resolve_into_stream(DOC, None, &schema, &graphql_vars! {}, &ctx).await)
+                .await,
+            Ok((graphql_value!({"audit": 1}), vec![])),
+        );
+    }
+}
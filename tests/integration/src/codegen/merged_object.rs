@@ -0,0 +1,301 @@
+//! Tests for `#[derive(GraphQLMergedObject)]` macro.
+
+use juniper::{
+    execute, execute_sync, graphql_object, graphql_value, graphql_vars, EmptySubscription,
+    GraphQLMergedObject, GraphQLObject, RootNode,
+};
+
+use crate::{custom_scalar::MyScalarValue, util::schema};
+
+mod tuple_struct {
+    use super::*;
+
+    struct UserQuery;
+
+    #[graphql_object]
+    impl UserQuery {
+        fn user_name() -> &'static str {
+            "John"
+        }
+    }
+
+    struct PostQuery;
+
+    #[graphql_object]
+    impl PostQuery {
+        async fn post_title(id: i32) -> String {
+            format!("Post {}", id)
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    struct Version {
+        api_version: &'static str,
+    }
+
+    #[derive(GraphQLMergedObject)]
+    struct Query(UserQuery, PostQuery, Version);
+
+    fn query() -> Query {
+        Query(UserQuery, PostQuery, Version { api_version: "1.0" })
+    }
+
+    #[tokio::test]
+    async fn resolves_fields_of_all_merged_objects() {
+        const DOC: &str = r#"{
+            userName
+            postTitle(id: 7)
+            apiVersion
+            __typename
+        }"#;
+
+        let schema = schema(query());
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "userName": "John",
+                    "postTitle": "Post 7",
+                    "apiVersion": "1.0",
+                    "__typename": "Query",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_sync_fields_synchronously() {
+        const DOC: &str = r#"{
+            userName
+            apiVersion
+        }"#;
+
+        let schema = schema(query());
+
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+            Ok((
+                graphql_value!({"userName": "John", "apiVersion": "1.0"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn exposes_fields_of_all_merged_objects() {
+        const DOC: &str = r#"{
+            __type(name: "Query") {
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(query());
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "userName"},
+                    {"name": "postTitle"},
+                    {"name": "apiVersion"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn does_not_expose_merged_objects() {
+        let schema = schema(query());
+
+        assert!(schema.schema.concrete_type_by_name("Query").is_some());
+        assert!(schema.schema.concrete_type_by_name("UserQuery").is_none());
+        assert!(schema.schema.concrete_type_by_name("PostQuery").is_none());
+    }
+}
+
+mod named_fields {
+    use super::*;
+
+    struct Database {
+        prefix: &'static str,
+    }
+
+    impl juniper::Context for Database {}
+
+    struct UserQuery;
+
+    #[graphql_object(context = Database)]
+    impl UserQuery {
+        fn user(context: &Database) -> String {
+            format!("{}user", context.prefix)
+        }
+    }
+
+    struct PostQuery;
+
+    #[graphql_object(context = Database)]
+    impl PostQuery {
+        fn post(context: &Database) -> String {
+            format!("{}post", context.prefix)
+        }
+    }
+
+    /// Root query.
+    #[derive(GraphQLMergedObject)]
+    #[graphql(name = "Root")]
+    struct Query {
+        users: UserQuery,
+        posts: PostQuery,
+    }
+
+    struct UserMutation;
+
+    #[graphql_object(context = Database)]
+    impl UserMutation {
+        fn create_user(name: String) -> String {
+            name
+        }
+    }
+
+    struct PostMutation;
+
+    #[graphql_object(context = Database)]
+    impl PostMutation {
+        fn create_post(title: String) -> String {
+            title
+        }
+    }
+
+    #[derive(GraphQLMergedObject)]
+    #[graphql(context = Database, desc = "Root mutation.")]
+    struct Mutation {
+        users: UserMutation,
+        posts: PostMutation,
+    }
+
+    fn schema<'q>() -> RootNode<'q, Query, Mutation, EmptySubscription<Database>> {
+        RootNode::new(
+            Query {
+                users: UserQuery,
+                posts: PostQuery,
+            },
+            Mutation {
+                users: UserMutation,
+                posts: PostMutation,
+            },
+            EmptySubscription::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn resolves_with_context() {
+        const DOC: &str = r#"{
+            user
+            post
+            __typename
+        }"#;
+
+        let db = Database { prefix: "my-" };
+
+        assert_eq!(
+            execute(DOC, None, &schema(), &graphql_vars! {}, &db).await,
+            Ok((
+                graphql_value!({"user": "my-user", "post": "my-post", "__typename": "Root"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_mutation() {
+        const DOC: &str = r#"mutation {
+            createUser(name: "John")
+            createPost(title: "Hello")
+        }"#;
+
+        let db = Database { prefix: "" };
+
+        assert_eq!(
+            execute(DOC, None, &schema(), &graphql_vars! {}, &db).await,
+            Ok((
+                graphql_value!({"createUser": "John", "createPost": "Hello"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_doc_comment_and_description() {
+        const DOC: &str = r#"{
+            query: __type(name: "Root") {
+                description
+            }
+            mutation: __type(name: "Mutation") {
+                description
+            }
+        }"#;
+
+        let db = Database { prefix: "" };
+
+        assert_eq!(
+            execute(DOC, None, &schema(), &graphql_vars! {}, &db).await,
+            Ok((
+                graphql_value!({
+                    "query": {"description": "Root query."},
+                    "mutation": {"description": "Root mutation."},
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod explicit_scalar {
+    use super::*;
+
+    struct UserQuery;
+
+    #[graphql_object(scalar = MyScalarValue)]
+    impl UserQuery {
+        fn user_name() -> &'static str {
+            "John"
+        }
+    }
+
+    struct PostQuery;
+
+    #[graphql_object]
+    impl PostQuery {
+        fn post_title() -> &'static str {
+            "Hello"
+        }
+    }
+
+    #[derive(GraphQLMergedObject)]
+    #[graphql(scalar = MyScalarValue)]
+    struct Query(UserQuery, PostQuery);
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            userName
+            postTitle
+        }"#;
+
+        let schema =
+            crate::util::schema_with_scalar::<MyScalarValue, _, _>(Query(UserQuery, PostQuery));
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"userName": "John", "postTitle": "Hello"}),
+                vec![],
+            )),
+        );
+    }
+}
@@ -1,11 +1,19 @@
 mod derive_enum;
 mod derive_input_object;
 mod derive_object_with_raw_idents;
+mod directives;
+mod flatten;
+mod guards;
 mod interface_attr_struct;
 mod interface_attr_trait;
 mod interface_derive;
+mod merged_object;
 mod object_attr;
+mod object_computed;
 mod object_derive;
+mod object_generic_names;
+mod remote_derive;
+mod resolve_with;
 mod scalar_attr_derive_input;
 mod scalar_attr_type_alias;
 mod scalar_derive;
@@ -13,3 +21,4 @@ mod scalar_value_derive;
 mod subscription_attr;
 mod union_attr;
 mod union_derive;
+mod validators;
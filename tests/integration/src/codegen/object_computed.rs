@@ -0,0 +1,257 @@
+//! Tests for computed fields merged into `#[derive(GraphQLObject)]` by
+//! `#[graphql_object(computed)]` macro.
+
+use juniper::{
+    execute, execute_sync, graphql_interface, graphql_object, graphql_value, graphql_vars,
+    macros::reflect::Fields, DefaultScalarValue, EmptyMutation, EmptySubscription, GraphQLObject,
+    RootNode,
+};
+
+use crate::util::schema;
+
+mod trivial {
+    use super::*;
+
+    /// Human being.
+    #[derive(GraphQLObject)]
+    #[graphql(computed)]
+    struct Human {
+        first_name: &'static str,
+        last_name: &'static str,
+    }
+
+    #[graphql_object(computed)]
+    impl Human {
+        /// Full name of this human.
+        fn full_name(&self) -> String {
+            format!("{} {}", self.first_name, self.last_name)
+        }
+
+        async fn initials(&self, separator: Option<String>) -> String {
+            let sep = separator.unwrap_or_default();
+            format!("{}{}{}", &self.first_name[..1], sep, &self.last_name[..1])
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                first_name: "John",
+                last_name: "Doe",
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_derived_and_computed_fields() {
+        const DOC: &str = r#"{
+            human {
+                firstName
+                lastName
+                fullName
+                initials(separator: ".")
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "firstName": "John",
+                    "lastName": "Doe",
+                    "fullName": "John Doe",
+                    "initials": "J.D",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_sync_computed_fields_synchronously() {
+        const DOC: &str = r#"{
+            human {
+                firstName
+                fullName
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+            Ok((
+                graphql_value!({"human": {"firstName": "John", "fullName": "John Doe"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn exposes_all_fields_in_single_type() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                description
+                fields {
+                    name
+                    description
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {
+                    "description": "Human being.",
+                    "fields": [
+                        {"name": "firstName", "description": null},
+                        {"name": "lastName", "description": null},
+                        {"name": "fullName", "description": "Full name of this human."},
+                        {"name": "initials", "description": null},
+                    ],
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn reflects_all_fields() {
+        assert_eq!(
+            <Human as Fields<DefaultScalarValue>>::NAMES,
+            ["firstName", "lastName", "fullName", "initials"],
+        );
+    }
+}
+
+mod with_context {
+    use super::*;
+
+    struct Database {
+        greeting: &'static str,
+    }
+
+    impl juniper::Context for Database {}
+
+    #[derive(GraphQLObject)]
+    #[graphql(computed, context = Database)]
+    struct Human {
+        name: &'static str,
+    }
+
+    #[graphql_object(computed)]
+    impl Human {
+        fn greeting(&self, context: &Database) -> String {
+            format!("{}, {}!", context.greeting, self.name)
+        }
+
+        fn name_length(&self) -> i32 {
+            self.name.len() as i32
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Database)]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human { name: "John" }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_with_context() {
+        const DOC: &str = r#"{
+            human {
+                name
+                greeting
+                nameLength
+            }
+        }"#;
+
+        let schema = RootNode::new(
+            QueryRoot,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+        let db = Database { greeting: "Hello" };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &db).await,
+            Ok((
+                graphql_value!({"human": {
+                    "name": "John",
+                    "greeting": "Hello, John!",
+                    "nameLength": 4,
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod implementing_interface {
+    use super::*;
+
+    #[graphql_interface(for = Human)]
+    trait Named {
+        fn full_name(&self) -> String;
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(computed, impl = NamedValue)]
+    struct Human {
+        first_name: &'static str,
+        last_name: &'static str,
+    }
+
+    #[graphql_object(computed)]
+    impl Human {
+        fn full_name(&self) -> String {
+            format!("{} {}", self.first_name, self.last_name)
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn named() -> NamedValue {
+            Human {
+                first_name: "John",
+                last_name: "Doe",
+            }
+            .into()
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_interface_field_by_computed_one() {
+        const DOC: &str = r#"{
+            named {
+                fullName
+                ... on Human {
+                    firstName
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"named": {"fullName": "John Doe", "firstName": "John"}}),
+                vec![],
+            )),
+        );
+    }
+}
@@ -0,0 +1,228 @@
+//! Tests for name templates and `concrete` instantiations of generic GraphQL
+//! objects.
+
+use juniper::{
+    execute, graphql_object, graphql_value, graphql_vars, macros::reflect::BaseType,
+    DefaultScalarValue, GraphQLObject, GraphQLType,
+};
+
+use crate::util::schema;
+
+#[derive(Clone, GraphQLObject)]
+struct User {
+    id: i32,
+}
+
+#[derive(Clone, GraphQLObject)]
+struct Post {
+    title: &'static str,
+}
+
+mod derive {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    #[graphql(
+        name = "{T}Page",
+        concrete(params(User)),
+        concrete(name = "PostsPage", params(Post))
+    )]
+    struct Page<T> {
+        items: Vec<T>,
+        total: i32,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn users() -> Page<User> {
+            Page {
+                items: vec![User { id: 1 }],
+                total: 1,
+            }
+        }
+
+        fn posts() -> Page<Post> {
+            Page {
+                items: vec![Post { title: "Hello" }],
+                total: 2,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_each_instantiation() {
+        const DOC: &str = r#"{
+            users {
+                __typename
+                items { id }
+                total
+            }
+            posts {
+                __typename
+                items { title }
+                total
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "users": {"__typename": "UserPage", "items": [{"id": 1}], "total": 1},
+                    "posts": {
+                        "__typename": "PostsPage",
+                        "items": [{"title": "Hello"}],
+                        "total": 2,
+                    },
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn registers_each_instantiation() {
+        let schema = schema(QueryRoot);
+
+        assert!(schema.schema.concrete_type_by_name("UserPage").is_some());
+        assert!(schema.schema.concrete_type_by_name("PostsPage").is_some());
+        assert!(schema.schema.concrete_type_by_name("{T}Page").is_none());
+    }
+
+    #[test]
+    fn uses_distinct_names() {
+        assert_eq!(
+            <Page<User> as GraphQLType<DefaultScalarValue>>::name(&()),
+            Some("UserPage")
+        );
+        assert_eq!(
+            <Page<Post> as GraphQLType<DefaultScalarValue>>::name(&()),
+            Some("PostsPage")
+        );
+        assert_eq!(
+            <Page<User> as BaseType<DefaultScalarValue>>::NAME,
+            "UserPage",
+        );
+        assert_eq!(
+            <Page<Post> as BaseType<DefaultScalarValue>>::NAME,
+            "PostsPage",
+        );
+    }
+}
+
+mod attr {
+    use super::*;
+
+    struct Connection<N>(Vec<N>);
+
+    #[graphql_object(name = "{N}Connection", concrete(params(User)), concrete(params(Post)))]
+    impl<N: Clone> Connection<N> {
+        fn nodes(&self) -> Vec<N> {
+            self.0.clone()
+        }
+
+        fn node(&self, idx: Option<i32>) -> Option<&N> {
+            self.0.get(idx.unwrap_or_default() as usize)
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn users() -> Connection<User> {
+            Connection(vec![User { id: 1 }, User { id: 2 }])
+        }
+
+        fn posts() -> Connection<Post> {
+            Connection(vec![])
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_each_instantiation() {
+        const DOC: &str = r#"{
+            users {
+                __typename
+                nodes { id }
+                node(idx: 1) { id }
+            }
+            posts {
+                __typename
+                nodes { title }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "users": {
+                        "__typename": "UserConnection",
+                        "nodes": [{"id": 1}, {"id": 2}],
+                        "node": {"id": 2},
+                    },
+                    "posts": {"__typename": "PostConnection", "nodes": []},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn uses_distinct_names() {
+        assert_eq!(
+            <Connection<User> as BaseType<DefaultScalarValue>>::NAME,
+            "UserConnection",
+        );
+        assert_eq!(
+            <Connection<Post> as BaseType<DefaultScalarValue>>::NAME,
+            "PostConnection",
+        );
+    }
+}
+
+mod conflicting_names {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    #[graphql(name = "Edge")]
+    struct UserEdge {
+        node: User,
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(name = "Edge")]
+    struct PostEdge {
+        node: Post,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn user() -> UserEdge {
+            UserEdge {
+                node: User { id: 1 },
+            }
+        }
+
+        fn post() -> PostEdge {
+            PostEdge {
+                node: Post { title: "Hello" },
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "GraphQL type `Edge` is defined differently")]
+    fn panics_on_schema_construction() {
+        let _ = schema(QueryRoot);
+    }
+}
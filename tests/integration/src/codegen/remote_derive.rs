@@ -0,0 +1,157 @@
+//! Tests for `remote` attribute argument of `#[derive(GraphQLEnum)]`,
+//! `#[derive(GraphQLInputObject)]` and `#[derive(GraphQLObject)]` macros.
+
+use juniper::{
+    execute, graphql_object, graphql_value, graphql_vars, GraphQLEnum, GraphQLInputObject,
+    GraphQLObject,
+};
+
+use crate::util::schema;
+
+/// Types of a domain crate, which doesn't depend on [`juniper`].
+mod domain {
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Role {
+        Admin,
+        Guest,
+    }
+
+    #[derive(Debug, Eq, PartialEq)]
+    pub struct User {
+        pub id: i32,
+        pub name: String,
+        pub role: Role,
+    }
+
+    #[derive(Debug, Eq, PartialEq)]
+    pub struct NewUser {
+        pub name: String,
+        pub role: Role,
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, GraphQLEnum, PartialEq)]
+#[graphql(name = "Role", remote = "domain::Role")]
+enum RoleDef {
+    Admin,
+    Guest,
+}
+
+#[derive(GraphQLObject)]
+#[graphql(name = "User", remote = domain::User)]
+struct UserDef {
+    id: i32,
+    name: String,
+    role: RoleDef,
+}
+
+#[derive(GraphQLInputObject)]
+#[graphql(name = "NewUser", remote = "domain::NewUser")]
+struct NewUserDef {
+    name: String,
+    role: RoleDef,
+}
+
+struct QueryRoot;
+
+#[graphql_object]
+impl QueryRoot {
+    fn user() -> UserDef {
+        domain::User {
+            id: 1,
+            name: "John".into(),
+            role: domain::Role::Admin,
+        }
+        .into()
+    }
+
+    fn create_user(new: NewUserDef) -> UserDef {
+        let new = domain::NewUser::from(new);
+        domain::User {
+            id: 2,
+            name: new.name,
+            role: new.role,
+        }
+        .into()
+    }
+
+    fn is_admin(role: RoleDef) -> bool {
+        domain::Role::from(role) == domain::Role::Admin
+    }
+}
+
+#[tokio::test]
+async fn resolves_remote_object() {
+    const DOC: &str = r#"{
+        user {
+            id
+            name
+            role
+        }
+    }"#;
+
+    let schema = schema(QueryRoot);
+
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+        Ok((
+            graphql_value!({"user": {"id": 1, "name": "John", "role": "ADMIN"}}),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn accepts_remote_input_object_and_enum() {
+    const DOC: &str = r#"{
+        createUser(new: {name: "Jane", role: GUEST}) {
+            id
+            name
+            role
+        }
+        isAdmin(role: GUEST)
+    }"#;
+
+    let schema = schema(QueryRoot);
+
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+        Ok((
+            graphql_value!({
+                "createUser": {"id": 2, "name": "Jane", "role": "GUEST"},
+                "isAdmin": false,
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[test]
+fn converts_enum_both_ways() {
+    assert_eq!(RoleDef::from(domain::Role::Admin), RoleDef::Admin);
+    assert_eq!(domain::Role::from(RoleDef::Guest), domain::Role::Guest);
+}
+
+#[test]
+fn converts_structs_both_ways() {
+    let user = UserDef::from(domain::User {
+        id: 3,
+        name: "Jim".into(),
+        role: domain::Role::Guest,
+    });
+    assert_eq!(
+        (user.id, user.name.as_str(), user.role),
+        (3, "Jim", RoleDef::Guest)
+    );
+
+    assert_eq!(
+        domain::NewUser::from(NewUserDef {
+            name: "Jim".into(),
+            role: RoleDef::Admin,
+        }),
+        domain::NewUser {
+            name: "Jim".into(),
+            role: domain::Role::Admin,
+        },
+    );
+}
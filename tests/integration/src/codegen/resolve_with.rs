@@ -0,0 +1,299 @@
+//! Tests for `#[graphql(with = ...)]` attribute argument of
+//! `#[derive(GraphQLObject)]` macro.
+
+use std::collections::HashMap;
+
+use juniper::{
+    execute, execute_sync, graphql_object, graphql_value, graphql_vars,
+    macros::reflect::{fnv1a128, FieldMeta},
+    DefaultScalarValue, Executor, GraphQLObject,
+};
+
+use crate::util::schema;
+
+mod sync_fn {
+    use super::*;
+
+    fn format_timestamp<S>(ts: &i64, _: &(), _: &Executor<'_, '_, (), S>) -> String {
+        format!("{}s", ts)
+    }
+
+    fn name_or_default<'a, S>(
+        name: &'a Option<String>,
+        _: &(),
+        _: &Executor<'_, '_, (), S>,
+    ) -> &'a str {
+        name.as_deref().unwrap_or("anonymous")
+    }
+
+    #[derive(GraphQLObject)]
+    struct Event {
+        /// Creation time.
+        #[graphql(with = format_timestamp)]
+        created_at: i64,
+        #[graphql(name = "author", with = self::name_or_default)]
+        author_name: Option<String>,
+        #[graphql(resolve = |v: &i32, _: &(), _: &Executor<'_, '_, (), _>| v * 2)]
+        doubled: i32,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn event() -> Event {
+            Event {
+                created_at: 42,
+                author_name: None,
+                doubled: 3,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            event {
+                createdAt
+                author
+                doubled
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"event": {
+                    "createdAt": "42s",
+                    "author": "anonymous",
+                    "doubled": 6,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_fields_synchronously() {
+        const DOC: &str = r#"{
+            event {
+                createdAt
+                author
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+            Ok((
+                graphql_value!({"event": {"createdAt": "42s", "author": "anonymous"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn infers_types_from_functions() {
+        const DOC: &str = r#"{
+            __type(name: "Event") {
+                fields {
+                    name
+                    description
+                    type {
+                        kind
+                        ofType {
+                            name
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {
+                        "name": "createdAt",
+                        "description": "Creation time.",
+                        "type": {"kind": "NON_NULL", "ofType": {"name": "String"}},
+                    },
+                    {
+                        "name": "author",
+                        "description": null,
+                        "type": {"kind": "NON_NULL", "ofType": {"name": "String"}},
+                    },
+                    {
+                        "name": "doubled",
+                        "description": null,
+                        "type": {"kind": "NON_NULL", "ofType": {"name": "Int"}},
+                    },
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn reflects_inferred_types() {
+        assert_eq!(
+            <Event as FieldMeta<DefaultScalarValue, { fnv1a128("createdAt") }>>::TYPE,
+            "String",
+        );
+        assert_eq!(
+            <Event as FieldMeta<DefaultScalarValue, { fnv1a128("author") }>>::TYPE,
+            "String",
+        );
+    }
+}
+
+mod context {
+    use super::*;
+
+    struct Database {
+        users: HashMap<i32, User>,
+        hidden_tags: Vec<&'static str>,
+    }
+
+    impl juniper::Context for Database {}
+
+    #[derive(GraphQLObject)]
+    struct User {
+        name: &'static str,
+    }
+
+    fn user_by_id<'a, S>(
+        id: &i32,
+        db: &'a Database,
+        _: &Executor<'_, '_, Database, S>,
+    ) -> Option<&'a User> {
+        db.users.get(id)
+    }
+
+    // `&Vec` is required here, as the function receives a reference to the
+    // struct field's value as is.
+    #[allow(clippy::ptr_arg)]
+    fn visible_tags<'a, S>(
+        tags: &'a Vec<&'static str>,
+        db: &Database,
+        _: &Executor<'_, '_, Database, S>,
+    ) -> Vec<&'a str> {
+        tags.iter()
+            .copied()
+            .filter(|t| !db.hidden_tags.contains(t))
+            .collect()
+    }
+
+    async fn likes<S>(post_id: &i32, _: &Database, _: &Executor<'_, '_, Database, S>) -> i32 {
+        post_id * 10
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(context = Database)]
+    struct Post {
+        #[graphql(with = likes)]
+        id: i32,
+        #[graphql(name = "author", with = user_by_id)]
+        author_id: i32,
+        #[graphql(with = visible_tags)]
+        tags: Vec<&'static str>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Database)]
+    impl QueryRoot {
+        fn post() -> Post {
+            Post {
+                id: 1,
+                author_id: 7,
+                tags: vec!["rust", "secret", "graphql"],
+            }
+        }
+    }
+
+    fn database() -> Database {
+        Database {
+            users: HashMap::from([(7, User { name: "John" })]),
+            hidden_tags: vec!["secret"],
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields_with_context() {
+        const DOC: &str = r#"{
+            post {
+                id
+                author {
+                    name
+                }
+                tags
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &database()).await,
+            Ok((
+                graphql_value!({"post": {
+                    "id": 10,
+                    "author": {"name": "John"},
+                    "tags": ["rust", "graphql"],
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn infers_types_from_functions() {
+        const DOC: &str = r#"{
+            __type(name: "Post") {
+                fields {
+                    name
+                    type {
+                        kind
+                        name
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &database()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id", "type": {"kind": "NON_NULL", "name": null}},
+                    {"name": "author", "type": {"kind": "OBJECT", "name": "User"}},
+                    {"name": "tags", "type": {"kind": "NON_NULL", "name": null}},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn panics_on_async_function_resolved_synchronously() {
+        const DOC: &str = r#"{
+            post {
+                id
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let db = database();
+
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &db)
+        }));
+        assert!(res.is_err());
+    }
+}
@@ -136,3 +136,64 @@ mod allow_missing_attributes {
             .is_type::<bool>());
     }
 }
+
+mod big_int {
+    use juniper::LongScalarValue;
+
+    use super::*;
+
+    #[derive(Clone, Debug, Deserialize, PartialEq, ScalarValue, Serialize)]
+    #[serde(untagged)]
+    pub enum CustomScalarValue {
+        #[value(as_float, as_int)]
+        Int(i32),
+        #[value(as_big_int)]
+        Long(i64),
+        #[value(as_big_int)]
+        Huge { v: i128 },
+        #[value(as_float)]
+        Float(f64),
+        #[value(as_str, as_string, into_string)]
+        String(String),
+        #[value(as_bool)]
+        Boolean(bool),
+    }
+
+    #[test]
+    fn from_big_int() {
+        assert_eq!(
+            CustomScalarValue::from_big_int(5),
+            Some(CustomScalarValue::Int(5)),
+        );
+        assert_eq!(
+            CustomScalarValue::from_big_int(i64::MAX.into()),
+            Some(CustomScalarValue::Long(i64::MAX)),
+        );
+        assert_eq!(
+            CustomScalarValue::from_big_int(i128::MAX),
+            Some(CustomScalarValue::Huge { v: i128::MAX }),
+        );
+        assert_eq!(DefaultScalarValue::from_big_int(i64::MAX.into()), None);
+    }
+
+    #[test]
+    fn as_big_int() {
+        assert_eq!(CustomScalarValue::Int(5).as_big_int(), Some(5));
+        assert_eq!(
+            CustomScalarValue::Long(i64::MIN).as_big_int(),
+            Some(i64::MIN.into()),
+        );
+        assert_eq!(CustomScalarValue::Float(5.0).as_big_int(), None);
+    }
+
+    #[test]
+    fn into_another() {
+        assert_eq!(
+            CustomScalarValue::Long(i64::MAX).into_another::<LongScalarValue>(),
+            LongScalarValue::Long(i64::MAX),
+        );
+        assert!(CustomScalarValue::Huge { v: i128::MAX }
+            .into_another::<LongScalarValue>()
+            .is_type::<String>());
+    }
+}
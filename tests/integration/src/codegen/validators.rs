@@ -0,0 +1,95 @@
+//! Tests for `validate(...)` attribute argument of code generation macros.
+
+use juniper::{execute, graphql_object, graphql_value, graphql_vars, GraphQLInputObject};
+
+use crate::util::schema;
+
+#[derive(GraphQLInputObject)]
+struct Filter {
+    #[graphql(validate(regex = "^[a-z]+$", max_length = 8))]
+    tag: String,
+    #[graphql(validate(min = -1.5))]
+    score: Option<f64>,
+}
+
+fn is_known(tags: &[Filter]) -> Result<(), String> {
+    if tags.iter().all(|f| f.tag != "unknown") {
+        Ok(())
+    } else {
+        Err("must contain known tags only".into())
+    }
+}
+
+struct QueryRoot;
+
+#[graphql_object]
+impl QueryRoot {
+    async fn search(
+        #[graphql(validate(max_length = 5))] query: String,
+        #[graphql(default, validate(max_items = 2, custom = |v: &Vec<_>| is_known(v)))]
+        filters: Vec<Filter>,
+    ) -> i32 {
+        query.len() as i32 + filters.len() as i32
+    }
+}
+
+#[tokio::test]
+async fn validates_async_field_arguments() {
+    const DOC: &str = r#"query($query: String!) {
+        search(query: $query, filters: [{tag: "rust", score: 0.5}])
+    }"#;
+
+    let schema = schema(QueryRoot);
+
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {"query": "abc"}, &()).await,
+        Ok((graphql_value!({"search": 4}), vec![])),
+    );
+
+    let (res, errs) = execute(DOC, None, &schema, &graphql_vars! {"query": "abcdef"}, &())
+        .await
+        .unwrap();
+    assert_eq!(res, graphql_value!(null));
+    assert_eq!(errs.len(), 1);
+    assert_eq!(
+        errs[0].error().message(),
+        "Invalid value for argument `query`: must be at most 5 characters long",
+    );
+}
+
+#[tokio::test]
+async fn validates_input_object_fields() {
+    let schema = schema(QueryRoot);
+
+    for (filters, msg) in [
+        (
+            r#"[{tag: "Rust"}]"#,
+            "Invalid value for input field `tag`: must match pattern `^[a-z]+$`",
+        ),
+        (
+            r#"[{tag: "rustacean!"}]"#,
+            "Invalid value for input field `tag`: must match pattern `^[a-z]+$`",
+        ),
+        (
+            r#"[{tag: "rust", score: -2}]"#,
+            "Invalid value for input field `score`: must be greater than or equal to -1.5",
+        ),
+        (
+            r#"[{tag: "a"}, {tag: "b"}, {tag: "c"}]"#,
+            "Invalid value for argument `filters`: must contain at most 2 items",
+        ),
+        (
+            r#"[{tag: "unknown"}]"#,
+            "Invalid value for argument `filters`: must contain known tags only",
+        ),
+    ] {
+        let doc = format!(r#"{{ search(query: "a", filters: {}) }}"#, filters);
+
+        let (_, errs) = execute(&doc, None, &schema, &graphql_vars! {}, &())
+            .await
+            .unwrap();
+        assert_eq!(errs.len(), 1, "{}", filters);
+        assert_eq!(errs[0].path(), ["search"]);
+        assert_eq!(errs[0].error().message(), msg);
+    }
+}
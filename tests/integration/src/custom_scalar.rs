@@ -2,12 +2,11 @@ use std::{convert::TryInto as _, fmt, pin::Pin};
 
 use futures::{stream, Stream};
 use juniper::{
-    execute, graphql_input_value, graphql_object, graphql_scalar, graphql_subscription,
-    graphql_vars,
+    execute, graphql_input_value, graphql_object, graphql_subscription, graphql_vars,
     parser::{ParseError, ScalarToken, Token},
     serde::{de, Deserialize, Deserializer, Serialize},
-    EmptyMutation, FieldResult, InputValue, Object, ParseScalarResult, RootNode, ScalarValue,
-    Value, Variables,
+    EmptyMutation, FieldResult, GraphQLScalar, InputValue, Object, ParseScalarResult, RootNode,
+    ScalarValue, Value, Variables,
 };
 
 #[derive(Clone, Debug, PartialEq, ScalarValue, Serialize)]
@@ -15,6 +14,7 @@ use juniper::{
 pub(crate) enum MyScalarValue {
     #[value(as_float, as_int)]
     Int(i32),
+    #[value(as_big_int)]
     Long(i64),
     #[value(as_float)]
     Float(f64),
@@ -91,19 +91,23 @@ impl<'de> Deserialize<'de> for MyScalarValue {
     }
 }
 
-#[graphql_scalar(with = long, scalar = MyScalarValue)]
-type Long = i64;
+/// Custom scalar over [`i64`], which can't be defined directly on [`i64`]
+/// anymore, as it's already represented with the built-in `Long` scalar.
+#[derive(Clone, Copy, Debug, GraphQLScalar, PartialEq)]
+#[graphql(with = my_long, scalar = MyScalarValue)]
+struct MyLong(i64);
 
-mod long {
+mod my_long {
     use super::*;
 
-    pub(super) fn to_output(v: &Long) -> Value<MyScalarValue> {
-        Value::scalar(*v)
+    pub(super) fn to_output(v: &MyLong) -> Value<MyScalarValue> {
+        Value::scalar(v.0)
     }
 
-    pub(super) fn from_input(v: &InputValue<MyScalarValue>) -> Result<Long, String> {
+    pub(super) fn from_input(v: &InputValue<MyScalarValue>) -> Result<MyLong, String> {
         v.as_scalar_value::<i64>()
             .copied()
+            .map(MyLong)
             .ok_or_else(|| format!("Expected `MyScalarValue::Long`, found: {}", v))
     }
 
@@ -129,6 +133,10 @@ impl TestType {
     fn long_with_arg(long_arg: i64) -> i64 {
         long_arg
     }
+
+    fn my_long_with_arg(my_long_arg: MyLong) -> MyLong {
+        my_long_arg
+    }
 }
 
 struct TestSubscriptionType;
@@ -208,6 +216,22 @@ async fn querying_long_variable() {
     .await;
 }
 
+#[tokio::test]
+async fn querying_newtype_long_variable() {
+    let num = i64::from(i32::MAX) + 42;
+    run_variable_query(
+        "query q($test: MyLong!){ myLongWithArg(myLongArg: $test) }",
+        graphql_vars! {"test": InputValue::<_>::scalar(num)},
+        |result| {
+            assert_eq!(
+                result.get_field_value("myLongWithArg"),
+                Some(&Value::scalar(num)),
+            );
+        },
+    )
+    .await;
+}
+
 #[test]
 fn deserialize_variable() {
     let json = format!("{{\"field\": {}}}", i64::from(i32::MAX) + 42);